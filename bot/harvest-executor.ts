@@ -18,7 +18,7 @@ import {
   Keypair,
   ComputeBudgetProgram,
 } from '@solana/web3.js';
-import { Program } from '@coral-xyz/anchor';
+import { Program, BN } from '@coral-xyz/anchor';
 import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
@@ -32,6 +32,11 @@ import { logger } from './logger';
 // Priority fee floor (micro-lamports per compute unit)
 const PRIORITY_FEE_FLOOR = 10_000;
 
+// close_position min-out floor as a share of the position's current amounts.
+// Leaves room for the protocol fee (capped at 10% on-chain) plus a few bins
+// crossing the active price between our read and the tx landing.
+const CLOSE_MIN_OUT_BPS = 8_500n;
+
 /** Build compute budget instructions with dynamic priority fee */
 async function buildPriorityFeeIxs(connection: Connection): Promise<any[]> {
   let microLamports = PRIORITY_FEE_FLOOR;
@@ -80,6 +85,34 @@ function roverAuthorityPDA(coreProgramId: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([Buffer.from('rover_authority')], coreProgramId);
 }
 
+function poolMetricsPDA(lbPair: PublicKey, coreProgramId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('pool_metrics'), lbPair.toBuffer()],
+    coreProgramId
+  );
+}
+
+function ownerStatsPDA(owner: PublicKey, coreProgramId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('owner_stats'), owner.toBuffer()],
+    coreProgramId
+  );
+}
+
+/** [min_x_out, min_y_out] for close_position from the Meteora per-bin amounts. */
+function closeMinOuts(binData: any[]): [BN, BN] {
+  let x = 0n;
+  let y = 0n;
+  for (const bin of binData) {
+    x += BigInt(bin.positionXAmount);
+    y += BigInt(bin.positionYAmount);
+  }
+  return [
+    new BN(((x * CLOSE_MIN_OUT_BPS) / 10_000n).toString()),
+    new BN(((y * CLOSE_MIN_OUT_BPS) / 10_000n).toString()),
+  ];
+}
+
 // ═══ EXECUTOR ═══
 
 export class HarvestExecutor extends EventEmitter {
@@ -284,12 +317,16 @@ export class HarvestExecutor extends EventEmitter {
       this.botKeypair.publicKey, roverFeeTokenY, roverAuthority, meteora.tokenYMint, meteora.tokenYProgram,
     );
 
+    // Opt-in per-pool counters; the program skips the update if never initialized.
+    const [poolMetrics] = poolMetricsPDA(meteora.lbPair, this.coreProgramId);
+
     // Priority fees to survive Solana congestion
     const priorityIxs = await buildPriorityFeeIxs(this.connection);
 
+    // Full harvest (10_000 bps), whole keeper tip to one ATA, no transfer-hook accounts.
     await withRetry(
       () => this.coreProgram.methods
-        .harvestBins(binIds)
+        .harvestBins(binIds, 10_000, 0, 0, 0)
         .accounts({
           bot:                this.botKeypair.publicKey,
           config:             configPDA,
@@ -298,6 +335,7 @@ export class HarvestExecutor extends EventEmitter {
           owner:              job.owner,
           meteoraPosition:    meteora.meteoraPosition,
           lbPair:             meteora.lbPair,
+          poolMetrics,
           binArrayBitmapExt:  meteora.binArrayBitmapExt,
           binArrayLower:      meteora.binArrayLower,
           binArrayUpper:      meteora.binArrayUpper,
@@ -360,6 +398,9 @@ export class HarvestExecutor extends EventEmitter {
 
     const allBinIds = meteoraPos.positionData.positionBinData.map((b: any) => b.binId);
     const meteora = buildMeteoraCPIAccounts(dlmm, meteoraPos, allBinIds, poolInfo);
+    const [minXOut, minYOut] = closeMinOuts(meteoraPos.positionData.positionBinData);
+    const [ownerStats] = ownerStatsPDA(job.owner, this.coreProgramId);
+    const [poolMetrics] = poolMetricsPDA(meteora.lbPair, this.coreProgramId);
 
     const vaultTokenX    = getAssociatedTokenAddressSync(meteora.tokenXMint, vaultPda, true, meteora.tokenXProgram);
     const vaultTokenY    = getAssociatedTokenAddressSync(meteora.tokenYMint, vaultPda, true, meteora.tokenYProgram);
@@ -387,17 +428,20 @@ export class HarvestExecutor extends EventEmitter {
     // Without this, every close attempt crashes with ReferenceError.
     const priorityIxs = await buildPriorityFeeIxs(this.connection);
 
+    // No transfer-hook accounts (hook_x_len = hook_y_len = 0).
     await withRetry(
       () => this.coreProgram.methods
-        .closePosition()
+        .closePosition(minXOut, minYOut, 0, 0)
         .accounts({
           bot:                this.botKeypair.publicKey,
           config:             configPDA,
           position:           new PublicKey(job.positionPDA),
           vault:              vaultPda,
+          ownerStats,
           owner:              job.owner,
           meteoraPosition:    meteora.meteoraPosition,
           lbPair:             meteora.lbPair,
+          poolMetrics,
           binArrayBitmapExt:  meteora.binArrayBitmapExt,
           binArrayLower:      meteora.binArrayLower,
           binArrayUpper:      meteora.binArrayUpper,
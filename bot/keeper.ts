@@ -27,7 +27,7 @@ import {
   SystemProgram,
   ComputeBudgetProgram,
 } from '@solana/web3.js';
-import { Program } from '@coral-xyz/anchor';
+import { Program, BN } from '@coral-xyz/anchor';
 import { logger } from './logger';
 import { buildMeteoraCPIAccounts, getDLMM, SPL_MEMO_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from './meteora-accounts';
 
@@ -111,6 +111,14 @@ function roverAuthorityPDA(coreProgramId: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([Buffer.from('rover_authority')], coreProgramId);
 }
 
+function ownerStatsPDA(owner: PublicKey, coreProgramId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('owner_stats'), owner.toBuffer()], coreProgramId);
+}
+
+function poolMetricsPDA(lbPair: PublicKey, coreProgramId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('pool_metrics'), lbPair.toBuffer()], coreProgramId);
+}
+
 // ═══ KEEPER ═══
 
 export class MonkeKeeper {
@@ -511,10 +519,11 @@ export class MonkeKeeper {
           );
 
           const amountBN = new BN(rawAmount.toString());
+          // max_active_bin_slippage 0 = on-chain DEFAULT_ROVER_ACTIVE_BIN_SLIPPAGE
 
           await withRetry(
             () => this.coreProgram.methods
-              .openFeeRover(amountBN, binStep)
+              .openFeeRover(amountBN, binStep, 0)
               .accounts({
                 bot:                  this.botKeypair.publicKey,
                 config:               configPDA,
@@ -732,18 +741,32 @@ export class MonkeKeeper {
               const ownerTokenY    = getAssociatedTokenAddressSync(meteora.tokenYMint, roverAuthority, true, meteora.tokenYProgram);
               const roverFeeTokenX = getAssociatedTokenAddressSync(meteora.tokenXMint, roverAuthority, true, meteora.tokenXProgram);
               const roverFeeTokenY = getAssociatedTokenAddressSync(meteora.tokenYMint, roverAuthority, true, meteora.tokenYProgram);
+              const [ownerStats] = ownerStatsPDA(roverAuthority, this.coreProgramId);
+              const [poolMetrics] = poolMetricsPDA(meteora.lbPair, this.coreProgramId);
+              // Buy-side rovers pay out token X: make sure rover_authority's X ATA exists
+              // so it lands there for crankOpenFeeRovers to recycle (Y likewise for sell-side).
+              const createRoverAtaX = createAssociatedTokenAccountIdempotentInstruction(
+                this.botKeypair.publicKey, ownerTokenX, roverAuthority, meteora.tokenXMint, meteora.tokenXProgram,
+              );
+              const createRoverAtaY = createAssociatedTokenAccountIdempotentInstruction(
+                this.botKeypair.publicKey, ownerTokenY, roverAuthority, meteora.tokenYMint, meteora.tokenYProgram,
+              );
 
+              // Every bin is already empty, so there is no liquidity to guard:
+              // min outs are 0, and no transfer-hook accounts are passed.
               await withRetry(
                 () => this.coreProgram.methods
-                  .closePosition()
+                  .closePosition(new BN(0), new BN(0), 0, 0)
                   .accounts({
                     bot:                this.botKeypair.publicKey,
                     config:             configPDA,
                     position:           pos.publicKey,
                     vault:              vaultPda,
+                    ownerStats,
                     owner:              roverAuthority,
                     meteoraPosition:    meteoraPosKey,
                     lbPair:             meteora.lbPair,
+                    poolMetrics,
                     binArrayBitmapExt:  meteora.binArrayBitmapExt,
                     binArrayLower:      meteora.binArrayLower,
                     binArrayUpper:      meteora.binArrayUpper,
@@ -765,7 +788,7 @@ export class MonkeKeeper {
                     memoProgram:        SPL_MEMO_PROGRAM_ID,
                     systemProgram:      new PublicKey('11111111111111111111111111111111'),
                   })
-                  .preInstructions(this.priorityIxs)
+                  .preInstructions([...this.priorityIxs, createRoverAtaX, createRoverAtaY])
                   .signers([this.botKeypair])
                   .rpc(),
                 `close rover ${pos.publicKey.toBase58().slice(0, 8)}`
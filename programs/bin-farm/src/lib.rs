@@ -14,7 +14,9 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
-use anchor_spl::token_interface::{TokenAccount as ITokenAccount, TransferChecked, transfer_checked};
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, TokenAccount as ITokenAccount, TransferChecked, transfer_checked,
+};
 
 mod meteora_dlmm_cpi;
 use meteora_dlmm_cpi::*;
@@ -26,17 +28,142 @@ pub const DEFAULT_FEE_BPS: u16 = 30;
 /// Minimum token deposit for rover positions (anti-griefing)
 pub const MIN_ROVER_DEPOSIT: u64 = 10_000;
 
-/// Minimum deposit amount for user positions (anti-griefing, prevents dust positions)
+/// Minimum rover deposit per bin in the computed range. BidAsk puts the least
+/// liquidity in the bins nearest active_id, so wide rovers need proportionally
+/// more tokens or the thin end becomes dust that Meteora may drop.
+pub const MIN_ROVER_DEPOSIT_PER_BIN: u64 = 1_000;
+
+/// Default minimum deposit for user positions (anti-griefing, prevents dust positions).
+/// Config.min_position_amount and PoolStatus.min_position_amount override it.
 pub const MIN_POSITION_AMOUNT: u64 = 10_000;
 
+/// Cap for config.priority_slots, config.close_priority_slots and
+/// Position.priority_slots_override (9000 slots / ~1 hour)
+/// — keeps the permissionless fallback reachable.
+pub const MAX_PRIORITY_SLOTS: u64 = 9000;
+
+/// open_position_v2 fee_bps_override sentinel: no override, the position pays config.fee_bps.
+pub const FEE_BPS_OVERRIDE_NONE: u16 = u16::MAX;
+
+/// Cap for config.harvest_cooldown_slots (9000 slots / ~1 hour, same as priority_slots).
+pub const MAX_HARVEST_COOLDOWN_SLOTS: u64 = 9000;
+
+/// Cap for config.min_sweep_lamports (1 SOL) — keeps keepers able to sweep weekly revenue.
+pub const MAX_MIN_SWEEP_LAMPORTS: u64 = 1_000_000_000;
+
+/// Cap for config.min_keeper_tip (0.01 SOL).
+pub const MAX_MIN_KEEPER_TIP: u64 = 10_000_000;
+
+/// Cap for config.referral_bps — share of the protocol fee paid to a position's referrer.
+pub const MAX_REFERRAL_BPS: u16 = 5000;
+
+/// open_position_v2 `strategy` argument, stored as Position.strategy.
+/// Spot = uniform, Curve = concentrated near active_id, BidAsk = concentrated at the
+/// far edge (rovers always use BidAsk).
+pub const STRATEGY_SPOT: u8 = 0;
+pub const STRATEGY_CURVE: u8 = 1;
+pub const STRATEGY_BID_ASK: u8 = 2;
+
+/// Position.position_kind, stamped by the open instruction and echoed in
+/// HarvestEvent / CloseEvent so indexers can bucket flows without the open event.
+pub const POSITION_KIND_USER: u8 = 0;      // open_position_v2
+pub const POSITION_KIND_ROVER: u8 = 1;     // open_rover_position / _sol / _batch (external deposits)
+pub const POSITION_KIND_FEE_ROVER: u8 = 2; // open_fee_rover (recycled protocol fees)
+
+/// compound_harvest redeposits only if the converted amount gives at least this much
+/// per bin in the harvested range. Below it Meteora rounds bins to dust, so the
+/// tokens go to the owner instead.
+pub const MIN_COMPOUND_AMOUNT_PER_BIN: u64 = 1_000;
+
 /// Minimum bin_step for rover positions. Prevents instant liquidation
 /// on low bin_step pools (bin_step=1 gives only 0.7% range with 2x formula).
 /// bin_step=20 gives ~346 bins at ~0.2% spacing, covering ~100% above current price.
 pub const MIN_ROVER_BIN_STEP: u16 = 20;
 
+/// Wrapped SOL mint. Native-SOL rover deposits require a SOL-quoted pool (token Y = wSOL).
+pub const WSOL_MINT: Pubkey =
+    solana_program::pubkey!("So11111111111111111111111111111111111111112");
+
+/// Bounds on open_position_v2 max_active_bin_slippage. Zero tolerance reverts the
+/// whole open on any active_id tick between signing and execution — almost never
+/// what the user meant — so at least 1 bin is required.
+pub const MIN_ACTIVE_BIN_SLIPPAGE: i32 = 1;
+pub const MAX_ACTIVE_BIN_SLIPPAGE: i32 = 20;
+/// Rover opens' tolerance when the caller passes 0 (open_rover_batch always uses it).
+pub const DEFAULT_ROVER_ACTIVE_BIN_SLIPPAGE: i32 = 10;
+/// open_position_v2 drift tolerance between the caller's quoted active_id and the
+/// one read at execution, used when max_drift is negative (no explicit tolerance).
+pub const DEFAULT_MAX_ACTIVE_BIN_DRIFT: i32 = 50;
+
+/// Hard ceiling on bins per harvest_bins call. Config.max_bins_per_harvest can
+/// lower the effective cap (expensive Token-2022 pools) but never raise it.
+pub const MAX_BINS_PER_HARVEST: u16 = 70;
+
+/// Config layout version written by initialize / migrate_config. Bump when a
+/// release claims more of Config._reserved.
+///   1: config_version
+///   2: bot_harvest_count / permissionless_harvest_count
+///   3: keeper_tip_bps / priority_slots timelock fields
+///   4: max_positions_per_owner
+///   5: min_position_amount
+///   6: pause_expiry_ts / max_pause_seconds (Config extension; migrate_config_space first)
+///   7: last_bot_heartbeat_slot (carved from _reserved_ext; no further growth)
+///   8: close_priority_slots (carved from _reserved_ext; no further growth)
+pub const CONFIG_VERSION: u8 = 8;
+/// Oldest Config layout that version-gated instructions accept (Config::require_version).
+pub const MIN_SUPPORTED_CONFIG_VERSION: u8 = 1;
+
+/// Delay between propose_* and apply_* for keeper_tip_bps and priority_slots.
+/// Same 24 hours as propose_fee, so keepers can react before the change lands.
+pub const PARAM_TIMELOCK_SECONDS: i64 = 86_400;
+
+/// How long a pause holds new deposits before lapsing on its own (dead-man's switch
+/// for a lost admin key). Config.max_pause_seconds overrides it, up to MAX_PAUSE_SECONDS.
+pub const DEFAULT_MAX_PAUSE_SECONDS: i64 = 7 * 86_400;
+pub const MAX_PAUSE_SECONDS: i64 = 30 * 86_400;
+
+/// Slots in Config.allowed_token_programs. Pubkey::default() marks an empty slot.
+pub const MAX_ALLOWED_TOKEN_PROGRAMS: usize = 4;
+
+/// open_rover_batch: remaining_accounts per pool, and the per-tx pool cap.
+/// 4 pools × 17 accounts + 6 fixed fits a v0 tx with an ALT and ~1.4M CU.
+pub const ROVER_BATCH_STRIDE: usize = 17;
+pub const MAX_ROVER_BATCH: usize = 4;
+
+/// harvest_bins_multi: fixed remaining_accounts per position (before its extras),
+/// and the per-tx position cap. The bin total is still bounded by
+/// Config::effective_max_bins_per_harvest across the batch.
+pub const HARVEST_BATCH_STRIDE: usize = 9;
+pub const MAX_HARVEST_BATCH: usize = 4;
+
+// Compute-unit estimation constants for quote_harvest_cost. Deliberately conservative:
+// an over-provisioned CU limit costs a little priority fee, an under-provisioned one
+// reverts the whole protective harvest.
+pub const HARVEST_CU_BASE: u64 = 60_000;          // account loading + remove_liquidity CPI overhead
+pub const HARVEST_CU_PER_BIN: u64 = 4_000;        // DLMM per-bin removal (SPL Token pools)
+pub const HARVEST_CU_PER_BIN_T22: u64 = 2_000;    // extra per bin when either side is Token-2022
+pub const HARVEST_CU_PER_TRANSFER: u64 = 7_000;   // transfer_checked (SPL Token)
+pub const HARVEST_CU_PER_TRANSFER_T22: u64 = 14_000; // transfer_checked (Token-2022)
+pub const HARVEST_CU_PER_MEMO: u64 = 5_000;       // memo_cpi prepended to every transfer
+pub const HARVEST_CU_MAX_TRANSFERS: u64 = 5;      // owner x/y + protocol fee + keeper tip (+1 spare)
+pub const HARVEST_CU_EXTENSION_MARGIN_PCT: u64 = 25; // mints with Token-2022 extensions (hooks, fees)
+pub const HARVEST_CU_SAFETY_MARGIN_PCT: u64 = 20;
+pub const MAX_TX_COMPUTE_UNITS: u64 = 1_400_000;
+
+/// Base SPL Mint size. Token-2022 mints carrying extensions are longer.
+pub const SPL_MINT_LEN: usize = 82;
+
 pub const TOKEN_2022_PROGRAM_ID: Pubkey =
     solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
+/// monke_bananas program (revenue share). Used by health_check to derive dist_pool/program_vault.
+pub const MONKE_BANANAS_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("myA2F4S7trnQUiksrrB1prR3k95d8znEXZXwHkZw5ZH");
+
+/// pegged_bridge program. revenue_dest may point at its bridge_vault PDA ($PEGGED flow).
+pub const PEGGED_BRIDGE_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("7oHSUPzkPDDtxjXcvjRYKHmSjoBigJ4HUvPRRhf1SCgN");
+
 #[program]
 pub mod bin_farm {
     use super::*;
@@ -68,240 +195,473 @@ pub mod bin_farm {
         config.emergency_close_at = 0;
         config.last_bot_close_slot = 0;
         config.last_bot_sweep_slot = 0;
-        config._reserved = [0u8; 96];
+        config.max_bins_per_harvest = MAX_BINS_PER_HARVEST;
+        config.fee_change_nonce = 0;
+        config.min_position_width = 1;
+        config.harvest_cooldown_slots = 0;
+        config.min_sweep_lamports = 0;
+        config.referral_bps = 0;
+        config.min_keeper_tip = 0;
+        config.config_version = CONFIG_VERSION;
+        config.bot_harvest_count = 0;
+        config.permissionless_harvest_count = 0;
+        config.pending_keeper_tip_bps = 0;
+        config.keeper_tip_change_at = 0;
+        config.pending_priority_slots = 0;
+        config.priority_slots_change_at = 0;
+        config.max_positions_per_owner = 0;
+        config.min_position_amount = MIN_POSITION_AMOUNT;
+        config._reserved = [0u8; 3];
+        config.allowed_token_programs = [Pubkey::default(); MAX_ALLOWED_TOKEN_PROGRAMS];
+        config.allowed_token_programs[0] = anchor_spl::token::ID;
+        config.allowed_token_programs[1] = TOKEN_2022_PROGRAM_ID;
+        config.pause_expiry_ts = 0;
+        config.max_pause_seconds = DEFAULT_MAX_PAUSE_SECONDS;
+        config.last_bot_heartbeat_slot = 0;
+        config.close_priority_slots = 0;  // 0 = same window as harvest (priority_slots)
+        config._reserved_ext = [0u8; 80];
 
         msg!("monke.army initialized | bot={} fee={}bps", bot, fee_bps);
         Ok(())
     }
 
-    pub fn open_position_v2<'info>(
-        ctx: Context<'_, '_, 'info, 'info, OpenPositionV2<'info>>,
-        amount: u64,
+    /// Create any Meteora bin arrays missing for [min_bin_id, max_bin_id] so
+    /// open_position_v2 / the rover opens don't fail on a fresh range. Meant as the
+    /// preceding instruction in the same transaction; payer funds the new arrays.
+    /// Idempotent: arrays that already exist are skipped.
+    ///
+    /// remaining_accounts: the bin array PDAs (bin_array_pda) for every index from
+    /// bin_id_to_array_index(min_bin_id) to bin_id_to_array_index(max_bin_id), in
+    /// ascending order — at most 3, same limit as open_position_v2.
+    pub fn ensure_bin_arrays<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EnsureBinArrays<'info>>,
         min_bin_id: i32,
         max_bin_id: i32,
-        _side: Side,
-        max_active_bin_slippage: i32,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, CoreError::Paused);
-        require!(amount > 0, CoreError::ZeroAmount);
-        require!(amount >= MIN_POSITION_AMOUNT, CoreError::PositionTooSmall);
-        require!(max_active_bin_slippage >= 0 && max_active_bin_slippage <= 20, CoreError::InvalidSlippage);
         require!(min_bin_id <= max_bin_id, CoreError::InvalidBinRange);
-        let width = max_bin_id - min_bin_id + 1;
-        require!(width <= MAX_POSITION_WIDTH, CoreError::PositionTooWide);
+        let lower_index = bin_id_to_array_index(min_bin_id);
+        let upper_index = bin_id_to_array_index(max_bin_id);
+        let array_span = upper_index.checked_sub(lower_index).ok_or(CoreError::Overflow)?;
+        require!(array_span <= 2, CoreError::PositionTooWide);
+        let count = array_span.checked_add(1).ok_or(CoreError::Overflow)? as usize;
+        require!(ctx.remaining_accounts.len() == count, CoreError::InvalidBinArray);
 
-        // Validate DLMM program
-        require!(ctx.accounts.dlmm_program.key() == METEORA_DLMM_PROGRAM_ID, CoreError::InvalidProgram);
-
-        // Validate token account owners (moved from struct constraints for stack savings)
-        {
-            let data = ctx.accounts.user_token_account.try_borrow_data()?;
-            require!(data.len() >= 64, CoreError::InvalidTokenOwner);
-            let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
-            require!(owner == ctx.accounts.user.key(), CoreError::InvalidTokenOwner);
-        }
-        {
-            let data = ctx.accounts.vault_token_x.try_borrow_data()?;
-            require!(data.len() >= 64, CoreError::InvalidTokenOwner);
-            let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
-            require!(owner == ctx.accounts.vault.key(), CoreError::InvalidTokenOwner);
-        }
-        {
-            let data = ctx.accounts.vault_token_y.try_borrow_data()?;
-            require!(data.len() >= 64, CoreError::InvalidTokenOwner);
-            let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
-            require!(owner == ctx.accounts.vault.key(), CoreError::InvalidTokenOwner);
+        let lb_pair_key = ctx.accounts.lb_pair.key();
+        let mut created: u8 = 0;
+        for (offset, bin_array) in ctx.remaining_accounts.iter().enumerate() {
+            let index = lower_index.checked_add(offset as i64).ok_or(CoreError::Overflow)?;
+            let (expected, _) = bin_array_pda(&lb_pair_key, index);
+            require!(bin_array.key() == expected, CoreError::InvalidBinArray);
+            if *bin_array.owner == METEORA_DLMM_PROGRAM_ID && !bin_array.data_is_empty() {
+                continue;
+            }
+            initialize_bin_array(
+                &[
+                    ctx.accounts.lb_pair.to_account_info(),
+                    bin_array.to_account_info(),
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                index,
+                &[],
+            )?;
+            created = created.checked_add(1).ok_or(CoreError::Overflow)?;
         }
 
-        // Derive side from on-chain active_id — never trust caller
-        let active_id = {
-            let data = ctx.accounts.lb_pair.try_borrow_data()?;
-            require!(data.len() >= 80, CoreError::InvalidPool);
-            i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
-        };
-        require!(active_id > -443636 && active_id < 443636, CoreError::InvalidBinRange);
-        let side = if min_bin_id > active_id { Side::Sell } else { Side::Buy };
-
-        let deposit_token_program = if side == Side::Sell {
-            &ctx.accounts.token_x_program
-        } else {
-            &ctx.accounts.token_y_program
-        };
-
-        let deposit_vault = if side == Side::Sell {
-            &ctx.accounts.vault_token_x
-        } else {
-            &ctx.accounts.vault_token_y
-        };
-        let transfer_ix = anchor_lang::solana_program::instruction::Instruction {
-            program_id: *deposit_token_program.key,
-            accounts: vec![
-                anchor_lang::solana_program::instruction::AccountMeta::new(ctx.accounts.user_token_account.key(), false),
-                anchor_lang::solana_program::instruction::AccountMeta::new(deposit_vault.key(), false),
-                anchor_lang::solana_program::instruction::AccountMeta::new_readonly(ctx.accounts.user.key(), true),
-            ],
-            data: {
-                let mut d = vec![3u8];
-                d.extend_from_slice(&amount.to_le_bytes());
-                d
-            },
-        };
-        anchor_lang::solana_program::program::invoke(
-            &transfer_ix,
-            &[
-                ctx.accounts.user_token_account.to_account_info(),
-                deposit_vault.to_account_info(),
-                ctx.accounts.user.to_account_info(),
-                deposit_token_program.to_account_info(),
-            ],
-        )?;
+        msg!("Bin arrays [{},{}] for {}: {} created, {} existing",
+            lower_index, upper_index, lb_pair_key, created, count.saturating_sub(created as usize));
+        Ok(())
+    }
 
-        // Build PDA signer seeds for meteora_position (replaces keypair signing)
-        let user_key = ctx.accounts.user.key();
-        let lb_pair_key = ctx.accounts.lb_pair.key();
-        let count_bytes = ctx.accounts.position_counter.count.to_le_bytes();
-        let meteora_pos_bump = [ctx.bumps.meteora_position];
+    /// Token-2022 mints with a transfer hook: pass the hook accounts for X then Y as
+    /// the trailing `hook_x_len + hook_y_len` remaining_accounts (0, 0 for plain mints).
+    /// `priority_slots_override` (0 = config.priority_slots, max MAX_PRIORITY_SLOTS) sets
+    /// this position's bot-priority window before permissionless harvest / close.
+    /// `fee_bps_override` (u16::MAX = config.fee_bps) gives this position a lower
+    /// protocol fee; it must be <= config.fee_bps and needs config.authority to
+    /// co-sign as fee_authority.
+    /// `expected_active_id` is the active bin the caller quoted against; the open
+    /// reverts with ActiveBinDrifted if the live one is more than `max_drift` bins
+    /// away (negative = DEFAULT_MAX_ACTIVE_BIN_DRIFT), so the derived side can't flip
+    /// unnoticed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn open_position_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenPositionV2<'info>>,
+        amount: u64,
+        min_bin_id: i32,
+        max_bin_id: i32,
+        _side: Side,
+        max_active_bin_slippage: i32,
+        take_profit_bins: u16,
+        strategy: u8,
+        referrer: Pubkey,
+        priority_slots_override: u64,
+        hook_x_len: u8,
+        hook_y_len: u8,
+        fee_bps_override: u16,
+        expected_active_id: i32,
+        max_drift: i32,
+    ) -> Result<()> {
+        execute_open_position(
+            ctx, amount, min_bin_id, max_bin_id, max_active_bin_slippage, take_profit_bins, strategy,
+            referrer, priority_slots_override, hook_x_len, hook_y_len, fee_bps_override,
+            expected_active_id, max_drift, false,
+        )
+    }
 
-        let meteora_pos_key = ctx.accounts.meteora_position.key();
-        let meteora_pos_seeds: &[&[u8]] = &[
-            b"meteora_pos",
-            user_key.as_ref(),
-            lb_pair_key.as_ref(),
-            &count_bytes,
-            &meteora_pos_bump,
-        ];
-        let vault_seeds: &[&[u8]] = &[
-            b"vault",
-            meteora_pos_key.as_ref(),
-            &[ctx.bumps.vault],
-        ];
-        let signer = &[vault_seeds, meteora_pos_seeds];
+    /// open_position_v2 funded with native SOL for WSOL-quoted pools: `amount` lamports
+    /// move from the user into the vault's wSOL account and are synced before the
+    /// add-liquidity CPI, so buy-side users skip the separate wrap transaction.
+    /// Reverts with NotSolPool unless token_y_mint is WSOL and the range is buy-side
+    /// (below the active bin). user_token_account is ignored. Atomic like every open:
+    /// a failure after the wrap reverts the lamport transfer, and any wSOL Meteora
+    /// doesn't take stays in the vault and returns to the owner on close.
+    #[allow(clippy::too_many_arguments)]
+    pub fn open_position_wrapped<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenPositionV2<'info>>,
+        amount: u64,
+        min_bin_id: i32,
+        max_bin_id: i32,
+        _side: Side,
+        max_active_bin_slippage: i32,
+        take_profit_bins: u16,
+        strategy: u8,
+        referrer: Pubkey,
+        priority_slots_override: u64,
+        hook_x_len: u8,
+        hook_y_len: u8,
+        fee_bps_override: u16,
+        expected_active_id: i32,
+        max_drift: i32,
+    ) -> Result<()> {
+        execute_open_position(
+            ctx, amount, min_bin_id, max_bin_id, max_active_bin_slippage, take_profit_bins, strategy,
+            referrer, priority_slots_override, hook_x_len, hook_y_len, fee_bps_override,
+            expected_active_id, max_drift, true,
+        )
+    }
 
-        let bin_array_lower = ctx.accounts.bin_array_lower.to_account_info();
-        let bin_array_upper = ctx.accounts.bin_array_upper.to_account_info();
-        let event_authority = ctx.accounts.event_authority.to_account_info();
-        let dlmm_program = ctx.accounts.dlmm_program.to_account_info();
-        let token_x_mint = ctx.accounts.token_x_mint.to_account_info();
-        let token_y_mint = ctx.accounts.token_y_mint.to_account_info();
+    /// Bot harvests fully-converted bins. Anti-backwash mechanic.
+    ///
+    /// `bps_to_remove` (1..=10_000) is forwarded to remove_liquidity_by_range2, so a
+    /// keeper can drain only part of a converted range on thin pools. The fee is still
+    /// taken on the actual vault delta, and a later harvest can pull the rest of the
+    /// same bins (the contiguity check is unchanged).
+    ///
+    /// remaining_accounts: [keeper ATA, second tip ATA (tip_split_bps > 0),
+    /// keeper pool (opt), ..., referrer ATA (opt), hook X accounts (hook_x_len),
+    /// hook Y accounts (hook_y_len)]. The keeper and referrer lookups only see the
+    /// entries before the hook run.
+    ///
+    /// `tip_split_bps` sends that share of the keeper tip to the second tip ATA
+    /// (0 = whole tip to the first). Both ATAs must hold the converted-side mint and
+    /// belong to the caller or the KeeperPool passed after them.
+    pub fn harvest_bins<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BotHarvest<'info>>,
+        bin_ids: Vec<i32>,
+        bps_to_remove: u16,
+        tip_split_bps: u16,
+        hook_x_len: u8,
+        hook_y_len: u8,
+    ) -> Result<()> {
+        // NOTE: harvest_bins is intentionally NOT gated by config.paused.
+        // Paused gates open_position only. Harvests must always work to protect
+        // existing positions from backwash. This is the core product promise.
+        require!(tip_split_bps <= 10_000, CoreError::InvalidTipSplit);
+        let (extra_accounts, hook_accounts) =
+            split_hook_accounts(ctx.remaining_accounts, hook_x_len, hook_y_len)
+                .ok_or(CoreError::MissingHookAccounts)?;
 
-        initialize_position2(
-            &[
-                ctx.accounts.user.to_account_info(),
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.lb_pair.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-                event_authority.clone(),
-                dlmm_program.clone(),
-            ],
-            min_bin_id,
-            width,
-            signer,
+        // --- Permissionless harvest fallback ---
+        // Authorized bot: update heartbeat, full fee to protocol.
+        // Permissionless: allowed only when bot is stale (priority_slots exceeded).
+        let clock = Clock::get()?;
+        let is_authorized_bot = ctx.accounts.bot.key() == ctx.accounts.config.bot;
+        let (from_bin, to_bin) = check_harvest(
+            &ctx.accounts.config,
+            &ctx.accounts.position,
+            &bin_ids,
+            bps_to_remove,
+            is_authorized_bot,
+            clock.slot,
         )?;
+        if is_authorized_bot {
+            ctx.accounts.config.last_bot_harvest_slot = clock.slot;
+        }
 
-        let (amount_x, amount_y) = if side == Side::Sell { (amount, 0u64) } else { (0u64, amount) };
-        let liquidity_params = LiquidityParameterByStrategy {
-            amount_x,
-            amount_y,
-            active_id,
-            max_active_bin_slippage,
-            strategy_parameters: StrategyParameters::spot_imbalanced(min_bin_id, max_bin_id),
-        };
-
-        add_liquidity_by_strategy2(
-            &[
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.lb_pair.to_account_info(),
-                ctx.accounts.bin_array_bitmap_ext.to_account_info(),
-                ctx.accounts.vault_token_x.to_account_info(),
-                ctx.accounts.vault_token_y.to_account_info(),
-                ctx.accounts.reserve_x.to_account_info(),
-                ctx.accounts.reserve_y.to_account_info(),
-                token_x_mint,
-                token_y_mint,
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.token_x_program.to_account_info(),
-                ctx.accounts.token_y_program.to_account_info(),
-                event_authority,
-                dlmm_program,
-            ],
-            liquidity_params,
-            RemainingAccountsInfo::empty_hooks(),
-            signer,
-            &[bin_array_lower, bin_array_upper],
+        let accs = Box::new(HarvestAccounts {
+            bot: ctx.accounts.bot.to_account_info(),
+            vault: ctx.accounts.vault.to_account_info(),
+            vault_bump: ctx.accounts.vault.bump,
+            meteora_position: ctx.accounts.meteora_position.to_account_info(),
+            lb_pair: ctx.accounts.lb_pair.to_account_info(),
+            bin_array_bitmap_ext: ctx.accounts.bin_array_bitmap_ext.to_account_info(),
+            bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+            bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+            reserve_x: ctx.accounts.reserve_x.to_account_info(),
+            reserve_y: ctx.accounts.reserve_y.to_account_info(),
+            token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
+            token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
+            event_authority: ctx.accounts.event_authority.to_account_info(),
+            dlmm_program: ctx.accounts.dlmm_program.to_account_info(),
+            owner_token_x: ctx.accounts.owner_token_x.to_account_info(),
+            owner_token_y: ctx.accounts.owner_token_y.to_account_info(),
+            rover_fee_token_x: ctx.accounts.rover_fee_token_x.to_account_info(),
+            rover_fee_token_y: ctx.accounts.rover_fee_token_y.to_account_info(),
+            token_x_program: ctx.accounts.token_x_program.to_account_info(),
+            token_y_program: ctx.accounts.token_y_program.to_account_info(),
+            memo_program: ctx.accounts.memo_program.to_account_info(),
+            extra_accounts,
+            hook_accounts,
+        });
+        let (harvested, fee_taken) = execute_harvest(
+            &accs,
+            &mut ctx.accounts.position,
+            &mut ctx.accounts.vault_token_x,
+            &mut ctx.accounts.vault_token_y,
+            &ctx.accounts.config,
+            &HarvestParams {
+                bin_ids: &bin_ids,
+                from_bin,
+                to_bin,
+                bps_to_remove,
+                tip_split_bps,
+                hook_x_len,
+                hook_y_len,
+                is_authorized_bot,
+                slot: clock.slot,
+            },
         )?;
+        record_pool_metrics(&ctx.accounts.pool_metrics, harvested, fee_taken, false)?;
+        ctx.accounts.config.record_harvest(harvested, is_authorized_bot)
+    }
 
-        let position = &mut ctx.accounts.position;
-        position.owner = ctx.accounts.user.key();
-        position.lb_pair = ctx.accounts.lb_pair.key();
-        position.meteora_position = ctx.accounts.meteora_position.key();
-        position.side = side;
-        position.min_bin_id = min_bin_id;
-        position.max_bin_id = max_bin_id;
-        position.initial_amount = amount;
-        position.harvested_amount = 0;
-        position.created_at = Clock::get()?.unix_timestamp;
-        position.bump = ctx.bumps.position;
+    /// harvest_bins for several positions on one lb_pair in a single transaction.
+    /// The pool-level accounts (lb_pair, reserves, mints, programs, rover fee ATAs)
+    /// are passed once; each position brings its own group of remaining_accounts:
+    ///
+    ///   [0] position (mut)           [5] vault_token_x (mut)
+    ///   [1] vault                    [6] vault_token_y (mut)
+    ///   [2] meteora_position (mut)   [7] owner_token_x (mut)
+    ///   [3] bin_array_lower (mut)    [8] owner_token_y (mut)
+    ///   [4] bin_array_upper (mut)
+    ///   then `extra_len` harvest_bins extras for that position, in the same order:
+    ///   [keeper ATA, second tip ATA (tip_split_bps > 0), keeper pool (opt), referrer ATA (opt)]
+    ///
+    /// Groups follow entry order. Positions that share bin arrays pass the same keys
+    /// (the runtime loads each account once). Transfer-hook pools are not supported
+    /// here — use harvest_bins with hook accounts.
+    ///
+    /// At most MAX_HARVEST_BATCH positions and effective_max_bins_per_harvest bins
+    /// across the whole batch. The bot heartbeat is written once. Each harvested
+    /// position emits its own HarvestEvent.
+    ///
+    /// Atomic by default: any failing position reverts the batch. With `skip_failures`,
+    /// a position that fails its pre-CPI checks (account mismatch, bin range, bot not
+    /// stale, cooldown) is logged and skipped. A failure inside the Meteora CPI or a
+    /// transfer still reverts the whole transaction — a failed CPI can't be caught.
+    pub fn harvest_bins_multi<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestBinsMulti<'info>>,
+        entries: Vec<HarvestBatchEntry>,
+        tip_split_bps: u16,
+        skip_failures: bool,
+    ) -> Result<()> {
+        require!(
+            !entries.is_empty() && entries.len() <= MAX_HARVEST_BATCH,
+            CoreError::InvalidHarvestBatch
+        );
+        require!(tip_split_bps <= 10_000, CoreError::InvalidTipSplit);
+        let total_bins = entries.iter()
+            .try_fold(0usize, |acc, entry| acc.checked_add(entry.bin_ids.len()))
+            .ok_or(CoreError::Overflow)?;
+        let max_bins = ctx.accounts.config.effective_max_bins_per_harvest();
+        require!(total_bins <= max_bins as usize, CoreError::TooManyBins);
+        let expected_accounts = entries.iter()
+            .try_fold(0usize, |acc, entry| {
+                acc.checked_add(HARVEST_BATCH_STRIDE)?.checked_add(entry.extra_len as usize)
+            })
+            .ok_or(CoreError::Overflow)?;
+        require!(ctx.remaining_accounts.len() == expected_accounts, CoreError::InvalidHarvestBatch);
 
-        let vault = &mut ctx.accounts.vault;
-        vault.position = ctx.accounts.meteora_position.key();
-        vault.bump = ctx.bumps.vault;
+        let clock = Clock::get()?;
+        let is_authorized_bot = ctx.accounts.bot.key() == ctx.accounts.config.bot;
+        let lb_pair_key = ctx.accounts.lb_pair.key();
+        let remaining: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+        let mut offset = 0usize;
+        let mut harvested_positions = 0usize;
+
+        for (index, entry) in entries.iter().enumerate() {
+            let end = offset
+                .checked_add(HARVEST_BATCH_STRIDE).ok_or(CoreError::Overflow)?
+                .checked_add(entry.extra_len as usize).ok_or(CoreError::Overflow)?;
+            let group = &remaining[offset..end];
+            offset = end;
+
+            let loaded = load_harvest_entry(
+                group,
+                &lb_pair_key,
+                &ctx.accounts.config,
+                entry,
+                is_authorized_bot,
+                clock.slot,
+            );
+            let mut loaded = match loaded {
+                Ok(loaded) => loaded,
+                Err(e) if skip_failures => {
+                    msg!("Harvest batch: skipping entry {} ({})", index, e);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
 
-        // Persist counter bump on first creation, then increment
-        let counter = &mut ctx.accounts.position_counter;
-        if counter.bump == 0 {
-            counter.bump = ctx.bumps.position_counter;
+            let accs = Box::new(HarvestAccounts {
+                bot: ctx.accounts.bot.to_account_info(),
+                vault: group[1].clone(),
+                vault_bump: loaded.vault_bump,
+                meteora_position: group[2].clone(),
+                lb_pair: ctx.accounts.lb_pair.to_account_info(),
+                bin_array_bitmap_ext: ctx.accounts.bin_array_bitmap_ext.to_account_info(),
+                bin_array_lower: group[3].clone(),
+                bin_array_upper: group[4].clone(),
+                reserve_x: ctx.accounts.reserve_x.to_account_info(),
+                reserve_y: ctx.accounts.reserve_y.to_account_info(),
+                token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
+                token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
+                event_authority: ctx.accounts.event_authority.to_account_info(),
+                dlmm_program: ctx.accounts.dlmm_program.to_account_info(),
+                owner_token_x: group[7].clone(),
+                owner_token_y: group[8].clone(),
+                rover_fee_token_x: ctx.accounts.rover_fee_token_x.to_account_info(),
+                rover_fee_token_y: ctx.accounts.rover_fee_token_y.to_account_info(),
+                token_x_program: ctx.accounts.token_x_program.to_account_info(),
+                token_y_program: ctx.accounts.token_y_program.to_account_info(),
+                memo_program: ctx.accounts.memo_program.to_account_info(),
+                extra_accounts: &group[HARVEST_BATCH_STRIDE..],
+                hook_accounts: &[],
+            });
+            let (harvested, fee_taken) = execute_harvest(
+                &accs,
+                &mut loaded.position,
+                &mut loaded.vault_token_x,
+                &mut loaded.vault_token_y,
+                &ctx.accounts.config,
+                &HarvestParams {
+                    bin_ids: &entry.bin_ids,
+                    from_bin: loaded.from_bin,
+                    to_bin: loaded.to_bin,
+                    bps_to_remove: entry.bps_to_remove,
+                    tip_split_bps,
+                    hook_x_len: 0,
+                    hook_y_len: 0,
+                    is_authorized_bot,
+                    slot: clock.slot,
+                },
+            )?;
+            loaded.position.exit(&crate::ID)?;
+            record_pool_metrics(&ctx.accounts.pool_metrics, harvested, fee_taken, false)?;
+            ctx.accounts.config.record_harvest(harvested, is_authorized_bot)?;
+            harvested_positions = harvested_positions.saturating_add(1);
         }
-        counter.count = counter.count.checked_add(1).ok_or(CoreError::Overflow)?;
 
-        let config = &mut ctx.accounts.config;
-        config.total_positions = config.total_positions.saturating_add(1);
-        config.total_volume = config.total_volume.saturating_add(amount);
+        if is_authorized_bot && harvested_positions > 0 {
+            ctx.accounts.config.last_bot_harvest_slot = clock.slot;
+        }
+        msg!("Harvest batch: {} of {} positions harvested", harvested_positions, entries.len());
+        Ok(())
+    }
 
-        emit!(PositionOpenedEvent {
-            position: ctx.accounts.position.key(),
-            user: ctx.accounts.user.key(),
-            lb_pair: ctx.accounts.lb_pair.key(),
-            side,
-            amount,
-            min_bin_id,
-            max_bin_id,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+    /// harvest_bins without the off-chain range math: the program picks the
+    /// contiguous converted range itself from the pool's active_id and the Meteora
+    /// position's liquidity_shares (see converted_bin_range), capped at
+    /// effective_max_bins_per_harvest, then runs harvest_bins on it.
+    /// Reverts with NothingConverted if the price hasn't fully crossed any bin that
+    /// still holds liquidity. Same accounts and remaining_accounts as harvest_bins;
+    /// the bin arrays passed must cover the chosen range. Requires a migrated Config.
+    pub fn harvest_converted<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BotHarvest<'info>>,
+        bps_to_remove: u16,
+        tip_split_bps: u16,
+        hook_x_len: u8,
+        hook_y_len: u8,
+    ) -> Result<()> {
+        ctx.accounts.config.require_version()?;
+        let active_id = {
+            let data = ctx.accounts.lb_pair.try_borrow_data()?;
+            require!(data.len() >= 80, CoreError::InvalidPool);
+            i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+        };
+        let (from_bin, to_bin) = {
+            let data = ctx.accounts.meteora_position.try_borrow_data()?;
+            let position = &ctx.accounts.position;
+            converted_bin_range(
+                &data,
+                position.side,
+                position.min_bin_id,
+                position.max_bin_id,
+                active_id,
+                ctx.accounts.config.effective_max_bins_per_harvest(),
+            )?
+            .ok_or(CoreError::NothingConverted)?
+        };
 
-        msg!("Position opened: {} | {} bins [{},{}] | {} lamports",
-            ctx.accounts.position.key(), width, min_bin_id, max_bin_id, amount);
-        Ok(())
+        msg!("Converted range [{},{}] at active_id {}", from_bin, to_bin, active_id);
+        let bin_ids: Vec<i32> = (from_bin..=to_bin).collect();
+        harvest_bins(ctx, bin_ids, bps_to_remove, tip_split_bps, hook_x_len, hook_y_len)
     }
 
-    /// Bot harvests fully-converted bins. Anti-backwash mechanic.
-    pub fn harvest_bins<'info>(
+    /// Auto-compound variant of harvest_bins. Same remove_liquidity_by_range2 + delta fee,
+    /// same referral split of the fee, rest of the fee to the rover ATAs, but the converted
+    /// side is redeposited into the harvested bin range (SpotImBalanced) instead of being
+    /// sent to the owner. The unconverted side, if any, still goes to the owner.
+    ///
+    /// Caller must be the authorized bot or the position owner (no keeper tip path); an
+    /// owner call counts toward permissionless_harvest_count. Falls back to a normal
+    /// transfer when the converted amount is below MIN_COMPOUND_AMOUNT_PER_BIN per bin
+    /// or the range now straddles the active bin.
+    /// Redeposited liquidity sits on the opposite side of the price and converts back
+    /// if price returns — that is the trade-off the owner opts into.
+    ///
+    /// remaining_accounts: referrer ATA (when position.referrer is set), then the
+    /// transfer-hook accounts (hook_x_len for X, then hook_y_len for Y), as in
+    /// harvest_bins. Both the removal and the redeposit get the hook accounts.
+    pub fn compound_harvest<'info>(
         ctx: Context<'_, '_, 'info, 'info, BotHarvest<'info>>,
         bin_ids: Vec<i32>,
+        bps_to_remove: u16,
+        hook_x_len: u8,
+        hook_y_len: u8,
     ) -> Result<()> {
-        // NOTE: harvest_bins is intentionally NOT gated by config.paused.
-        // Paused gates open_position only. Harvests must always work to protect
-        // existing positions from backwash. This is the core product promise.
-        require!(!bin_ids.is_empty(), CoreError::NoBinsProvided);
-        require!(bin_ids.len() <= 70, CoreError::TooManyBins);
+        let (extra_accounts, hook_accounts) =
+            split_hook_accounts(ctx.remaining_accounts, hook_x_len, hook_y_len)
+                .ok_or(CoreError::MissingHookAccounts)?;
+        let caller = ctx.accounts.bot.key();
+        let is_authorized_bot = caller == ctx.accounts.config.bot;
+        require!(
+            is_authorized_bot || caller == ctx.accounts.position.owner,
+            CoreError::Unauthorized
+        );
+        // Same bin-list checks as harvest_bins (count, range, no duplicates, contiguous).
+        // No keeper path here, so the owner skips the staleness window and cooldown too.
+        let clock = Clock::get()?;
+        let (from_bin, to_bin) = check_harvest(
+            &ctx.accounts.config,
+            &ctx.accounts.position,
+            &bin_ids,
+            bps_to_remove,
+            true,
+            clock.slot,
+        )?;
+        if is_authorized_bot {
+            ctx.accounts.config.last_bot_harvest_slot = clock.slot;
+        }
 
         let x_decimals = read_mint_decimals(&ctx.accounts.token_x_mint)?;
         let y_decimals = read_mint_decimals(&ctx.accounts.token_y_mint)?;
 
         let position_key = ctx.accounts.position.key();
-        let owner_key = ctx.accounts.owner.key();
         let side = ctx.accounts.position.side;
-        let min_bin_id = ctx.accounts.position.min_bin_id;
-        let max_bin_id = ctx.accounts.position.max_bin_id;
         let meteora_pos_key = ctx.accounts.position.meteora_position;
 
-        for &bin_id in &bin_ids {
-            require!(
-                bin_id >= min_bin_id && bin_id <= max_bin_id,
-                CoreError::BinOutOfPositionRange
-            );
-        }
-
         let vault_seeds: &[&[u8]] = &[
             b"vault",
             meteora_pos_key.as_ref(),
@@ -309,41 +669,14 @@ pub mod bin_farm {
         ];
         let signer = &[vault_seeds];
 
-        let from_bin = *bin_ids.iter().min().ok_or(CoreError::NoBinsProvided)?;
-        let to_bin = *bin_ids.iter().max().ok_or(CoreError::NoBinsProvided)?;
-
-        // Enforce contiguous range — remove_liquidity_by_range removes ALL bins
-        // between from_bin and to_bin. Non-contiguous bin_ids would remove unconverted bins.
-        require!(
-            (to_bin - from_bin + 1) == bin_ids.len() as i32,
-            CoreError::NonContiguousBins
-        );
-
-        // --- Permissionless harvest fallback ---
-        // Authorized bot: update heartbeat, full fee to protocol.
-        // Permissionless: allowed only when bot is stale (priority_slots exceeded).
-        let clock = Clock::get()?;
-        let is_authorized_bot = ctx.accounts.bot.key() == ctx.accounts.config.bot;
-
-        if is_authorized_bot {
-            ctx.accounts.config.last_bot_harvest_slot = clock.slot;
-        } else {
-            // Permissionless path: bot must be stale
-            let slots_since = clock.slot
-                .checked_sub(ctx.accounts.config.last_bot_harvest_slot)
-                .ok_or(CoreError::Overflow)?;
-            require!(slots_since > ctx.accounts.config.priority_slots, CoreError::BotNotStale);
-        }
-
-        // Snapshot vault balances BEFORE CPI for delta-based fee calculation
         let x_before = ctx.accounts.vault_token_x.amount;
         let y_before = ctx.accounts.vault_token_y.amount;
 
-        let remaining = &[
+        let remaining = hooks_then_bin_arrays(hook_accounts, &[
             ctx.accounts.bin_array_lower.to_account_info(),
             ctx.accounts.bin_array_upper.to_account_info(),
-        ];
-        remove_liquidity_by_range2(
+        ]);
+        remove_liquidity_by_range_auto(
             &[
                 ctx.accounts.meteora_position.to_account_info(),
                 ctx.accounts.lb_pair.to_account_info(),
@@ -363,170 +696,133 @@ pub mod bin_farm {
             ],
             from_bin,
             to_bin,
-            10_000,
-            RemainingAccountsInfo::none(),
+            bps_to_remove,
+            RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
             signer,
-            remaining,
+            &remaining,
         )?;
 
-        // Reload balances after CPI — use delta for fee calculation
         ctx.accounts.vault_token_x.reload()?;
         ctx.accounts.vault_token_y.reload()?;
         let x_received = ctx.accounts.vault_token_x.amount.saturating_sub(x_before);
         let y_received = ctx.accounts.vault_token_y.amount.saturating_sub(y_before);
 
-        if x_received == 0 && y_received == 0 {
-            msg!("WARNING: harvest produced 0 tokens — bins may not have been converted");
-        }
-
-        // Fee on converted output only (delta-based, not total balance)
-        let fee_bps = ctx.accounts.config.fee_bps as u128;
-        let (x_fee, y_fee) = match side {
-            Side::Buy => {
-                let f = (x_received as u128)
-                    .checked_mul(fee_bps).ok_or(CoreError::Overflow)?
-                    .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
-                (f, 0u64)
-            }
-            Side::Sell => {
-                let f = (y_received as u128)
-                    .checked_mul(fee_bps).ok_or(CoreError::Overflow)?
-                    .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
-                (0u64, f)
-            }
-        };
-
-        // --- Keeper tip (permissionless only, from converted-side fee) ---
-        let (x_tip, y_tip) = if !is_authorized_bot && ctx.accounts.config.keeper_tip_bps > 0 {
-            let tip_bps = ctx.accounts.config.keeper_tip_bps as u128;
-            let xt = (x_fee as u128)
-                .checked_mul(tip_bps).ok_or(CoreError::Overflow)?
-                .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
-            let yt = (y_fee as u128)
-                .checked_mul(tip_bps).ok_or(CoreError::Overflow)?
-                .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
-            (xt, yt)
+        // Fee on converted output only (delta-based), identical to harvest_bins
+        let fee_bps = ctx.accounts.position.effective_fee_bps(ctx.accounts.config.fee_bps);
+        let (x_fee, y_fee) = converted_side_fee(side, fee_bps, x_received, y_received)?;
+
+        // Referral cut, as in harvest_bins (no keeper tip to take first)
+        let referrer = ctx.accounts.position.referrer;
+        let referral_bps = ctx.accounts.config.effective_referral_bps(&referrer);
+        let x_referral = referral_share(x_fee, referral_bps)?;
+        let y_referral = referral_share(y_fee, referral_bps)?;
+        let x_to_protocol = x_fee.checked_sub(x_referral).ok_or(CoreError::Overflow)?;
+        let y_to_protocol = y_fee.checked_sub(y_referral).ok_or(CoreError::Overflow)?;
+
+        let referrer_ata = if x_referral > 0 || y_referral > 0 {
+            let mint = if x_referral > 0 { ctx.accounts.token_x_mint.key() } else { ctx.accounts.token_y_mint.key() };
+            Some(validate_referrer_ata(extra_accounts, &referrer, &mint)?.clone())
         } else {
-            (0u64, 0u64)
+            None
         };
-        let x_to_protocol = x_fee.checked_sub(x_tip).ok_or(CoreError::Overflow)?;
-        let y_to_protocol = y_fee.checked_sub(y_tip).ok_or(CoreError::Overflow)?;
-
-        // Use post-reload vault amounts (vault-as-pipe: transfer full balance minus fee)
-        let x_to_owner = ctx.accounts.vault_token_x.amount.checked_sub(x_fee).ok_or(CoreError::Overflow)?;
-        let y_to_owner = ctx.accounts.vault_token_y.amount.checked_sub(y_fee).ok_or(CoreError::Overflow)?;
-
-        // Tip -> keeper (permissionless path only, via remaining_accounts[0])
-        if x_tip > 0 || y_tip > 0 {
-            require!(ctx.remaining_accounts.len() >= 1, CoreError::MissingKeeperAta);
-            let keeper_ata_info = &ctx.remaining_accounts[0];
-            require!(
-                *keeper_ata_info.owner == anchor_spl::token::ID
-                    || *keeper_ata_info.owner == TOKEN_2022_PROGRAM_ID,
-                CoreError::MissingKeeperAta
-            );
-            // Prevent duplicate mutable account exploitation — keeper ATA
-            // must not be the same as any fee destination or owner token account
-            require!(
-                keeper_ata_info.key() != ctx.accounts.rover_fee_token_y.key()
-                    && keeper_ata_info.key() != ctx.accounts.rover_fee_token_x.key()
-                    && keeper_ata_info.key() != ctx.accounts.owner_token_x.key()
-                    && keeper_ata_info.key() != ctx.accounts.owner_token_y.key(),
-                CoreError::MissingKeeperAta
-            );
-            // Validate keeper ATA mint matches the converted-side token.
-            // Without this, a griefer can pass a wrong-mint ATA causing the entire harvest
-            // to revert at the CPI level, wasting gas. This gives a clearer error earlier.
-            {
-                let keeper_data = keeper_ata_info.try_borrow_data()?;
-                // SPL TokenAccount layout: mint is at offset 0 (32 bytes)
-                require!(keeper_data.len() >= 32, CoreError::MissingKeeperAta);
-                let keeper_mint = Pubkey::try_from(&keeper_data[0..32])
-                    .map_err(|_| CoreError::MissingKeeperAta)?;
-                if x_tip > 0 {
-                    require!(keeper_mint == ctx.accounts.token_x_mint.key(), CoreError::MissingKeeperAta);
-                } else {
-                    require!(keeper_mint == ctx.accounts.token_y_mint.key(), CoreError::MissingKeeperAta);
-                }
-            }
-            let keeper_ata = keeper_ata_info;
-            if x_tip > 0 {
-                memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
-                transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_x_program.to_account_info(),
-                        TransferChecked {
-                            from: ctx.accounts.vault_token_x.to_account_info(),
-                            mint: ctx.accounts.token_x_mint.to_account_info(),
-                            to: keeper_ata.to_account_info(),
-                            authority: ctx.accounts.vault.to_account_info(),
-                        },
-                        signer,
-                    ),
-                    x_tip,
-                    x_decimals,
-                )?;
-            }
-            if y_tip > 0 {
-                memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
-                transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_y_program.to_account_info(),
-                        TransferChecked {
-                            from: ctx.accounts.vault_token_y.to_account_info(),
-                            mint: ctx.accounts.token_y_mint.to_account_info(),
-                            to: keeper_ata.to_account_info(),
-                            authority: ctx.accounts.vault.to_account_info(),
-                        },
-                        signer,
-                    ),
-                    y_tip,
-                    y_decimals,
-                )?;
+        let fee_payouts = [
+            (x_referral, referrer_ata.clone(), true),
+            (y_referral, referrer_ata, false),
+            (x_to_protocol, Some(ctx.accounts.rover_fee_token_x.to_account_info()), true),
+            (y_to_protocol, Some(ctx.accounts.rover_fee_token_y.to_account_info()), false),
+        ];
+        for (amount, to, is_x) in fee_payouts {
+            let Some(to) = to else { continue };
+            if amount == 0 {
+                continue;
             }
-        }
-
-        // Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40: monke holders + bot)
-        //   TOKEN fees (Buy side) → rover_fee_token_x for DLMM recycling
-        //   SOL fees (Sell side)  → rover_fee_token_y (WSOL, unwrapped later via close_rover_token_account)
-        if x_to_protocol > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
+            let (program_info, vault_token, mint_info, decimals) = if is_x {
+                (ctx.accounts.token_x_program.to_account_info(), ctx.accounts.vault_token_x.to_account_info(), ctx.accounts.token_x_mint.to_account_info(), x_decimals)
+            } else {
+                (ctx.accounts.token_y_program.to_account_info(), ctx.accounts.vault_token_y.to_account_info(), ctx.accounts.token_y_mint.to_account_info(), y_decimals)
+            };
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &to, signer)?;
             transfer_checked(
                 CpiContext::new_with_signer(
-                    ctx.accounts.token_x_program.to_account_info(),
+                    program_info,
                     TransferChecked {
-                        from: ctx.accounts.vault_token_x.to_account_info(),
-                        mint: ctx.accounts.token_x_mint.to_account_info(),
-                        to: ctx.accounts.rover_fee_token_x.to_account_info(),
+                        from: vault_token,
+                        mint: mint_info,
+                        to,
                         authority: ctx.accounts.vault.to_account_info(),
                     },
                     signer,
                 ),
-                x_to_protocol,
-                x_decimals,
+                amount,
+                decimals,
             )?;
         }
-        if y_to_protocol > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
-            transfer_checked(
-                CpiContext::new_with_signer(
+
+        ctx.accounts.vault_token_x.reload()?;
+        ctx.accounts.vault_token_y.reload()?;
+        let x_net = ctx.accounts.vault_token_x.amount;
+        let y_net = ctx.accounts.vault_token_y.amount;
+        let converted = match side { Side::Buy => x_net, Side::Sell => y_net };
+
+        // Redeposit only if it clears the dust floor and the range sits entirely on the
+        // side of active_id that accepts the converted token (X above, Y below).
+        let active_id = {
+            let data = ctx.accounts.lb_pair.try_borrow_data()?;
+            require!(data.len() >= 80, CoreError::InvalidPool);
+            i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+        };
+        let bins = bin_ids.len() as u64;
+        let min_compound = MIN_COMPOUND_AMOUNT_PER_BIN.checked_mul(bins).ok_or(CoreError::Overflow)?;
+        let range_ok = match side {
+            Side::Buy  => from_bin > active_id,
+            Side::Sell => to_bin < active_id,
+        };
+        let compounded = if converted >= min_compound && range_ok {
+            let (amount_x, amount_y) = match side {
+                Side::Buy  => (converted, 0u64),
+                Side::Sell => (0u64, converted),
+            };
+            add_liquidity_by_strategy2(
+                &[
+                    ctx.accounts.meteora_position.to_account_info(),
+                    ctx.accounts.lb_pair.to_account_info(),
+                    ctx.accounts.bin_array_bitmap_ext.to_account_info(),
+                    ctx.accounts.vault_token_x.to_account_info(),
+                    ctx.accounts.vault_token_y.to_account_info(),
+                    ctx.accounts.reserve_x.to_account_info(),
+                    ctx.accounts.reserve_y.to_account_info(),
+                    ctx.accounts.token_x_mint.to_account_info(),
+                    ctx.accounts.token_y_mint.to_account_info(),
+                    ctx.accounts.vault.to_account_info(),
+                    ctx.accounts.token_x_program.to_account_info(),
                     ctx.accounts.token_y_program.to_account_info(),
-                    TransferChecked {
-                        from: ctx.accounts.vault_token_y.to_account_info(),
-                        mint: ctx.accounts.token_y_mint.to_account_info(),
-                        to: ctx.accounts.rover_fee_token_y.to_account_info(),
-                        authority: ctx.accounts.vault.to_account_info(),
-                    },
-                    signer,
-                ),
-                y_to_protocol,
-                y_decimals,
+                    ctx.accounts.event_authority.to_account_info(),
+                    ctx.accounts.dlmm_program.to_account_info(),
+                ],
+                LiquidityParameterByStrategy {
+                    amount_x,
+                    amount_y,
+                    active_id,
+                    max_active_bin_slippage: MAX_ACTIVE_BIN_SLIPPAGE,
+                    strategy_parameters: StrategyParameters::spot_imbalanced(from_bin, to_bin),
+                },
+                RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
+                signer,
+                &remaining,
             )?;
-        }
+            converted
+        } else {
+            msg!("Compound skipped (amount={} min={} range_ok={}) — paying out", converted, min_compound, range_ok);
+            0
+        };
 
-        // Remainder -> owner
+        // Whatever is left in the vault (unconverted side, or everything on fallback) → owner
+        ctx.accounts.vault_token_x.reload()?;
+        ctx.accounts.vault_token_y.reload()?;
+        let x_to_owner = ctx.accounts.vault_token_x.amount;
+        let y_to_owner = ctx.accounts.vault_token_y.amount;
         if x_to_owner > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.owner_token_x.to_account_info(), signer)?;
             transfer_checked(
                 CpiContext::new_with_signer(
                     ctx.accounts.token_x_program.to_account_info(),
@@ -543,7 +839,7 @@ pub mod bin_farm {
             )?;
         }
         if y_to_owner > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.owner_token_y.to_account_info(), signer)?;
             transfer_checked(
                 CpiContext::new_with_signer(
                     ctx.accounts.token_y_program.to_account_info(),
@@ -560,65 +856,401 @@ pub mod bin_farm {
             )?;
         }
 
-        let harvested = match side {
-            Side::Buy  => x_to_owner,
-            Side::Sell => y_to_owner,
-        };
-        let fee_taken = match side {
-            Side::Buy  => x_fee,
-            Side::Sell => y_fee,
-        };
-
-        // Capture lb_pair before mutable borrow of position (borrow checker)
-        let lb_pair_key = ctx.accounts.position.lb_pair;
-
+        let paid_out = match side { Side::Buy => x_to_owner, Side::Sell => y_to_owner };
         let position = &mut ctx.accounts.position;
+        position.last_harvest_slot = clock.slot;
         position.harvested_amount = position.harvested_amount
-            .checked_add(harvested).ok_or(CoreError::Overflow)?;
-        ctx.accounts.config.total_harvested = ctx.accounts.config.total_harvested
-            .checked_add(harvested).ok_or(CoreError::Overflow)?;
+            .checked_add(paid_out).ok_or(CoreError::Overflow)?;
+        position.total_fees_paid_x = position.total_fees_paid_x
+            .checked_add(x_fee).ok_or(CoreError::Overflow)?;
+        position.total_fees_paid_y = position.total_fees_paid_y
+            .checked_add(y_fee).ok_or(CoreError::Overflow)?;
+        let fee_taken = match side { Side::Buy => x_fee, Side::Sell => y_fee };
+        record_pool_metrics(&ctx.accounts.pool_metrics, paid_out, fee_taken, false)?;
+        ctx.accounts.config.record_harvest(paid_out, is_authorized_bot)?;
+
+        emit!(CompoundEvent {
+            position: position_key,
+            owner: position.owner,
+            caller,
+            side,
+            from_bin,
+            to_bin,
+            bps_removed: bps_to_remove,
+            redeposited_amount: compounded,
+            fee_amount: x_fee.checked_add(y_fee).ok_or(CoreError::Overflow)?,
+            token_x_to_owner: x_to_owner,
+            token_y_to_owner: y_to_owner,
+            min_bin_id: position.min_bin_id,
+            max_bin_id: position.max_bin_id,
+            referral_amount: x_referral.checked_add(y_referral).ok_or(CoreError::Overflow)?,
+        });
+
+        msg!("Compound [{},{}] | redeposited={} | paid_out={}", from_bin, to_bin, compounded, paid_out);
+        Ok(())
+    }
+
+    /// Read-only CU estimate for a harvest_bins call. Keepers simulate this first and
+    /// set a matching ComputeBudget limit on the real harvest. Emits HarvestCostQuoteEvent.
+    ///
+    /// estimate = BASE
+    ///          + bins * (PER_BIN + PER_BIN_T22 if either token program is Token-2022)
+    ///          + MAX_TRANSFERS * (PER_TRANSFER or PER_TRANSFER_T22 + PER_MEMO)
+    ///          then + EXTENSION_MARGIN% if either mint carries Token-2022 extensions,
+    ///          then + SAFETY_MARGIN%, capped at MAX_TX_COMPUTE_UNITS.
+    pub fn quote_harvest_cost(ctx: Context<QuoteHarvestCost>, bin_ids: Vec<i32>) -> Result<()> {
+        require!(!bin_ids.is_empty(), CoreError::NoBinsProvided);
+        let max_bins = ctx.accounts.config.effective_max_bins_per_harvest();
+        require!(bin_ids.len() <= max_bins as usize, CoreError::TooManyBins);
+
+        let min_bin_id = ctx.accounts.position.min_bin_id;
+        let max_bin_id = ctx.accounts.position.max_bin_id;
+        for &bin_id in &bin_ids {
+            require!(
+                bin_id >= min_bin_id && bin_id <= max_bin_id,
+                CoreError::BinOutOfPositionRange
+            );
+        }
 
-        let keeper_tip_taken = match side {
-            Side::Buy  => x_tip,
-            Side::Sell => y_tip,
+        let is_t22 = *ctx.accounts.token_x_program.key == TOKEN_2022_PROGRAM_ID
+            || *ctx.accounts.token_y_program.key == TOKEN_2022_PROGRAM_ID;
+        let has_extensions = ctx.accounts.token_x_mint.data_len() > SPL_MINT_LEN
+            || ctx.accounts.token_y_mint.data_len() > SPL_MINT_LEN;
+
+        let bins = bin_ids.len() as u64;
+        let per_bin = if is_t22 {
+            HARVEST_CU_PER_BIN.checked_add(HARVEST_CU_PER_BIN_T22).ok_or(CoreError::Overflow)?
+        } else {
+            HARVEST_CU_PER_BIN
         };
+        let per_transfer = if is_t22 { HARVEST_CU_PER_TRANSFER_T22 } else { HARVEST_CU_PER_TRANSFER }
+            .checked_add(HARVEST_CU_PER_MEMO).ok_or(CoreError::Overflow)?;
 
-        emit!(HarvestEvent {
-            position: position_key,
-            owner: owner_key,
-            lb_pair: lb_pair_key,
-            harvester: ctx.accounts.bot.key(),
-            bin_ids: bin_ids.clone(),
-            token_x_amount: x_to_owner,
-            token_y_amount: y_to_owner,
-            fee_amount: fee_taken,
-            keeper_tip: keeper_tip_taken,
-            total_harvested: position.harvested_amount,
+        let mut estimate = HARVEST_CU_BASE
+            .checked_add(bins.checked_mul(per_bin).ok_or(CoreError::Overflow)?)
+            .ok_or(CoreError::Overflow)?
+            .checked_add(HARVEST_CU_MAX_TRANSFERS.checked_mul(per_transfer).ok_or(CoreError::Overflow)?)
+            .ok_or(CoreError::Overflow)?;
+        if has_extensions {
+            estimate = estimate
+                .checked_mul(100u64.checked_add(HARVEST_CU_EXTENSION_MARGIN_PCT).ok_or(CoreError::Overflow)?)
+                .ok_or(CoreError::Overflow)?
+                .checked_div(100).ok_or(CoreError::Overflow)?;
+        }
+        estimate = estimate
+            .checked_mul(100u64.checked_add(HARVEST_CU_SAFETY_MARGIN_PCT).ok_or(CoreError::Overflow)?)
+            .ok_or(CoreError::Overflow)?
+            .checked_div(100).ok_or(CoreError::Overflow)?;
+        let estimate = core::cmp::min(estimate, MAX_TX_COMPUTE_UNITS);
+
+        emit!(HarvestCostQuoteEvent {
+            position: ctx.accounts.position.key(),
+            bin_count: bin_ids.len() as u16,
+            token_2022: is_t22,
+            mint_extensions: has_extensions,
+            estimated_cu: estimate as u32,
         });
 
-        msg!("Harvested bins [{},{}] | fee={} | tip={} | cumulative={}",
-            from_bin, to_bin, fee_taken, keeper_tip_taken, position.harvested_amount);
+        msg!("Harvest CU estimate: {} ({} bins, t22={}, ext={})", estimate, bins, is_t22, has_extensions);
         Ok(())
     }
 
-    /// Bot closes position: remove all + claim fees + close Meteora position.
-    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
-        // --- Permissionless close fallback (same pattern as harvest_bins) ---
+    /// Bot liveness ping: records the slot in config.last_bot_heartbeat_slot and emits
+    /// HeartbeatEvent. Unlike the harvest / close / sweep slots it moves with no work
+    /// pending, so monitoring can tell an idle bot from a dead one. Does not reset the
+    /// priority windows — permissionless keepers still unlock on the work slots.
+    pub fn bot_heartbeat(ctx: Context<BotHeartbeat>) -> Result<()> {
         let clock = Clock::get()?;
-        let is_authorized_bot = ctx.accounts.bot.key() == ctx.accounts.config.bot;
+        ctx.accounts.config.record_heartbeat(ctx.accounts.bot.key, clock.slot)?;
+        emit!(HeartbeatEvent {
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
 
-        if is_authorized_bot {
-            // Bot-paused only applies to the authorized bot
-            require!(!ctx.accounts.config.bot_paused, CoreError::BotPaused);
-            ctx.accounts.config.last_bot_close_slot = clock.slot;
+    /// Read-only bot liveness snapshot for monitoring — one simulation covers harvest,
+    /// close, and sweep. Unlocked flags mirror the BotNotStale checks in harvest_bins
+    /// and close_position; sweep_rover has no staleness gate, so it is always unlocked.
+    pub fn quote_bot_health(ctx: Context<QuoteBotHealth>) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let current_slot = Clock::get()?.slot;
+        let harvest_staleness = current_slot.saturating_sub(config.last_bot_harvest_slot);
+        let close_staleness = current_slot.saturating_sub(config.last_bot_close_slot);
+        let sweep_staleness = current_slot.saturating_sub(config.last_bot_sweep_slot);
+
+        emit!(BotHealthEvent {
+            last_bot_harvest_slot: config.last_bot_harvest_slot,
+            last_bot_close_slot: config.last_bot_close_slot,
+            last_bot_sweep_slot: config.last_bot_sweep_slot,
+            current_slot,
+            harvest_staleness,
+            close_staleness,
+            sweep_staleness,
+            harvest_unlocked: harvest_staleness > config.priority_slots,
+            close_unlocked: close_staleness > config.effective_close_priority_slots(),
+            sweep_unlocked: true,
+            priority_slots: config.priority_slots,
+            bot_paused: config.bot_paused,
+            last_bot_heartbeat_slot: config.last_bot_heartbeat_slot,
+            close_priority_slots: config.effective_close_priority_slots(),
+        });
+
+        msg!("Bot staleness: harvest={} close={} sweep={} (priority_slots={}, close_priority_slots={})",
+            harvest_staleness, close_staleness, sweep_staleness, config.priority_slots,
+            config.effective_close_priority_slots());
+        Ok(())
+    }
+
+    /// Read-only protocol counters for monitoring / abuse detection. Emits
+    /// ConfigStatsEvent and sets bot_harvest_count, permissionless_harvest_count,
+    /// total_harvested, total_positions as 4 little-endian u64s of return data.
+    pub fn config_stats(ctx: Context<ConfigStats>) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        let mut data = [0u8; 32];
+        data[0..8].copy_from_slice(&config.bot_harvest_count.to_le_bytes());
+        data[8..16].copy_from_slice(&config.permissionless_harvest_count.to_le_bytes());
+        data[16..24].copy_from_slice(&config.total_harvested.to_le_bytes());
+        data[24..32].copy_from_slice(&config.total_positions.to_le_bytes());
+        solana_program::program::set_return_data(&data);
+
+        emit!(ConfigStatsEvent {
+            bot_harvest_count: config.bot_harvest_count,
+            permissionless_harvest_count: config.permissionless_harvest_count,
+            total_harvested: config.total_harvested,
+            total_positions: config.total_positions,
+        });
+
+        msg!("Harvests: bot={} permissionless={} | harvested={} | positions={}",
+            config.bot_harvest_count, config.permissionless_harvest_count,
+            config.total_harvested, config.total_positions);
+        Ok(())
+    }
+
+    /// Permissionless: create lb_pair's PoolMetrics so harvests and bot closes on it
+    /// start counting. Payer covers rent. Nothing before creation is backfilled.
+    pub fn init_pool_metrics(ctx: Context<InitPoolMetrics>) -> Result<()> {
+        let metrics = &mut ctx.accounts.pool_metrics;
+        metrics.lb_pair = ctx.accounts.lb_pair.key();
+        metrics.lifetime_harvested = 0;
+        metrics.lifetime_fees = 0;
+        metrics.position_count = 0;
+        metrics.bump = ctx.bumps.pool_metrics;
+        msg!("Pool metrics initialized for {}", metrics.lb_pair);
+        Ok(())
+    }
+
+    /// Read-only pool counters for yield ranking. Emits PoolStatsEvent and sets
+    /// lifetime_harvested, lifetime_fees, position_count as 3 little-endian u64s of
+    /// return data.
+    pub fn pool_stats(ctx: Context<PoolStats>) -> Result<()> {
+        let metrics = &ctx.accounts.pool_metrics;
+
+        let mut data = [0u8; 24];
+        data[0..8].copy_from_slice(&metrics.lifetime_harvested.to_le_bytes());
+        data[8..16].copy_from_slice(&metrics.lifetime_fees.to_le_bytes());
+        data[16..24].copy_from_slice(&metrics.position_count.to_le_bytes());
+        solana_program::program::set_return_data(&data);
+
+        emit!(PoolStatsEvent {
+            lb_pair: metrics.lb_pair,
+            lifetime_harvested: metrics.lifetime_harvested,
+            lifetime_fees: metrics.lifetime_fees,
+            position_count: metrics.position_count,
+        });
+
+        msg!("Pool {}: harvested={} fees={} closed={}",
+            metrics.lb_pair, metrics.lifetime_harvested, metrics.lifetime_fees, metrics.position_count);
+        Ok(())
+    }
+
+    /// Permissionless consistency check for the 1:1 vault-to-position invariant:
+    /// vault.position must equal position.meteora_position. Emits VaultBindingEvent;
+    /// with require_match set, a mismatch reverts (useful as a CI / monitoring gate).
+    pub fn verify_vault_binding(ctx: Context<VerifyVaultBinding>, require_match: bool) -> Result<()> {
+        let matches = ctx.accounts.vault.position == ctx.accounts.position.meteora_position;
+
+        emit!(VaultBindingEvent {
+            position: ctx.accounts.position.key(),
+            vault: ctx.accounts.vault.key(),
+            meteora_position: ctx.accounts.position.meteora_position,
+            vault_bound_to: ctx.accounts.vault.position,
+            matches,
+        });
+
+        if require_match {
+            require!(matches, CoreError::VaultBindingMismatch);
+        }
+        msg!("Vault binding: matches={}", matches);
+        Ok(())
+    }
+
+    /// Read-only: sum the LP fees already settled into the Meteora position so
+    /// frontends can gray out "claim fees" when there is nothing to claim. No CPI.
+    /// Lower bound — fees accrued since the position's last update are not counted.
+    /// Layout offsets and length guards: see read_position_pending_fees.
+    /// Emits LpFeesQuoteEvent and sets x_fees, y_fees as 2 little-endian u64s of
+    /// return data. claim_fees records the same quote (ClaimFeesEvent.quoted_x/y).
+    pub fn quote_lp_fees(ctx: Context<QuoteLpFees>) -> Result<()> {
+        let (x_fees, y_fees) = quote_position_fees(&ctx.accounts.meteora_position)?;
+        solana_program::program::set_return_data(&lp_fees_return_data(x_fees, y_fees));
+
+        emit!(LpFeesQuoteEvent {
+            position: ctx.accounts.position.key(),
+            meteora_position: ctx.accounts.meteora_position.key(),
+            x_fees,
+            y_fees,
+        });
+
+        msg!("LP fees pending: x={} y={}", x_fees, y_fees);
+        Ok(())
+    }
+
+    /// Read-only: does the Meteora position still hold liquidity, and where is the
+    /// pool price relative to the stored range? Lets keepers pick close_position vs
+    /// close_position_empty without parsing Meteora layouts. No CPI, no signer.
+    /// Emits PositionStatusEvent.
+    pub fn position_status(ctx: Context<PositionStatus>) -> Result<()> {
+        let bins_with_liquidity = {
+            let data = ctx.accounts.meteora_position.try_borrow_data()?;
+            read_position_liquidity_bins(&data).ok_or(CoreError::InvalidPositionData)?
+        };
+        let active_id = {
+            let data = ctx.accounts.lb_pair.try_borrow_data()?;
+            require!(data.len() >= 80, CoreError::InvalidPool);
+            i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+        };
+        let position = &ctx.accounts.position;
+        // -1 = price below range, 0 = inside, 1 = above
+        let active_vs_range: i8 = if active_id < position.min_bin_id {
+            -1
+        } else if active_id > position.max_bin_id {
+            1
         } else {
-            let slots_since = clock.slot
-                .checked_sub(ctx.accounts.config.last_bot_close_slot)
-                .ok_or(CoreError::Overflow)?;
-            require!(slots_since > ctx.accounts.config.priority_slots, CoreError::BotNotStale);
+            0
+        };
+
+        emit!(PositionStatusEvent {
+            position: position.key(),
+            meteora_position: ctx.accounts.meteora_position.key(),
+            is_empty: bins_with_liquidity == 0,
+            bins_with_liquidity,
+            active_id,
+            min_bin_id: position.min_bin_id,
+            max_bin_id: position.max_bin_id,
+            active_vs_range,
+        });
+
+        msg!("Position status: {} bins live | active {} vs [{},{}]",
+            bins_with_liquidity, active_id, position.min_bin_id, position.max_bin_id);
+        Ok(())
+    }
+
+    /// Read-only close quote: applies the close fee math (close_fee_split, the same
+    /// function execute_close_transfers uses) to the vault's *current* balances.
+    /// Frontends simulate the remove first, then this, so the quote can't drift from
+    /// on-chain math across upgrades. Moves no tokens.
+    ///
+    /// Return data: x_fee, y_fee, x_out, y_out as 4 little-endian u64s. x_fee / y_fee
+    /// include any referral cut (it comes out of the fee, not the owner's share).
+    pub fn preview_close(ctx: Context<PreviewClose>) -> Result<()> {
+        let (x_fee, y_fee, x_out, y_out) = close_fee_split(
+            ctx.accounts.position.side,
+            ctx.accounts.position.effective_fee_bps(ctx.accounts.config.fee_bps),
+            ctx.accounts.vault_token_x.amount,
+            ctx.accounts.vault_token_y.amount,
+        )?;
+
+        let mut data = [0u8; 32];
+        data[0..8].copy_from_slice(&x_fee.to_le_bytes());
+        data[8..16].copy_from_slice(&y_fee.to_le_bytes());
+        data[16..24].copy_from_slice(&x_out.to_le_bytes());
+        data[24..32].copy_from_slice(&y_out.to_le_bytes());
+        solana_program::program::set_return_data(&data);
+
+        msg!("Close preview: fee x={} y={} | out x={} y={}", x_fee, y_fee, x_out, y_out);
+        Ok(())
+    }
+
+    /// Bot closes position: remove all + claim fees + close Meteora position.
+    /// `min_x_out` / `min_y_out` bound what the owner receives after the protocol fee
+    /// (SlippageExceeded otherwise). Pass 0 to skip the check.
+    pub fn close_position<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClosePosition<'info>>,
+        min_x_out: u64,
+        min_y_out: u64,
+        hook_x_len: u8,
+        hook_y_len: u8,
+    ) -> Result<()> {
+        execute_bot_close(ctx, min_x_out, min_y_out, true, (hook_x_len, hook_y_len))
+    }
+
+    /// Cheap close for positions already harvested down to zero liquidity: skips the
+    /// remove_liquidity_by_range2 CPI and closes via Meteora close_position_if_empty,
+    /// which reverts if any liquidity is left (use close_position then).
+    /// Same bot / stale-bot gating, fee claim, and min-output guard as close_position.
+    pub fn close_position_empty<'info>(ctx: Context<'_, '_, 'info, 'info, ClosePosition<'info>>, min_x_out: u64, min_y_out: u64) -> Result<()> {
+        execute_bot_close(ctx, min_x_out, min_y_out, false, (0, 0))
+    }
+
+    /// Take-profit close: identical to close_position (same bot / stale-bot gating),
+    /// but only once the pool has traded through the range by the owner's margin:
+    /// active_id >= max_bin_id + position.take_profit_bins. Lets keepers close
+    /// fully-converted sell positions on a price condition the chain can verify.
+    pub fn close_if_above<'info>(ctx: Context<'_, '_, 'info, 'info, ClosePosition<'info>>, min_x_out: u64, min_y_out: u64) -> Result<()> {
+        let active_id = {
+            let data = ctx.accounts.lb_pair.try_borrow_data()?;
+            require!(data.len() >= 80, CoreError::InvalidPool);
+            i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+        };
+        let trigger = ctx.accounts.position.max_bin_id
+            .checked_add(ctx.accounts.position.take_profit_bins as i32)
+            .ok_or(CoreError::Overflow)?;
+        if active_id < trigger {
+            msg!("TakeProfitNotReached: active_id {} < trigger {}", active_id, trigger);
+            return err!(CoreError::TakeProfitNotReached);
         }
 
-        let side = ctx.accounts.position.side;
+        close_position(ctx, min_x_out, min_y_out, 0, 0)
+    }
+
+    /// User manually closes their own position. Same min-output guard as close_position.
+    pub fn user_close<'info>(ctx: Context<'_, '_, 'info, 'info, UserClose<'info>>, min_x_out: u64, min_y_out: u64) -> Result<()> {
+        execute_user_close(ctx, min_x_out, min_y_out, false, false)
+    }
+
+    /// user_close for WSOL-quoted pools that also unwraps the payout: after the
+    /// transfers, user_token_y (a temporary WSOL account of the user's holding only
+    /// this close's Y output) is closed to the user, so they get native SOL in the
+    /// same transaction. Reverts if token_y_mint isn't WSOL or the account held
+    /// other WSOL. CloseEvent.unwrapped_lamports carries the amount (incl. its rent).
+    pub fn user_close_unwrap<'info>(ctx: Context<'_, '_, 'info, 'info, UserClose<'info>>, min_x_out: u64, min_y_out: u64) -> Result<()> {
+        execute_user_close(ctx, min_x_out, min_y_out, true, false)
+    }
+
+    /// User closes their own position, claiming LP fees first (single transaction).
+    /// Same as claim_fees followed by user_close: LP fees go to the user fee-free,
+    /// then the protocol fee applies only to what the vault holds afterwards.
+    pub fn user_close_claim_first<'info>(ctx: Context<'_, '_, 'info, 'info, UserClose<'info>>, min_x_out: u64, min_y_out: u64) -> Result<()> {
+        execute_user_close(ctx, min_x_out, min_y_out, false, true)
+    }
+
+    /// Claim accrued Meteora LP fees -> owner (no protocol fee on LP fees).
+    /// Signed by the owner or position.fee_delegate; fees always land in the
+    /// owner's token accounts, never the delegate's.
+    // NOTE: claim_fees is intentionally NOT gated by config.paused.
+    // Users must always be able to withdraw their accrued LP trading fees,
+    // even when the protocol is paused for new deposits. Same rationale as
+    // harvest_bins — existing positions must remain fully accessible.
+    // Transfer-hook mints: hook accounts for X then Y are the remaining_accounts.
+    pub fn claim_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>,
+        hook_x_len: u8,
+        hook_y_len: u8,
+    ) -> Result<()> {
+        let (_, hook_accounts) = split_hook_accounts(ctx.remaining_accounts, hook_x_len, hook_y_len)
+            .ok_or(CoreError::MissingHookAccounts)?;
         let min_bin_id = ctx.accounts.position.min_bin_id;
         let max_bin_id = ctx.accounts.position.max_bin_id;
         let meteora_pos_key = ctx.accounts.position.meteora_position;
@@ -630,23 +1262,24 @@ pub mod bin_farm {
         ];
         let signer = &[vault_seeds];
 
-        // 1. Remove ALL remaining liquidity
-        let remaining = &[
+        // What quote_lp_fees would have shown, for comparison with the claimed amounts.
+        let (quoted_x, quoted_y) = claim_fees_quote(&ctx.accounts.meteora_position);
+
+        let remaining = hooks_then_bin_arrays(hook_accounts, &[
             ctx.accounts.bin_array_lower.to_account_info(),
             ctx.accounts.bin_array_upper.to_account_info(),
-        ];
-        remove_liquidity_by_range2(
+        ]);
+        claim_fee2(
             &[
-                ctx.accounts.meteora_position.to_account_info(),
                 ctx.accounts.lb_pair.to_account_info(),
-                ctx.accounts.bin_array_bitmap_ext.to_account_info(),
-                ctx.accounts.vault_token_x.to_account_info(),
-                ctx.accounts.vault_token_y.to_account_info(),
+                ctx.accounts.meteora_position.to_account_info(),
+                ctx.accounts.vault.to_account_info(),
                 ctx.accounts.reserve_x.to_account_info(),
                 ctx.accounts.reserve_y.to_account_info(),
+                ctx.accounts.vault_token_x.to_account_info(),
+                ctx.accounts.vault_token_y.to_account_info(),
                 ctx.accounts.token_x_mint.to_account_info(),
                 ctx.accounts.token_y_mint.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
                 ctx.accounts.token_x_program.to_account_info(),
                 ctx.accounts.token_y_program.to_account_info(),
                 ctx.accounts.memo_program.to_account_info(),
@@ -655,17 +1288,99 @@ pub mod bin_farm {
             ],
             min_bin_id,
             max_bin_id,
-            10_000,
-            RemainingAccountsInfo::none(),
+            RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
             signer,
-            remaining,
+            &remaining,
         )?;
 
-        // 2. Claim accrued trading fees
-        let remaining = &[
+        // Transfer claimed fees directly to user (no protocol fee on LP fees)
+        // Use token_x_program for X, token_y_program for Y (Token-2022 support)
+        ctx.accounts.vault_token_x.reload()?;
+        ctx.accounts.vault_token_y.reload()?;
+
+        let x_decimals = read_mint_decimals(&ctx.accounts.token_x_mint)?;
+        let y_decimals = read_mint_decimals(&ctx.accounts.token_y_mint)?;
+
+        // Capture amounts BEFORE transfer for event (Anchor caches deserialized data)
+        let x_claimed = ctx.accounts.vault_token_x.amount;
+        let y_claimed = ctx.accounts.vault_token_y.amount;
+
+        if ctx.accounts.vault_token_x.amount > 0 {
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.user_token_x.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_x_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_x.to_account_info(),
+                    mint: ctx.accounts.token_x_mint.to_account_info(),
+                    to: ctx.accounts.user_token_x.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), ctx.accounts.vault_token_x.amount, x_decimals)?;
+        }
+        if ctx.accounts.vault_token_y.amount > 0 {
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.user_token_y.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_y_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_y.to_account_info(),
+                    mint: ctx.accounts.token_y_mint.to_account_info(),
+                    to: ctx.accounts.user_token_y.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), ctx.accounts.vault_token_y.amount, y_decimals)?;
+        }
+
+        // Emit event using pre-transfer captured amounts (stale cache fix)
+        emit!(ClaimFeesEvent {
+            position: ctx.accounts.position.key(),
+            user: ctx.accounts.position.owner,
+            claimed_by: ctx.accounts.user.key(),
+            lb_pair: ctx.accounts.position.lb_pair,
+            x_amount: x_claimed,
+            y_amount: y_claimed,
+            quoted_x,
+            quoted_y,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("LP fees claimed");
+        Ok(())
+    }
+
+    /// claim_fees, but the claimed token the range can take is redeposited into
+    /// [min_bin_id, max_bin_id] (SpotImBalanced, vault signs) instead of being paid
+    /// out: X when the whole range sits above active_id, Y when it sits below. No
+    /// protocol fee, same as claim_fees. The other token — or both, when the range
+    /// straddles active_id — goes to the owner.
+    ///
+    /// Only what this claim moved into the vault (the before/after delta) is
+    /// compounded or paid out; anything the vault already held stays put. A claimed
+    /// amount under MIN_COMPOUND_AMOUNT_PER_BIN per bin is paid out instead.
+    pub fn claim_fees_compound<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimFeesCompound<'info>>,
+        hook_x_len: u8,
+        hook_y_len: u8,
+    ) -> Result<()> {
+        let (_, hook_accounts) = split_hook_accounts(ctx.remaining_accounts, hook_x_len, hook_y_len)
+            .ok_or(CoreError::MissingHookAccounts)?;
+        let min_bin_id = ctx.accounts.position.min_bin_id;
+        let max_bin_id = ctx.accounts.position.max_bin_id;
+        let meteora_pos_key = ctx.accounts.position.meteora_position;
+
+        let vault_seeds: &[&[u8]] = &[
+            b"vault",
+            meteora_pos_key.as_ref(),
+            &[ctx.accounts.vault.bump],
+        ];
+        let signer = &[vault_seeds];
+
+        let x_before = ctx.accounts.vault_token_x.amount;
+        let y_before = ctx.accounts.vault_token_y.amount;
+
+        let remaining = hooks_then_bin_arrays(hook_accounts, &[
             ctx.accounts.bin_array_lower.to_account_info(),
             ctx.accounts.bin_array_upper.to_account_info(),
-        ];
+        ]);
         claim_fee2(
             &[
                 ctx.accounts.lb_pair.to_account_info(),
@@ -685,66 +1400,113 @@ pub mod bin_farm {
             ],
             min_bin_id,
             max_bin_id,
-            RemainingAccountsInfo::none(),
+            RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
             signer,
-            remaining,
+            &remaining,
         )?;
 
-        // 3. Close Meteora position (rent -> bot)
-        close_position2(
-            &[
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.bot.to_account_info(),
-                ctx.accounts.event_authority.to_account_info(),
-                ctx.accounts.dlmm_program.to_account_info(),
-            ],
-            signer,
-        )?;
+        ctx.accounts.vault_token_x.reload()?;
+        ctx.accounts.vault_token_y.reload()?;
+        let x_claimed = ctx.accounts.vault_token_x.amount.saturating_sub(x_before);
+        let y_claimed = ctx.accounts.vault_token_y.amount.saturating_sub(y_before);
 
-        let position_key = ctx.accounts.position.key();
-        let owner_key = ctx.accounts.owner.key();
+        // X is accepted only above active_id, Y only below (same rule as compound_harvest)
+        let active_id = {
+            let data = ctx.accounts.lb_pair.try_borrow_data()?;
+            require!(data.len() >= 80, CoreError::InvalidPool);
+            i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+        };
+        let (x_compounded, y_compounded, x_paid_out, y_paid_out) =
+            fee_compound_amounts(min_bin_id, max_bin_id, active_id, x_claimed, y_claimed)?;
+        if x_compounded > 0 || y_compounded > 0 {
+            add_liquidity_by_strategy2(
+                &[
+                    ctx.accounts.meteora_position.to_account_info(),
+                    ctx.accounts.lb_pair.to_account_info(),
+                    ctx.accounts.bin_array_bitmap_ext.to_account_info(),
+                    ctx.accounts.vault_token_x.to_account_info(),
+                    ctx.accounts.vault_token_y.to_account_info(),
+                    ctx.accounts.reserve_x.to_account_info(),
+                    ctx.accounts.reserve_y.to_account_info(),
+                    ctx.accounts.token_x_mint.to_account_info(),
+                    ctx.accounts.token_y_mint.to_account_info(),
+                    ctx.accounts.vault.to_account_info(),
+                    ctx.accounts.token_x_program.to_account_info(),
+                    ctx.accounts.token_y_program.to_account_info(),
+                    ctx.accounts.event_authority.to_account_info(),
+                    ctx.accounts.dlmm_program.to_account_info(),
+                ],
+                LiquidityParameterByStrategy {
+                    amount_x: x_compounded,
+                    amount_y: y_compounded,
+                    active_id,
+                    max_active_bin_slippage: MAX_ACTIVE_BIN_SLIPPAGE,
+                    strategy_parameters: StrategyParameters::spot_imbalanced(min_bin_id, max_bin_id),
+                },
+                RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
+                signer,
+                &remaining,
+            )?;
+        } else {
+            msg!("Compound skipped (x={} y={} active={})", x_claimed, y_claimed, active_id);
+        }
 
-        let (x_fee, y_fee, x_out, y_out) = execute_close_transfers(
-            side,
-            ctx.accounts.config.fee_bps,
-            &mut ctx.accounts.vault_token_x,
-            &mut ctx.accounts.vault_token_y,
-            &ctx.accounts.owner_token_x.to_account_info(),
-            &ctx.accounts.owner_token_y.to_account_info(),
-            &ctx.accounts.rover_fee_token_y.to_account_info(),
-            &ctx.accounts.rover_fee_token_x.to_account_info(),
-            &ctx.accounts.vault.to_account_info(),
-            &ctx.accounts.owner,
-            &ctx.accounts.token_x_program.to_account_info(),
-            &ctx.accounts.token_y_program.to_account_info(),
-            &ctx.accounts.token_x_mint.to_account_info(),
-            &ctx.accounts.token_y_mint.to_account_info(),
-            &ctx.accounts.memo_program,
-            signer,
-        )?;
+        // Pay out the rest of the claim: the token the range can't take, or a
+        // compoundable one below the floor.
 
-        let close_harvested = match side { Side::Buy => x_out, Side::Sell => y_out };
-        ctx.accounts.config.total_harvested = ctx.accounts.config.total_harvested
-            .checked_add(close_harvested).ok_or(CoreError::Overflow)?;
+        if x_paid_out > 0 {
+            let x_decimals = read_mint_decimals(&ctx.accounts.token_x_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.user_token_x.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_x_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_x.to_account_info(),
+                    mint: ctx.accounts.token_x_mint.to_account_info(),
+                    to: ctx.accounts.user_token_x.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), x_paid_out, x_decimals)?;
+        }
+        if y_paid_out > 0 {
+            let y_decimals = read_mint_decimals(&ctx.accounts.token_y_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.user_token_y.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_y_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_y.to_account_info(),
+                    mint: ctx.accounts.token_y_mint.to_account_info(),
+                    to: ctx.accounts.user_token_y.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), y_paid_out, y_decimals)?;
+        }
 
-        emit!(CloseEvent {
-            position: position_key,
-            owner: owner_key,
-            side,
-            token_x_out: x_out,
-            token_y_out: y_out,
-            x_fee,
-            y_fee,
-            bot_initiated: true,
+        emit!(FeesCompoundedEvent {
+            position: ctx.accounts.position.key(),
+            user: ctx.accounts.position.owner,
+            claimed_by: ctx.accounts.user.key(),
+            lb_pair: ctx.accounts.position.lb_pair,
+            x_claimed,
+            y_claimed,
+            x_compounded,
+            y_compounded,
+            x_paid_out,
+            y_paid_out,
+            timestamp: Clock::get()?.unix_timestamp,
         });
 
+        msg!("LP fees compounded: x={} y={} | paid out x={} y={}",
+            x_compounded, y_compounded, x_paid_out, y_paid_out);
         Ok(())
     }
 
-    /// User manually closes their own position
-    pub fn user_close(ctx: Context<UserClose>) -> Result<()> {
-        let side = ctx.accounts.position.side;
+    /// Claim Meteora LM (farm) rewards for one reward slot. Rewards land in the vault's
+    /// reward-mint account, the position's protocol fee (effective_fee_bps) goes to the
+    /// rover_authority account for that mint, and the remainder to the owner. Owner-only,
+    /// like claim_fees.
+    pub fn claim_reward(ctx: Context<ClaimReward>, reward_index: u64) -> Result<()> {
+        require!(reward_index < NUM_REWARDS, CoreError::InvalidRewardIndex);
+
         let min_bin_id = ctx.accounts.position.min_bin_id;
         let max_bin_id = ctx.accounts.position.max_bin_id;
         let meteora_pos_key = ctx.accounts.position.meteora_position;
@@ -756,59 +1518,26 @@ pub mod bin_farm {
         ];
         let signer = &[vault_seeds];
 
-        // 1. Remove all liquidity
-        let remaining = &[
-            ctx.accounts.bin_array_lower.to_account_info(),
-            ctx.accounts.bin_array_upper.to_account_info(),
-        ];
-        remove_liquidity_by_range2(
-            &[
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.lb_pair.to_account_info(),
-                ctx.accounts.bin_array_bitmap_ext.to_account_info(),
-                ctx.accounts.vault_token_x.to_account_info(),
-                ctx.accounts.vault_token_y.to_account_info(),
-                ctx.accounts.reserve_x.to_account_info(),
-                ctx.accounts.reserve_y.to_account_info(),
-                ctx.accounts.token_x_mint.to_account_info(),
-                ctx.accounts.token_y_mint.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.token_x_program.to_account_info(),
-                ctx.accounts.token_y_program.to_account_info(),
-                ctx.accounts.memo_program.to_account_info(),
-                ctx.accounts.event_authority.to_account_info(),
-                ctx.accounts.dlmm_program.to_account_info(),
-            ],
-            min_bin_id,
-            max_bin_id,
-            10_000,
-            RemainingAccountsInfo::none(),
-            signer,
-            remaining,
-        )?;
+        let before = ctx.accounts.vault_reward_token.amount;
 
-        // 2. Claim fees
         let remaining = &[
             ctx.accounts.bin_array_lower.to_account_info(),
             ctx.accounts.bin_array_upper.to_account_info(),
         ];
-        claim_fee2(
+        claim_reward2(
             &[
                 ctx.accounts.lb_pair.to_account_info(),
                 ctx.accounts.meteora_position.to_account_info(),
                 ctx.accounts.vault.to_account_info(),
-                ctx.accounts.reserve_x.to_account_info(),
-                ctx.accounts.reserve_y.to_account_info(),
-                ctx.accounts.vault_token_x.to_account_info(),
-                ctx.accounts.vault_token_y.to_account_info(),
-                ctx.accounts.token_x_mint.to_account_info(),
-                ctx.accounts.token_y_mint.to_account_info(),
-                ctx.accounts.token_x_program.to_account_info(),
-                ctx.accounts.token_y_program.to_account_info(),
+                ctx.accounts.reward_vault.to_account_info(),
+                ctx.accounts.reward_mint.to_account_info(),
+                ctx.accounts.vault_reward_token.to_account_info(),
+                ctx.accounts.reward_token_program.to_account_info(),
                 ctx.accounts.memo_program.to_account_info(),
                 ctx.accounts.event_authority.to_account_info(),
                 ctx.accounts.dlmm_program.to_account_info(),
             ],
+            reward_index,
             min_bin_id,
             max_bin_id,
             RemainingAccountsInfo::none(),
@@ -816,68 +1545,63 @@ pub mod bin_farm {
             remaining,
         )?;
 
-        // 3. Close Meteora position (rent -> user)
-        close_position2(
-            &[
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.user.to_account_info(),
-                ctx.accounts.event_authority.to_account_info(),
-                ctx.accounts.dlmm_program.to_account_info(),
-            ],
-            signer,
-        )?;
-
-        let position_key = ctx.accounts.position.key();
-        let user_key = ctx.accounts.user.key();
-
-        let (x_fee, y_fee, x_out, y_out) = execute_close_transfers(
-            side,
-            ctx.accounts.config.fee_bps,
-            &mut ctx.accounts.vault_token_x,
-            &mut ctx.accounts.vault_token_y,
-            &ctx.accounts.user_token_x.to_account_info(),
-            &ctx.accounts.user_token_y.to_account_info(),
-            &ctx.accounts.rover_fee_token_y.to_account_info(),
-            &ctx.accounts.rover_fee_token_x.to_account_info(),
-            &ctx.accounts.vault.to_account_info(),
-            &ctx.accounts.user.to_account_info(),
-            &ctx.accounts.token_x_program.to_account_info(),
-            &ctx.accounts.token_y_program.to_account_info(),
-            &ctx.accounts.token_x_mint.to_account_info(),
-            &ctx.accounts.token_y_mint.to_account_info(),
-            &ctx.accounts.memo_program,
-            signer,
-        )?;
+        ctx.accounts.vault_reward_token.reload()?;
+        let fee_bps = ctx.accounts.position.effective_fee_bps(ctx.accounts.config.fee_bps);
+        let (claimed, fee, to_owner) =
+            reward_claim_split(before, ctx.accounts.vault_reward_token.amount, fee_bps)?;
+        let decimals = read_mint_decimals(&ctx.accounts.reward_mint)?;
 
-        let close_harvested = match side { Side::Buy => x_out, Side::Sell => y_out };
-        ctx.accounts.config.total_harvested = ctx.accounts.config.total_harvested
-            .checked_add(close_harvested).ok_or(CoreError::Overflow)?;
+        if fee > 0 {
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.rover_fee_reward.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.reward_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_reward_token.to_account_info(),
+                    mint: ctx.accounts.reward_mint.to_account_info(),
+                    to: ctx.accounts.rover_fee_reward.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), fee, decimals)?;
+        }
+        if to_owner > 0 {
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.user_reward_token.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.reward_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_reward_token.to_account_info(),
+                    mint: ctx.accounts.reward_mint.to_account_info(),
+                    to: ctx.accounts.user_reward_token.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), to_owner, decimals)?;
+        }
 
-        emit!(CloseEvent {
-            position: position_key,
-            owner: user_key,
-            side,
-            token_x_out: x_out,
-            token_y_out: y_out,
-            x_fee,
-            y_fee,
-            bot_initiated: false,
+        emit!(RewardClaimedEvent {
+            position: ctx.accounts.position.key(),
+            user: ctx.accounts.user.key(),
+            lb_pair: ctx.accounts.position.lb_pair,
+            reward_mint: ctx.accounts.reward_mint.key(),
+            reward_index,
+            claimed,
+            fee,
+            to_owner,
+            timestamp: Clock::get()?.unix_timestamp,
         });
 
+        msg!("Reward {} claimed: {} (fee {})", reward_index, claimed, fee);
         Ok(())
     }
 
-    /// Claim accrued Meteora LP fees -> user (no protocol fee on LP fees)
-    // NOTE: claim_fees is intentionally NOT gated by config.paused.
-    // Users must always be able to withdraw their accrued LP trading fees,
-    // even when the protocol is paused for new deposits. Same rationale as
-    // harvest_bins — existing positions must remain fully accessible.
-    pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
-        let min_bin_id = ctx.accounts.position.min_bin_id;
-        let max_bin_id = ctx.accounts.position.max_bin_id;
-        let meteora_pos_key = ctx.accounts.position.meteora_position;
+    /// Owner pulls stray X/Y balances out of the vault token accounts while keeping the
+    /// position open. The vault is a pipe — every harvest / claim empties it in the same
+    /// tx — so anything sitting there (e.g. left by a failed mid-harvest CPI) is not
+    /// liquidity. No Meteora CPI, no protocol fee. Not gated by pause.
+    pub fn rescue_vault_dust(ctx: Context<RescueVaultDust>) -> Result<()> {
+        let x_amount = ctx.accounts.vault_token_x.amount;
+        let y_amount = ctx.accounts.vault_token_y.amount;
+        require!(x_amount > 0 || y_amount > 0, CoreError::ZeroAmount);
 
+        let meteora_pos_key = ctx.accounts.position.meteora_position;
         let vault_seeds: &[&[u8]] = &[
             b"vault",
             meteora_pos_key.as_ref(),
@@ -885,205 +1609,755 @@ pub mod bin_farm {
         ];
         let signer = &[vault_seeds];
 
-        let remaining = &[
-            ctx.accounts.bin_array_lower.to_account_info(),
-            ctx.accounts.bin_array_upper.to_account_info(),
-        ];
-        claim_fee2(
-            &[
-                ctx.accounts.lb_pair.to_account_info(),
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.reserve_x.to_account_info(),
-                ctx.accounts.reserve_y.to_account_info(),
-                ctx.accounts.vault_token_x.to_account_info(),
-                ctx.accounts.vault_token_y.to_account_info(),
-                ctx.accounts.token_x_mint.to_account_info(),
-                ctx.accounts.token_y_mint.to_account_info(),
-                ctx.accounts.token_x_program.to_account_info(),
-                ctx.accounts.token_y_program.to_account_info(),
-                ctx.accounts.memo_program.to_account_info(),
-                ctx.accounts.event_authority.to_account_info(),
-                ctx.accounts.dlmm_program.to_account_info(),
-            ],
-            min_bin_id,
-            max_bin_id,
-            RemainingAccountsInfo::none(),
-            signer,
-            remaining,
-        )?;
-
-        // Transfer claimed fees directly to user (no protocol fee on LP fees)
-        // Use token_x_program for X, token_y_program for Y (Token-2022 support)
-        ctx.accounts.vault_token_x.reload()?;
-        ctx.accounts.vault_token_y.reload()?;
-
-        let x_decimals = read_mint_decimals(&ctx.accounts.token_x_mint)?;
-        let y_decimals = read_mint_decimals(&ctx.accounts.token_y_mint)?;
-
-        // Capture amounts BEFORE transfer for event (Anchor caches deserialized data)
-        let x_claimed = ctx.accounts.vault_token_x.amount;
-        let y_claimed = ctx.accounts.vault_token_y.amount;
-
-        if ctx.accounts.vault_token_x.amount > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
+        if x_amount > 0 {
+            let x_decimals = read_mint_decimals(&ctx.accounts.token_x_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.owner_token_x.to_account_info(), signer)?;
             transfer_checked(CpiContext::new_with_signer(
                 ctx.accounts.token_x_program.to_account_info(),
                 TransferChecked {
                     from: ctx.accounts.vault_token_x.to_account_info(),
                     mint: ctx.accounts.token_x_mint.to_account_info(),
-                    to: ctx.accounts.user_token_x.to_account_info(),
+                    to: ctx.accounts.owner_token_x.to_account_info(),
                     authority: ctx.accounts.vault.to_account_info(),
                 }, signer,
-            ), ctx.accounts.vault_token_x.amount, x_decimals)?;
+            ), x_amount, x_decimals)?;
         }
-        if ctx.accounts.vault_token_y.amount > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
+        if y_amount > 0 {
+            let y_decimals = read_mint_decimals(&ctx.accounts.token_y_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.owner_token_y.to_account_info(), signer)?;
             transfer_checked(CpiContext::new_with_signer(
                 ctx.accounts.token_y_program.to_account_info(),
                 TransferChecked {
                     from: ctx.accounts.vault_token_y.to_account_info(),
                     mint: ctx.accounts.token_y_mint.to_account_info(),
-                    to: ctx.accounts.user_token_y.to_account_info(),
+                    to: ctx.accounts.owner_token_y.to_account_info(),
                     authority: ctx.accounts.vault.to_account_info(),
                 }, signer,
-            ), ctx.accounts.vault_token_y.amount, y_decimals)?;
+            ), y_amount, y_decimals)?;
         }
 
-        // Emit event using pre-transfer captured amounts (stale cache fix)
-        emit!(ClaimFeesEvent {
+        emit!(VaultRescueEvent {
             position: ctx.accounts.position.key(),
-            user: ctx.accounts.user.key(),
-            lb_pair: ctx.accounts.position.lb_pair,
-            x_amount: x_claimed,
-            y_amount: y_claimed,
+            owner: ctx.accounts.owner.key(),
+            x_amount,
+            y_amount,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("LP fees claimed");
+        msg!("Vault dust rescued: x={} y={}", x_amount, y_amount);
         Ok(())
     }
 
-    // ============ ADMIN ============
+    /// Hand a position to another wallet (OTC sale, multisig) without closing it.
+    /// Only Position.owner is rewritten — the Meteora position, vault, and accrued LP
+    /// fees stay put, and close/harvest already route to token accounts owned by
+    /// whatever position.owner is at that time. PDA seeds don't include the owner.
+    /// A counted position moves its OwnerStats slot along, so the new owner's
+    /// max_positions_per_owner cap applies (owner pays the new owner's OwnerStats rent).
+    /// Uncounted positions (rovers, pre-cap) leave both OwnerStats untouched.
+    pub fn transfer_position(ctx: Context<TransferPosition>, new_owner: Pubkey) -> Result<()> {
+        if ctx.accounts.position.owner_counted {
+            release_owner_slot(&ctx.accounts.owner_stats, &ctx.accounts.position)?;
+            take_owner_slot(
+                &ctx.accounts.new_owner_stats,
+                &new_owner,
+                ctx.bumps.new_owner_stats,
+                ctx.accounts.config.max_positions_per_owner,
+                &ctx.accounts.owner.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
 
-    pub fn pause(ctx: Context<AdminOnly>) -> Result<()> {
-        ctx.accounts.config.paused = true;
+        let position = &mut ctx.accounts.position;
+        let previous_owner = position.transfer_to(new_owner)?;
+
+        emit!(PositionTransferredEvent {
+            position: position.key(),
+            previous_owner,
+            new_owner,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Position transferred: {} -> {}", previous_owner, new_owner);
         Ok(())
     }
 
-    pub fn unpause(ctx: Context<AdminOnly>) -> Result<()> {
-        ctx.accounts.config.paused = false;
+    /// Owner-set label / strategy tag (e.g. "DCA exit", "moon bag"). Cosmetic only —
+    /// no financial logic reads it. UTF-8, zero-padded to 32 bytes.
+    /// Positions created before the label field existed are reallocated here (owner
+    /// pays the rent top-up), so the position is read raw rather than as Account<Position>.
+    pub fn set_position_label(ctx: Context<SetPositionLabel>, label: [u8; 32]) -> Result<()> {
+        let position_info = ctx.accounts.position.to_account_info();
+        let owner = read_raw_position_owner(&position_info)?;
+        require!(owner == ctx.accounts.owner.key(), CoreError::Unauthorized);
+
+        // Label must be UTF-8 up to the first zero byte, zero padding after it
+        let len = label.iter().position(|b| *b == 0).unwrap_or(label.len());
+        require!(core::str::from_utf8(&label[..len]).is_ok(), CoreError::InvalidLabel);
+        require!(label[len..].iter().all(|b| *b == 0), CoreError::InvalidLabel);
+
+        // Legacy layout: grow to Position::SIZE, owner covers the extra rent
+        grow_account(
+            &position_info,
+            Position::SIZE,
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        position_info.try_borrow_mut_data()?[Position::LABEL_OFFSET..Position::LABEL_END]
+            .copy_from_slice(&label);
+
+        emit!(LabelSetEvent {
+            position: position_info.key(),
+            owner: ctx.accounts.owner.key(),
+            label,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Position label set: {}", core::str::from_utf8(&label[..len]).unwrap_or(""));
         Ok(())
     }
 
-    pub fn bot_pause(ctx: Context<AdminOnly>) -> Result<()> {
-        ctx.accounts.config.bot_paused = true;
-        emit!(AdminConfigEvent {
-            field: "bot_paused".into(),
-            authority: ctx.accounts.authority.key(),
+    /// Owner sets (or clears, with Pubkey::default()) a key that may call claim_fees
+    /// on their behalf. The delegate can only trigger the claim — fees still go to
+    /// the owner's token accounts. Legacy positions need migrate_position first.
+    pub fn set_fee_delegate(ctx: Context<SetFeeDelegate>, fee_delegate: Pubkey) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.fee_delegate = fee_delegate;
+        emit!(FeeDelegateSetEvent {
+            position: position.key(),
+            owner: ctx.accounts.owner.key(),
+            fee_delegate,
             timestamp: Clock::get()?.unix_timestamp,
         });
-        msg!("Bot close paused");
+        msg!("Fee delegate set: {}", fee_delegate);
         Ok(())
     }
 
-    pub fn bot_unpause(ctx: Context<AdminOnly>) -> Result<()> {
-        ctx.accounts.config.bot_paused = false;
-        emit!(AdminConfigEvent {
-            field: "bot_unpaused".into(),
-            authority: ctx.accounts.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
+    /// Permissionless: grow a Position created with an older layout to Position::SIZE so
+    /// it deserializes as Account<Position> again. New fields start at zero (fee totals
+    /// only count fees taken after migration). Payer covers the rent top-up. No-op if
+    /// the account is already current.
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        let position_info = ctx.accounts.position.to_account_info();
+        read_raw_position_owner(&position_info)?;
+        let old_len = position_info.data_len();
+
+        grow_account(
+            &position_info,
+            Position::SIZE,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        emit!(PositionMigratedEvent {
+            position: position_info.key(),
+            old_len: old_len as u32,
+            new_len: position_info.data_len() as u32,
+            payer: ctx.accounts.payer.key(),
         });
-        msg!("Bot close unpaused");
+
+        msg!("Position migrated: {} -> {} bytes", old_len, position_info.data_len());
         Ok(())
     }
 
-    pub fn update_bot(ctx: Context<AdminOnly>, new_bot: Pubkey) -> Result<()> {
-        ctx.accounts.config.bot = new_bot;
-        emit!(AdminConfigEvent {
-            field: "bot".into(),
-            authority: ctx.accounts.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
+    /// Read-only: per-position tax summary. Gross proceeds (harvested_amount, converted
+    /// side, net of fees) and cumulative protocol fees paid, straight from account state.
+    /// Emits PositionTaxQuoteEvent.
+    pub fn quote_position_tax(ctx: Context<QuotePositionTax>) -> Result<()> {
+        let position = &ctx.accounts.position;
+
+        emit!(PositionTaxQuoteEvent {
+            position: position.key(),
+            owner: position.owner,
+            lb_pair: position.lb_pair,
+            side: position.side,
+            initial_amount: position.initial_amount,
+            harvested_amount: position.harvested_amount,
+            total_fees_paid_x: position.total_fees_paid_x,
+            total_fees_paid_y: position.total_fees_paid_y,
+            created_at: position.created_at,
         });
+
+        msg!("Position tax: harvested={} fees_x={} fees_y={}",
+            position.harvested_amount, position.total_fees_paid_x, position.total_fees_paid_y);
         Ok(())
     }
 
-    pub fn update_keeper_tip_bps(ctx: Context<AdminOnly>, new_bps: u16) -> Result<()> {
-        require!(new_bps <= 5000, CoreError::FeeTooHigh); // cap at 50%
-        ctx.accounts.config.keeper_tip_bps = new_bps;
-        emit!(AdminConfigEvent {
-            field: "keeper_tip_bps".into(),
+    /// Quarantine (or release) one Meteora pool: blocks open_position_v2 and rover
+    /// opens on lb_pair while the rest of the protocol stays live. Harvest, close and
+    /// claim never read PoolStatus, so existing positions on the pool can still exit.
+    pub fn set_pool_blocked(ctx: Context<SetPoolBlocked>, blocked: bool) -> Result<()> {
+        let status = &mut ctx.accounts.pool_status;
+        status.lb_pair = ctx.accounts.lb_pair.key();
+        status.blocked = blocked;
+        status.bump = ctx.bumps.pool_status;
+
+        emit!(PoolBlockedEvent {
+            lb_pair: status.lb_pair,
+            blocked,
             authority: ctx.accounts.authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
-        msg!("Keeper tip updated: {} bps", new_bps);
+
+        msg!("Pool {} blocked={}", status.lb_pair, blocked);
         Ok(())
     }
 
-    // Cap priority_slots to prevent permanent disabling of permissionless fallback
-    pub fn update_priority_slots(ctx: Context<AdminOnly>, new_slots: u64) -> Result<()> {
-        require!(new_slots <= 9000, CoreError::PrioritySlotsExceedMax);
-        ctx.accounts.config.priority_slots = new_slots;
-        emit!(AdminConfigEvent {
-            field: "priority_slots".into(),
+    /// Per-pool dust floor for open_position_v2, in the deposit token's base units
+    /// (e.g. higher for 9-decimal memecoin pairs than 6-decimal stables). 0 clears the
+    /// override so Config.min_position_amount applies. Rover opens keep their own
+    /// per-bin minimum.
+    pub fn set_pool_min_position_amount(ctx: Context<SetPoolBlocked>, min_position_amount: u64) -> Result<()> {
+        let status = &mut ctx.accounts.pool_status;
+        status.lb_pair = ctx.accounts.lb_pair.key();
+        status.min_position_amount = min_position_amount;
+        status.bump = ctx.bumps.pool_status;
+
+        emit!(PoolMinPositionAmountEvent {
+            lb_pair: status.lb_pair,
+            min_position_amount,
             authority: ctx.accounts.authority.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
-        msg!("Priority slots updated: {}", new_slots);
+
+        msg!("Pool {} min_position_amount={}", status.lb_pair, min_position_amount);
         Ok(())
     }
 
-    /// Fee changes use a 24-hour timelock.
-    /// Step 1: propose_fee sets pending_fee_bps and fee_change_at.
-    /// Step 2: apply_fee (permissionless) applies it after the delay.
-    /// Users can see pending changes on-chain and close positions before they take effect.
-    pub fn propose_fee(ctx: Context<AdminOnly>, new_fee_bps: u16) -> Result<()> {
-        require!(new_fee_bps <= 1000, CoreError::FeeTooHigh);
-        let config = &mut ctx.accounts.config;
-        if config.fee_change_at > 0 {
-            emit!(FeeChangeCancelledEvent {
-                cancelled_fee_bps: config.pending_fee_bps,
-                was_effective_at: config.fee_change_at,
+    /// Permissionless: grow a PoolStatus created before min_position_amount existed to
+    /// PoolStatus::SIZE so set_pool_blocked / set_pool_min_position_amount can load it
+    /// again. The new field starts at 0 (no override). Payer covers the rent top-up.
+    pub fn migrate_pool_status(ctx: Context<MigratePoolStatus>) -> Result<()> {
+        let status_info = ctx.accounts.pool_status.to_account_info();
+        require!(status_info.owner == &crate::ID, CoreError::InvalidPoolStatus);
+        {
+            let data = status_info.try_borrow_data()?;
+            require!(
+                data.len() >= PoolStatus::LEGACY_SIZE && data[..8] == *PoolStatus::DISCRIMINATOR,
+                CoreError::InvalidPoolStatus
+            );
+        }
+        let old_len = status_info.data_len();
+
+        grow_account(
+            &status_info,
+            PoolStatus::SIZE,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        msg!("PoolStatus migrated: {} -> {} bytes", old_len, status_info.data_len());
+        Ok(())
+    }
+
+    /// Permissionless: grow the deployed Config (Config::DEPLOYED_SIZE) to Config::SIZE
+    /// in one step. The whole extension, reserved tail included, is sized up front, so
+    /// this is the only growth the Config goes through; later fields carve out of
+    /// _reserved_ext. Defaults are written by Config::init_extension.
+    ///
+    /// Upgrade order for the deployed Config: deploy bin-farm, then call this in the
+    /// same session — until it runs the shorter account fails to load everywhere, so
+    /// every other instruction is down. Then monke-bananas migrate_state and
+    /// init_sol_liability (see that program).
+    pub fn migrate_config_space(ctx: Context<MigrateConfigSpace>) -> Result<()> {
+        let config_info = ctx.accounts.config.to_account_info();
+        require!(config_info.owner == &crate::ID, CoreError::Unauthorized);
+        {
+            let data = config_info.try_borrow_data()?;
+            require!(
+                data.len() >= Config::DEPLOYED_SIZE && data[..8] == *Config::DISCRIMINATOR,
+                CoreError::ConfigVersionUnsupported
+            );
+        }
+        let old_len = config_info.data_len();
+        if old_len >= Config::SIZE {
+            msg!("Config already {} bytes", old_len);
+            return Ok(());
+        }
+        require!(old_len == Config::DEPLOYED_SIZE, CoreError::ConfigVersionUnsupported);
+
+        grow_account(
+            &config_info,
+            Config::SIZE,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        {
+            let mut data = config_info.try_borrow_mut_data()?;
+            let mut config = Config::try_deserialize(&mut &data[..])?;
+            config.init_extension(Clock::get()?.unix_timestamp)?;
+            config.try_serialize(&mut &mut data[..])?;
+        }
+
+        msg!("Config migrated: {} -> {} bytes", old_len, Config::SIZE);
+        Ok(())
+    }
+
+    // ============ KEEPER POOLS ============
+
+    /// Register a KeeperPool PDA (["keeper_pool", authority]). Permissionless harvesters
+    /// can then direct tips to ATAs owned by the pool instead of their own wallet.
+    /// The pool's ATAs must be created (owner = pool PDA) before they receive tips.
+    pub fn register_keeper_pool(ctx: Context<RegisterKeeperPool>) -> Result<()> {
+        let pool = &mut ctx.accounts.keeper_pool;
+        pool.authority = ctx.accounts.authority.key();
+        pool.bump = ctx.bumps.keeper_pool;
+        pool.created_at = Clock::get()?.unix_timestamp;
+        pool._reserved = [0u8; 32];
+
+        emit!(KeeperPoolRegisteredEvent {
+            keeper_pool: pool.key(),
+            authority: pool.authority,
+            timestamp: pool.created_at,
+        });
+
+        msg!("Keeper pool registered: {}", pool.key());
+        Ok(())
+    }
+
+    /// Pool authority pays out pooled tips to a member (or any destination).
+    pub fn withdraw_keeper_pool(ctx: Context<WithdrawKeeperPool>, amount: u64) -> Result<()> {
+        require!(amount > 0, CoreError::ZeroAmount);
+        let authority_key = ctx.accounts.authority.key();
+        let pool_seeds: &[&[u8]] = &[
+            b"keeper_pool",
+            authority_key.as_ref(),
+            &[ctx.accounts.keeper_pool.bump],
+        ];
+        let decimals = read_mint_decimals(&ctx.accounts.mint.to_account_info())?;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.pool_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.keeper_pool.to_account_info(),
+                },
+                &[pool_seeds],
+            ),
+            amount,
+            decimals,
+        )?;
+
+        emit!(KeeperPoolWithdrawEvent {
+            keeper_pool: ctx.accounts.keeper_pool.key(),
+            destination: ctx.accounts.destination.key(),
+            mint: ctx.accounts.mint.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Keeper pool withdraw: {} to {}", amount, ctx.accounts.destination.key());
+        Ok(())
+    }
+
+    // ============ ADMIN ============
+
+    /// Blocks new deposits until unpause or config.max_pause_seconds from now,
+    /// whichever comes first. Pausing again while paused extends the window.
+    /// Harvest, close and claim paths ignore the pause.
+    pub fn pause(ctx: Context<AdminOnly>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.pause_at(Clock::get()?.unix_timestamp)?;
+        msg!("Paused until {}", config.pause_expiry_ts);
+        Ok(())
+    }
+
+    pub fn unpause(ctx: Context<AdminOnly>) -> Result<()> {
+        ctx.accounts.config.paused = false;
+        ctx.accounts.config.pause_expiry_ts = 0;
+        Ok(())
+    }
+
+    /// Bot-stale window before permissionless close_position unlocks, kept separate from
+    /// priority_slots so closes can wait longer than harvests. 0 falls back to
+    /// priority_slots. Capped at MAX_PRIORITY_SLOTS like the harvest window.
+    pub fn update_close_priority_slots(ctx: Context<AdminOnly>, new_slots: u64) -> Result<()> {
+        require!(new_slots <= MAX_PRIORITY_SLOTS, CoreError::PrioritySlotsExceedMax);
+        ctx.accounts.config.close_priority_slots = new_slots;
+        emit!(AdminConfigEvent {
+            field: "close_priority_slots".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Close priority slots updated: {}", new_slots);
+        Ok(())
+    }
+
+    /// Upper bound on a single pause (see pause). Takes effect on the next pause call.
+    pub fn update_max_pause_seconds(ctx: Context<AdminOnly>, new_seconds: i64) -> Result<()> {
+        require!(
+            (1..=MAX_PAUSE_SECONDS).contains(&new_seconds),
+            CoreError::InvalidMaxPauseSeconds
+        );
+        ctx.accounts.config.max_pause_seconds = new_seconds;
+        emit!(AdminConfigEvent {
+            field: "max_pause_seconds".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Max pause updated: {}s", new_seconds);
+        Ok(())
+    }
+
+    pub fn bot_pause(ctx: Context<AdminOnly>) -> Result<()> {
+        ctx.accounts.config.bot_paused = true;
+        emit!(AdminConfigEvent {
+            field: "bot_paused".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Bot close paused");
+        Ok(())
+    }
+
+    pub fn bot_unpause(ctx: Context<AdminOnly>) -> Result<()> {
+        ctx.accounts.config.bot_paused = false;
+        emit!(AdminConfigEvent {
+            field: "bot_unpaused".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Bot close unpaused");
+        Ok(())
+    }
+
+    /// Bring Config up to CONFIG_VERSION. Fields claimed out of _reserved since the
+    /// account's version were zero-initialized reserved bytes, so they start at 0 and
+    /// are left alone (harvest counters may already be counting); the remaining
+    /// reserved tail is cleared. No-op if already current.
+    pub fn migrate_config(ctx: Context<AdminOnly>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let from_version = config.config_version;
+        require!(from_version <= CONFIG_VERSION, CoreError::ConfigVersionUnsupported);
+        if from_version == CONFIG_VERSION {
+            msg!("Config already at version {}", CONFIG_VERSION);
+            return Ok(());
+        }
+        config._reserved = [0u8; 3];
+        config._reserved_ext = [0u8; 80];
+        config.config_version = CONFIG_VERSION;
+        emit!(AdminConfigEvent {
+            field: "config_version".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Config migrated: version {} -> {}", from_version, CONFIG_VERSION);
+        Ok(())
+    }
+
+    pub fn update_bot(ctx: Context<AdminOnly>, new_bot: Pubkey) -> Result<()> {
+        ctx.accounts.config.bot = new_bot;
+        emit!(AdminConfigEvent {
+            field: "bot".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Keeper tip changes use the propose_fee timelock (PARAM_TIMELOCK_SECONDS).
+    /// Keepers relying on the tip see ParamChangeProposedEvent a day before it lands.
+    /// Proposing over a pending change cancels it and restarts the clock.
+    pub fn propose_keeper_tip_bps(ctx: Context<AdminOnly>, new_bps: u16) -> Result<()> {
+        require!(new_bps <= 5000, CoreError::FeeTooHigh); // cap at 50%
+        let config = &mut ctx.accounts.config;
+        if config.keeper_tip_change_at > 0 {
+            emit!(ParamChangeCancelledEvent {
+                field: "keeper_tip_bps".into(),
+                cancelled_value: config.pending_keeper_tip_bps as u64,
+                was_effective_at: config.keeper_tip_change_at,
             });
         }
-        config.pending_fee_bps = new_fee_bps;
-        config.fee_change_at = Clock::get()?.unix_timestamp
-            .checked_add(86_400) // 24 hours
-            .ok_or(CoreError::Overflow)?;
-        msg!("Fee change proposed: {} bps, effective at {}", new_fee_bps, config.fee_change_at);
+        config.propose_keeper_tip_change(new_bps, Clock::get()?.unix_timestamp)?;
+        msg!("Keeper tip change proposed: {} bps, effective at {}", new_bps, config.keeper_tip_change_at);
+        emit!(ParamChangeProposedEvent {
+            field: "keeper_tip_bps".into(),
+            new_value: new_bps as u64,
+            effective_at: config.keeper_tip_change_at,
+        });
+        Ok(())
+    }
+
+    /// Apply a pending keeper tip change. Permissionless once the timelock expires.
+    pub fn apply_keeper_tip_bps(ctx: Context<ApplyFee>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let old_bps = config.apply_keeper_tip_change(Clock::get()?.unix_timestamp)?;
+        msg!("Keeper tip applied: {} bps → {} bps", old_bps, config.keeper_tip_bps);
+        emit!(ParamChangeAppliedEvent {
+            field: "keeper_tip_bps".into(),
+            old_value: old_bps as u64,
+            new_value: config.keeper_tip_bps as u64,
+        });
+        Ok(())
+    }
+
+    pub fn cancel_pending_keeper_tip_bps(ctx: Context<AdminOnly>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.keeper_tip_change_at > 0, CoreError::NoPendingFeeChange);
+        emit!(ParamChangeCancelledEvent {
+            field: "keeper_tip_bps".into(),
+            cancelled_value: config.pending_keeper_tip_bps as u64,
+            was_effective_at: config.keeper_tip_change_at,
+        });
+        config.pending_keeper_tip_bps = 0;
+        config.keeper_tip_change_at = 0;
+        msg!("Pending keeper tip change cancelled");
+        Ok(())
+    }
+
+    /// Lamport floor for permissionless keeper tips paid in wSOL, so dust harvests
+    /// still attract keepers. Never exceeds the fee actually collected.
+    pub fn update_min_keeper_tip(ctx: Context<AdminOnly>, min_keeper_tip: u64) -> Result<()> {
+        require!(min_keeper_tip <= MAX_MIN_KEEPER_TIP, CoreError::FeeTooHigh);
+        ctx.accounts.config.min_keeper_tip = min_keeper_tip;
+        emit!(AdminConfigEvent {
+            field: "min_keeper_tip".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Min keeper tip updated: {} lamports", min_keeper_tip);
+        Ok(())
+    }
+
+    /// Cap on open_position_v2 positions one wallet can hold open at once, tracked in
+    /// its OwnerStats PDA. 0 = unlimited. Lowering it never closes anything; owners
+    /// already above the cap just can't open more until they close down below it.
+    pub fn update_max_positions_per_owner(ctx: Context<AdminOnly>, max_positions: u64) -> Result<()> {
+        ctx.accounts.config.max_positions_per_owner = max_positions;
+        emit!(AdminConfigEvent {
+            field: "max_positions_per_owner".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Max positions per owner updated: {}", max_positions);
+        Ok(())
+    }
+
+    /// Share of the protocol fee paid to a position's referrer on harvest and close.
+    /// Taken after the keeper tip; the rest still goes to the rover ATAs.
+    pub fn update_referral_bps(ctx: Context<AdminOnly>, new_bps: u16) -> Result<()> {
+        require!(new_bps <= MAX_REFERRAL_BPS, CoreError::FeeTooHigh);
+        ctx.accounts.config.referral_bps = new_bps;
+        emit!(AdminConfigEvent {
+            field: "referral_bps".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Referral share updated: {} bps", new_bps);
+        Ok(())
+    }
+
+    /// Lower the per-call bin cap for harvest_bins. 0 is not allowed (use 70 to reset).
+    /// Lets operators avoid CU exhaustion mid-harvest on pools with transfer hooks.
+    pub fn update_max_bins_per_harvest(ctx: Context<AdminOnly>, new_max: u16) -> Result<()> {
+        require!(
+            new_max > 0 && new_max <= MAX_BINS_PER_HARVEST,
+            CoreError::InvalidMaxBinsPerHarvest
+        );
+        ctx.accounts.config.max_bins_per_harvest = new_max;
+        emit!(AdminConfigEvent {
+            field: "max_bins_per_harvest".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Max bins per harvest updated: {}", new_max);
+        Ok(())
+    }
+
+    /// Replace the token program allowlist. Governance can tighten (e.g. drop Token-2022)
+    /// or extend it without a redeploy. Only gates new deposits — exits stay open.
+    pub fn set_allowed_token_programs(ctx: Context<AdminOnly>, programs: Vec<Pubkey>) -> Result<()> {
+        require!(
+            !programs.is_empty() && programs.len() <= MAX_ALLOWED_TOKEN_PROGRAMS,
+            CoreError::InvalidTokenProgramAllowlist
+        );
+        let mut allowed = [Pubkey::default(); MAX_ALLOWED_TOKEN_PROGRAMS];
+        for (i, program) in programs.iter().enumerate() {
+            require!(*program != Pubkey::default(), CoreError::InvalidTokenProgramAllowlist);
+            require!(!programs[..i].contains(program), CoreError::InvalidTokenProgramAllowlist);
+            allowed[i] = *program;
+        }
+        ctx.accounts.config.allowed_token_programs = allowed;
+        emit!(AdminConfigEvent {
+            field: "allowed_token_programs".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Token program allowlist updated: {} programs", programs.len());
+        Ok(())
+    }
+
+    /// Discourage single-bin (all-or-nothing) positions where gradual conversion is
+    /// the intended product. 1 preserves the original behavior.
+    pub fn update_min_position_width(ctx: Context<AdminOnly>, new_min: u16) -> Result<()> {
+        require!(
+            new_min >= 1 && new_min as i32 <= MAX_POSITION_WIDTH,
+            CoreError::InvalidMinPositionWidth
+        );
+        ctx.accounts.config.min_position_width = new_min;
+        emit!(AdminConfigEvent {
+            field: "min_position_width".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Min position width updated: {}", new_min);
+        Ok(())
+    }
+
+    /// Global dust floor for open_position_v2 deposits, in deposit-token base units.
+    /// Pools whose decimals make that wrong get a PoolStatus override instead
+    /// (set_pool_min_position_amount). 0 is not allowed.
+    pub fn update_min_position_amount(ctx: Context<AdminOnly>, new_min: u64) -> Result<()> {
+        require!(new_min >= 1, CoreError::InvalidMinPositionAmount);
+        ctx.accounts.config.min_position_amount = new_min;
+        emit!(AdminConfigEvent {
+            field: "min_position_amount".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Min position amount updated: {}", new_min);
+        Ok(())
+    }
+
+    /// Timelocked like propose_keeper_tip_bps, so the bot-priority window can't be
+    /// stretched without a day's notice. Capped at MAX_PRIORITY_SLOTS so the
+    /// permissionless fallback can't be disabled permanently.
+    pub fn propose_priority_slots(ctx: Context<AdminOnly>, new_slots: u64) -> Result<()> {
+        require!(new_slots <= MAX_PRIORITY_SLOTS, CoreError::PrioritySlotsExceedMax);
+        let config = &mut ctx.accounts.config;
+        if config.priority_slots_change_at > 0 {
+            emit!(ParamChangeCancelledEvent {
+                field: "priority_slots".into(),
+                cancelled_value: config.pending_priority_slots,
+                was_effective_at: config.priority_slots_change_at,
+            });
+        }
+        config.propose_priority_slots_change(new_slots, Clock::get()?.unix_timestamp)?;
+        msg!("Priority slots change proposed: {}, effective at {}", new_slots, config.priority_slots_change_at);
+        emit!(ParamChangeProposedEvent {
+            field: "priority_slots".into(),
+            new_value: new_slots,
+            effective_at: config.priority_slots_change_at,
+        });
+        Ok(())
+    }
+
+    /// Apply a pending priority_slots change. Permissionless once the timelock expires.
+    pub fn apply_priority_slots(ctx: Context<ApplyFee>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let old_slots = config.apply_priority_slots_change(Clock::get()?.unix_timestamp)?;
+        msg!("Priority slots applied: {} → {}", old_slots, config.priority_slots);
+        emit!(ParamChangeAppliedEvent {
+            field: "priority_slots".into(),
+            old_value: old_slots,
+            new_value: config.priority_slots,
+        });
+        Ok(())
+    }
+
+    pub fn cancel_pending_priority_slots(ctx: Context<AdminOnly>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.priority_slots_change_at > 0, CoreError::NoPendingFeeChange);
+        emit!(ParamChangeCancelledEvent {
+            field: "priority_slots".into(),
+            cancelled_value: config.pending_priority_slots,
+            was_effective_at: config.priority_slots_change_at,
+        });
+        config.pending_priority_slots = 0;
+        config.priority_slots_change_at = 0;
+        msg!("Pending priority slots change cancelled");
+        Ok(())
+    }
+
+    /// Minimum slots between permissionless harvests of the same position.
+    /// The authorized bot is exempt. 0 disables the cooldown.
+    pub fn update_harvest_cooldown(ctx: Context<AdminOnly>, new_slots: u64) -> Result<()> {
+        require!(new_slots <= MAX_HARVEST_COOLDOWN_SLOTS, CoreError::HarvestCooldownExceedsMax);
+        ctx.accounts.config.harvest_cooldown_slots = new_slots;
+        emit!(AdminConfigEvent {
+            field: "harvest_cooldown_slots".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Harvest cooldown updated: {} slots", new_slots);
+        Ok(())
+    }
+
+    /// Set the minimum sweepable balance for permissionless sweep_rover calls.
+    /// The authorized bot is exempt. 0 disables the floor.
+    pub fn update_min_sweep(ctx: Context<AdminOnly>, min_sweep_lamports: u64) -> Result<()> {
+        require!(min_sweep_lamports <= MAX_MIN_SWEEP_LAMPORTS, CoreError::MinSweepExceedsMax);
+        ctx.accounts.config.min_sweep_lamports = min_sweep_lamports;
+        emit!(AdminConfigEvent {
+            field: "min_sweep_lamports".into(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Min sweep updated: {} lamports", min_sweep_lamports);
+        Ok(())
+    }
+
+    /// Fee changes use a 24-hour timelock.
+    /// Step 1: propose_fee sets pending_fee_bps and fee_change_at.
+    /// Step 2: apply_fee (permissionless) applies it after the delay.
+    /// Users can see pending changes on-chain and close positions before they take effect.
+    ///
+    /// Race semantics (config writes are serialized by account locking, so the
+    /// first landed tx wins and later ones see its result):
+    ///   - propose over pending: the old change is cancelled, nonce bumps, clock restarts.
+    ///   - apply vs cancel: whichever lands first wins; the other reverts NoPendingFeeChange.
+    ///   - stale apply: an apply signed for nonce N reverts StaleFeeChange once any
+    ///     later propose exists, so it can never apply a change its signer didn't see.
+    pub fn propose_fee(ctx: Context<AdminOnly>, new_fee_bps: u16) -> Result<()> {
+        require!(new_fee_bps <= 1000, CoreError::FeeTooHigh);
+        let config = &mut ctx.accounts.config;
+        if config.fee_change_at > 0 {
+            emit!(FeeChangeCancelledEvent {
+                cancelled_fee_bps: config.pending_fee_bps,
+                was_effective_at: config.fee_change_at,
+                nonce: config.fee_change_nonce,
+            });
+        }
+        config.propose_fee_change(new_fee_bps, Clock::get()?.unix_timestamp)?;
+        msg!("Fee change proposed: {} bps, effective at {} (nonce {})",
+            new_fee_bps, config.fee_change_at, config.fee_change_nonce);
         emit!(FeeChangeProposedEvent {
             new_fee_bps,
             effective_at: config.fee_change_at,
+            nonce: config.fee_change_nonce,
         });
         Ok(())
     }
 
     /// Step 2: Apply a previously proposed fee change. Permissionless — anyone can call
     /// once the timelock has expired. This ensures the change happens on schedule.
-    pub fn apply_fee(ctx: Context<ApplyFee>) -> Result<()> {
+    /// `nonce` must match the pending change (from FeeChangeProposedEvent) so a stale
+    /// apply cannot land a later proposal after the one it was signed for was cancelled.
+    pub fn apply_fee(ctx: Context<ApplyFee>, nonce: u32) -> Result<()> {
         let config = &mut ctx.accounts.config;
-        require!(config.fee_change_at > 0, CoreError::NoPendingFeeChange);
-        require!(
-            Clock::get()?.unix_timestamp >= config.fee_change_at,
-            CoreError::FeeTimelockNotExpired
-        );
-        let old_fee = config.fee_bps;
-        config.fee_bps = config.pending_fee_bps;
-        config.pending_fee_bps = 0;
-        config.fee_change_at = 0;
+        let old_fee = config.apply_fee_change(nonce, Clock::get()?.unix_timestamp)?;
         msg!("Fee applied: {} bps → {} bps", old_fee, config.fee_bps);
         emit!(FeeAppliedEvent {
             old_fee_bps: old_fee,
             new_fee_bps: config.fee_bps,
+            nonce,
         });
         Ok(())
     }
 
     pub fn cancel_pending_fee(ctx: Context<AdminOnly>) -> Result<()> {
         let config = &mut ctx.accounts.config;
-        require!(config.fee_change_at > 0, CoreError::NoPendingFeeChange);
-        config.pending_fee_bps = 0;
-        config.fee_change_at = 0;
+        let (cancelled_fee_bps, was_effective_at) = (config.pending_fee_bps, config.fee_change_at);
+        config.cancel_fee_change()?;
+        emit!(FeeChangeCancelledEvent {
+            cancelled_fee_bps,
+            was_effective_at,
+            nonce: config.fee_change_nonce,
+        });
         msg!("Pending fee change cancelled");
         Ok(())
     }
@@ -1125,6 +2399,7 @@ pub mod bin_farm {
             Clock::get()?.unix_timestamp >= config.emergency_close_at,
             CoreError::EmergencyCloseTimelockNotExpired
         );
+        release_owner_slot(&ctx.accounts.owner_stats, &ctx.accounts.position)?;
 
         // Transfer any remaining vault tokens to position owner before closing PDAs.
         // This resolves the deadlock where Meteora CPI is broken on deprecated pools
@@ -1141,7 +2416,7 @@ pub mod bin_farm {
 
         let x_amount = ctx.accounts.vault_token_x.amount;
         if x_amount > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.owner_token_x.to_account_info(), signer)?;
             transfer_checked(CpiContext::new_with_signer(
                 ctx.accounts.token_x_program.to_account_info(),
                 TransferChecked {
@@ -1155,7 +2430,7 @@ pub mod bin_farm {
 
         let y_amount = ctx.accounts.vault_token_y.amount;
         if y_amount > 0 {
-            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), signer)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.owner_token_y.to_account_info(), signer)?;
             transfer_checked(CpiContext::new_with_signer(
                 ctx.accounts.token_y_program.to_account_info(),
                 TransferChecked {
@@ -1180,16 +2455,97 @@ pub mod bin_farm {
         Ok(())
     }
 
-    // Timelocked propose/apply pattern for revenue_dest
-    pub fn propose_revenue_dest(
-        ctx: Context<UpdateRoverDistPool>,
-        new_revenue_dest: Pubkey,
-    ) -> Result<()> {
-        require!(new_revenue_dest != Pubkey::default(), CoreError::InvalidDistPool);
-        let rover = &mut ctx.accounts.rover_authority;
-        rover.pending_revenue_dest = new_revenue_dest;
-        rover.revenue_dest_change_at = Clock::get()?.unix_timestamp
-            .checked_add(86_400).ok_or(CoreError::Overflow)?;
+    /// Close a Vault whose Position PDA no longer exists, plus its two token
+    /// accounts; all rent goes to the caller. Permissionless while the vault token
+    /// accounts are empty. Leftover tokens have no recorded owner once the Position
+    /// is gone, so sweeping them to destination_token_x / _y needs config.authority
+    /// as the caller.
+    pub fn reclaim_orphan_vault(ctx: Context<ReclaimOrphanVault>) -> Result<()> {
+        let x_amount = ctx.accounts.vault_token_x.amount;
+        let y_amount = ctx.accounts.vault_token_y.amount;
+        require_orphan_reclaim(
+            &ctx.accounts.position.to_account_info(),
+            x_amount,
+            y_amount,
+            &ctx.accounts.caller.key(),
+            &ctx.accounts.config,
+        )?;
+
+        let meteora_pos_key = ctx.accounts.vault.position;
+        let vault_seeds: &[&[u8]] = &[
+            b"vault",
+            meteora_pos_key.as_ref(),
+            &[ctx.accounts.vault.bump],
+        ];
+        let signer = &[vault_seeds];
+
+        if x_amount > 0 {
+            let x_decimals = read_mint_decimals(&ctx.accounts.token_x_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.destination_token_x, signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_x_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_x.to_account_info(),
+                    mint: ctx.accounts.token_x_mint.to_account_info(),
+                    to: ctx.accounts.destination_token_x.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), x_amount, x_decimals)?;
+        }
+        if y_amount > 0 {
+            let y_decimals = read_mint_decimals(&ctx.accounts.token_y_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.destination_token_y, signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_y_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_y.to_account_info(),
+                    mint: ctx.accounts.token_y_mint.to_account_info(),
+                    to: ctx.accounts.destination_token_y.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), y_amount, y_decimals)?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_x_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault_token_x.to_account_info(),
+                destination: ctx.accounts.caller.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            }, signer,
+        ))?;
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_y_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault_token_y.to_account_info(),
+                destination: ctx.accounts.caller.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            }, signer,
+        ))?;
+
+        // Vault itself is closed by the Anchor `close` constraint on the context
+        emit!(OrphanVaultReclaimedEvent {
+            vault: ctx.accounts.vault.key(),
+            meteora_position: meteora_pos_key,
+            caller: ctx.accounts.caller.key(),
+            x_amount,
+            y_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Orphan vault reclaimed: {} x_swept={} y_swept={}", ctx.accounts.vault.key(), x_amount, y_amount);
+        Ok(())
+    }
+
+    // Timelocked propose/apply pattern for revenue_dest
+    pub fn propose_revenue_dest(
+        ctx: Context<UpdateRoverDistPool>,
+        new_revenue_dest: Pubkey,
+    ) -> Result<()> {
+        require!(new_revenue_dest != Pubkey::default(), CoreError::InvalidDistPool);
+        let rover = &mut ctx.accounts.rover_authority;
+        rover.pending_revenue_dest = new_revenue_dest;
+        rover.revenue_dest_change_at = Clock::get()?.unix_timestamp
+            .checked_add(86_400).ok_or(CoreError::Overflow)?;
         msg!("Revenue dest change proposed: {}, effective at {}", new_revenue_dest, rover.revenue_dest_change_at);
         emit!(AdminConfigEvent {
             field: "revenue_dest".into(),
@@ -1224,6 +2580,77 @@ pub mod bin_farm {
         Ok(())
     }
 
+    /// Read-only wiring check for monitoring and post-deploy verification. Permissionless.
+    /// Simulate it: reverts with a specific error if any invariant is broken, otherwise
+    /// emits HealthEvent. Checks:
+    ///   - config / rover_authority bumps are canonical
+    ///   - monke_state is the monke_bananas state PDA and dist_pool is its state.dist_pool
+    ///     (the [b"dist_pool"] PDA unless changed via propose_dist_pool / apply_dist_pool)
+    ///   - program_vault derives from [b"program_vault"] under monke_bananas
+    ///   - rover revenue_dest is either that dist_pool or the pegged_bridge bridge_vault
+    pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+        let (config_pda, config_bump) = Pubkey::find_program_address(&[b"config"], &crate::ID);
+        require!(
+            config_pda == ctx.accounts.config.key() && config_bump == ctx.accounts.config.bump,
+            CoreError::HealthConfigMismatch
+        );
+
+        let (rover_pda, rover_bump) = Pubkey::find_program_address(&[b"rover_authority"], &crate::ID);
+        require!(
+            rover_pda == ctx.accounts.rover_authority.key()
+                && rover_bump == ctx.accounts.rover_authority.bump,
+            CoreError::HealthRoverMismatch
+        );
+
+        // monke_bananas MonkeState: disc(8) + authority, pending_authority, bananas_mint,
+        // smb_collection, smb_gen3_collection (5 * 32) → dist_pool at [168..200]
+        let (state_pda, _) = Pubkey::find_program_address(&[b"monke_state"], &MONKE_BANANAS_PROGRAM_ID);
+        require!(
+            ctx.accounts.monke_state.key() == state_pda
+                && *ctx.accounts.monke_state.owner == MONKE_BANANAS_PROGRAM_ID,
+            CoreError::HealthMonkeStateMismatch
+        );
+        let state_dist_pool = {
+            let data = ctx.accounts.monke_state.try_borrow_data()?;
+            require!(data.len() >= 200, CoreError::HealthMonkeStateMismatch);
+            let disc = solana_program::hash::hash(b"account:MonkeState").to_bytes();
+            require!(data[0..8] == disc[0..8], CoreError::HealthMonkeStateMismatch);
+            Pubkey::try_from(&data[168..200]).map_err(|_| CoreError::HealthMonkeStateMismatch)?
+        };
+
+        require!(
+            state_dist_pool != Pubkey::default() && ctx.accounts.dist_pool.key() == state_dist_pool,
+            CoreError::HealthDistPoolMismatch
+        );
+
+        let (program_vault_pda, _) = Pubkey::find_program_address(&[b"program_vault"], &MONKE_BANANAS_PROGRAM_ID);
+        require!(
+            ctx.accounts.program_vault.key() == program_vault_pda,
+            CoreError::HealthProgramVaultMismatch
+        );
+
+        let (bridge_vault_pda, _) = Pubkey::find_program_address(&[b"bridge_vault"], &PEGGED_BRIDGE_PROGRAM_ID);
+        let revenue_dest = ctx.accounts.rover_authority.revenue_dest;
+        let revenue_dest_is_bridge = revenue_dest == bridge_vault_pda;
+        require!(
+            revenue_dest_is_bridge || revenue_dest == state_dist_pool,
+            CoreError::HealthRevenueDestMismatch
+        );
+
+        emit!(HealthEvent {
+            config: config_pda,
+            rover_authority: rover_pda,
+            revenue_dest,
+            revenue_dest_is_bridge,
+            dist_pool: state_dist_pool,
+            program_vault: program_vault_pda,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Health OK | revenue_dest={} (bridge={})", revenue_dest, revenue_dest_is_bridge);
+        Ok(())
+    }
+
     // ============ ROVER (bribe positions) ============
 
     /// Initialize the rover authority PDA. Admin only. Called once.
@@ -1246,173 +2673,198 @@ pub mod bin_farm {
         Ok(())
     }
 
-    /// Anyone deposits tokens into a sell-side DLMM position owned by rover_authority.
+    /// Anyone deposits tokens into a DLMM position owned by rover_authority.
     /// The harvest bot liquidates these like normal positions. SOL accumulates in
     /// rover_authority ATAs. sweep_rover sends it to dist_pool for all monke holders.
     /// This IS the bribe mechanism — rover TVL ranks pools in the frontend.
     ///
     /// Range is hardcoded to 2x current price (or MAX_POSITION_WIDTH bins, whichever
-    /// is smaller). The depositor only chooses how many tokens to put in.
-    /// Bins are placed from active_id+1 upward (sell side, above current price).
+    /// is smaller). The depositor only chooses how many tokens to put in and the side:
+    ///   Sell: token X, bins from active_id+1 upward (above current price).
+    ///   Buy:  token Y, bins from active_id-1 downward (accumulates X on the way down).
+    /// `max_active_bin_slippage` (1..=20, 0 = DEFAULT_ROVER_ACTIVE_BIN_SLIPPAGE) is
+    /// forwarded to add_liquidity; widen it on volatile pools.
     pub fn open_rover_position<'info>(
         ctx: Context<'_, '_, 'info, 'info, OpenRoverPosition<'info>>,
         amount: u64,
         bin_step: u16,
+        side: Side,
+        max_active_bin_slippage: i32,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, CoreError::Paused);
+        require!(!ctx.accounts.config.deposits_paused(Clock::get()?.unix_timestamp), CoreError::Paused);
+        require_pool_open(&ctx.accounts.pool_status)?;
         require!(amount > 0, CoreError::ZeroAmount);
         require!(amount >= MIN_ROVER_DEPOSIT, CoreError::RoverDepositTooSmall);
         require!(bin_step >= MIN_ROVER_BIN_STEP, CoreError::RoverBinStepTooSmall);
 
-        // Validate token account owners
-        {
-            let data = ctx.accounts.depositor_token_account.try_borrow_data()?;
-            require!(data.len() >= 64, CoreError::InvalidTokenOwner);
-            let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
-            require!(owner == ctx.accounts.depositor.key(), CoreError::InvalidTokenOwner);
-        }
-        {
-            let data = ctx.accounts.vault_token_x.try_borrow_data()?;
-            require!(data.len() >= 64, CoreError::InvalidTokenOwner);
-            let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
-            require!(owner == ctx.accounts.vault.key(), CoreError::InvalidTokenOwner);
-        }
+        // Overflow accounts: event_authority, dlmm_program
+        require!(ctx.remaining_accounts.len() >= 2, CoreError::NoBinsProvided);
+        let range = execute_rover_open(
+            &RoverOpenAccounts {
+                depositor: ctx.accounts.depositor.as_ref(),
+                lb_pair: &ctx.accounts.lb_pair,
+                meteora_position: ctx.accounts.meteora_position.as_ref(),
+                bin_array_bitmap_ext: &ctx.accounts.bin_array_bitmap_ext,
+                reserve_x: &ctx.accounts.reserve_x,
+                reserve_y: &ctx.accounts.reserve_y,
+                bin_array_lower: &ctx.accounts.bin_array_lower,
+                bin_array_upper: &ctx.accounts.bin_array_upper,
+                vault: ctx.accounts.vault.as_ref().as_ref(),
+                depositor_token_account: Some(&ctx.accounts.depositor_token_account),
+                vault_token_x: &ctx.accounts.vault_token_x,
+                vault_token_y: &ctx.accounts.vault_token_y,
+                token_x_mint: &ctx.accounts.token_x_mint,
+                token_y_mint: &ctx.accounts.token_y_mint,
+                token_x_program: &ctx.accounts.token_x_program,
+                token_y_program: &ctx.accounts.token_y_program,
+                system_program: ctx.accounts.system_program.as_ref(),
+                event_authority: &ctx.remaining_accounts[0],
+                dlmm_program: &ctx.remaining_accounts[1],
+            },
+            amount,
+            bin_step,
+            ctx.bumps.vault,
+            side,
+            rover_active_bin_slippage(max_active_bin_slippage)?,
+        )?;
+        let RoverRange { active_id, min_bin_id, max_bin_id, width } = range;
 
-        // C1: Read active_id from on-chain lb_pair — never trust the caller
-        let active_id = {
-            let data = ctx.accounts.lb_pair.try_borrow_data()?;
-            // Validate data length before byte slice (prevents panic on malformed accounts)
-            require!(data.len() >= 80, CoreError::InvalidPool);
-            i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+        // Capture keys before mutable borrows
+        let position_key = ctx.accounts.position.key();
+        let depositor_key = ctx.accounts.depositor.key();
+        let lb_pair_key = ctx.accounts.lb_pair.key();
+        let rover_key = ctx.accounts.rover_authority.key();
+        let meteora_pos_key2 = ctx.accounts.meteora_position.key();
+        let created_at = Clock::get()?.unix_timestamp;
+
+        let token_mint = match side {
+            Side::Sell => ctx.accounts.token_x_mint.key(),
+            Side::Buy => ctx.accounts.token_y_mint.key(),
         };
-        require!(active_id > -443636 && active_id < 443636, CoreError::InvalidBinRange);
 
-        // Overflow accounts: event_authority, dlmm_program
-        require!(ctx.remaining_accounts.len() >= 2, CoreError::NoBinsProvided);
-        let event_authority = ctx.remaining_accounts[0].to_account_info();
-        let dlmm_program = ctx.remaining_accounts[1].to_account_info();
-        require!(dlmm_program.key() == METEORA_DLMM_PROGRAM_ID, CoreError::InvalidProgram);
+        // Store position metadata — owner is rover_authority
+        ctx.accounts.position.set_inner(Position::opened(
+            POSITION_KIND_ROVER,
+            rover_key,
+            lb_pair_key,
+            meteora_pos_key2,
+            side,
+            (min_bin_id, max_bin_id),
+            amount,
+            created_at,
+            ctx.bumps.position,
+        ));
 
-        // Compute 2x range: ln(2)/ln(1+binStep/10000) ≈ 6931/binStep bins
-        // Capped at MAX_POSITION_WIDTH (70 bins)
-        let bins_for_2x = 6931_i32 / (bin_step as i32);
-        let width = if bins_for_2x < 1 { 1 } else if bins_for_2x > MAX_POSITION_WIDTH { MAX_POSITION_WIDTH } else { bins_for_2x };
-        let min_bin_id = active_id + 1; // sell side: just above current price
-        let max_bin_id = min_bin_id + width - 1;
-        let max_active_bin_slippage = 10; // hardcoded for rovers
+        let vault = &mut ctx.accounts.vault;
+        vault.position = ctx.accounts.meteora_position.key();
+        vault.bump = ctx.bumps.vault;
 
-        // Transfer deposit tokens from caller to vault
-        {
-            let transfer_ix = anchor_lang::solana_program::instruction::Instruction {
-                program_id: *ctx.accounts.token_x_program.key,
-                accounts: vec![
-                    anchor_lang::solana_program::instruction::AccountMeta::new(ctx.accounts.depositor_token_account.key(), false),
-                    anchor_lang::solana_program::instruction::AccountMeta::new(ctx.accounts.vault_token_x.key(), false),
-                    anchor_lang::solana_program::instruction::AccountMeta::new_readonly(ctx.accounts.depositor.key(), true),
-                ],
-                data: {
-                    let mut d = vec![3u8];
-                    d.extend_from_slice(&amount.to_le_bytes());
-                    d
-                },
-            };
-            anchor_lang::solana_program::program::invoke(
-                &transfer_ix,
-                &[
-                    ctx.accounts.depositor_token_account.to_account_info(),
-                    ctx.accounts.vault_token_x.to_account_info(),
-                    ctx.accounts.depositor.to_account_info(),
-                    ctx.accounts.token_x_program.to_account_info(),
-                ],
-            )?;
-        }
+        let config = &mut ctx.accounts.config;
+        config.total_positions = config.total_positions.saturating_add(1);
+        config.total_volume = config.total_volume.saturating_add(amount);
 
-        // Vault PDA signs Meteora CPIs
-        let meteora_pos_key = ctx.accounts.meteora_position.key();
-        let vault_seeds: &[&[u8]] = &[
-            b"vault",
-            meteora_pos_key.as_ref(),
-            &[ctx.bumps.vault],
-        ];
-        let signer = &[vault_seeds];
+        let rover = &mut ctx.accounts.rover_authority;
+        rover.total_rover_positions = rover.total_rover_positions.saturating_add(1);
 
-        // Initialize Meteora position (vault PDA = owner)
-        initialize_position2(
-            &[
-                ctx.accounts.depositor.to_account_info(),
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.lb_pair.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-                event_authority.clone(),
-                dlmm_program.clone(),
-            ],
+        emit!(RoverOpenedEvent {
+            depositor: depositor_key,
+            lb_pair: lb_pair_key,
+            position: position_key,
+            token_mint,
+            amount,
+            active_id,
+            bin_step,
             min_bin_id,
-            width,
-            signer,
-        )?;
+            max_bin_id,
+            native_sol_amount: 0,
+            side,
+            timestamp: created_at,
+        });
 
-        // Add sell-side liquidity with BidAsk distribution via V2 two-sided CPI
-        // (amount_y = 0 makes it effectively one-sided)
-        let liquidity_params = LiquidityParameterByStrategy {
-            amount_x: amount,
-            amount_y: 0,
-            active_id,
-            max_active_bin_slippage,
-            strategy_parameters: StrategyParameters::bid_ask_imbalanced(min_bin_id, max_bin_id),
-        };
+        msg!("Rover position opened: {:?} {} bins [{},{}] amount={}",
+            side, width, min_bin_id, max_bin_id, amount);
+        Ok(())
+    }
 
-        add_liquidity_by_strategy2(
-            &[
-                ctx.accounts.meteora_position.to_account_info(),
-                ctx.accounts.lb_pair.to_account_info(),
-                ctx.accounts.bin_array_bitmap_ext.to_account_info(),
-                ctx.accounts.vault_token_x.to_account_info(),
-                ctx.accounts.vault_token_y.to_account_info(),
-                ctx.accounts.reserve_x.to_account_info(),
-                ctx.accounts.reserve_y.to_account_info(),
-                ctx.accounts.token_x_mint.to_account_info(),
-                ctx.accounts.token_y_mint.to_account_info(),
-                ctx.accounts.vault.to_account_info(),
-                ctx.accounts.token_x_program.to_account_info(),
-                ctx.accounts.token_y_program.to_account_info(),
-                event_authority,
-                dlmm_program,
-            ],
-            liquidity_params,
-            RemainingAccountsInfo::empty_hooks(),
-            signer,
-            &[ctx.accounts.bin_array_lower.to_account_info(), ctx.accounts.bin_array_upper.to_account_info()],
+    /// Native-SOL bribe on the buy side of a SOL-quoted pool (token Y = wSOL).
+    /// Lamports are wrapped into the vault's wSOL ATA (created by the client beforehand,
+    /// like the token-deposit path), and BidAsk liquidity is placed from active_id-1
+    /// downward over the same 2x-width range. No pre-wrapping by the depositor.
+    /// `max_active_bin_slippage` as in open_rover_position.
+    ///
+    /// The position is owned by rover_authority, so harvested token X lands in a
+    /// rover_authority token X account (owner_token_x) and is recycled by open_fee_rover.
+    /// On close, leftover wSOL goes out with the rest of the vault balance and the vault
+    /// wSOL ATA is closed to rover_authority (picked up by sweep_rover).
+    pub fn open_rover_position_sol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenRoverPositionSol<'info>>,
+        lamports: u64,
+        bin_step: u16,
+        max_active_bin_slippage: i32,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.deposits_paused(Clock::get()?.unix_timestamp), CoreError::Paused);
+        require_pool_open(&ctx.accounts.pool_status)?;
+        require!(lamports > 0, CoreError::ZeroAmount);
+        require!(lamports >= MIN_ROVER_DEPOSIT, CoreError::RoverDepositTooSmall);
+        require!(bin_step >= MIN_ROVER_BIN_STEP, CoreError::RoverBinStepTooSmall);
+
+        // Overflow accounts: event_authority, dlmm_program
+        require!(ctx.remaining_accounts.len() >= 2, CoreError::NoBinsProvided);
+        let RoverRange { active_id, min_bin_id, max_bin_id, width } = execute_rover_open(
+            &RoverOpenAccounts {
+                depositor: ctx.accounts.depositor.as_ref(),
+                lb_pair: &ctx.accounts.lb_pair,
+                meteora_position: ctx.accounts.meteora_position.as_ref(),
+                bin_array_bitmap_ext: &ctx.accounts.bin_array_bitmap_ext,
+                reserve_x: &ctx.accounts.reserve_x,
+                reserve_y: &ctx.accounts.reserve_y,
+                bin_array_lower: &ctx.accounts.bin_array_lower,
+                bin_array_upper: &ctx.accounts.bin_array_upper,
+                vault: ctx.accounts.vault.as_ref().as_ref(),
+                depositor_token_account: None,
+                vault_token_x: &ctx.accounts.vault_token_x,
+                vault_token_y: &ctx.accounts.vault_token_y,
+                token_x_mint: &ctx.accounts.token_x_mint,
+                token_y_mint: &ctx.accounts.token_y_mint,
+                token_x_program: &ctx.accounts.token_x_program,
+                token_y_program: &ctx.accounts.token_y_program,
+                system_program: ctx.accounts.system_program.as_ref(),
+                event_authority: &ctx.remaining_accounts[0],
+                dlmm_program: &ctx.remaining_accounts[1],
+            },
+            lamports,
+            bin_step,
+            ctx.bumps.vault,
+            Side::Buy,
+            rover_active_bin_slippage(max_active_bin_slippage)?,
         )?;
 
-        // Capture keys before mutable borrows
         let position_key = ctx.accounts.position.key();
         let depositor_key = ctx.accounts.depositor.key();
         let lb_pair_key = ctx.accounts.lb_pair.key();
         let rover_key = ctx.accounts.rover_authority.key();
-        let meteora_pos_key2 = ctx.accounts.meteora_position.key();
+        let meteora_pos_key = ctx.accounts.meteora_position.key();
         let created_at = Clock::get()?.unix_timestamp;
 
-        // Store position metadata — owner is rover_authority, side is always Sell
-        let position = &mut ctx.accounts.position;
-        position.owner = rover_key;
-        position.lb_pair = lb_pair_key;
-        position.meteora_position = meteora_pos_key2;
-        position.side = Side::Sell;
-        position.min_bin_id = min_bin_id;
-        position.max_bin_id = max_bin_id;
-        position.initial_amount = amount;
-        position.harvested_amount = 0;
-        position.created_at = created_at;
-        position.bump = ctx.bumps.position;
+        ctx.accounts.position.set_inner(Position::opened(
+            POSITION_KIND_ROVER,
+            rover_key,
+            lb_pair_key,
+            meteora_pos_key,
+            Side::Buy,
+            (min_bin_id, max_bin_id),
+            lamports,
+            created_at,
+            ctx.bumps.position,
+        ));
 
         let vault = &mut ctx.accounts.vault;
-        vault.position = ctx.accounts.meteora_position.key();
+        vault.position = meteora_pos_key;
         vault.bump = ctx.bumps.vault;
 
         let config = &mut ctx.accounts.config;
         config.total_positions = config.total_positions.saturating_add(1);
-        config.total_volume = config.total_volume.saturating_add(amount);
+        config.total_volume = config.total_volume.saturating_add(lamports);
 
         let rover = &mut ctx.accounts.rover_authority;
         rover.total_rover_positions = rover.total_rover_positions.saturating_add(1);
@@ -1421,23 +2873,181 @@ pub mod bin_farm {
             depositor: depositor_key,
             lb_pair: lb_pair_key,
             position: position_key,
-            token_mint: ctx.accounts.token_x_mint.key(),
-            amount,
+            token_mint: WSOL_MINT,
+            amount: lamports,
             active_id,
             bin_step,
             min_bin_id,
             max_bin_id,
+            native_sol_amount: lamports,
+            side: Side::Buy,
             timestamp: created_at,
         });
 
-        msg!("Rover position opened: {} bins [{},{}] amount={}",
-            width, min_bin_id, max_bin_id, amount);
+        msg!("SOL rover position opened: {} bins [{},{}] lamports={}",
+            width, min_bin_id, max_bin_id, lamports);
+        Ok(())
+    }
+
+    /// Open several rover positions across pools in one transaction (bribe campaigns).
+    /// Same rules as open_rover_position per pool: active_id read on-chain per pool,
+    /// 2x range, width-scaled minimum. Each pool gets its own Position/Vault PDA.
+    ///
+    /// remaining_accounts: entries.len() groups of ROVER_BATCH_STRIDE (17), in order:
+    ///   0  lb_pair (mut)                   8  vault PDA (mut, not yet created) — ["vault", meteora_position]
+    ///   1  meteora_position (signer, mut)   9  depositor_token_account (mut)
+    ///   2  bin_array_bitmap_ext            10  vault_token_x (mut, owned by vault PDA)
+    ///   3  reserve_x (mut)                 11  vault_token_y (mut)
+    ///   4  reserve_y (mut)                 12  token_x_mint
+    ///   5  bin_array_lower (mut)           13  token_y_mint
+    ///   6  bin_array_upper (mut)           14  token_x_program (allowlisted)
+    ///   7  position PDA (mut, not yet created) — ["position", meteora_position]
+    ///                                      15  token_y_program (allowlisted)
+    ///                                      16  pool_status PDA — ["pool_status", lb_pair]
+    /// A blocked pool reverts the whole batch with PoolBlocked.
+    /// Capped at MAX_ROVER_BATCH pools; callers need an Address Lookup Table.
+    pub fn open_rover_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenRoverBatch<'info>>,
+        entries: Vec<RoverBatchEntry>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.deposits_paused(Clock::get()?.unix_timestamp), CoreError::Paused);
+        require!(
+            !entries.is_empty() && entries.len() <= MAX_ROVER_BATCH,
+            CoreError::InvalidRoverBatch
+        );
+        let expected_accounts = entries.len()
+            .checked_mul(ROVER_BATCH_STRIDE).ok_or(CoreError::Overflow)?;
+        require!(ctx.remaining_accounts.len() == expected_accounts, CoreError::InvalidRoverBatch);
+
+        let depositor_key = ctx.accounts.depositor.key();
+        let rover_key = ctx.accounts.rover_authority.key();
+        let created_at = Clock::get()?.unix_timestamp;
+
+        for (entry, accs) in entries.iter().zip(ctx.remaining_accounts.chunks(ROVER_BATCH_STRIDE)) {
+            let amount = entry.amount;
+            let bin_step = entry.bin_step;
+            require!(amount > 0, CoreError::ZeroAmount);
+            require!(amount >= MIN_ROVER_DEPOSIT, CoreError::RoverDepositTooSmall);
+            require!(bin_step >= MIN_ROVER_BIN_STEP, CoreError::RoverBinStepTooSmall);
+
+            let meteora_position = &accs[1];
+            let position_info = &accs[7];
+            let vault_info = &accs[8];
+            require!(meteora_position.is_signer, CoreError::RoverBatchAccountMismatch);
+            require_batch_pool_open(&accs[0], &accs[16])?;
+            require!(
+                ctx.accounts.config.is_token_program_allowed(accs[14].key)
+                    && ctx.accounts.config.is_token_program_allowed(accs[15].key),
+                CoreError::InvalidProgram
+            );
+
+            let meteora_pos_key = meteora_position.key();
+            let (position_pda, position_bump) =
+                Pubkey::find_program_address(&[b"position", meteora_pos_key.as_ref()], ctx.program_id);
+            let (vault_pda, vault_bump) =
+                Pubkey::find_program_address(&[b"vault", meteora_pos_key.as_ref()], ctx.program_id);
+            require!(position_info.key() == position_pda, CoreError::RoverBatchAccountMismatch);
+            require!(vault_info.key() == vault_pda, CoreError::RoverBatchAccountMismatch);
+
+            create_pda_account(
+                ctx.accounts.depositor.as_ref(),
+                position_info,
+                ctx.accounts.system_program.as_ref(),
+                Position::SIZE,
+                &[b"position", meteora_pos_key.as_ref(), &[position_bump]],
+            )?;
+            create_pda_account(
+                ctx.accounts.depositor.as_ref(),
+                vault_info,
+                ctx.accounts.system_program.as_ref(),
+                Vault::SIZE,
+                &[b"vault", meteora_pos_key.as_ref(), &[vault_bump]],
+            )?;
+            Vault { position: meteora_pos_key, bump: vault_bump }
+                .try_serialize(&mut &mut vault_info.try_borrow_mut_data()?[..])?;
+
+            let RoverRange { active_id, min_bin_id, max_bin_id, width } = execute_rover_open(
+                &RoverOpenAccounts {
+                    depositor: ctx.accounts.depositor.as_ref(),
+                    lb_pair: &accs[0],
+                    meteora_position,
+                    bin_array_bitmap_ext: &accs[2],
+                    reserve_x: &accs[3],
+                    reserve_y: &accs[4],
+                    bin_array_lower: &accs[5],
+                    bin_array_upper: &accs[6],
+                    vault: vault_info,
+                    depositor_token_account: Some(&accs[9]),
+                    vault_token_x: &accs[10],
+                    vault_token_y: &accs[11],
+                    token_x_mint: &accs[12],
+                    token_y_mint: &accs[13],
+                    token_x_program: &accs[14],
+                    token_y_program: &accs[15],
+                    system_program: ctx.accounts.system_program.as_ref(),
+                    event_authority: &ctx.accounts.event_authority,
+                    dlmm_program: &ctx.accounts.dlmm_program,
+                },
+                amount,
+                bin_step,
+                vault_bump,
+                Side::Sell,
+                DEFAULT_ROVER_ACTIVE_BIN_SLIPPAGE,
+            )?;
+
+            let lb_pair_key = accs[0].key();
+            Position::opened(
+                POSITION_KIND_ROVER,
+                rover_key,
+                lb_pair_key,
+                meteora_pos_key,
+                Side::Sell,
+                (min_bin_id, max_bin_id),
+                amount,
+                created_at,
+                position_bump,
+            )
+            .try_serialize(&mut &mut position_info.try_borrow_mut_data()?[..])?;
+
+            let config = &mut ctx.accounts.config;
+            config.total_positions = config.total_positions.saturating_add(1);
+            config.total_volume = config.total_volume.saturating_add(amount);
+            let rover = &mut ctx.accounts.rover_authority;
+            rover.total_rover_positions = rover.total_rover_positions.saturating_add(1);
+
+            emit!(RoverOpenedEvent {
+                depositor: depositor_key,
+                lb_pair: lb_pair_key,
+                position: position_pda,
+                token_mint: accs[12].key(),
+                amount,
+                active_id,
+                bin_step,
+                min_bin_id,
+                max_bin_id,
+                native_sol_amount: 0,
+                side: Side::Sell,
+                timestamp: created_at,
+            });
+
+            msg!("Rover position opened: {} bins [{},{}] amount={}",
+                width, min_bin_id, max_bin_id, amount);
+        }
+
+        msg!("Rover batch opened: {} pools", entries.len());
         Ok(())
     }
 
     /// Sweep SOL from rover_authority — 60% to bridge_vault (monke holders), 40% to bot (operations).
     /// Hardcoded split. Permissionless — anyone can call.
+    /// Blocked while a revenue_dest change is pending so a sweep in flight never lands
+    /// on the outgoing destination; apply or cancel the change first.
     pub fn sweep_rover(ctx: Context<SweepRover>) -> Result<()> {
+        require!(
+            ctx.accounts.rover_authority.revenue_dest_change_at == 0,
+            CoreError::RevenueDestPending
+        );
+
         let is_authorized_bot = ctx.accounts.caller.key() == ctx.accounts.config.bot;
         if is_authorized_bot {
             ctx.accounts.config.last_bot_sweep_slot = Clock::get()?.slot;
@@ -1447,14 +3057,15 @@ pub mod bin_farm {
         let rent = Rent::get()?.minimum_balance(RoverAuthority::SIZE);
         let sweepable = rover_lamports.saturating_sub(rent);
 
-        require!(sweepable > 0, CoreError::NothingToSweep);
-
-        let monke_share = sweepable * 3 / 5;   // 60%
-        let operator_share = sweepable - monke_share; // 40%
+        ctx.accounts.config.require_sweepable(sweepable, ctx.accounts.caller.key)?;
 
-        **ctx.accounts.rover_authority.to_account_info().try_borrow_mut_lamports()? -= sweepable;
-        **ctx.accounts.revenue_dest.try_borrow_mut_lamports()? += monke_share;
-        **ctx.accounts.bot_dest.try_borrow_mut_lamports()? += operator_share;
+        let (monke_share, operator_share) = move_swept_lamports(
+            &ctx.accounts.rover_authority.to_account_info(),
+            &ctx.accounts.revenue_dest,
+            &ctx.accounts.bot_dest,
+            sweepable,
+            rent,
+        )?;
 
         emit!(RoverSweptEvent {
             amount: sweepable,
@@ -1494,13 +3105,24 @@ pub mod bin_farm {
     /// Open a fee rover position from accumulated token fees in rover_authority ATA.
     /// Bot-gated. Uses BidAskOneSide distribution (more tokens at higher bins).
     /// Bot pays rent for Position + Vault PDAs (refunded on close).
+    /// The source can be any token account owned by rover_authority, not just the
+    /// canonical ATA — stray balances in a replaced rover_fee_token_x/y account
+    /// recycle the same way. Its mint must be the pool's token X.
+    /// `max_active_bin_slippage` as in open_rover_position.
     pub fn open_fee_rover<'info>(
         ctx: Context<'_, '_, 'info, 'info, OpenFeeRover<'info>>,
         amount: u64,
         bin_step: u16,
+        max_active_bin_slippage: i32,
     ) -> Result<()> {
+        let max_active_bin_slippage = rover_active_bin_slippage(max_active_bin_slippage)?;
         require!(amount > 0, CoreError::ZeroAmount);
         require!(bin_step >= MIN_ROVER_BIN_STEP, CoreError::RoverBinStepTooSmall);
+        require_rover_fee_source(
+            &ctx.accounts.rover_token_account,
+            &ctx.accounts.rover_authority.key(),
+            ctx.accounts.token_x_mint.key,
+        )?;
 
         // Validate vault_token_x owner
         {
@@ -1518,19 +3140,24 @@ pub mod bin_farm {
             i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
         };
         require!(active_id > -443636 && active_id < 443636, CoreError::InvalidBinRange);
+        require_lb_pair_mints(
+            &ctx.accounts.lb_pair,
+            ctx.accounts.token_x_mint.key,
+            ctx.accounts.token_y_mint.key,
+        )?;
 
         // Overflow accounts: event_authority, dlmm_program
         require!(ctx.remaining_accounts.len() >= 2, CoreError::NoBinsProvided);
         let event_authority = ctx.remaining_accounts[0].to_account_info();
         let dlmm_program = ctx.remaining_accounts[1].to_account_info();
         require!(dlmm_program.key() == METEORA_DLMM_PROGRAM_ID, CoreError::InvalidProgram);
+        require!(is_event_authority(event_authority.key), CoreError::InvalidEventAuthority);
 
         // Same range as external rovers: active_id+1 to +70 max
         let min_bin_id = active_id.checked_add(1).ok_or(CoreError::Overflow)?;
         let width = core::cmp::min(70_i32, core::cmp::max(1_i32, 6931_i32 / (bin_step as i32)));
         let max_bin_id = min_bin_id.checked_add(width).ok_or(CoreError::Overflow)?
             .checked_sub(1).ok_or(CoreError::Overflow)?;
-        let max_active_bin_slippage = 10;
 
         // Transfer from rover_authority ATA to vault ATA (rover_authority PDA signs)
         let rover_signer_seeds: &[&[u8]] = &[b"rover_authority", &[ctx.accounts.rover_authority.bump]];
@@ -1625,17 +3252,18 @@ pub mod bin_farm {
         let lb_pair_key = ctx.accounts.lb_pair.key();
         let created_at = Clock::get()?.unix_timestamp;
 
-        let position = &mut ctx.accounts.position;
-        position.owner = rover_key;
-        position.lb_pair = lb_pair_key;
-        position.meteora_position = ctx.accounts.meteora_position.key();
-        position.side = Side::Sell;
-        position.min_bin_id = min_bin_id;
-        position.max_bin_id = max_bin_id;
-        position.initial_amount = amount;
-        position.harvested_amount = 0;
-        position.created_at = created_at;
-        position.bump = ctx.bumps.position;
+        let meteora_pos_key = ctx.accounts.meteora_position.key();
+        ctx.accounts.position.set_inner(Position::opened(
+            POSITION_KIND_FEE_ROVER,
+            rover_key,
+            lb_pair_key,
+            meteora_pos_key,
+            Side::Sell,
+            (min_bin_id, max_bin_id),
+            amount,
+            created_at,
+            ctx.bumps.position,
+        ));
 
         let vault = &mut ctx.accounts.vault;
         vault.position = ctx.accounts.meteora_position.key();
@@ -1658,6 +3286,8 @@ pub mod bin_farm {
             bin_step,
             min_bin_id,
             max_bin_id,
+            native_sol_amount: 0,
+            side: Side::Sell,
             timestamp: created_at,
         });
 
@@ -1666,14 +3296,496 @@ pub mod bin_farm {
     }
 }
 
+/// Accounts one rover open touches. Borrowed so open_rover_position and
+/// open_rover_batch share a single code path without copying AccountInfos.
+struct RoverOpenAccounts<'a, 'info> {
+    depositor: &'a AccountInfo<'info>,
+    lb_pair: &'a AccountInfo<'info>,
+    meteora_position: &'a AccountInfo<'info>,
+    bin_array_bitmap_ext: &'a AccountInfo<'info>,
+    reserve_x: &'a AccountInfo<'info>,
+    reserve_y: &'a AccountInfo<'info>,
+    bin_array_lower: &'a AccountInfo<'info>,
+    bin_array_upper: &'a AccountInfo<'info>,
+    vault: &'a AccountInfo<'info>,
+    depositor_token_account: Option<&'a AccountInfo<'info>>, // None for native SOL deposits
+    vault_token_x: &'a AccountInfo<'info>,
+    vault_token_y: &'a AccountInfo<'info>,
+    token_x_mint: &'a AccountInfo<'info>,
+    token_y_mint: &'a AccountInfo<'info>,
+    token_x_program: &'a AccountInfo<'info>,
+    token_y_program: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    event_authority: &'a AccountInfo<'info>,
+    dlmm_program: &'a AccountInfo<'info>,
+}
+
+/// Bin range a rover open landed on.
+struct RoverRange {
+    active_id: i32,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    width: i32,
+}
+
+/// `width` bins next to the active bin on the rover's side: Sell starts at
+/// active_id + 1 and runs up, Buy ends at active_id - 1 and runs down.
+fn rover_bin_range(active_id: i32, width: i32, side: Side) -> Result<(i32, i32)> {
+    let span = width.checked_sub(1).ok_or(CoreError::Overflow)?;
+    match side {
+        Side::Sell => {
+            let min_bin_id = active_id.checked_add(1).ok_or(CoreError::Overflow)?;
+            Ok((min_bin_id, min_bin_id.checked_add(span).ok_or(CoreError::Overflow)?))
+        }
+        Side::Buy => {
+            let max_bin_id = active_id.checked_sub(1).ok_or(CoreError::Overflow)?;
+            Ok((max_bin_id.checked_sub(span).ok_or(CoreError::Overflow)?, max_bin_id))
+        }
+    }
+}
+
+/// Rover max_active_bin_slippage: 0 picks DEFAULT_ROVER_ACTIVE_BIN_SLIPPAGE, anything
+/// else must sit in the same MIN..=MAX bounds as open_position_v2 (InvalidSlippage).
+fn rover_active_bin_slippage(requested: i32) -> Result<i32> {
+    if requested == 0 {
+        return Ok(DEFAULT_ROVER_ACTIVE_BIN_SLIPPAGE);
+    }
+    require!(
+        (MIN_ACTIVE_BIN_SLIPPAGE..=MAX_ACTIVE_BIN_SLIPPAGE).contains(&requested),
+        CoreError::InvalidSlippage
+    );
+    Ok(requested)
+}
+
+/// sweep_rover's lamport move: 60% of `sweepable` to revenue_dest, 40% to bot_dest.
+/// Post-conditions: the rover is left exactly at `rent` and the destinations gained
+/// exactly `sweepable` (SweepInvariantViolated otherwise). The destinations may be the
+/// same account — its balance is counted once. Returns (monke_share, operator_share).
+fn move_swept_lamports(
+    rover: &AccountInfo,
+    revenue_dest: &AccountInfo,
+    bot_dest: &AccountInfo,
+    sweepable: u64,
+    rent: u64,
+) -> Result<(u64, u64)> {
+    let monke_share = sweepable.checked_mul(3).ok_or(CoreError::Overflow)? / 5; // 60%
+    let operator_share = sweepable.checked_sub(monke_share).ok_or(CoreError::Overflow)?; // 40%
+
+    let same_dest = revenue_dest.key() == bot_dest.key();
+    let dest_lamports = || -> Result<u64> {
+        if same_dest {
+            Ok(revenue_dest.lamports())
+        } else {
+            revenue_dest.lamports()
+                .checked_add(bot_dest.lamports())
+                .ok_or_else(|| error!(CoreError::Overflow))
+        }
+    };
+    let dest_before = dest_lamports()?;
+
+    let rover_after = rover.lamports().checked_sub(sweepable).ok_or(CoreError::Overflow)?;
+    **rover.try_borrow_mut_lamports()? = rover_after;
+    let revenue_after = revenue_dest.lamports().checked_add(monke_share).ok_or(CoreError::Overflow)?;
+    **revenue_dest.try_borrow_mut_lamports()? = revenue_after;
+    let bot_after = bot_dest.lamports().checked_add(operator_share).ok_or(CoreError::Overflow)?;
+    **bot_dest.try_borrow_mut_lamports()? = bot_after;
+
+    let dest_delta = dest_lamports()?
+        .checked_sub(dest_before)
+        .ok_or(CoreError::SweepInvariantViolated)?;
+    require!(rover.lamports() == rent && dest_delta == sweepable, CoreError::SweepInvariantViolated);
+    Ok((monke_share, operator_share))
+}
+
+/// open_fee_rover source check: the address is not pinned to the canonical ATA,
+/// only the token account's owner (rover_authority) and mint (the pool's token X).
+fn require_rover_fee_source(source: &ITokenAccount, rover_authority: &Pubkey, token_x_mint: &Pubkey) -> Result<()> {
+    require!(source.owner == *rover_authority, CoreError::InvalidTokenOwner);
+    require!(source.mint == *token_x_mint, CoreError::RoverFeeMintMismatch);
+    Ok(())
+}
+
+/// Validate, transfer the deposit into the vault, and open the DLMM position.
+/// Sell: token X from depositor_token_account, bins above active_id.
+/// Buy: token Y from depositor_token_account, or native SOL wrapped into the vault's
+/// wSOL ATA when depositor_token_account is None; bins below active_id.
+/// Caller owns Position/Vault bookkeeping and event emission.
+fn execute_rover_open<'info>(
+    accs: &RoverOpenAccounts<'_, 'info>,
+    amount: u64,
+    bin_step: u16,
+    vault_bump: u8,
+    side: Side,
+    max_active_bin_slippage: i32,
+) -> Result<RoverRange> {
+    // Validate token account owners
+    let (vault_deposit_account, deposit_token_program) = match side {
+        Side::Sell => (accs.vault_token_x, accs.token_x_program),
+        Side::Buy => (accs.vault_token_y, accs.token_y_program),
+    };
+    let native_sol = accs.depositor_token_account.is_none();
+    match accs.depositor_token_account {
+        Some(depositor_token_account) => {
+            let data = depositor_token_account.try_borrow_data()?;
+            require!(data.len() >= 64, CoreError::InvalidTokenOwner);
+            let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
+            require!(owner == *accs.depositor.key, CoreError::InvalidTokenOwner);
+        }
+        // Native SOL can only fund the wSOL (token Y) side
+        None => {
+            require!(side == Side::Buy, CoreError::InvalidTokenOwner);
+            require!(*accs.token_y_mint.key == WSOL_MINT, CoreError::NotSolPool);
+            require!(*accs.token_y_program.key == anchor_spl::token::ID, CoreError::InvalidProgram);
+        }
+    }
+    {
+        let data = vault_deposit_account.try_borrow_data()?;
+        require!(data.len() >= 64, CoreError::InvalidTokenOwner);
+        let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
+        require!(owner == *accs.vault.key, CoreError::InvalidTokenOwner);
+        if native_sol {
+            let mint = Pubkey::try_from(&data[0..32]).map_err(|_| CoreError::InvalidTokenOwner)?;
+            require!(mint == WSOL_MINT, CoreError::NotSolPool);
+        }
+    }
+
+    // C1: Read active_id from on-chain lb_pair — never trust the caller
+    let active_id = {
+        let data = accs.lb_pair.try_borrow_data()?;
+        // Validate data length before byte slice (prevents panic on malformed accounts)
+        require!(data.len() >= 80, CoreError::InvalidPool);
+        i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+    };
+    require!(active_id > -443636 && active_id < 443636, CoreError::InvalidBinRange);
+    require_lb_pair_mints(accs.lb_pair, accs.token_x_mint.key, accs.token_y_mint.key)?;
+
+    let event_authority = accs.event_authority.clone();
+    let dlmm_program = accs.dlmm_program.clone();
+    require!(*dlmm_program.key == METEORA_DLMM_PROGRAM_ID, CoreError::InvalidProgram);
+    require!(is_event_authority(event_authority.key), CoreError::InvalidEventAuthority);
+
+    // Compute 2x range: ln(2)/ln(1+binStep/10000) ≈ 6931/binStep bins
+    // Capped at MAX_POSITION_WIDTH (70 bins)
+    let bins_for_2x = 6931_i32 / (bin_step as i32);
+    let width = if bins_for_2x < 1 { 1 } else if bins_for_2x > MAX_POSITION_WIDTH { MAX_POSITION_WIDTH } else { bins_for_2x };
+    let (min_bin_id, max_bin_id) = rover_bin_range(active_id, width, side)?;
+
+    // Small bin steps need more than MAX_POSITION_WIDTH bins for 2x. Tell the frontend
+    // which bins the position does not cover so it can surface (or top up) the gap.
+    if bins_for_2x > MAX_POSITION_WIDTH {
+        // Last uncovered bin sits (bins_for_2x - MAX_POSITION_WIDTH - 1) past the first
+        let gap_span = bins_for_2x.checked_sub(MAX_POSITION_WIDTH)
+            .and_then(|m| m.checked_sub(1)).ok_or(CoreError::Overflow)?;
+        let (uncovered_min_bin_id, uncovered_max_bin_id) = match side {
+            Side::Sell => {
+                let lo = max_bin_id.checked_add(1).ok_or(CoreError::Overflow)?;
+                (lo, lo.checked_add(gap_span).ok_or(CoreError::Overflow)?)
+            }
+            Side::Buy => {
+                let hi = min_bin_id.checked_sub(1).ok_or(CoreError::Overflow)?;
+                (hi.checked_sub(gap_span).ok_or(CoreError::Overflow)?, hi)
+            }
+        };
+        emit!(RoverRangeTruncatedEvent {
+            lb_pair: *accs.lb_pair.key,
+            meteora_position: *accs.meteora_position.key,
+            side,
+            bin_step,
+            requested_width: bins_for_2x,
+            placed_width: width,
+            uncovered_min_bin_id,
+            uncovered_max_bin_id,
+        });
+        msg!("Rover range truncated: {} of {} bins for 2x", width, bins_for_2x);
+    }
+
+    // Width-scaled minimum so every bin in the range gets a meaningful allocation
+    let min_for_width = MIN_ROVER_DEPOSIT_PER_BIN
+        .checked_mul(width as u64).ok_or(CoreError::Overflow)?
+        .max(MIN_ROVER_DEPOSIT);
+    if amount < min_for_width {
+        msg!("RoverDepositTooSmall: {} bins require at least {} (got {})", width, min_for_width, amount);
+        return err!(CoreError::RoverDepositTooSmall);
+    }
+
+    // Transfer deposit from caller to vault
+    if native_sol {
+        // Wrap: lamports into the vault's wSOL ATA, then sync_native to credit the balance.
+        // The ATA stays open for the position's lifetime (harvest/close read it); any
+        // wSOL Meteora doesn't take remains there and returns to the owner on close.
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                accs.system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: accs.depositor.clone(),
+                    to: accs.vault_token_y.clone(),
+                },
+            ),
+            amount,
+        )?;
+        anchor_spl::token::sync_native(CpiContext::new(
+            accs.token_y_program.clone(),
+            anchor_spl::token::SyncNative { account: accs.vault_token_y.clone() },
+        ))?;
+    } else {
+        let depositor_token_account = accs.depositor_token_account
+            .ok_or(CoreError::InvalidTokenOwner)?;
+        let transfer_ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: *deposit_token_program.key,
+            accounts: vec![
+                anchor_lang::solana_program::instruction::AccountMeta::new(*depositor_token_account.key, false),
+                anchor_lang::solana_program::instruction::AccountMeta::new(*vault_deposit_account.key, false),
+                anchor_lang::solana_program::instruction::AccountMeta::new_readonly(*accs.depositor.key, true),
+            ],
+            data: {
+                let mut d = vec![3u8];
+                d.extend_from_slice(&amount.to_le_bytes());
+                d
+            },
+        };
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                depositor_token_account.clone(),
+                vault_deposit_account.clone(),
+                accs.depositor.clone(),
+                deposit_token_program.clone(),
+            ],
+        )?;
+    }
+
+    // Vault PDA signs Meteora CPIs
+    let meteora_pos_key = *accs.meteora_position.key;
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        meteora_pos_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer = &[vault_seeds];
+
+    // Initialize Meteora position (vault PDA = owner)
+    initialize_position2(
+        &[
+            accs.depositor.clone(),
+            accs.meteora_position.clone(),
+            accs.lb_pair.clone(),
+            accs.vault.clone(),
+            accs.system_program.clone(),
+            event_authority.clone(),
+            dlmm_program.clone(),
+        ],
+        min_bin_id,
+        width,
+        signer,
+    )?;
+
+    // Add one-sided liquidity with BidAsk distribution via V2 two-sided CPI
+    // (the other amount = 0 makes it effectively one-sided)
+    let (amount_x, amount_y) = match side {
+        Side::Sell => (amount, 0),
+        Side::Buy => (0, amount),
+    };
+    let liquidity_params = LiquidityParameterByStrategy {
+        amount_x,
+        amount_y,
+        active_id,
+        max_active_bin_slippage,
+        strategy_parameters: StrategyParameters::bid_ask_imbalanced(min_bin_id, max_bin_id),
+    };
+
+    add_liquidity_by_strategy2(
+        &[
+            accs.meteora_position.clone(),
+            accs.lb_pair.clone(),
+            accs.bin_array_bitmap_ext.clone(),
+            accs.vault_token_x.clone(),
+            accs.vault_token_y.clone(),
+            accs.reserve_x.clone(),
+            accs.reserve_y.clone(),
+            accs.token_x_mint.clone(),
+            accs.token_y_mint.clone(),
+            accs.vault.clone(),
+            accs.token_x_program.clone(),
+            accs.token_y_program.clone(),
+            event_authority,
+            dlmm_program,
+        ],
+        liquidity_params,
+        RemainingAccountsInfo::empty_hooks(),
+        signer,
+        &[accs.bin_array_lower.clone(), accs.bin_array_upper.clone()],
+    )?;
+
+    Ok(RoverRange { active_id, min_bin_id, max_bin_id, width })
+}
+
+/// Create a program-owned PDA account. Used where Anchor `init` is unavailable
+/// (accounts arriving via remaining_accounts). A pre-funded address is handled the
+/// way `init` handles it: top up to rent exemption, then allocate and assign, so
+/// sending lamports to a PDA ahead of time can't block its creation.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    require!(
+        *target.owner == anchor_lang::system_program::ID && target.data_is_empty(),
+        CoreError::RoverBatchAccountMismatch
+    );
+    let rent = Rent::get()?.minimum_balance(space);
+    let current = target.lamports();
+    if current == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    let top_up = rent.saturating_sub(current);
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate { account_to_allocate: target.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign { account_to_assign: target.clone() },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+/// Map open_position_v2's `strategy` argument to Meteora StrategyParameters
+/// (imbalanced variants — the unused side is zero).
+fn strategy_parameters_for(strategy: u8, min_bin_id: i32, max_bin_id: i32) -> Result<StrategyParameters> {
+    match strategy {
+        STRATEGY_SPOT => Ok(StrategyParameters::spot_imbalanced(min_bin_id, max_bin_id)),
+        STRATEGY_CURVE => Ok(StrategyParameters::curve_imbalanced(min_bin_id, max_bin_id)),
+        STRATEGY_BID_ASK => Ok(StrategyParameters::bid_ask_imbalanced(min_bin_id, max_bin_id)),
+        _ => err!(CoreError::InvalidStrategy),
+    }
+}
+
+/// Bin arrays covering [min_bin_id, max_bin_id], in ascending order. Two arrays (or
+/// one, passed as both lower and upper) cover any range up to BINS_PER_ARRAY wide; a
+/// third is only needed if the span crosses two array boundaries, and must then be
+/// the lb_pair's bin array PDA between lower and upper.
+fn open_bin_arrays<'info>(
+    lb_pair: &Pubkey,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    lower: AccountInfo<'info>,
+    middle: Option<AccountInfo<'info>>,
+    upper: AccountInfo<'info>,
+) -> Result<Vec<AccountInfo<'info>>> {
+    let lower_index = bin_id_to_array_index(min_bin_id);
+    let upper_index = bin_id_to_array_index(max_bin_id);
+    let array_span = upper_index.checked_sub(lower_index).ok_or(CoreError::Overflow)?;
+    require!(array_span <= 2, CoreError::PositionTooWide);
+    let mut bin_arrays = vec![lower];
+    if array_span == 2 {
+        let middle = middle.ok_or(CoreError::MissingBinArrayMiddle)?;
+        let middle_index = lower_index.checked_add(1).ok_or(CoreError::Overflow)?;
+        let (expected, _) = bin_array_pda(lb_pair, middle_index);
+        require!(middle.key() == expected, CoreError::MissingBinArrayMiddle);
+        bin_arrays.push(middle);
+    }
+    bin_arrays.push(upper);
+    Ok(bin_arrays)
+}
+
+/// Validate a Position read raw (possibly an older, shorter layout): program-owned,
+/// Position discriminator, and the stored bump reproduces the PDA. Returns the owner.
+fn read_raw_position_owner(position_info: &AccountInfo) -> Result<Pubkey> {
+    require!(*position_info.owner == crate::ID, CoreError::InvalidPositionAccount);
+    let data = position_info.try_borrow_data()?;
+    require!(data.len() >= Position::LABEL_OFFSET, CoreError::InvalidPositionAccount);
+    require!(data[..8] == *Position::DISCRIMINATOR, CoreError::InvalidPositionAccount);
+    let owner = Pubkey::try_from(&data[8..40]).map_err(|_| CoreError::InvalidPositionAccount)?;
+    let meteora_position = Pubkey::try_from(&data[72..104])
+        .map_err(|_| CoreError::InvalidPositionAccount)?;
+    let bump = data[Position::BUMP_OFFSET];
+    let expected = Pubkey::create_program_address(
+        &[b"position", meteora_position.as_ref(), &[bump]],
+        &crate::ID,
+    ).map_err(|_| CoreError::InvalidPositionAccount)?;
+    require!(expected == position_info.key(), CoreError::InvalidPositionAccount);
+    Ok(owner)
+}
+
+/// Grow a program account to `space` bytes (zero-filled), topping up rent from `payer`.
+/// No-op when already at least that size.
+fn grow_account<'info>(
+    account_info: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if account_info.data_len() >= space {
+        return Ok(());
+    }
+    let required = Rent::get()?.minimum_balance(space)
+        .saturating_sub(account_info.lamports());
+    if required > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account_info.clone(),
+                },
+            ),
+            required,
+        )?;
+    }
+    account_info.resize(space)?;
+    Ok(())
+}
+
 /// Prepend a memo CPI before token transfers. Satisfies the Memo Transfer extension
 /// on Token-2022 token accounts that require a memo on every incoming transfer.
 /// ~5,000 CU per call. The vault PDA signs as the transfer authority.
+///
+/// Skipped when `destination` does not require a memo. A failed CPI aborts the whole
+/// transaction (the runtime gives us no way to catch it), so the only way to keep a
+/// memo-program hiccup from blocking a protective harvest is to not invoke it at all
+/// for plain SPL accounts and Token-2022 accounts without the extension enabled.
 fn memo_cpi<'info>(
     memo_program: &AccountInfo<'info>,
     signer_account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
+    if !destination_requires_memo(destination) {
+        return Ok(());
+    }
     solana_program::program::invoke_signed(
         &solana_program::instruction::Instruction {
             program_id: *memo_program.key,
@@ -1688,6 +3800,104 @@ fn memo_cpi<'info>(
     Ok(())
 }
 
+/// Token-2022 extension type for MemoTransfer (`required_incoming_transfer_memos: bool`).
+const T22_EXTENSION_MEMO_TRANSFER: u16 = 8;
+/// Token-2022 mint extension type for TransferFeeConfig.
+const T22_EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+/// Token-2022 account TLV layout: base Account (165) + account_type (1), then TLV entries.
+/// Mints are padded to the same offset, so their TLV starts here too.
+const T22_ACCOUNT_TLV_START: usize = 166;
+/// TransferFeeConfig: two authorities (32 + 32) + withheld_amount (8), then
+/// older_transfer_fee and newer_transfer_fee, each epoch (8) + maximum_fee (8) + bps (2).
+const T22_TRANSFER_FEE_OLDER_OFFSET: usize = 72;
+const T22_TRANSFER_FEE_NEWER_OFFSET: usize = 90;
+const T22_TRANSFER_FEE_LEN: usize = 18;
+
+/// Walk a Token-2022 TLV region for `ext_type`. Ok(None) when absent, Err(()) when
+/// the entries are malformed.
+fn find_t22_extension(data: &[u8], ext_type: u16) -> core::result::Result<Option<&[u8]>, ()> {
+    let mut offset = T22_ACCOUNT_TLV_START;
+    loop {
+        let value_start = match offset.checked_add(4) {
+            Some(v) if v <= data.len() => v,
+            _ => return Ok(None),
+        };
+        let header = &data[offset..value_start];
+        let found = u16::from_le_bytes([header[0], header[1]]);
+        let ext_len = u16::from_le_bytes([header[2], header[3]]) as usize;
+        if found == 0 {
+            return Ok(None); // Uninitialized padding
+        }
+        let value_end = value_start.checked_add(ext_len).ok_or(())?;
+        if found == ext_type {
+            return Ok(Some(data.get(value_start..value_end).unwrap_or(&data[value_start..])));
+        }
+        offset = value_end;
+    }
+}
+
+/// Token-2022 transfer fee withheld from the destination when `amount` is sent with
+/// transfer_checked in `epoch`. 0 for SPL Token mints and mints without the extension.
+/// Mirrors spl-token-2022: ceil(amount * bps / 10_000), capped at maximum_fee.
+fn expected_transfer_fee(mint_info: &AccountInfo, amount: u64, epoch: u64) -> Result<u64> {
+    if *mint_info.owner != TOKEN_2022_PROGRAM_ID || amount == 0 {
+        return Ok(0);
+    }
+    let data = mint_info.try_borrow_data()?;
+    if data.len() <= T22_ACCOUNT_TLV_START {
+        return Ok(0);
+    }
+    let config = match find_t22_extension(&data, T22_EXTENSION_TRANSFER_FEE_CONFIG) {
+        Ok(Some(config)) => config,
+        Ok(None) => return Ok(0),
+        Err(()) => return err!(CoreError::InvalidMintData),
+    };
+    let fee_at = |offset: usize| -> Option<(u64, u64, u16)> {
+        let fee = config.get(offset..offset.checked_add(T22_TRANSFER_FEE_LEN)?)?;
+        Some((
+            u64::from_le_bytes(fee[0..8].try_into().ok()?),
+            u64::from_le_bytes(fee[8..16].try_into().ok()?),
+            u16::from_le_bytes(fee[16..18].try_into().ok()?),
+        ))
+    };
+    let older = fee_at(T22_TRANSFER_FEE_OLDER_OFFSET).ok_or(CoreError::InvalidMintData)?;
+    let newer = fee_at(T22_TRANSFER_FEE_NEWER_OFFSET).ok_or(CoreError::InvalidMintData)?;
+    let (_, maximum_fee, bps) = if epoch >= newer.0 { newer } else { older };
+    if bps == 0 {
+        return Ok(0);
+    }
+    let fee = (amount as u128)
+        .checked_mul(bps as u128).ok_or(CoreError::Overflow)?
+        .checked_add(9_999).ok_or(CoreError::Overflow)?
+        .checked_div(10_000).ok_or(CoreError::Overflow)?;
+    Ok(core::cmp::min(fee, maximum_fee as u128) as u64)
+}
+
+/// True if a transfer into `destination` needs a preceding memo.
+/// SPL Token accounts never do. Token-2022 accounts only when the MemoTransfer extension
+/// is present and enabled. Any other owner (allowlisted future token programs) or
+/// unparseable data errs on the side of sending the memo.
+fn destination_requires_memo(destination: &AccountInfo) -> bool {
+    if *destination.owner == anchor_spl::token::ID {
+        return false;
+    }
+    if *destination.owner != TOKEN_2022_PROGRAM_ID {
+        return true;
+    }
+    let data = match destination.try_borrow_data() {
+        Ok(d) => d,
+        Err(_) => return true,
+    };
+    if data.len() <= T22_ACCOUNT_TLV_START {
+        return false; // No extensions
+    }
+    match find_t22_extension(&data, T22_EXTENSION_MEMO_TRANSFER) {
+        Ok(Some(value)) => value.first() != Some(&0),
+        Ok(None) => false,
+        Err(()) => true,
+    }
+}
+
 /// Shared fee calc + transfer logic for close_position and user_close.
 /// Uses separate token_x_program/token_y_program for Token-2022 support.
 fn read_mint_decimals(mint_info: &AccountInfo) -> Result<u8> {
@@ -1696,458 +3906,3173 @@ fn read_mint_decimals(mint_info: &AccountInfo) -> Result<u8> {
     Ok(data[44])
 }
 
-/// Zeros vault lamports entirely (garbage-collected at end of tx).
-/// Returns (x_fee, y_fee, x_to_recipient, y_to_recipient) for event emission.
-///
-/// NOTE: The 0.3% performance fee is charged on the FULL vault balance
-/// after both remove_all_liquidity and claim_fee CPIs. This means accrued LP trading
-/// fees are included in the fee base on close. This is an intentional simplification —
-/// LP fees are typically <1% of position value. Users who want fee-free LP fee
-/// withdrawal should call `claim_fees` before closing their position.
-fn execute_close_transfers<'info>(
-    side: Side,
-    fee_bps: u16,
-    vault_token_x: &mut InterfaceAccount<'info, ITokenAccount>,
-    vault_token_y: &mut InterfaceAccount<'info, ITokenAccount>,
-    recipient_token_x: &AccountInfo<'info>,
-    recipient_token_y: &AccountInfo<'info>,
-    rover_fee_token_y: &AccountInfo<'info>,
-    rover_fee_token_x: &AccountInfo<'info>,
-    vault: &AccountInfo<'info>,
-    _recipient: &AccountInfo<'info>,
-    token_x_program: &AccountInfo<'info>,
-    token_y_program: &AccountInfo<'info>,
-    token_x_mint: &AccountInfo<'info>,
-    token_y_mint: &AccountInfo<'info>,
-    memo_program: &AccountInfo<'info>,
-    signer: &[&[&[u8]]],
-) -> Result<(u64, u64, u64, u64)> {
-    vault_token_x.reload()?;
-    vault_token_y.reload()?;
-    let vault_x_balance = vault_token_x.amount;
-    let vault_y_balance = vault_token_y.amount;
-    let fee = fee_bps as u128;
-
-    let x_decimals = read_mint_decimals(token_x_mint)?;
-    let y_decimals = read_mint_decimals(token_y_mint)?;
-
-    let (x_fee, y_fee) = match side {
-        Side::Buy => {
-            let f = (vault_x_balance as u128)
-                .checked_mul(fee).ok_or(CoreError::Overflow)?
-                .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
-            (f, 0u64)
-        }
-        Side::Sell => {
-            let f = (vault_y_balance as u128)
-                .checked_mul(fee).ok_or(CoreError::Overflow)?
-                .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
-            (0u64, f)
-        }
-    };
+/// Shared body of open_position_v2 / open_position_wrapped. `wrap` funds the deposit
+/// with native lamports straight into the vault's wSOL account instead of a token
+/// transfer from user_token_account.
+#[allow(clippy::too_many_arguments)]
+fn execute_open_position<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OpenPositionV2<'info>>,
+    amount: u64,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    max_active_bin_slippage: i32,
+    take_profit_bins: u16,
+    strategy: u8,
+    referrer: Pubkey,
+    priority_slots_override: u64,
+    hook_x_len: u8,
+    hook_y_len: u8,
+    fee_bps_override: u16,
+    expected_active_id: i32,
+    max_drift: i32,
+    wrap: bool,
+) -> Result<()> {
+    require!(!ctx.accounts.config.deposits_paused(Clock::get()?.unix_timestamp), CoreError::Paused);
+    require_pool_open(&ctx.accounts.pool_status)?;
+    require!(referrer != ctx.accounts.user.key(), CoreError::InvalidReferrer);
+    require!(priority_slots_override <= MAX_PRIORITY_SLOTS, CoreError::PrioritySlotsExceedMax);
+    let fee_bps_override = resolve_fee_bps_override(
+        fee_bps_override,
+        &ctx.accounts.config,
+        ctx.accounts.fee_authority.as_ref().map(|signer| signer.key()),
+    )?;
+    let (_, hook_accounts) = split_hook_accounts(ctx.remaining_accounts, hook_x_len, hook_y_len)
+        .ok_or(CoreError::MissingHookAccounts)?;
+    require!(amount > 0, CoreError::ZeroAmount);
+    let min_amount = min_position_amount(&ctx.accounts.config, &ctx.accounts.pool_status)?;
+    if amount < min_amount {
+        msg!("PositionTooSmall: amount {} below minimum {}", amount, min_amount);
+        return err!(CoreError::PositionTooSmall);
+    }
+    require!(
+        (MIN_ACTIVE_BIN_SLIPPAGE..=MAX_ACTIVE_BIN_SLIPPAGE).contains(&max_active_bin_slippage),
+        CoreError::InvalidSlippage
+    );
+    require!(min_bin_id <= max_bin_id, CoreError::InvalidBinRange);
+    let width = max_bin_id - min_bin_id + 1;
+    require!(width <= MAX_POSITION_WIDTH, CoreError::PositionTooWide);
+    let min_width = ctx.accounts.config.effective_min_position_width();
+    if width < min_width as i32 {
+        msg!("PositionTooNarrow: width {} below minimum {}", width, min_width);
+        return err!(CoreError::PositionTooNarrow);
+    }
 
-    let x_to_recipient = vault_x_balance.checked_sub(x_fee).ok_or(CoreError::Overflow)?;
-    let y_to_recipient = vault_y_balance.checked_sub(y_fee).ok_or(CoreError::Overflow)?;
+    // Validate DLMM program
+    require!(ctx.accounts.dlmm_program.key() == METEORA_DLMM_PROGRAM_ID, CoreError::InvalidProgram);
+    require!(is_event_authority(ctx.accounts.event_authority.key), CoreError::InvalidEventAuthority);
 
-    // Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40: monke holders + bot)
-    //   TOKEN fees (Buy side, x_fee) → rover_fee_token_x for DLMM recycling
-    //   SOL fees (Sell side, y_fee)  → rover_fee_token_y (WSOL, unwrapped later)
-    // B2 FIX: Prepend memo before each transfer (supports Memo Transfer extension)
-    if x_fee > 0 {
-        memo_cpi(memo_program, vault, signer)?;
-        transfer_checked(CpiContext::new_with_signer(
-            token_x_program.to_account_info(),
-            TransferChecked {
-                from: vault_token_x.to_account_info(),
-                mint: token_x_mint.to_account_info(),
-                to: rover_fee_token_x.to_account_info(),
-                authority: vault.to_account_info(),
-            }, signer,
-        ), x_fee, x_decimals)?;
+    // Validate token account owners (moved from struct constraints for stack savings)
+    if !wrap {
+        let data = ctx.accounts.user_token_account.try_borrow_data()?;
+        require!(data.len() >= 64, CoreError::InvalidTokenOwner);
+        let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
+        require!(owner == ctx.accounts.user.key(), CoreError::InvalidTokenOwner);
     }
-    if y_fee > 0 {
-        memo_cpi(memo_program, vault, signer)?;
-        transfer_checked(CpiContext::new_with_signer(
-            token_y_program.to_account_info(),
-            TransferChecked {
-                from: vault_token_y.to_account_info(),
-                mint: token_y_mint.to_account_info(),
-                to: rover_fee_token_y.to_account_info(),
-                authority: vault.to_account_info(),
-            }, signer,
-        ), y_fee, y_decimals)?;
+    {
+        let data = ctx.accounts.vault_token_x.try_borrow_data()?;
+        require!(data.len() >= 64, CoreError::InvalidTokenOwner);
+        let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
+        require!(owner == ctx.accounts.vault.key(), CoreError::InvalidTokenOwner);
     }
-    if x_to_recipient > 0 {
-        memo_cpi(memo_program, vault, signer)?;
-        transfer_checked(CpiContext::new_with_signer(
-            token_x_program.to_account_info(),
-            TransferChecked {
-                from: vault_token_x.to_account_info(),
-                mint: token_x_mint.to_account_info(),
-                to: recipient_token_x.to_account_info(),
-                authority: vault.to_account_info(),
-            }, signer,
-        ), x_to_recipient, x_decimals)?;
+    {
+        let data = ctx.accounts.vault_token_y.try_borrow_data()?;
+        require!(data.len() >= 64, CoreError::InvalidTokenOwner);
+        let owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::InvalidTokenOwner)?;
+        require!(owner == ctx.accounts.vault.key(), CoreError::InvalidTokenOwner);
+        if wrap {
+            let mint = Pubkey::try_from(&data[0..32]).map_err(|_| CoreError::InvalidTokenOwner)?;
+            require!(mint == WSOL_MINT, CoreError::NotSolPool);
+        }
     }
-    if y_to_recipient > 0 {
-        memo_cpi(memo_program, vault, signer)?;
-        transfer_checked(CpiContext::new_with_signer(
-            token_y_program.to_account_info(),
-            TransferChecked {
-                from: vault_token_y.to_account_info(),
-                mint: token_y_mint.to_account_info(),
-                to: recipient_token_y.to_account_info(),
-                authority: vault.to_account_info(),
-            }, signer,
-        ), y_to_recipient, y_decimals)?;
+
+    // Derive side from on-chain active_id — never trust caller
+    let active_id = {
+        let data = ctx.accounts.lb_pair.try_borrow_data()?;
+        require!(data.len() >= 80, CoreError::InvalidPool);
+        i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+    };
+    require!(active_id > -443636 && active_id < 443636, CoreError::InvalidBinRange);
+    require_active_bin_within_drift(active_id, expected_active_id, max_drift)?;
+    require_lb_pair_mints(
+        &ctx.accounts.lb_pair,
+        ctx.accounts.token_x_mint.key,
+        ctx.accounts.token_y_mint.key,
+    )?;
+    let side = if min_bin_id > active_id { Side::Sell } else { Side::Buy };
+    // Native SOL can only fund the wSOL (token Y) side
+    if wrap {
+        require!(side == Side::Buy, CoreError::NotSolPool);
+        require!(*ctx.accounts.token_y_mint.key == WSOL_MINT, CoreError::NotSolPool);
     }
 
-    // Vault lamports handled by Anchor `close` constraint on the context
-    // (close = owner in ClosePosition, close = user in UserClose).
+    let deposit_token_program = if side == Side::Sell {
+        &ctx.accounts.token_x_program
+    } else {
+        &ctx.accounts.token_y_program
+    };
 
-    msg!("Position closed | x_fee={} y_fee={} x_out={} y_out={}",
-        x_fee, y_fee, x_to_recipient, y_to_recipient);
-    Ok((x_fee, y_fee, x_to_recipient, y_to_recipient))
-}
+    let deposit_vault = if side == Side::Sell {
+        &ctx.accounts.vault_token_x
+    } else {
+        &ctx.accounts.vault_token_y
+    };
+    let wrapped_lamports = if wrap {
+        // Wrap: lamports into the vault's wSOL account, then sync_native to credit the balance
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: deposit_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        anchor_spl::token::sync_native(CpiContext::new(
+            deposit_token_program.to_account_info(),
+            anchor_spl::token::SyncNative { account: deposit_vault.to_account_info() },
+        ))?;
+        amount
+    } else {
+        let transfer_ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: *deposit_token_program.key,
+            accounts: vec![
+                anchor_lang::solana_program::instruction::AccountMeta::new(ctx.accounts.user_token_account.key(), false),
+                anchor_lang::solana_program::instruction::AccountMeta::new(deposit_vault.key(), false),
+                anchor_lang::solana_program::instruction::AccountMeta::new_readonly(ctx.accounts.user.key(), true),
+            ],
+            data: {
+                let mut d = vec![3u8];
+                d.extend_from_slice(&amount.to_le_bytes());
+                d
+            },
+        };
+        anchor_lang::solana_program::program::invoke(
+            &transfer_ix,
+            &[
+                ctx.accounts.user_token_account.to_account_info(),
+                deposit_vault.to_account_info(),
+                ctx.accounts.user.to_account_info(),
+                deposit_token_program.to_account_info(),
+            ],
+        )?;
+        0
+    };
 
-// ============ ENUMS ============
+    // Build PDA signer seeds for meteora_position (replaces keypair signing)
+    let user_key = ctx.accounts.user.key();
+    let lb_pair_key = ctx.accounts.lb_pair.key();
+    let count_bytes = ctx.accounts.position_counter.count.to_le_bytes();
+    let meteora_pos_bump = [ctx.bumps.meteora_position];
+
+    let meteora_pos_key = ctx.accounts.meteora_position.key();
+    let meteora_pos_seeds: &[&[u8]] = &[
+        b"meteora_pos",
+        user_key.as_ref(),
+        lb_pair_key.as_ref(),
+        &count_bytes,
+        &meteora_pos_bump,
+    ];
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        meteora_pos_key.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let signer = &[vault_seeds, meteora_pos_seeds];
+
+    let bin_arrays = open_bin_arrays(
+        &ctx.accounts.lb_pair.key(),
+        min_bin_id,
+        max_bin_id,
+        ctx.accounts.bin_array_lower.to_account_info(),
+        ctx.accounts.bin_array_middle.as_ref().map(|middle| middle.to_account_info()),
+        ctx.accounts.bin_array_upper.to_account_info(),
+    )?;
+    let event_authority = ctx.accounts.event_authority.to_account_info();
+    let dlmm_program = ctx.accounts.dlmm_program.to_account_info();
+    let token_x_mint = ctx.accounts.token_x_mint.to_account_info();
+    let token_y_mint = ctx.accounts.token_y_mint.to_account_info();
+
+    initialize_position2(
+        &[
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.meteora_position.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            event_authority.clone(),
+            dlmm_program.clone(),
+        ],
+        min_bin_id,
+        width,
+        signer,
+    )?;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
-pub enum Side {
-    Buy,
-    Sell,
+    let (amount_x, amount_y) = if side == Side::Sell { (amount, 0u64) } else { (0u64, amount) };
+    let liquidity_params = LiquidityParameterByStrategy {
+        amount_x,
+        amount_y,
+        active_id,
+        max_active_bin_slippage,
+        strategy_parameters: strategy_parameters_for(strategy, min_bin_id, max_bin_id)?,
+    };
+
+    add_liquidity_by_strategy2(
+        &[
+            ctx.accounts.meteora_position.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.accounts.bin_array_bitmap_ext.to_account_info(),
+            ctx.accounts.vault_token_x.to_account_info(),
+            ctx.accounts.vault_token_y.to_account_info(),
+            ctx.accounts.reserve_x.to_account_info(),
+            ctx.accounts.reserve_y.to_account_info(),
+            token_x_mint,
+            token_y_mint,
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.token_y_program.to_account_info(),
+            event_authority,
+            dlmm_program,
+        ],
+        liquidity_params,
+        RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
+        signer,
+        &hooks_then_bin_arrays(hook_accounts, &bin_arrays),
+    )?;
+
+    let mut opened = Position::opened(
+        POSITION_KIND_USER,
+        ctx.accounts.user.key(),
+        ctx.accounts.lb_pair.key(),
+        ctx.accounts.meteora_position.key(),
+        side,
+        (min_bin_id, max_bin_id),
+        amount,
+        Clock::get()?.unix_timestamp,
+        ctx.bumps.position,
+    );
+    opened.take_profit_bins = take_profit_bins;
+    opened.strategy = strategy;
+    opened.referrer = referrer;
+    opened.priority_slots_override = priority_slots_override;
+    opened.owner_counted = true;
+    opened.fee_bps_override = fee_bps_override;
+    ctx.accounts.position.set_inner(opened);
+
+    let stats = &mut ctx.accounts.owner_stats;
+    if stats.bump == 0 {
+        stats.owner = ctx.accounts.user.key();
+        stats.bump = ctx.bumps.owner_stats;
+    }
+    stats.take_slot(ctx.accounts.config.max_positions_per_owner)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.position = ctx.accounts.meteora_position.key();
+    vault.bump = ctx.bumps.vault;
+
+    // Persist counter bump on first creation, then increment
+    let counter = &mut ctx.accounts.position_counter;
+    if counter.bump == 0 {
+        counter.bump = ctx.bumps.position_counter;
+    }
+    counter.count = counter.count.checked_add(1).ok_or(CoreError::Overflow)?;
+
+    let config = &mut ctx.accounts.config;
+    config.total_positions = config.total_positions.saturating_add(1);
+    config.total_volume = config.total_volume.saturating_add(amount);
+
+    emit!(PositionOpenedEvent {
+        position: ctx.accounts.position.key(),
+        user: ctx.accounts.user.key(),
+        lb_pair: ctx.accounts.lb_pair.key(),
+        side,
+        amount,
+        min_bin_id,
+        max_bin_id,
+        timestamp: Clock::get()?.unix_timestamp,
+        take_profit_bins,
+        strategy,
+        wrapped_lamports,
+    });
+
+    msg!("Position opened: {} | {} bins [{},{}] | {} lamports",
+        ctx.accounts.position.key(), width, min_bin_id, max_bin_id, amount);
+    Ok(())
 }
 
-// ============ EVENTS ============
+/// Shared body of user_close / user_close_unwrap / user_close_claim_first. `unwrap`
+/// closes user_token_y (WSOL) to the user after the transfers. `claim_first` claims
+/// LP fees before removing liquidity and passes only the claimed amounts to the user
+/// fee-free; anything already sitting in the vault stays in the close fee base.
+fn execute_user_close<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UserClose<'info>>,
+    min_x_out: u64,
+    min_y_out: u64,
+    unwrap: bool,
+    claim_first: bool,
+) -> Result<()> {
+    release_owner_slot(&ctx.accounts.owner_stats, &ctx.accounts.position)?;
+
+    let side = ctx.accounts.position.side;
+    let referrer = ctx.accounts.position.referrer;
+    let min_bin_id = ctx.accounts.position.min_bin_id;
+    let max_bin_id = ctx.accounts.position.max_bin_id;
+    let meteora_pos_key = ctx.accounts.position.meteora_position;
+
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        meteora_pos_key.as_ref(),
+        &[ctx.accounts.vault.bump],
+    ];
+    let signer = &[vault_seeds];
+
+    // 1. Optional claim-first: claim LP fees and pass the claimed delta to the user
+    let y_lp_fees = if claim_first {
+        let (quoted_x, quoted_y) = claim_fees_quote(&ctx.accounts.meteora_position);
+        let x_before = ctx.accounts.vault_token_x.amount;
+        let y_before = ctx.accounts.vault_token_y.amount;
+        user_close_claim_fee(&ctx, min_bin_id, max_bin_id, signer)?;
+        ctx.accounts.vault_token_x.reload()?;
+        ctx.accounts.vault_token_y.reload()?;
+        let x_lp_fees = ctx.accounts.vault_token_x.amount.saturating_sub(x_before);
+        let y_lp_fees = ctx.accounts.vault_token_y.amount.saturating_sub(y_before);
 
-#[event]
-pub struct PositionOpenedEvent {
-    pub position: Pubkey,
-    pub user: Pubkey,
-    pub lb_pair: Pubkey,
-    pub side: Side,
-    pub amount: u64,
-    pub min_bin_id: i32,
-    pub max_bin_id: i32,
-    pub timestamp: i64,
+        if x_lp_fees > 0 {
+            let decimals = read_mint_decimals(&ctx.accounts.token_x_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.user_token_x.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_x_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_x.to_account_info(),
+                    mint: ctx.accounts.token_x_mint.to_account_info(),
+                    to: ctx.accounts.user_token_x.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), x_lp_fees, decimals)?;
+        }
+        if y_lp_fees > 0 {
+            let decimals = read_mint_decimals(&ctx.accounts.token_y_mint)?;
+            memo_cpi(&ctx.accounts.memo_program, &ctx.accounts.vault.to_account_info(), &ctx.accounts.user_token_y.to_account_info(), signer)?;
+            transfer_checked(CpiContext::new_with_signer(
+                ctx.accounts.token_y_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_y.to_account_info(),
+                    mint: ctx.accounts.token_y_mint.to_account_info(),
+                    to: ctx.accounts.user_token_y.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                }, signer,
+            ), y_lp_fees, decimals)?;
+        }
+        ctx.accounts.vault_token_x.reload()?;
+        ctx.accounts.vault_token_y.reload()?;
+
+        emit!(ClaimFeesEvent {
+            position: ctx.accounts.position.key(),
+            user: ctx.accounts.user.key(),
+            claimed_by: ctx.accounts.user.key(),
+            lb_pair: ctx.accounts.position.lb_pair,
+            x_amount: x_lp_fees,
+            y_amount: y_lp_fees,
+            quoted_x,
+            quoted_y,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        y_lp_fees
+    } else {
+        0
+    };
+
+    // 2. Remove all liquidity
+    let remaining = &[
+        ctx.accounts.bin_array_lower.to_account_info(),
+        ctx.accounts.bin_array_upper.to_account_info(),
+    ];
+    remove_liquidity_by_range_auto(
+        &[
+            ctx.accounts.meteora_position.to_account_info(),
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.accounts.bin_array_bitmap_ext.to_account_info(),
+            ctx.accounts.vault_token_x.to_account_info(),
+            ctx.accounts.vault_token_y.to_account_info(),
+            ctx.accounts.reserve_x.to_account_info(),
+            ctx.accounts.reserve_y.to_account_info(),
+            ctx.accounts.token_x_mint.to_account_info(),
+            ctx.accounts.token_y_mint.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.token_y_program.to_account_info(),
+            ctx.accounts.memo_program.to_account_info(),
+            ctx.accounts.event_authority.to_account_info(),
+            ctx.accounts.dlmm_program.to_account_info(),
+        ],
+        min_bin_id,
+        max_bin_id,
+        10_000,
+        RemainingAccountsInfo::none(),
+        signer,
+        remaining,
+    )?;
+
+    // 3. Claim fees (already done in step 1 for claim_first)
+    if !claim_first {
+        user_close_claim_fee(&ctx, min_bin_id, max_bin_id, signer)?;
+    }
+
+    // 4. Close Meteora position (rent -> user)
+    close_position2(
+        &[
+            ctx.accounts.meteora_position.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.event_authority.to_account_info(),
+            ctx.accounts.dlmm_program.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    let position_key = ctx.accounts.position.key();
+    let user_key = ctx.accounts.user.key();
+
+    let (x_fee, y_fee, x_out, y_out, referral_amount) = execute_close_transfers(
+        side,
+        ctx.accounts.position.effective_fee_bps(ctx.accounts.config.fee_bps),
+        &mut ctx.accounts.vault_token_x,
+        &mut ctx.accounts.vault_token_y,
+        &ctx.accounts.user_token_x.to_account_info(),
+        &ctx.accounts.user_token_y.to_account_info(),
+        &ctx.accounts.rover_fee_token_y.to_account_info(),
+        &ctx.accounts.rover_fee_token_x.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_x_program.to_account_info(),
+        &ctx.accounts.token_y_program.to_account_info(),
+        &ctx.accounts.token_x_mint.to_account_info(),
+        &ctx.accounts.token_y_mint.to_account_info(),
+        &ctx.accounts.memo_program,
+        signer,
+        (min_x_out, min_y_out),
+        ReferralSplit {
+            referrer,
+            bps: ctx.accounts.config.effective_referral_bps(&referrer),
+            remaining_accounts: ctx.remaining_accounts,
+        },
+    )?;
+
+    // 5. Optional unwrap: close the temporary WSOL account to the user
+    let unwrapped_lamports = if unwrap {
+        require!(ctx.accounts.token_y_mint.key() == WSOL_MINT, CoreError::UnwrapNotWsol);
+        ctx.accounts.user_token_y.reload()?;
+        let expected = y_out.checked_add(y_lp_fees).ok_or(CoreError::Overflow)?;
+        require!(ctx.accounts.user_token_y.amount == expected, CoreError::UnwrapAccountNotEmpty);
+        let lamports = ctx.accounts.user_token_y.to_account_info().lamports();
+        close_account(CpiContext::new(
+            ctx.accounts.token_y_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.user_token_y.to_account_info(),
+                destination: ctx.accounts.user.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ))?;
+        lamports
+    } else {
+        0
+    };
+
+    let close_harvested = match side { Side::Buy => x_out, Side::Sell => y_out };
+    ctx.accounts.config.total_harvested = ctx.accounts.config.total_harvested
+        .checked_add(close_harvested).ok_or(CoreError::Overflow)?;
+    let close_fee = match side { Side::Buy => x_fee, Side::Sell => y_fee };
+    record_pool_metrics(&ctx.accounts.pool_metrics, close_harvested, close_fee, true)?;
+
+    let position = &mut ctx.accounts.position;
+    position.total_fees_paid_x = position.total_fees_paid_x
+        .checked_add(x_fee).ok_or(CoreError::Overflow)?;
+    position.total_fees_paid_y = position.total_fees_paid_y
+        .checked_add(y_fee).ok_or(CoreError::Overflow)?;
+
+    emit!(CloseEvent {
+        position: position_key,
+        owner: user_key,
+        side,
+        token_x_out: x_out,
+        token_y_out: y_out,
+        x_fee,
+        y_fee,
+        bot_initiated: false,
+        total_fees_paid_x: position.total_fees_paid_x,
+        total_fees_paid_y: position.total_fees_paid_y,
+        referral_amount,
+        position_kind: position.position_kind,
+        unwrapped_lamports,
+    });
+
+    Ok(())
 }
 
-#[event]
-pub struct HarvestEvent {
-    pub position: Pubkey,
-    pub owner: Pubkey,
-    pub lb_pair: Pubkey,       // Pool address for indexers
-    pub harvester: Pubkey,     // Who called harvest (bot key or permissionless keeper)
-    pub bin_ids: Vec<i32>,
-    pub token_x_amount: u64,
-    pub token_y_amount: u64,
-    pub fee_amount: u64,
-    pub keeper_tip: u64,       // Tip paid to permissionless harvester (0 if authorized bot)
-    pub total_harvested: u64,
+/// claim_fee CPI for execute_user_close (both the claim-first and the default order).
+fn user_close_claim_fee<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, UserClose<'info>>,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    let remaining = &[
+        ctx.accounts.bin_array_lower.to_account_info(),
+        ctx.accounts.bin_array_upper.to_account_info(),
+    ];
+    claim_fee_auto(
+        &[
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.accounts.meteora_position.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.reserve_x.to_account_info(),
+            ctx.accounts.reserve_y.to_account_info(),
+            ctx.accounts.vault_token_x.to_account_info(),
+            ctx.accounts.vault_token_y.to_account_info(),
+            ctx.accounts.token_x_mint.to_account_info(),
+            ctx.accounts.token_y_mint.to_account_info(),
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.token_y_program.to_account_info(),
+            ctx.accounts.memo_program.to_account_info(),
+            ctx.accounts.event_authority.to_account_info(),
+            ctx.accounts.dlmm_program.to_account_info(),
+        ],
+        min_bin_id,
+        max_bin_id,
+        RemainingAccountsInfo::none(),
+        signer,
+        remaining,
+    )
 }
 
-#[event]
-pub struct ClaimFeesEvent {
-    pub position: Pubkey,
-    pub user: Pubkey,
-    pub lb_pair: Pubkey,
-    pub x_amount: u64,
-    pub y_amount: u64,
-    pub timestamp: i64,
+/// Shared body of close_position / close_position_empty (close_if_above delegates to
+/// close_position). `remove_liquidity = false` skips the remove CPI and relies on
+/// Meteora close_position_if_empty to reject positions that still hold liquidity.
+/// `hook_lens` = (X, Y) transfer-hook account counts at the tail of remaining_accounts,
+/// after the optional referrer ATA; forwarded to both the remove and claim CPIs.
+fn execute_bot_close<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClosePosition<'info>>,
+    min_x_out: u64,
+    min_y_out: u64,
+    remove_liquidity: bool,
+    hook_lens: (u8, u8),
+) -> Result<()> {
+    let (hook_x_len, hook_y_len) = hook_lens;
+    let (extra_accounts, hook_accounts) =
+        split_hook_accounts(ctx.remaining_accounts, hook_x_len, hook_y_len)
+            .ok_or(CoreError::MissingHookAccounts)?;
+
+    // --- Permissionless close fallback (same pattern as harvest_bins) ---
+    let clock = Clock::get()?;
+    let is_authorized_bot = ctx.accounts.bot.key() == ctx.accounts.config.bot;
+
+    if is_authorized_bot {
+        // Bot-paused only applies to the authorized bot
+        require!(!ctx.accounts.config.bot_paused, CoreError::BotPaused);
+        ctx.accounts.config.last_bot_close_slot = clock.slot;
+    } else {
+        require_close_unlocked(&ctx.accounts.config, &ctx.accounts.position, clock.slot)?;
+    }
+
+    release_owner_slot(&ctx.accounts.owner_stats, &ctx.accounts.position)?;
+
+    let side = ctx.accounts.position.side;
+    let referrer = ctx.accounts.position.referrer;
+    let min_bin_id = ctx.accounts.position.min_bin_id;
+    let max_bin_id = ctx.accounts.position.max_bin_id;
+    let meteora_pos_key = ctx.accounts.position.meteora_position;
+
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        meteora_pos_key.as_ref(),
+        &[ctx.accounts.vault.bump],
+    ];
+    let signer = &[vault_seeds];
+
+    // 1. Remove ALL remaining liquidity (skipped on the empty-position fast path)
+    let remaining = hooks_then_bin_arrays(hook_accounts, &[
+        ctx.accounts.bin_array_lower.to_account_info(),
+        ctx.accounts.bin_array_upper.to_account_info(),
+    ]);
+    if remove_liquidity {
+        remove_liquidity_by_range_auto(
+            &[
+                ctx.accounts.meteora_position.to_account_info(),
+                ctx.accounts.lb_pair.to_account_info(),
+                ctx.accounts.bin_array_bitmap_ext.to_account_info(),
+                ctx.accounts.vault_token_x.to_account_info(),
+                ctx.accounts.vault_token_y.to_account_info(),
+                ctx.accounts.reserve_x.to_account_info(),
+                ctx.accounts.reserve_y.to_account_info(),
+                ctx.accounts.token_x_mint.to_account_info(),
+                ctx.accounts.token_y_mint.to_account_info(),
+                ctx.accounts.vault.to_account_info(),
+                ctx.accounts.token_x_program.to_account_info(),
+                ctx.accounts.token_y_program.to_account_info(),
+                ctx.accounts.memo_program.to_account_info(),
+                ctx.accounts.event_authority.to_account_info(),
+                ctx.accounts.dlmm_program.to_account_info(),
+            ],
+            min_bin_id,
+            max_bin_id,
+            10_000,
+            RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
+            signer,
+            &remaining,
+        )?;
+    }
+
+    // 2. Claim accrued trading fees
+    claim_fee_auto(
+        &[
+            ctx.accounts.lb_pair.to_account_info(),
+            ctx.accounts.meteora_position.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.reserve_x.to_account_info(),
+            ctx.accounts.reserve_y.to_account_info(),
+            ctx.accounts.vault_token_x.to_account_info(),
+            ctx.accounts.vault_token_y.to_account_info(),
+            ctx.accounts.token_x_mint.to_account_info(),
+            ctx.accounts.token_y_mint.to_account_info(),
+            ctx.accounts.token_x_program.to_account_info(),
+            ctx.accounts.token_y_program.to_account_info(),
+            ctx.accounts.memo_program.to_account_info(),
+            ctx.accounts.event_authority.to_account_info(),
+            ctx.accounts.dlmm_program.to_account_info(),
+        ],
+        min_bin_id,
+        max_bin_id,
+        RemainingAccountsInfo::with_hooks(hook_x_len, hook_y_len),
+        signer,
+        &remaining,
+    )?;
+
+    // 3. Close Meteora position (rent -> bot)
+    let close_accounts = [
+        ctx.accounts.meteora_position.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.bot.to_account_info(),
+        ctx.accounts.event_authority.to_account_info(),
+        ctx.accounts.dlmm_program.to_account_info(),
+    ];
+    if remove_liquidity {
+        close_position2(&close_accounts, signer)?;
+    } else {
+        close_position_if_empty(&close_accounts, signer)?;
+    }
+
+    let position_key = ctx.accounts.position.key();
+    let owner_key = ctx.accounts.owner.key();
+
+    let (x_fee, y_fee, x_out, y_out, referral_amount) = execute_close_transfers(
+        side,
+        ctx.accounts.position.effective_fee_bps(ctx.accounts.config.fee_bps),
+        &mut ctx.accounts.vault_token_x,
+        &mut ctx.accounts.vault_token_y,
+        &ctx.accounts.owner_token_x.to_account_info(),
+        &ctx.accounts.owner_token_y.to_account_info(),
+        &ctx.accounts.rover_fee_token_y.to_account_info(),
+        &ctx.accounts.rover_fee_token_x.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.owner,
+        &ctx.accounts.token_x_program.to_account_info(),
+        &ctx.accounts.token_y_program.to_account_info(),
+        &ctx.accounts.token_x_mint.to_account_info(),
+        &ctx.accounts.token_y_mint.to_account_info(),
+        &ctx.accounts.memo_program,
+        signer,
+        (min_x_out, min_y_out),
+        ReferralSplit {
+            referrer,
+            bps: ctx.accounts.config.effective_referral_bps(&referrer),
+            remaining_accounts: extra_accounts,
+        },
+    )?;
+
+    // 4. Rover wSOL vault ATA (funded by open_rover_position_sol or a wSOL deposit) is
+    // empty after the transfers above; close it so its rent follows the vault PDA's
+    // to the owner (rover_authority, swept by sweep_rover)
+    if ctx.accounts.position.position_kind == POSITION_KIND_ROVER
+        && ctx.accounts.token_y_mint.key() == WSOL_MINT
+    {
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_y_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault_token_y.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            }, signer,
+        ))?;
+    }
+
+    let close_harvested = match side { Side::Buy => x_out, Side::Sell => y_out };
+    ctx.accounts.config.total_harvested = ctx.accounts.config.total_harvested
+        .checked_add(close_harvested).ok_or(CoreError::Overflow)?;
+    let close_fee = match side { Side::Buy => x_fee, Side::Sell => y_fee };
+    record_pool_metrics(&ctx.accounts.pool_metrics, close_harvested, close_fee, true)?;
+
+    let position = &mut ctx.accounts.position;
+    position.total_fees_paid_x = position.total_fees_paid_x
+        .checked_add(x_fee).ok_or(CoreError::Overflow)?;
+    position.total_fees_paid_y = position.total_fees_paid_y
+        .checked_add(y_fee).ok_or(CoreError::Overflow)?;
+
+    emit!(CloseEvent {
+        position: position_key,
+        owner: owner_key,
+        side,
+        token_x_out: x_out,
+        token_y_out: y_out,
+        x_fee,
+        y_fee,
+        bot_initiated: true,
+        total_fees_paid_x: position.total_fees_paid_x,
+        total_fees_paid_y: position.total_fees_paid_y,
+        referral_amount,
+        position_kind: position.position_kind,
+        unwrapped_lamports: 0,
+    });
+
+    Ok(())
 }
 
-#[event]
-pub struct CloseEvent {
-    pub position: Pubkey,
-    pub owner: Pubkey,
-    pub side: Side,
-    pub token_x_out: u64,
-    pub token_y_out: u64,
-    pub x_fee: u64,
-    pub y_fee: u64,
-    pub bot_initiated: bool,
+/// Load a PoolStatus PDA, or None if it was never set. Accounts still on the
+/// pre-override layout (PoolStatus::LEGACY_SIZE) read min_position_amount as 0.
+fn read_pool_status(pool_status: &AccountInfo) -> Result<Option<PoolStatus>> {
+    if pool_status.owner != &crate::ID || pool_status.data_is_empty() {
+        return Ok(None);
+    }
+    let data = pool_status.try_borrow_data()?;
+    let mut buf = [0u8; PoolStatus::SIZE];
+    let len = core::cmp::min(data.len(), PoolStatus::SIZE);
+    buf[..len].copy_from_slice(&data[..len]);
+    Ok(Some(PoolStatus::try_deserialize(&mut &buf[..])?))
 }
 
-#[event]
-pub struct FeeChangeProposedEvent {
-    pub new_fee_bps: u16,
-    pub effective_at: i64,
+/// Add one harvest / close to lb_pair's PoolMetrics. Metrics are opt-in per pool
+/// (init_pool_metrics); an uninitialized PDA is skipped.
+fn record_pool_metrics(pool_metrics: &AccountInfo, harvested: u64, fees: u64, closed: bool) -> Result<()> {
+    if pool_metrics.owner != &crate::ID || pool_metrics.data_is_empty() {
+        return Ok(());
+    }
+    let mut data = pool_metrics.try_borrow_mut_data()?;
+    let mut metrics = PoolMetrics::try_deserialize(&mut &data[..])?;
+    metrics.lifetime_harvested = metrics.lifetime_harvested.saturating_add(harvested);
+    metrics.lifetime_fees = metrics.lifetime_fees.saturating_add(fees);
+    if closed {
+        metrics.position_count = metrics.position_count.saturating_add(1);
+    }
+    metrics.try_serialize(&mut &mut data[..])?;
+    Ok(())
 }
 
-#[event]
-pub struct FeeAppliedEvent {
-    pub old_fee_bps: u16,
-    pub new_fee_bps: u16,
+/// Settled LP fees (x, y) of a Meteora PositionV2 account (quote_lp_fees).
+/// InvalidPositionData if it is too short to hold the fee_infos.
+fn quote_position_fees(meteora_position: &AccountInfo) -> Result<(u64, u64)> {
+    let data = meteora_position.try_borrow_data()?;
+    read_position_pending_fees(&data).ok_or_else(|| error!(CoreError::InvalidPositionData))
 }
 
-#[event]
-pub struct FeeChangeCancelledEvent {
-    pub cancelled_fee_bps: u16,
-    pub was_effective_at: i64,
+/// quote_lp_fees return data: x_fees then y_fees, little-endian u64s.
+fn lp_fees_return_data(x_fees: u64, y_fees: u64) -> [u8; 16] {
+    let mut data = [0u8; 16];
+    data[0..8].copy_from_slice(&x_fees.to_le_bytes());
+    data[8..16].copy_from_slice(&y_fees.to_le_bytes());
+    data
 }
 
-#[event]
-pub struct EmergencyCloseEvent {
-    pub position: Pubkey,
-    pub timestamp: i64,
+/// The quote claim paths record on ClaimFeesEvent.quoted_x/y before the claim CPI.
+/// Never blocks the claim: an unreadable layout just quotes 0.
+fn claim_fees_quote(meteora_position: &AccountInfo) -> (u64, u64) {
+    quote_position_fees(meteora_position).unwrap_or((0, 0))
 }
 
-#[event]
-pub struct AdminConfigEvent {
-    pub field: String,
-    pub authority: Pubkey,
-    pub timestamp: i64,
+/// claim_fees_compound's split of the claimed fees:
+/// (x_compounded, y_compounded, x_paid_out, y_paid_out).
+/// X is accepted only above active_id, Y only below; a compoundable amount under
+/// MIN_COMPOUND_AMOUNT_PER_BIN per bin is paid out with the other token.
+fn fee_compound_amounts(
+    min_bin_id: i32,
+    max_bin_id: i32,
+    active_id: i32,
+    x_claimed: u64,
+    y_claimed: u64,
+) -> Result<(u64, u64, u64, u64)> {
+    let compound_x = min_bin_id > active_id;
+    let compound_y = max_bin_id < active_id;
+    let bins = max_bin_id.checked_sub(min_bin_id).and_then(|d| d.checked_add(1))
+        .ok_or(CoreError::Overflow)? as u64;
+    let min_compound = MIN_COMPOUND_AMOUNT_PER_BIN.checked_mul(bins).ok_or(CoreError::Overflow)?;
+    let (x_compounded, y_compounded) = match (compound_x, compound_y) {
+        (true, _) if x_claimed >= min_compound => (x_claimed, 0u64),
+        (_, true) if y_claimed >= min_compound => (0u64, y_claimed),
+        _ => (0u64, 0u64),
+    };
+    Ok((
+        x_compounded,
+        y_compounded,
+        x_claimed.checked_sub(x_compounded).ok_or(CoreError::Overflow)?,
+        y_claimed.checked_sub(y_compounded).ok_or(CoreError::Overflow)?,
+    ))
 }
 
-// ============ STATE ============
+/// claim_reward split of what claim_reward2 moved into the vault's reward account:
+/// (claimed, protocol fee, owner share). Only this call's delta is paid out — any
+/// balance already in the account before the claim stays put.
+fn reward_claim_split(before: u64, after: u64, fee_bps: u16) -> Result<(u64, u64, u64)> {
+    let claimed = after.saturating_sub(before);
+    let fee = (claimed as u128)
+        .checked_mul(fee_bps as u128).ok_or(CoreError::Overflow)?
+        .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
+    let to_owner = claimed.checked_sub(fee).ok_or(CoreError::Overflow)?;
+    Ok((claimed, fee, to_owner))
+}
 
-#[account]
-pub struct Config {
-    pub authority: Pubkey,
-    pub pending_authority: Pubkey,
-    pub bot: Pubkey,
-    pub fee_bps: u16,
-    pub pending_fee_bps: u16,    // Timelock — proposed fee (0 = none pending)
-    pub fee_change_at: i64,      // Timelock — Unix timestamp when pending fee can be applied (0 = none)
-    pub total_positions: u64,
-    pub total_volume: u64,
-    pub paused: bool,
-    pub bot_paused: bool,
-    pub bump: u8,
-    // --- Permissionless harvest fallback ---
-    pub last_bot_harvest_slot: u64, // Slot of last authorized bot harvest (heartbeat)
-    pub keeper_tip_bps: u16,        // Tip % for permissionless harvesters (e.g. 1000 = 10%)
-    pub priority_slots: u64,        // Staleness threshold (~100 slots = ~40s)
-    pub total_harvested: u64,       // Lifetime harvested output across all positions
-    // --- Emergency escape hatch ---
-    pub pending_emergency_close: Pubkey, // Position key pending emergency close (default = none)
-    pub emergency_close_at: i64,         // Timestamp when emergency close can execute (0 = none)
-    // --- Permissionless close + sweep heartbeat ---
-    pub last_bot_close_slot: u64,        // Slot of last bot-initiated close_position
-    pub last_bot_sweep_slot: u64,        // Slot of last bot-initiated sweep_rover
-    // Reserved space for future fields (e.g. strategy platform)
-    pub _reserved: [u8; 96],
+/// reclaim_orphan_vault guard: the vault's Position PDA must be absent (empty or
+/// not owned by this program), and leftover tokens can only be swept by
+/// config.authority.
+fn require_orphan_reclaim(
+    position: &AccountInfo,
+    x_amount: u64,
+    y_amount: u64,
+    caller: &Pubkey,
+    config: &Config,
+) -> Result<()> {
+    require!(
+        position.owner != &crate::ID || position.data_is_empty(),
+        CoreError::VaultNotOrphaned
+    );
+    if x_amount > 0 || y_amount > 0 {
+        require!(*caller == config.authority, CoreError::Unauthorized);
+    }
+    Ok(())
 }
 
-impl Config {
-    // 8 (disc) + 32*3 (authority, pending_authority, bot) + 2+2+8 (fee_bps, pending, change_at)
-    // + 8+8 (positions, volume) + 1+1+1 (paused, bot_paused, bump)
-    // + 8+2+8+8 (harvest slot, keeper_tip, priority, harvested)
-    // + 32+8 (emergency close) + 8+8 (close/sweep slots) + 96 (reserved)
-    pub const SIZE: usize = 8 + 32 + 32 + 32 + 2 + 2 + 8 + 8 + 8 + 1 + 1 + 1 + 8 + 2 + 8 + 8 + 32 + 8 + 8 + 8 + 96;
+/// open_position_v2's anti-sandwich check: the live active_id must be within
+/// `max_drift` bins of the caller's quote. Negative max_drift uses
+/// DEFAULT_MAX_ACTIVE_BIN_DRIFT.
+fn require_active_bin_within_drift(active_id: i32, expected_active_id: i32, max_drift: i32) -> Result<()> {
+    let max_drift = if max_drift < 0 { DEFAULT_MAX_ACTIVE_BIN_DRIFT } else { max_drift };
+    let drift = (active_id as i64).checked_sub(expected_active_id as i64)
+        .ok_or(CoreError::Overflow)?
+        .unsigned_abs();
+    if drift > max_drift as u64 {
+        msg!("ActiveBinDrifted: active {} vs expected {} (max drift {})", active_id, expected_active_id, max_drift);
+        return err!(CoreError::ActiveBinDrifted);
+    }
+    Ok(())
 }
 
-#[account]
-pub struct Position {
-    pub owner: Pubkey,
-    pub lb_pair: Pubkey,
-    pub meteora_position: Pubkey,
-    pub side: Side,
-    pub min_bin_id: i32,
-    pub max_bin_id: i32,
-    pub initial_amount: u64,
-    pub harvested_amount: u64,
-    pub created_at: i64,
-    pub bump: u8,
+/// open_position_v2's fee_bps_override: FEE_BPS_OVERRIDE_NONE keeps config.fee_bps,
+/// anything else must not exceed it and needs config.authority as fee_authority.
+fn resolve_fee_bps_override(requested: u16, config: &Config, fee_authority: Option<Pubkey>) -> Result<Option<u16>> {
+    if requested == FEE_BPS_OVERRIDE_NONE {
+        return Ok(None);
+    }
+    require!(requested <= config.fee_bps, CoreError::InvalidFeeOverride);
+    require!(fee_authority == Some(config.authority), CoreError::Unauthorized);
+    Ok(Some(requested))
 }
 
-impl Position {
-    pub const SIZE: usize = 8 + 32 + 32 + 32 + 1 + 4 + 4 + 8 + 8 + 8 + 1;
+/// open_position_v2's deposit floor: the pool's PoolStatus override when set,
+/// otherwise Config's minimum.
+fn min_position_amount(config: &Config, pool_status: &AccountInfo) -> Result<u64> {
+    Ok(read_pool_status(pool_status)?
+        .map(|status| status.min_position_amount)
+        .filter(|&min| min > 0)
+        .unwrap_or_else(|| config.effective_min_position_amount()))
 }
 
-#[account]
-pub struct Vault {
-    pub position: Pubkey,  // meteora_position this vault is bound to (1:1)
-    pub bump: u8,
+/// Reverts with PoolBlocked if the PoolStatus PDA exists and is blocked.
+/// An uninitialized PDA (never set) means the pool is open.
+fn require_pool_open(pool_status: &AccountInfo) -> Result<()> {
+    if let Some(status) = read_pool_status(pool_status)? {
+        require!(!status.blocked, CoreError::PoolBlocked);
+    }
+    Ok(())
 }
 
-impl Vault {
-    pub const SIZE: usize = 8 + 32 + 1;
+/// open_rover_batch entries carry their PoolStatus in remaining_accounts, so the
+/// address Anchor's seeds would enforce is checked here before require_pool_open.
+fn require_batch_pool_open(lb_pair: &AccountInfo, pool_status: &AccountInfo) -> Result<()> {
+    let (pool_status_pda, _) =
+        Pubkey::find_program_address(&[b"pool_status", lb_pair.key.as_ref()], &crate::ID);
+    require!(*pool_status.key == pool_status_pda, CoreError::RoverBatchAccountMismatch);
+    require_pool_open(pool_status)
 }
 
-/// Per-user-per-pool counter for deterministic meteora position PDA derivation.
-/// Enables multiple positions per pool without requiring a keypair signer.
-#[account]
-pub struct PositionCounter {
-    pub count: u64,
-    pub bump: u8,
+/// Give back the owner's OwnerStats slot for a position about to be closed or
+/// handed to another wallet. Uncounted positions (rovers, opened before the cap
+/// shipped) leave OwnerStats alone; counted ones always have it initialized.
+fn release_owner_slot(owner_stats: &AccountInfo, position: &Position) -> Result<()> {
+    if !position.owner_counted {
+        return Ok(());
+    }
+    require!(owner_stats.owner == &crate::ID, CoreError::InvalidOwnerStats);
+    let mut data = owner_stats.try_borrow_mut_data()?;
+    let mut stats = OwnerStats::try_deserialize(&mut &data[..])?;
+    require!(stats.owner == position.owner, CoreError::InvalidOwnerStats);
+    stats.open_positions = stats.open_positions.saturating_sub(1);
+    stats.try_serialize(&mut &mut data[..])?;
+    Ok(())
 }
 
-impl PositionCounter {
-    pub const SIZE: usize = 8 + 8 + 1;
+/// Count a position against `owner`'s OwnerStats (transfer_position's receiving
+/// side), creating the PDA on first use. An empty account is the uninitialized
+/// one; anything else must deserialize as this owner's OwnerStats.
+fn take_owner_slot<'info>(
+    owner_stats: &AccountInfo<'info>,
+    owner: &Pubkey,
+    bump: u8,
+    max_positions: u64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if owner_stats.data_is_empty() {
+        create_pda_account(
+            payer,
+            owner_stats,
+            system_program,
+            OwnerStats::SIZE,
+            &[b"owner_stats", owner.as_ref(), &[bump]],
+        )?;
+        OwnerStats { owner: *owner, open_positions: 0, bump }
+            .try_serialize(&mut &mut owner_stats.try_borrow_mut_data()?[..])?;
+    }
+    require!(owner_stats.owner == &crate::ID, CoreError::InvalidOwnerStats);
+    let mut data = owner_stats.try_borrow_mut_data()?;
+    let mut stats = OwnerStats::try_deserialize(&mut &data[..])?;
+    require!(stats.owner == *owner, CoreError::InvalidOwnerStats);
+    stats.take_slot(max_positions)?;
+    stats.try_serialize(&mut &mut data[..])?;
+    Ok(())
 }
 
-/// Rover authority PDA — owns rover (bribe) positions.
-/// Harvest proceeds accumulate here. sweep_rover splits SOL 60/40: 60% to revenue_dest (bridge_vault), 40% to Config.bot.
-#[account]
-pub struct RoverAuthority {
-    pub revenue_dest: Pubkey,              // Where monke share goes (bridge_vault PDA — 60% of swept SOL)
-    pub total_rover_positions: u64,        // Lifetime count
-    pub bump: u8,
-    pub pending_revenue_dest: Pubkey,      // Timelocked: proposed new revenue_dest
-    pub revenue_dest_change_at: i64,       // Timelocked: timestamp when pending can be applied (0 = none)
-    // Reserved space for future fields (avoids account reallocation post-deploy)
-    pub _reserved: [u8; 64],
+/// Reverts with MintMismatch unless the passed mints are the lb_pair's own
+/// token_x_mint / token_y_mint, so deposits and fees can't route to the wrong vaults.
+fn require_lb_pair_mints(lb_pair: &AccountInfo, token_x_mint: &Pubkey, token_y_mint: &Pubkey) -> Result<()> {
+    let data = lb_pair.try_borrow_data()?;
+    let (pool_x, pool_y) = read_lb_pair_mints(&data).ok_or(CoreError::InvalidPool)?;
+    require!(pool_x == *token_x_mint && pool_y == *token_y_mint, CoreError::MintMismatch);
+    Ok(())
 }
 
-impl RoverAuthority {
+/// Accounts one harvest touches. harvest_bins fills it from BotHarvest;
+/// harvest_bins_multi from the shared pool accounts plus one position's
+/// remaining_accounts group. Position and the vault token accounts are passed
+/// to execute_harvest separately because they are mutated.
+struct HarvestAccounts<'a, 'info> {
+    bot: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    vault_bump: u8,
+    meteora_position: AccountInfo<'info>,
+    lb_pair: AccountInfo<'info>,
+    bin_array_bitmap_ext: AccountInfo<'info>,
+    bin_array_lower: AccountInfo<'info>,
+    bin_array_upper: AccountInfo<'info>,
+    reserve_x: AccountInfo<'info>,
+    reserve_y: AccountInfo<'info>,
+    token_x_mint: AccountInfo<'info>,
+    token_y_mint: AccountInfo<'info>,
+    event_authority: AccountInfo<'info>,
+    dlmm_program: AccountInfo<'info>,
+    owner_token_x: AccountInfo<'info>,
+    owner_token_y: AccountInfo<'info>,
+    rover_fee_token_x: AccountInfo<'info>,
+    rover_fee_token_y: AccountInfo<'info>,
+    token_x_program: AccountInfo<'info>,
+    token_y_program: AccountInfo<'info>,
+    memo_program: AccountInfo<'info>,
+    extra_accounts: &'a [AccountInfo<'info>], // keeper ATA(s), keeper pool, referrer ATA
+    hook_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Per-harvest arguments for execute_harvest. `from_bin` / `to_bin` come from check_harvest.
+struct HarvestParams<'b> {
+    bin_ids: &'b [i32],
+    from_bin: i32,
+    to_bin: i32,
+    bps_to_remove: u16,
+    tip_split_bps: u16,
+    hook_x_len: u8,
+    hook_y_len: u8,
+    is_authorized_bot: bool,
+    slot: u64,
+}
+
+/// close_position's permissionless gate: the bot's last close is older than the
+/// close window (close_priority_slots, or priority_slots while that is unset).
+/// Harvests use check_harvest's own window.
+fn require_close_unlocked(config: &Config, position: &Position, slot: u64) -> Result<()> {
+    let slots_since = slot
+        .checked_sub(config.last_bot_close_slot)
+        .ok_or(CoreError::Overflow)?;
+    let priority_slots = position.effective_priority_slots(config.effective_close_priority_slots());
+    require!(slots_since > priority_slots, CoreError::BotNotStale);
+    Ok(())
+}
+
+/// Everything harvest_bins rejects before the removal CPI: bin count and range,
+/// contiguity, bps, and for keepers the bot-staleness window and per-position
+/// cooldown. Returns (from_bin, to_bin). harvest_bins_multi runs it per position
+/// so skip_failures can skip a position without touching Meteora.
+fn check_harvest(
+    config: &Config,
+    position: &Position,
+    bin_ids: &[i32],
+    bps_to_remove: u16,
+    is_authorized_bot: bool,
+    slot: u64,
+) -> Result<(i32, i32)> {
+    require!(!bin_ids.is_empty(), CoreError::NoBinsProvided);
+    let max_bins = config.effective_max_bins_per_harvest();
+    if bin_ids.len() > max_bins as usize {
+        msg!("TooManyBins: {} bins provided, effective cap is {}", bin_ids.len(), max_bins);
+        return err!(CoreError::TooManyBins);
+    }
+    require!(bps_to_remove > 0 && bps_to_remove <= 10_000, CoreError::InvalidBpsToRemove);
+
+    for &bin_id in bin_ids {
+        require!(
+            bin_id >= position.min_bin_id && bin_id <= position.max_bin_id,
+            CoreError::BinOutOfPositionRange
+        );
+    }
+
+    let from_bin = *bin_ids.iter().min().ok_or(CoreError::NoBinsProvided)?;
+    let to_bin = *bin_ids.iter().max().ok_or(CoreError::NoBinsProvided)?;
+
+    // Enforce contiguous range — remove_liquidity_by_range removes ALL bins
+    // between from_bin and to_bin. Non-contiguous bin_ids would remove unconverted bins,
+    // and so would duplicates padding the count over a gap.
+    for (i, bin_id) in bin_ids.iter().enumerate() {
+        require!(!bin_ids[..i].contains(bin_id), CoreError::NonContiguousBins);
+    }
+    let span = to_bin
+        .checked_sub(from_bin).ok_or(CoreError::Overflow)?
+        .checked_add(1).ok_or(CoreError::Overflow)?;
+    require!(span == bin_ids.len() as i32, CoreError::NonContiguousBins);
+
+    if !is_authorized_bot {
+        // Permissionless path: bot must be stale
+        let slots_since = slot
+            .checked_sub(config.last_bot_harvest_slot)
+            .ok_or(CoreError::Overflow)?;
+        let priority_slots = position.effective_priority_slots(config.priority_slots);
+        require!(slots_since > priority_slots, CoreError::BotNotStale);
+
+        // Per-position cooldown: stops keepers churning tiny harvests for tips
+        let since_last = slot.saturating_sub(position.last_harvest_slot);
+        require!(
+            since_last >= config.harvest_cooldown_slots,
+            CoreError::HarvestCooldownActive
+        );
+    }
+    Ok((from_bin, to_bin))
+}
+
+/// Shared body of harvest_bins / harvest_bins_multi, after check_harvest: removal CPI,
+/// delta fee, keeper tip, referral cut, fee routing, owner payout, position stats and
+/// HarvestEvent. Returns the amount credited to config.total_harvested; the caller
+/// updates Config (heartbeat, counters) and persists the position.
+fn execute_harvest<'a, 'info>(
+    accs: &HarvestAccounts<'a, 'info>,
+    position: &mut Account<'info, Position>,
+    vault_token_x: &mut InterfaceAccount<'info, ITokenAccount>,
+    vault_token_y: &mut InterfaceAccount<'info, ITokenAccount>,
+    config: &Config,
+    params: &HarvestParams,
+) -> Result<(u64, u64)> {
+    let extra_accounts = accs.extra_accounts;
+    let is_authorized_bot = params.is_authorized_bot;
+    let tip_split_bps = params.tip_split_bps;
+    let (from_bin, to_bin) = (params.from_bin, params.to_bin);
+
+    let x_decimals = read_mint_decimals(&accs.token_x_mint)?;
+    let y_decimals = read_mint_decimals(&accs.token_y_mint)?;
+
+    let position_key = position.key();
+    let owner_key = position.owner;
+    let side = position.side;
+    let min_bin_id = position.min_bin_id;
+    let max_bin_id = position.max_bin_id;
+    let meteora_pos_key = position.meteora_position;
+
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        meteora_pos_key.as_ref(),
+        &[accs.vault_bump],
+    ];
+    let signer = &[vault_seeds];
+
+    // Telemetry: pool price at harvest time and how much of the range sits
+    // outside the harvested slice (below from_bin / above to_bin).
+    let active_id_at_harvest = {
+        let data = accs.lb_pair.try_borrow_data()?;
+        require!(data.len() >= 80, CoreError::InvalidPool);
+        i32::from_le_bytes(data[76..80].try_into().map_err(|_| CoreError::Overflow)?)
+    };
+    let remaining_bins_low = from_bin.checked_sub(min_bin_id).ok_or(CoreError::Overflow)? as u32;
+    let remaining_bins_high = max_bin_id.checked_sub(to_bin).ok_or(CoreError::Overflow)? as u32;
+
+    // Prevent duplicate mutable account exploitation — tip ATAs
+    // must not be the same as any fee destination or owner token account
+    let fee_destinations = [
+        accs.rover_fee_token_y.key(),
+        accs.rover_fee_token_x.key(),
+        accs.owner_token_x.key(),
+        accs.owner_token_y.key(),
+    ];
+
+    // Fail fast on a wrong-mint tip ATA, before the removal CPI does any work.
+    // The tip is paid from the converted side, which the position side fixes.
+    // The post-CPI checks below still run against the actual tip.
+    let tips_enabled = !is_authorized_bot
+        && (config.keeper_tip_bps > 0 || config.min_keeper_tip > 0);
+    if tips_enabled {
+        let tip_mint = match side {
+            Side::Buy => accs.token_x_mint.key(),
+            Side::Sell => accs.token_y_mint.key(),
+        };
+        precheck_tip_atas(extra_accounts, &tip_mint, tip_split_bps, &fee_destinations)?;
+    }
+
+    // Snapshot vault balances BEFORE CPI for delta-based fee calculation
+    let x_before = vault_token_x.amount;
+    let y_before = vault_token_y.amount;
+
+    let remaining = hooks_then_bin_arrays(accs.hook_accounts, &[
+        accs.bin_array_lower.clone(),
+        accs.bin_array_upper.clone(),
+    ]);
+    remove_liquidity_by_range_auto(
+        &[
+            accs.meteora_position.clone(),
+            accs.lb_pair.clone(),
+            accs.bin_array_bitmap_ext.clone(),
+            vault_token_x.to_account_info(),
+            vault_token_y.to_account_info(),
+            accs.reserve_x.clone(),
+            accs.reserve_y.clone(),
+            accs.token_x_mint.clone(),
+            accs.token_y_mint.clone(),
+            accs.vault.clone(),
+            accs.token_x_program.clone(),
+            accs.token_y_program.clone(),
+            accs.memo_program.clone(),
+            accs.event_authority.clone(),
+            accs.dlmm_program.clone(),
+        ],
+        from_bin,
+        to_bin,
+        params.bps_to_remove,
+        RemainingAccountsInfo::with_hooks(params.hook_x_len, params.hook_y_len),
+        signer,
+        &remaining,
+    )?;
+
+    // Reload balances after CPI — use delta for fee calculation
+    vault_token_x.reload()?;
+    vault_token_y.reload()?;
+    let x_received = vault_token_x.amount.saturating_sub(x_before);
+    let y_received = vault_token_y.amount.saturating_sub(y_before);
+
+    if x_received == 0 && y_received == 0 {
+        msg!("WARNING: harvest produced 0 tokens — bins may not have been converted");
+    }
+
+    // Fee on converted output only (delta-based, not total balance)
+    let fee_bps = position.effective_fee_bps(config.fee_bps);
+    let (x_fee, y_fee) = converted_side_fee(side, fee_bps, x_received, y_received)?;
+
+    // --- Keeper tip (permissionless only, from converted-side fee) ---
+    // min_keeper_tip is in lamports, so the floor only applies to wSOL fees.
+    let min_keeper_tip = config.min_keeper_tip;
+    let (x_tip, y_tip) = if tips_enabled {
+        let tip_bps = config.keeper_tip_bps;
+        let x_floor = if accs.token_x_mint.key() == WSOL_MINT { min_keeper_tip } else { 0 };
+        let y_floor = if accs.token_y_mint.key() == WSOL_MINT { min_keeper_tip } else { 0 };
+        (keeper_tip_for(x_fee, tip_bps, x_floor)?, keeper_tip_for(y_fee, tip_bps, y_floor)?)
+    } else {
+        (0u64, 0u64)
+    };
+    let x_after_tip = x_fee.checked_sub(x_tip).ok_or(CoreError::Overflow)?;
+    let y_after_tip = y_fee.checked_sub(y_tip).ok_or(CoreError::Overflow)?;
+
+    // --- Referral cut (from what's left of the fee after the tip) ---
+    let referrer = position.referrer;
+    let referral_bps = config.effective_referral_bps(&referrer);
+    let x_referral = referral_share(x_after_tip, referral_bps)?;
+    let y_referral = referral_share(y_after_tip, referral_bps)?;
+    let x_to_protocol = x_after_tip.checked_sub(x_referral).ok_or(CoreError::Overflow)?;
+    let y_to_protocol = y_after_tip.checked_sub(y_referral).ok_or(CoreError::Overflow)?;
+
+    // Use post-reload vault amounts (vault-as-pipe: transfer full balance minus fee)
+    let x_to_owner = vault_token_x.amount.checked_sub(x_fee).ok_or(CoreError::Overflow)?;
+    let y_to_owner = vault_token_y.amount.checked_sub(y_fee).ok_or(CoreError::Overflow)?;
+
+    // Tip -> keeper (permissionless path only, via remaining_accounts[0]).
+    // The keeper ATA must belong to the caller, or to a registered KeeperPool PDA
+    // passed after the tip ATAs (keeper collectives pool tips for later payout).
+    // With tip_split_bps > 0, remaining_accounts[1] is a second tip ATA that
+    // receives tip_split_bps of the tip; the total tip is unchanged. The same
+    // ownership rule applies to it, so a split can't route the tip elsewhere.
+    let mut tip_recipient = Pubkey::default();
+    let mut tip_amount = 0u64;
+    let mut tip_recipient_second = Pubkey::default();
+    let mut tip_amount_second = 0u64;
+    if x_tip > 0 || y_tip > 0 {
+        let (mint_info, program_info, vault_token, tip, decimals) = if x_tip > 0 {
+            (&accs.token_x_mint, &accs.token_x_program, vault_token_x.to_account_info(), x_tip, x_decimals)
+        } else {
+            (&accs.token_y_mint, &accs.token_y_program, vault_token_y.to_account_info(), y_tip, y_decimals)
+        };
+        require!(!extra_accounts.is_empty(), CoreError::MissingKeeperAta);
+        let keeper_ata = &extra_accounts[0];
+        let keeper_owner = validate_tip_ata(keeper_ata, &mint_info.key(), &fee_destinations)?;
+        let (second_ata, second_owner) = if tip_split_bps > 0 {
+            require!(extra_accounts.len() >= 2, CoreError::MissingKeeperAta);
+            let second = &extra_accounts[1];
+            require!(second.key() != keeper_ata.key(), CoreError::DuplicateKeeperAta);
+            let owner = validate_tip_ata(second, &mint_info.key(), &fee_destinations)?;
+            (Some(second), Some(owner))
+        } else {
+            (None, None)
+        };
+        let pool_info = extra_accounts.get(if second_ata.is_some() { 2 } else { 1 });
+        require_tip_ata_owner(&keeper_owner, &accs.bot.key(), pool_info)?;
+        if let Some(owner) = second_owner {
+            require_tip_ata_owner(&owner, &accs.bot.key(), pool_info)?;
+        }
+
+        tip_amount_second = (tip as u128)
+            .checked_mul(tip_split_bps as u128).ok_or(CoreError::Overflow)?
+            .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
+        tip_amount = tip.checked_sub(tip_amount_second).ok_or(CoreError::Overflow)?;
+        tip_recipient = keeper_ata.key();
+        if let Some(second) = second_ata {
+            tip_recipient_second = second.key();
+        }
+
+        for (to, amount) in [(Some(keeper_ata), tip_amount), (second_ata, tip_amount_second)] {
+            let Some(to) = to else { continue };
+            if amount == 0 {
+                continue;
+            }
+            memo_cpi(&accs.memo_program, &accs.vault, to, signer)?;
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    program_info.clone(),
+                    TransferChecked {
+                        from: vault_token.clone(),
+                        mint: mint_info.clone(),
+                        to: to.to_account_info(),
+                        authority: accs.vault.clone(),
+                    },
+                    signer,
+                ),
+                amount,
+                decimals,
+            )?;
+        }
+    }
+
+    // Referral -> referrer ATA (last entry before the hook accounts)
+    if x_referral > 0 || y_referral > 0 {
+        let (mint_info, program_info, vault_token, amount, decimals) = if x_referral > 0 {
+            (&accs.token_x_mint, &accs.token_x_program, vault_token_x.to_account_info(), x_referral, x_decimals)
+        } else {
+            (&accs.token_y_mint, &accs.token_y_program, vault_token_y.to_account_info(), y_referral, y_decimals)
+        };
+        let referrer_ata = validate_referrer_ata(extra_accounts, &referrer, &mint_info.key())?;
+        memo_cpi(&accs.memo_program, &accs.vault, referrer_ata, signer)?;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                program_info.clone(),
+                TransferChecked {
+                    from: vault_token,
+                    mint: mint_info.clone(),
+                    to: referrer_ata.to_account_info(),
+                    authority: accs.vault.clone(),
+                },
+                signer,
+            ),
+            amount,
+            decimals,
+        )?;
+    }
+
+    // Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40: monke holders + bot)
+    //   TOKEN fees (Buy side) → rover_fee_token_x for DLMM recycling
+    //   SOL fees (Sell side)  → rover_fee_token_y (WSOL, unwrapped later via close_rover_token_account)
+    // Remainder -> owner
+    let payouts = [
+        (x_to_protocol, &accs.rover_fee_token_x, true),
+        (y_to_protocol, &accs.rover_fee_token_y, false),
+        (x_to_owner, &accs.owner_token_x, true),
+        (y_to_owner, &accs.owner_token_y, false),
+    ];
+    for (amount, to, is_x) in payouts {
+        if amount == 0 {
+            continue;
+        }
+        let (program_info, vault_token, mint_info, decimals) = if is_x {
+            (&accs.token_x_program, vault_token_x.to_account_info(), &accs.token_x_mint, x_decimals)
+        } else {
+            (&accs.token_y_program, vault_token_y.to_account_info(), &accs.token_y_mint, y_decimals)
+        };
+        memo_cpi(&accs.memo_program, &accs.vault, to, signer)?;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                program_info.clone(),
+                TransferChecked {
+                    from: vault_token,
+                    mint: mint_info.clone(),
+                    to: to.clone(),
+                    authority: accs.vault.clone(),
+                },
+                signer,
+            ),
+            amount,
+            decimals,
+        )?;
+    }
+
+    let harvested = match side {
+        Side::Buy  => x_to_owner,
+        Side::Sell => y_to_owner,
+    };
+    let fee_taken = match side {
+        Side::Buy  => x_fee,
+        Side::Sell => y_fee,
+    };
+
+    let lb_pair_key = position.lb_pair;
+    position.last_harvest_slot = params.slot;
+    position.harvested_amount = position.harvested_amount
+        .checked_add(harvested).ok_or(CoreError::Overflow)?;
+    position.total_fees_paid_x = position.total_fees_paid_x
+        .checked_add(x_fee).ok_or(CoreError::Overflow)?;
+    position.total_fees_paid_y = position.total_fees_paid_y
+        .checked_add(y_fee).ok_or(CoreError::Overflow)?;
+
+    let keeper_tip_taken = match side {
+        Side::Buy  => x_tip,
+        Side::Sell => y_tip,
+    };
+
+    emit!(HarvestEvent {
+        position: position_key,
+        owner: owner_key,
+        lb_pair: lb_pair_key,
+        harvester: accs.bot.key(),
+        bin_ids: params.bin_ids.to_vec(),
+        token_x_amount: x_to_owner,
+        token_y_amount: y_to_owner,
+        fee_amount: fee_taken,
+        keeper_tip: keeper_tip_taken,
+        tip_recipient,
+        tip_amount,
+        tip_recipient_second,
+        tip_amount_second,
+        total_harvested: position.harvested_amount,
+        bps_removed: params.bps_to_remove,
+        remaining_bins_low,
+        remaining_bins_high,
+        active_id_at_harvest,
+        referral_amount: x_referral.checked_add(y_referral).ok_or(CoreError::Overflow)?,
+        position_kind: position.position_kind,
+    });
+
+    msg!("Harvested bins [{},{}] | fee={} | tip={} | cumulative={}",
+        from_bin, to_bin, fee_taken, keeper_tip_taken, position.harvested_amount);
+    Ok((harvested, fee_taken))
+}
+
+/// One harvest_bins_multi position, loaded and checked. Boxed accounts keep the
+/// batch loop's stack frame small.
+struct LoadedHarvestEntry<'info> {
+    position: Box<Account<'info, Position>>,
+    vault_bump: u8,
+    vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+    vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+    from_bin: i32,
+    to_bin: i32,
+}
+
+/// Deserialize and validate one HARVEST_BATCH_STRIDE group — the same checks
+/// BotHarvest's constraints apply — then run check_harvest. Nothing here touches
+/// Meteora, so harvest_bins_multi can skip an entry that fails.
+fn load_harvest_entry<'info>(
+    group: &'info [AccountInfo<'info>],
+    lb_pair: &Pubkey,
+    config: &Config,
+    entry: &HarvestBatchEntry,
+    is_authorized_bot: bool,
+    slot: u64,
+) -> Result<LoadedHarvestEntry<'info>> {
+    let position = Box::new(Account::<Position>::try_from(&group[0])?);
+    let vault = Account::<Vault>::try_from(&group[1])?;
+    let meteora_pos_key = position.meteora_position;
+
+    let position_pda = Pubkey::create_program_address(
+        &[b"position", meteora_pos_key.as_ref(), &[position.bump]],
+        &crate::ID,
+    ).map_err(|_| CoreError::HarvestBatchAccountMismatch)?;
+    let vault_pda = Pubkey::create_program_address(
+        &[b"vault", meteora_pos_key.as_ref(), &[vault.bump]],
+        &crate::ID,
+    ).map_err(|_| CoreError::HarvestBatchAccountMismatch)?;
+    require!(
+        group[0].key() == position_pda
+            && group[1].key() == vault_pda
+            && group[2].key() == meteora_pos_key,
+        CoreError::HarvestBatchAccountMismatch
+    );
+    require!(position.lb_pair == *lb_pair, CoreError::InvalidPool);
+
+    let vault_token_x = Box::new(InterfaceAccount::<ITokenAccount>::try_from(&group[5])?);
+    let vault_token_y = Box::new(InterfaceAccount::<ITokenAccount>::try_from(&group[6])?);
+    require!(
+        vault_token_x.owner == vault_pda && vault_token_y.owner == vault_pda,
+        CoreError::InvalidTokenOwner
+    );
+    let owner_token_x = InterfaceAccount::<ITokenAccount>::try_from(&group[7])?;
+    let owner_token_y = InterfaceAccount::<ITokenAccount>::try_from(&group[8])?;
+    require!(
+        owner_token_x.owner == position.owner && owner_token_y.owner == position.owner,
+        CoreError::InvalidTokenOwner
+    );
+
+    let (from_bin, to_bin) = check_harvest(
+        config,
+        &position,
+        &entry.bin_ids,
+        entry.bps_to_remove,
+        is_authorized_bot,
+        slot,
+    )?;
+    Ok(LoadedHarvestEntry {
+        position,
+        vault_bump: vault.bump,
+        vault_token_x,
+        vault_token_y,
+        from_bin,
+        to_bin,
+    })
+}
+
+/// Permissionless keeper tip on one side's fee: `tip_bps` of the fee, raised to `floor`
+/// when the fee can cover it. A fee below the floor pays the percentage tip only.
+fn keeper_tip_for(fee: u64, tip_bps: u16, floor: u64) -> Result<u64> {
+    let pct = (fee as u128)
+        .checked_mul(tip_bps as u128).ok_or(CoreError::Overflow)?
+        .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
+    if floor > 0 && fee >= floor {
+        Ok(pct.max(floor))
+    } else {
+        Ok(pct)
+    }
+}
+
+/// Pre-CPI tip ATA check for harvest_bins: the keeper ATA (and the second tip ATA
+/// when splitting) must be for the converted-side mint. No tip ATA is fine here;
+/// the post-CPI path requires one only if a tip is actually owed.
+fn precheck_tip_atas(
+    extra_accounts: &[AccountInfo],
+    tip_mint: &Pubkey,
+    tip_split_bps: u16,
+    fee_destinations: &[Pubkey],
+) -> Result<()> {
+    let Some(keeper_ata) = extra_accounts.first() else {
+        return Ok(());
+    };
+    validate_tip_ata(keeper_ata, tip_mint, fee_destinations)?;
+    if tip_split_bps > 0 {
+        let second = extra_accounts.get(1).ok_or(CoreError::MissingKeeperAta)?;
+        validate_tip_ata(second, tip_mint, fee_destinations)?;
+    }
+    Ok(())
+}
+
+/// Checks a keeper tip ATA is a token account for `mint` that isn't one of the
+/// position's fee/owner destinations. Returns the token account owner.
+fn validate_tip_ata(ata: &AccountInfo, mint: &Pubkey, fee_destinations: &[Pubkey]) -> Result<Pubkey> {
+    require!(
+        *ata.owner == anchor_spl::token::ID || *ata.owner == TOKEN_2022_PROGRAM_ID,
+        CoreError::MissingKeeperAta
+    );
+    require!(!fee_destinations.contains(&ata.key()), CoreError::MissingKeeperAta);
+    // Validate the tip ATA mint matches the converted-side token.
+    // Without this, a griefer can pass a wrong-mint ATA causing the entire harvest
+    // to revert at the CPI level, wasting gas. This gives a clearer error earlier.
+    let data = ata.try_borrow_data()?;
+    // SPL TokenAccount layout: mint at offset 0, owner at offset 32
+    require!(data.len() >= 64, CoreError::MissingKeeperAta);
+    let ata_mint = Pubkey::try_from(&data[0..32]).map_err(|_| CoreError::MissingKeeperAta)?;
+    require!(ata_mint == *mint, CoreError::MissingKeeperAta);
+    Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::MissingKeeperAta.into())
+}
+
+/// A tip ATA must belong to the harvester, or to the registered KeeperPool PDA
+/// passed after the tip ATAs.
+fn require_tip_ata_owner(owner: &Pubkey, caller: &Pubkey, pool_info: Option<&AccountInfo>) -> Result<()> {
+    if owner == caller {
+        return Ok(());
+    }
+    let pool_info = pool_info.ok_or(CoreError::KeeperAtaOwnerMismatch)?;
+    require!(
+        pool_info.key() == *owner && *pool_info.owner == crate::ID,
+        CoreError::KeeperAtaOwnerMismatch
+    );
+    let pool_data = pool_info.try_borrow_data()?;
+    require!(
+        pool_data.len() >= 8 && pool_data[..8] == *KeeperPool::DISCRIMINATOR,
+        CoreError::KeeperAtaOwnerMismatch
+    );
+    Ok(())
+}
+
+/// Contiguous range of bins the price has fully crossed, for harvest_converted.
+/// Sell positions hold X above the price and convert from min_bin_id up to
+/// active_id - 1; Buy positions hold Y below the price and convert from
+/// max_bin_id down to active_id + 1. The active bin itself is only partly
+/// converted and never included. Already-harvested bins (zero liquidity_shares in
+/// the Meteora position) are trimmed from the far end, then the range is capped
+/// at `max_bins` counting from that end. None if nothing is converted.
+fn converted_bin_range(
+    position_data: &[u8],
+    side: Side,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    active_id: i32,
+    max_bins: u16,
+) -> Result<Option<(i32, i32)>> {
+    let has_liquidity = |bin_id: i32| -> Result<bool> {
+        let share = read_position_bin_share(position_data, bin_id)
+            .ok_or(CoreError::InvalidPositionData)?;
+        Ok(share > 0)
+    };
+    let span = (max_bins as i32).saturating_sub(1);
+    match side {
+        Side::Sell => {
+            let mut lo = min_bin_id;
+            let hi = core::cmp::min(active_id.saturating_sub(1), max_bin_id);
+            while lo <= hi && !has_liquidity(lo)? {
+                lo = lo.checked_add(1).ok_or(CoreError::Overflow)?;
+            }
+            if lo > hi {
+                return Ok(None);
+            }
+            Ok(Some((lo, core::cmp::min(hi, lo.saturating_add(span)))))
+        }
+        Side::Buy => {
+            let lo = core::cmp::max(active_id.saturating_add(1), min_bin_id);
+            let mut hi = max_bin_id;
+            while hi >= lo && !has_liquidity(hi)? {
+                hi = hi.checked_sub(1).ok_or(CoreError::Overflow)?;
+            }
+            if hi < lo {
+                return Ok(None);
+            }
+            Ok(Some((core::cmp::max(lo, hi.saturating_sub(span)), hi)))
+        }
+    }
+}
+
+/// `bps` of a protocol fee amount, rounded down.
+fn referral_share(fee: u64, bps: u16) -> Result<u64> {
+    Ok((fee as u128)
+        .checked_mul(bps as u128).ok_or(CoreError::Overflow)?
+        .checked_div(10_000).ok_or(CoreError::Overflow)? as u64)
+}
+
+/// Referrer ATA is the last entry of `remaining_accounts` (callers pass the slice
+/// before any transfer-hook accounts). Must be a token account
+/// for `mint` owned by `referrer` (same layout checks as the keeper ATA).
+fn validate_referrer_ata<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    referrer: &Pubkey,
+    mint: &Pubkey,
+) -> Result<&'a AccountInfo<'info>> {
+    let ata = remaining_accounts.last().ok_or(CoreError::MissingReferrerAta)?;
+    require!(
+        *ata.owner == anchor_spl::token::ID || *ata.owner == TOKEN_2022_PROGRAM_ID,
+        CoreError::MissingReferrerAta
+    );
+    let data = ata.try_borrow_data()?;
+    require!(data.len() >= 64, CoreError::MissingReferrerAta);
+    let ata_mint = Pubkey::try_from(&data[0..32]).map_err(|_| CoreError::MissingReferrerAta)?;
+    let ata_owner = Pubkey::try_from(&data[32..64]).map_err(|_| CoreError::MissingReferrerAta)?;
+    require!(ata_mint == *mint && ata_owner == *referrer, CoreError::MissingReferrerAta);
+    Ok(ata)
+}
+
+/// Referral cut for execute_close_transfers. `remaining_accounts` holds the
+/// referrer ATA (last entry); only read when the cut is non-zero.
+struct ReferralSplit<'a, 'info> {
+    referrer: Pubkey,
+    bps: u16,
+    remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Close fee on the converted side of the full vault balances:
+/// fee_bps of the converted side (X for a Buy, Y for a Sell) as (x_fee, y_fee).
+/// Harvests charge it on the removal delta, closes on the full vault balance.
+fn converted_side_fee(side: Side, fee_bps: u16, x_amount: u64, y_amount: u64) -> Result<(u64, u64)> {
+    let (amount, is_x) = match side {
+        Side::Buy => (x_amount, true),
+        Side::Sell => (y_amount, false),
+    };
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128).ok_or(CoreError::Overflow)?
+        .checked_div(10_000).ok_or(CoreError::Overflow)? as u64;
+    Ok(if is_x { (fee, 0) } else { (0, fee) })
+}
+
+/// (x_fee, y_fee, x_to_recipient, y_to_recipient). Shared by execute_close_transfers
+/// and preview_close.
+///
+/// The fee base is already gain-only: positions are opened one-sided, so the
+/// converted side starts at 0 and holds nothing but converted output (plus LP fees
+/// earned in that token — see the NOTE on execute_close_transfers). The deposit
+/// side, i.e. unconverted principal, is returned fee-free. A position that barely
+/// moved pays a fee on the sliver that converted, never on initial_amount, so no
+/// initial_amount snapshot is needed. initial_amount is in the deposit-side token
+/// and can't be netted against the converted side anyway.
+fn close_fee_split(side: Side, fee_bps: u16, x_balance: u64, y_balance: u64) -> Result<(u64, u64, u64, u64)> {
+    let (x_fee, y_fee) = converted_side_fee(side, fee_bps, x_balance, y_balance)?;
+    let x_out = x_balance.checked_sub(x_fee).ok_or(CoreError::Overflow)?;
+    let y_out = y_balance.checked_sub(y_fee).ok_or(CoreError::Overflow)?;
+    Ok((x_fee, y_fee, x_out, y_out))
+}
+
+/// Zeros vault lamports entirely (garbage-collected at end of tx).
+/// Returns (x_fee, y_fee, x_to_recipient, y_to_recipient, referral) for event emission.
+///
+/// NOTE: The 0.3% performance fee is charged on the FULL vault balance
+/// after both remove_all_liquidity and claim_fee CPIs. This means accrued LP trading
+/// fees are included in the fee base on close. This is an intentional simplification —
+/// LP fees are typically <1% of position value. Users who want fee-free LP fee
+/// withdrawal should call `claim_fees` before closing, or use `user_close_claim_first`.
+fn execute_close_transfers<'info>(
+    side: Side,
+    fee_bps: u16,
+    vault_token_x: &mut InterfaceAccount<'info, ITokenAccount>,
+    vault_token_y: &mut InterfaceAccount<'info, ITokenAccount>,
+    recipient_token_x: &AccountInfo<'info>,
+    recipient_token_y: &AccountInfo<'info>,
+    rover_fee_token_y: &AccountInfo<'info>,
+    rover_fee_token_x: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    _recipient: &AccountInfo<'info>,
+    token_x_program: &AccountInfo<'info>,
+    token_y_program: &AccountInfo<'info>,
+    token_x_mint: &AccountInfo<'info>,
+    token_y_mint: &AccountInfo<'info>,
+    memo_program: &AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+    min_out: (u64, u64),
+    referral: ReferralSplit<'_, 'info>,
+) -> Result<(u64, u64, u64, u64, u64)> {
+    vault_token_x.reload()?;
+    vault_token_y.reload()?;
+
+    let x_decimals = read_mint_decimals(token_x_mint)?;
+    let y_decimals = read_mint_decimals(token_y_mint)?;
+
+    let (x_fee, y_fee, x_to_recipient, y_to_recipient) =
+        close_fee_split(side, fee_bps, vault_token_x.amount, vault_token_y.amount)?;
+
+    // Min-output guard on what actually lands, checked before any transfer.
+    // transfer_checked debits the vault exactly x/y_to_recipient, so the split
+    // can't underflow; a Token-2022 transfer fee is withheld on the recipient's
+    // side instead, so budget it here.
+    let epoch = Clock::get()?.epoch;
+    let x_received = x_to_recipient
+        .checked_sub(expected_transfer_fee(token_x_mint, x_to_recipient, epoch)?)
+        .ok_or(CoreError::Overflow)?;
+    let y_received = y_to_recipient
+        .checked_sub(expected_transfer_fee(token_y_mint, y_to_recipient, epoch)?)
+        .ok_or(CoreError::Overflow)?;
+    if x_received < min_out.0 || y_received < min_out.1 {
+        msg!("SlippageExceeded: received x={} y={} (sent {} / {}) | min x={} y={}",
+            x_received, y_received, x_to_recipient, y_to_recipient, min_out.0, min_out.1);
+        return err!(CoreError::SlippageExceeded);
+    }
+
+    // Referral cut comes out of the fee, never out of the recipient's share
+    let x_referral = referral_share(x_fee, referral.bps)?;
+    let y_referral = referral_share(y_fee, referral.bps)?;
+    let x_to_rover = x_fee.checked_sub(x_referral).ok_or(CoreError::Overflow)?;
+    let y_to_rover = y_fee.checked_sub(y_referral).ok_or(CoreError::Overflow)?;
+    if x_referral > 0 || y_referral > 0 {
+        let (mint_info, program_info, vault_token, amount, decimals) = if x_referral > 0 {
+            (token_x_mint, token_x_program, vault_token_x.to_account_info(), x_referral, x_decimals)
+        } else {
+            (token_y_mint, token_y_program, vault_token_y.to_account_info(), y_referral, y_decimals)
+        };
+        let referrer_ata = validate_referrer_ata(referral.remaining_accounts, &referral.referrer, mint_info.key)?;
+        memo_cpi(memo_program, vault, referrer_ata, signer)?;
+        transfer_checked(CpiContext::new_with_signer(
+            program_info.to_account_info(),
+            TransferChecked {
+                from: vault_token,
+                mint: mint_info.to_account_info(),
+                to: referrer_ata.to_account_info(),
+                authority: vault.to_account_info(),
+            }, signer,
+        ), amount, decimals)?;
+    }
+
+    // Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40: monke holders + bot)
+    //   TOKEN fees (Buy side, x_fee) → rover_fee_token_x for DLMM recycling
+    //   SOL fees (Sell side, y_fee)  → rover_fee_token_y (WSOL, unwrapped later)
+    // B2 FIX: Prepend memo before each transfer (supports Memo Transfer extension)
+    if x_to_rover > 0 {
+        memo_cpi(memo_program, vault, rover_fee_token_x, signer)?;
+        transfer_checked(CpiContext::new_with_signer(
+            token_x_program.to_account_info(),
+            TransferChecked {
+                from: vault_token_x.to_account_info(),
+                mint: token_x_mint.to_account_info(),
+                to: rover_fee_token_x.to_account_info(),
+                authority: vault.to_account_info(),
+            }, signer,
+        ), x_to_rover, x_decimals)?;
+    }
+    if y_to_rover > 0 {
+        memo_cpi(memo_program, vault, rover_fee_token_y, signer)?;
+        transfer_checked(CpiContext::new_with_signer(
+            token_y_program.to_account_info(),
+            TransferChecked {
+                from: vault_token_y.to_account_info(),
+                mint: token_y_mint.to_account_info(),
+                to: rover_fee_token_y.to_account_info(),
+                authority: vault.to_account_info(),
+            }, signer,
+        ), y_to_rover, y_decimals)?;
+    }
+    if x_to_recipient > 0 {
+        memo_cpi(memo_program, vault, recipient_token_x, signer)?;
+        transfer_checked(CpiContext::new_with_signer(
+            token_x_program.to_account_info(),
+            TransferChecked {
+                from: vault_token_x.to_account_info(),
+                mint: token_x_mint.to_account_info(),
+                to: recipient_token_x.to_account_info(),
+                authority: vault.to_account_info(),
+            }, signer,
+        ), x_to_recipient, x_decimals)?;
+    }
+    if y_to_recipient > 0 {
+        memo_cpi(memo_program, vault, recipient_token_y, signer)?;
+        transfer_checked(CpiContext::new_with_signer(
+            token_y_program.to_account_info(),
+            TransferChecked {
+                from: vault_token_y.to_account_info(),
+                mint: token_y_mint.to_account_info(),
+                to: recipient_token_y.to_account_info(),
+                authority: vault.to_account_info(),
+            }, signer,
+        ), y_to_recipient, y_decimals)?;
+    }
+
+    // Vault lamports handled by Anchor `close` constraint on the context
+    // (close = owner in ClosePosition, close = user in UserClose).
+
+    let referral_amount = x_referral.checked_add(y_referral).ok_or(CoreError::Overflow)?;
+    msg!("Position closed | x_fee={} y_fee={} x_out={} y_out={} referral={}",
+        x_fee, y_fee, x_to_recipient, y_to_recipient, referral_amount);
+    Ok((x_fee, y_fee, x_to_recipient, y_to_recipient, referral_amount))
+}
+
+// ============ ENUMS ============
+
+/// One pool in an open_rover_batch call.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct RoverBatchEntry {
+    pub amount: u64,
+    pub bin_step: u16,
+}
+
+/// One position in a harvest_bins_multi call. `extra_len` counts the
+/// harvest_bins extras (tip / pool / referrer ATAs) after its account group.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct HarvestBatchEntry {
+    pub bin_ids: Vec<i32>,
+    pub bps_to_remove: u16,
+    pub extra_len: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+// ============ EVENTS ============
+
+#[event]
+pub struct PositionOpenedEvent {
+    pub position: Pubkey,
+    pub user: Pubkey,
+    pub lb_pair: Pubkey,
+    pub side: Side,
+    pub amount: u64,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub timestamp: i64,
+    pub take_profit_bins: u16,   // close_if_above margin past max_bin_id (0 = at range edge)
+    pub strategy: u8,            // STRATEGY_SPOT / _CURVE / _BID_ASK
+    pub wrapped_lamports: u64,   // Native SOL wrapped by open_position_wrapped (0 = token deposit)
+}
+
+#[event]
+pub struct HarvestEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub lb_pair: Pubkey,       // Pool address for indexers
+    pub harvester: Pubkey,     // Who called harvest (bot key or permissionless keeper)
+    pub bin_ids: Vec<i32>,
+    pub token_x_amount: u64,
+    pub token_y_amount: u64,
+    pub fee_amount: u64,
+    pub keeper_tip: u64,       // Tip paid to permissionless harvester (0 if authorized bot)
+    pub tip_recipient: Pubkey, // First tip ATA (default if no tip)
+    pub tip_amount: u64,       // Share of keeper_tip sent to tip_recipient
+    pub tip_recipient_second: Pubkey, // Second tip ATA (default if unsplit)
+    pub tip_amount_second: u64,       // Share of keeper_tip sent to tip_recipient_second
+    pub total_harvested: u64,
+    pub bps_removed: u16,      // Share of each bin's liquidity pulled (10_000 = full drain)
+    pub remaining_bins_low: u32,  // Position bins below the harvested range (min_bin_id..from_bin)
+    pub remaining_bins_high: u32, // Position bins above the harvested range (to_bin..=max_bin_id)
+    pub active_id_at_harvest: i32, // lb_pair activeId read before the removal CPI
+    pub referral_amount: u64,  // Converted-side fee paid to position.referrer (0 if none)
+    pub position_kind: u8,     // POSITION_KIND_* stamped at open
+}
+
+#[event]
+pub struct CompoundEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub caller: Pubkey,
+    pub side: Side,
+    pub from_bin: i32,             // Range removed and (if compounded) redeposited
+    pub to_bin: i32,
+    pub bps_removed: u16,
+    pub redeposited_amount: u64,   // Converted side re-added to the position (0 = fell back to payout)
+    pub fee_amount: u64,
+    pub token_x_to_owner: u64,
+    pub token_y_to_owner: u64,
+    pub min_bin_id: i32,           // Position range (unchanged — redeposit stays inside it)
+    pub max_bin_id: i32,
+    pub referral_amount: u64,      // Part of fee_amount paid to position.referrer (0 if none)
+}
+
+#[event]
+pub struct PositionTransferredEvent {
+    pub position: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LabelSetEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub label: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeDelegateSetEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub fee_delegate: Pubkey,  // Pubkey::default() = cleared
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimFeesEvent {
+    pub position: Pubkey,
+    pub user: Pubkey,          // Position owner (fee recipient)
+    pub claimed_by: Pubkey,    // Signer: the owner or the position's fee_delegate
+    pub lb_pair: Pubkey,
+    pub x_amount: u64,
+    pub y_amount: u64,
+    pub quoted_x: u64,         // Settled fees read from the Meteora position before the claim (quote_lp_fees)
+    pub quoted_y: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesCompoundedEvent {
+    pub position: Pubkey,
+    pub user: Pubkey,          // Position owner (receives anything not compounded)
+    pub claimed_by: Pubkey,    // Signer: the owner or the position's fee_delegate
+    pub lb_pair: Pubkey,
+    pub x_claimed: u64,        // Fees claimed from Meteora this call
+    pub y_claimed: u64,
+    pub x_compounded: u64,     // Redeposited into the position (includes earlier dust)
+    pub y_compounded: u64,
+    pub x_paid_out: u64,       // Sent to the owner (side the range can't take)
+    pub y_paid_out: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardClaimedEvent {
+    pub position: Pubkey,
+    pub user: Pubkey,
+    pub lb_pair: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_index: u64,
+    pub claimed: u64,          // Delta received from Meteora this call
+    pub fee: u64,              // Protocol fee → rover_authority reward-mint account
+    pub to_owner: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CloseEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub side: Side,
+    pub token_x_out: u64,
+    pub token_y_out: u64,
+    pub x_fee: u64,
+    pub y_fee: u64,
+    pub bot_initiated: bool,
+    pub total_fees_paid_x: u64,    // Lifetime fees incl. this close (tax record)
+    pub total_fees_paid_y: u64,
+    pub referral_amount: u64,      // Part of the fee paid to position.referrer (0 if none)
+    pub position_kind: u8,         // POSITION_KIND_* stamped at open
+    pub unwrapped_lamports: u64,   // user_close_unwrap: SOL returned by closing the WSOL account (0 otherwise)
+}
+
+#[event]
+pub struct VaultRescueEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub x_amount: u64,
+    pub y_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeChangeProposedEvent {
+    pub new_fee_bps: u16,
+    pub effective_at: i64,
+    pub nonce: u32,            // Pass to apply_fee
+}
+
+#[event]
+pub struct FeeAppliedEvent {
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+    pub nonce: u32,
+}
+
+#[event]
+pub struct FeeChangeCancelledEvent {
+    pub cancelled_fee_bps: u16,
+    pub was_effective_at: i64,
+    pub nonce: u32,
+}
+
+/// keeper_tip_bps / priority_slots timelock (`field` names the Config field).
+#[event]
+pub struct ParamChangeProposedEvent {
+    pub field: String,
+    pub new_value: u64,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct ParamChangeAppliedEvent {
+    pub field: String,
+    pub old_value: u64,
+    pub new_value: u64,
+}
+
+#[event]
+pub struct ParamChangeCancelledEvent {
+    pub field: String,
+    pub cancelled_value: u64,
+    pub was_effective_at: i64,
+}
+
+#[event]
+pub struct EmergencyCloseEvent {
+    pub position: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrphanVaultReclaimedEvent {
+    pub vault: Pubkey,
+    pub meteora_position: Pubkey,
+    pub caller: Pubkey,
+    pub x_amount: u64,          // Swept to destination_token_x (authority only)
+    pub y_amount: u64,          // Swept to destination_token_y (authority only)
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminConfigEvent {
+    pub field: String,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigStatsEvent {
+    pub bot_harvest_count: u64,
+    pub permissionless_harvest_count: u64,
+    pub total_harvested: u64,
+    pub total_positions: u64,
+}
+
+#[event]
+pub struct PoolStatsEvent {
+    pub lb_pair: Pubkey,
+    pub lifetime_harvested: u64,
+    pub lifetime_fees: u64,
+    pub position_count: u64,
+}
+
+#[event]
+pub struct BotHealthEvent {
+    pub last_bot_harvest_slot: u64,
+    pub last_bot_close_slot: u64,
+    pub last_bot_sweep_slot: u64,
+    pub current_slot: u64,
+    pub harvest_staleness: u64,   // Slots since last bot harvest
+    pub close_staleness: u64,
+    pub sweep_staleness: u64,
+    pub harvest_unlocked: bool,   // Permissionless harvest_bins currently allowed
+    pub close_unlocked: bool,     // Permissionless close_position currently allowed
+    pub sweep_unlocked: bool,     // sweep_rover is always permissionless
+    pub priority_slots: u64,      // Staleness threshold for harvest_unlocked
+    pub bot_paused: bool,
+    pub last_bot_heartbeat_slot: u64, // Last bot_heartbeat (0 = never)
+    pub close_priority_slots: u64, // Staleness threshold for close_unlocked
+}
+
+#[event]
+pub struct HeartbeatEvent {
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultBindingEvent {
+    pub position: Pubkey,
+    pub vault: Pubkey,
+    pub meteora_position: Pubkey,   // From Position
+    pub vault_bound_to: Pubkey,     // From Vault.position
+    pub matches: bool,
+}
+
+#[event]
+pub struct PositionMigratedEvent {
+    pub position: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct PositionTaxQuoteEvent {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub lb_pair: Pubkey,
+    pub side: Side,
+    pub initial_amount: u64,
+    pub harvested_amount: u64,     // Net proceeds delivered to owner (converted side)
+    pub total_fees_paid_x: u64,
+    pub total_fees_paid_y: u64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct LpFeesQuoteEvent {
+    pub position: Pubkey,
+    pub meteora_position: Pubkey,
+    pub x_fees: u64,    // Settled pending fees (lower bound)
+    pub y_fees: u64,
+}
+
+#[event]
+pub struct HarvestCostQuoteEvent {
+    pub position: Pubkey,
+    pub bin_count: u16,
+    pub token_2022: bool,        // Either side uses Token-2022
+    pub mint_extensions: bool,   // Either mint carries Token-2022 extensions (hooks, transfer fees)
+    pub estimated_cu: u32,       // Suggested ComputeBudget limit for the real harvest
+}
+
+#[event]
+pub struct HealthEvent {
+    pub config: Pubkey,
+    pub rover_authority: Pubkey,
+    pub revenue_dest: Pubkey,
+    pub revenue_dest_is_bridge: bool, // true = pegged_bridge bridge_vault, false = dist_pool
+    pub dist_pool: Pubkey,
+    pub program_vault: Pubkey,
+    pub timestamp: i64,
+}
+
+// ============ STATE ============
+
+#[account]
+pub struct Config {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub bot: Pubkey,
+    pub fee_bps: u16,
+    pub pending_fee_bps: u16,    // Timelock — proposed fee (0 = none pending)
+    pub fee_change_at: i64,      // Timelock — Unix timestamp when pending fee can be applied (0 = none)
+    pub total_positions: u64,
+    pub total_volume: u64,
+    pub paused: bool,
+    pub bot_paused: bool,
+    pub bump: u8,
+    // --- Permissionless harvest fallback ---
+    pub last_bot_harvest_slot: u64, // Slot of last authorized bot harvest (heartbeat)
+    pub keeper_tip_bps: u16,        // Tip % for permissionless harvesters (e.g. 1000 = 10%)
+    pub priority_slots: u64,        // Staleness threshold (~100 slots = ~40s)
+    pub total_harvested: u64,       // Lifetime harvested output across all positions
+    // --- Emergency escape hatch ---
+    pub pending_emergency_close: Pubkey, // Position key pending emergency close (default = none)
+    pub emergency_close_at: i64,         // Timestamp when emergency close can execute (0 = none)
+    // --- Permissionless close + sweep heartbeat ---
+    pub last_bot_close_slot: u64,        // Slot of last bot-initiated close_position
+    pub last_bot_sweep_slot: u64,        // Slot of last bot-initiated sweep_rover
+    // --- Harvest tuning ---
+    pub max_bins_per_harvest: u16,       // Per-call bin cap for harvest_bins (0 = unset → 70)
+    // --- Fee timelock versioning ---
+    pub fee_change_nonce: u32,           // Bumped on every propose_fee; apply_fee must name it
+    // --- Open tuning ---
+    pub min_position_width: u16,         // Minimum bins for open_position_v2 (0 = unset → 1)
+    // --- Permissionless harvest cooldown ---
+    pub harvest_cooldown_slots: u64,     // Min slots between harvests of one position (keepers only)
+    // --- Sweep dust floor ---
+    pub min_sweep_lamports: u64,         // sweep_rover floor for non-bot callers (0 = no floor)
+    // --- Referrals ---
+    pub referral_bps: u16,               // Share of the protocol fee paid to position.referrer
+    // --- Keeper tip floor ---
+    pub min_keeper_tip: u64,             // Lamport floor for wSOL keeper tips (0 = percentage only)
+    // --- Layout version ---
+    pub config_version: u8,              // 0 = pre-versioning config; see migrate_config
+    // --- Harvest telemetry ---
+    pub bot_harvest_count: u64,          // harvest_bins calls by the authorized bot
+    pub permissionless_harvest_count: u64, // harvest_bins calls by keepers (stale-bot fallback)
+    // --- Keeper parameter timelocks ---
+    pub pending_keeper_tip_bps: u16,     // Proposed keeper_tip_bps (valid while keeper_tip_change_at > 0)
+    pub keeper_tip_change_at: i64,       // Unix timestamp when the tip change can be applied (0 = none)
+    pub pending_priority_slots: u64,     // Proposed priority_slots (valid while priority_slots_change_at > 0)
+    pub priority_slots_change_at: i64,   // Unix timestamp when the slots change can be applied (0 = none)
+    // --- Per-owner position cap ---
+    pub max_positions_per_owner: u64,    // open_position_v2 cap per wallet via OwnerStats (0 = unlimited)
+    // --- Dust floor ---
+    pub min_position_amount: u64,        // open_position_v2 minimum deposit (0 = unset → MIN_POSITION_AMOUNT)
+    // Reserved space for future fields (e.g. strategy platform)
+    pub _reserved: [u8; 3],
+    // ==== Config extension: appended past DEPLOYED_SIZE by one migrate_config_space ====
+    // --- Token program allowlist ---
+    pub allowed_token_programs: [Pubkey; MAX_ALLOWED_TOKEN_PROGRAMS], // All default = SPL Token + Token-2022
+    // --- Pause dead-man's switch ---
+    pub pause_expiry_ts: i64,            // Unix timestamp the current pause lapses at (0 = not paused)
+    pub max_pause_seconds: i64,          // Length of a pause (0 = unset → DEFAULT_MAX_PAUSE_SECONDS)
+    // --- Bot liveness (carved from _reserved_ext) ---
+    pub last_bot_heartbeat_slot: u64,    // Slot of the last bot_heartbeat (moves even when idle)
+    // --- Close window (carved from _reserved_ext) ---
+    pub close_priority_slots: u64,       // Staleness threshold for permissionless close (0 = priority_slots)
+    // Reserved tail of the extension: later fields carve from here instead of growing again
+    pub _reserved_ext: [u8; 80],
+}
+
+impl Config {
+    // 8 (disc) + 32*3 (authority, pending_authority, bot) + 2+2+8 (fee_bps, pending, change_at)
+    // + 8+8 (positions, volume) + 1+1+1 (paused, bot_paused, bump)
+    // + 8+2+8+8 (harvest slot, keeper_tip, priority, harvested)
+    // + 32+8 (emergency close) + 8+8 (close/sweep slots) + 2 (max bins) + 4 (fee nonce)
+    // + 2 (min width) + 8 (harvest cooldown) + 8 (min sweep) + 2 (referral) + 8 (tip floor)
+    // + 1 (config version) + 8+8 (harvest counts) + 2+8+8+8 (tip/slots timelocks)
+    // + 8 (owner cap) + 8 (min amount) + 3 (reserved) + EXTENSION_SIZE
+    pub const SIZE: usize = Config::DEPLOYED_SIZE + Config::EXTENSION_SIZE;
+    /// Everything past DEPLOYED_SIZE, sized once so the deployed Config grows a single
+    /// time: 32*4 (allowed token programs) + 8+8 (pause expiry, max pause) + 96 reserved.
+    /// Carved from the 96 so far: 8 (heartbeat slot) + 8 (close priority); 80 remain
+    /// in _reserved_ext.
+    pub const EXTENSION_SIZE: usize = 32 * MAX_ALLOWED_TOKEN_PROGRAMS + 8 + 8 + 96;
+    /// Mainnet layout before the extension: the fields from max_bins_per_harvest
+    /// through _reserved fill its 96 reserved bytes exactly (313 bytes total).
+    pub const DEPLOYED_SIZE: usize = 8 + 32 + 32 + 32 + 2 + 2 + 8 + 8 + 8 + 1 + 1 + 1 + 8 + 2 + 8 + 8 + 32 + 8 + 8 + 8
+        + 2 + 4 + 2 + 8 + 8 + 2 + 8 + 1 + 8 + 8 + 2 + 8 + 8 + 8 + 8 + 8 + 3;
+
+    /// Schedule `new_fee_bps` 24 hours from `now`, replacing any pending change. The
+    /// nonce bump makes applies signed for the replaced change revert StaleFeeChange.
+    pub fn propose_fee_change(&mut self, new_fee_bps: u16, now: i64) -> Result<()> {
+        self.fee_change_nonce = self.fee_change_nonce
+            .checked_add(1).ok_or(CoreError::Overflow)?;
+        self.pending_fee_bps = new_fee_bps;
+        self.fee_change_at = now
+            .checked_add(86_400) // 24 hours
+            .ok_or(CoreError::Overflow)?;
+        Ok(())
+    }
+
+    /// Apply the pending fee change if it is the one `nonce` names and its timelock
+    /// has passed. Returns the old fee.
+    pub fn apply_fee_change(&mut self, nonce: u32, now: i64) -> Result<u16> {
+        require!(self.fee_change_at > 0, CoreError::NoPendingFeeChange);
+        require!(nonce == self.fee_change_nonce, CoreError::StaleFeeChange);
+        require!(now >= self.fee_change_at, CoreError::FeeTimelockNotExpired);
+        let old_fee = self.fee_bps;
+        self.fee_bps = self.pending_fee_bps;
+        self.pending_fee_bps = 0;
+        self.fee_change_at = 0;
+        Ok(old_fee)
+    }
+
+    /// Drop the pending fee change. The nonce is kept, so a later propose still bumps
+    /// past any apply signed for the cancelled one.
+    pub fn cancel_fee_change(&mut self) -> Result<()> {
+        require!(self.fee_change_at > 0, CoreError::NoPendingFeeChange);
+        self.pending_fee_bps = 0;
+        self.fee_change_at = 0;
+        Ok(())
+    }
+
+    /// Stage a keeper_tip_bps change PARAM_TIMELOCK_SECONDS out, replacing any pending one.
+    pub fn propose_keeper_tip_change(&mut self, new_bps: u16, now: i64) -> Result<()> {
+        self.pending_keeper_tip_bps = new_bps;
+        self.keeper_tip_change_at = now
+            .checked_add(PARAM_TIMELOCK_SECONDS)
+            .ok_or(CoreError::Overflow)?;
+        Ok(())
+    }
+
+    /// Apply the pending keeper_tip_bps change once its timelock has passed.
+    /// Returns the old tip.
+    pub fn apply_keeper_tip_change(&mut self, now: i64) -> Result<u16> {
+        require!(self.keeper_tip_change_at > 0, CoreError::NoPendingFeeChange);
+        require!(now >= self.keeper_tip_change_at, CoreError::FeeTimelockNotExpired);
+        let old_bps = self.keeper_tip_bps;
+        self.keeper_tip_bps = self.pending_keeper_tip_bps;
+        self.pending_keeper_tip_bps = 0;
+        self.keeper_tip_change_at = 0;
+        Ok(old_bps)
+    }
+
+    /// Stage a priority_slots change PARAM_TIMELOCK_SECONDS out, replacing any pending one.
+    pub fn propose_priority_slots_change(&mut self, new_slots: u64, now: i64) -> Result<()> {
+        self.pending_priority_slots = new_slots;
+        self.priority_slots_change_at = now
+            .checked_add(PARAM_TIMELOCK_SECONDS)
+            .ok_or(CoreError::Overflow)?;
+        Ok(())
+    }
+
+    /// Apply the pending priority_slots change once its timelock has passed.
+    /// Returns the old window.
+    pub fn apply_priority_slots_change(&mut self, now: i64) -> Result<u64> {
+        require!(self.priority_slots_change_at > 0, CoreError::NoPendingFeeChange);
+        require!(now >= self.priority_slots_change_at, CoreError::FeeTimelockNotExpired);
+        let old_slots = self.priority_slots;
+        self.priority_slots = self.pending_priority_slots;
+        self.pending_priority_slots = 0;
+        self.priority_slots_change_at = 0;
+        Ok(old_slots)
+    }
+
+    /// Gate for new deposits (open_position_v2 / rover opens). A pause lapses once
+    /// pause_expiry_ts has passed, even if the admin never unpauses.
+    pub fn deposits_paused(&self, now: i64) -> bool {
+        self.paused && now <= self.pause_expiry_ts
+    }
+
+    /// Values for a Config grown from DEPLOYED_SIZE by migrate_config_space: the fields
+    /// carved out of the deployed _reserved and the extension get what initialize
+    /// writes, and a pause already in force lapses DEFAULT_MAX_PAUSE_SECONDS from `now`.
+    /// The liveness fields and _reserved_ext stay 0.
+    fn init_extension(&mut self, now: i64) -> Result<()> {
+        self.max_bins_per_harvest = MAX_BINS_PER_HARVEST;
+        self.min_position_width = 1;
+        self.min_position_amount = MIN_POSITION_AMOUNT;
+        self._reserved = [0u8; 3];
+        self.allowed_token_programs = [Pubkey::default(); MAX_ALLOWED_TOKEN_PROGRAMS];
+        self.allowed_token_programs[0] = anchor_spl::token::ID;
+        self.allowed_token_programs[1] = TOKEN_2022_PROGRAM_ID;
+        self.config_version = CONFIG_VERSION;
+        self.max_pause_seconds = DEFAULT_MAX_PAUSE_SECONDS;
+        if self.paused {
+            self.pause_expiry_ts = now
+                .checked_add(DEFAULT_MAX_PAUSE_SECONDS)
+                .ok_or(CoreError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Pause deposits until `now` + the effective max pause. Re-pausing extends.
+    fn pause_at(&mut self, now: i64) -> Result<()> {
+        self.paused = true;
+        self.pause_expiry_ts = now
+            .checked_add(self.effective_max_pause_seconds())
+            .ok_or(CoreError::Overflow)?;
+        Ok(())
+    }
+
+    /// Bot-priority window for close_position. Configs from before the field read 0
+    /// and keep sharing priority_slots with harvest_bins.
+    pub fn effective_close_priority_slots(&self) -> u64 {
+        if self.close_priority_slots == 0 {
+            self.priority_slots
+        } else {
+            self.close_priority_slots
+        }
+    }
+
+    pub fn effective_max_pause_seconds(&self) -> i64 {
+        if self.max_pause_seconds == 0 {
+            DEFAULT_MAX_PAUSE_SECONDS
+        } else {
+            core::cmp::min(self.max_pause_seconds, MAX_PAUSE_SECONDS)
+        }
+    }
+
+    /// Book one harvested position: lifetime output plus the bot / keeper counter.
+    fn record_harvest(&mut self, harvested: u64, is_authorized_bot: bool) -> Result<()> {
+        self.total_harvested = self.total_harvested
+            .checked_add(harvested).ok_or(CoreError::Overflow)?;
+        if is_authorized_bot {
+            self.bot_harvest_count = self.bot_harvest_count.saturating_add(1);
+        } else {
+            self.permissionless_harvest_count = self.permissionless_harvest_count.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    /// Guard for instructions that depend on a Config layout newer than the
+    /// account may have. Configs from before versioning read 0 and must go
+    /// through migrate_config first.
+    pub fn require_version(&self) -> Result<()> {
+        require!(
+            self.config_version >= MIN_SUPPORTED_CONFIG_VERSION,
+            CoreError::ConfigVersionUnsupported
+        );
+        Ok(())
+    }
+
+    /// Effective per-call bin cap. Configs created before max_bins_per_harvest
+    /// existed read 0 from the reserved bytes and fall back to the hard ceiling.
+    pub fn effective_max_bins_per_harvest(&self) -> u16 {
+        if self.max_bins_per_harvest == 0 {
+            MAX_BINS_PER_HARVEST
+        } else {
+            core::cmp::min(self.max_bins_per_harvest, MAX_BINS_PER_HARVEST)
+        }
+    }
+
+    /// Minimum open_position_v2 width. 0 (pre-field configs) behaves as 1.
+    pub fn effective_min_position_width(&self) -> u16 {
+        core::cmp::max(self.min_position_width, 1)
+    }
+
+    /// Global minimum open_position_v2 deposit. 0 (pre-field configs) behaves as
+    /// MIN_POSITION_AMOUNT. A PoolStatus override takes precedence (see open path).
+    pub fn effective_min_position_amount(&self) -> u64 {
+        if self.min_position_amount == 0 {
+            MIN_POSITION_AMOUNT
+        } else {
+            self.min_position_amount
+        }
+    }
+
+    /// bot_heartbeat: only config.bot can mark itself alive. The harvest / close /
+    /// sweep slots are left alone.
+    pub fn record_heartbeat(&mut self, caller: &Pubkey, slot: u64) -> Result<()> {
+        require!(*caller == self.bot, CoreError::Unauthorized);
+        self.last_bot_heartbeat_slot = slot;
+        Ok(())
+    }
+
+    /// sweep_rover gate: something to sweep, and at least min_sweep_lamports for
+    /// permissionless callers. The bot can always flush before distribution.
+    pub fn require_sweepable(&self, sweepable: u64, caller: &Pubkey) -> Result<()> {
+        require!(sweepable > 0, CoreError::NothingToSweep);
+        if *caller != self.bot && sweepable < self.min_sweep_lamports {
+            msg!("NothingToSweep: {} lamports below min_sweep_lamports {}",
+                sweepable, self.min_sweep_lamports);
+            return err!(CoreError::NothingToSweep);
+        }
+        Ok(())
+    }
+
+    /// Referral share for a position: 0 when it has no referrer.
+    pub fn effective_referral_bps(&self, referrer: &Pubkey) -> u16 {
+        if *referrer == Pubkey::default() { 0 } else { self.referral_bps }
+    }
+
+    /// Token programs accepted for new deposits (open_position_v2, rover opens).
+    /// An all-empty allowlist falls back to SPL Token + Token-2022.
+    pub fn is_token_program_allowed(&self, program: &Pubkey) -> bool {
+        if self.allowed_token_programs.iter().all(|p| *p == Pubkey::default()) {
+            return *program == anchor_spl::token::ID || *program == TOKEN_2022_PROGRAM_ID;
+        }
+        *program != Pubkey::default() && self.allowed_token_programs.contains(program)
+    }
+
+    /// Token programs accepted on exit paths (harvest, close). Always includes SPL Token
+    /// and Token-2022 so tightening the allowlist never strands an open position.
+    pub fn is_token_program_accepted(&self, program: &Pubkey) -> bool {
+        *program == anchor_spl::token::ID
+            || *program == TOKEN_2022_PROGRAM_ID
+            || self.is_token_program_allowed(program)
+    }
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub lb_pair: Pubkey,
+    pub meteora_position: Pubkey,
+    pub side: Side,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub initial_amount: u64,
+    pub harvested_amount: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub label: [u8; 32],     // Owner-set UTF-8 tag, zero-padded (cosmetic)
+    pub total_fees_paid_x: u64, // Cumulative protocol fee (incl. keeper tip) taken in X
+    pub total_fees_paid_y: u64, // Cumulative protocol fee (incl. keeper tip) taken in Y
+    pub take_profit_bins: u16,  // close_if_above fires at active_id >= max_bin_id + this
+    pub strategy: u8,           // STRATEGY_SPOT / _CURVE / _BID_ASK — liquidity shape at open
+    pub last_harvest_slot: u64, // Slot of the last harvest_bins / compound_harvest (cooldown)
+    pub referrer: Pubkey,       // Growth partner paid config.referral_bps of the protocol fee (default = none)
+    pub priority_slots_override: u64, // Bot-priority window for this position (0 = config.priority_slots)
+    pub position_kind: u8,      // POSITION_KIND_* (positions from before the field read 0 = user)
+    pub fee_delegate: Pubkey,   // May sign claim_fees for the owner (default = none)
+    pub owner_counted: bool,    // Counted in OwnerStats(owner).open_positions (false for rovers / legacy)
+    pub fee_bps_override: Option<u16>, // Per-position protocol fee, <= config.fee_bps (None = config's)
+}
+
+impl Position {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 1 + 4 + 4 + 8 + 8 + 8 + 1 + 32 + 8 + 8 + 2 + 1 + 8 + 32 + 8 + 1 + 32 + 1 + 3;
+    /// Byte offsets for raw access (migrate_position / set_position_label handle
+    /// accounts created with an older, shorter layout).
+    pub const BUMP_OFFSET: usize = 8 + 32 + 32 + 32 + 1 + 4 + 4 + 8 + 8 + 8;
+    pub const LABEL_OFFSET: usize = Position::BUMP_OFFSET + 1;
+    pub const LABEL_END: usize = Position::LABEL_OFFSET + 32;
+
+    /// Fresh record for an open, stamped with the open path's POSITION_KIND_*.
+    /// Rovers keep the defaults (BidAsk, uncounted, no overrides);
+    /// open_position_v2 sets its per-position extras on top.
+    #[allow(clippy::too_many_arguments)]
+    pub fn opened(
+        position_kind: u8,
+        owner: Pubkey,
+        lb_pair: Pubkey,
+        meteora_position: Pubkey,
+        side: Side,
+        (min_bin_id, max_bin_id): (i32, i32),
+        initial_amount: u64,
+        created_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            owner,
+            lb_pair,
+            meteora_position,
+            side,
+            min_bin_id,
+            max_bin_id,
+            initial_amount,
+            harvested_amount: 0,
+            created_at,
+            bump,
+            label: [0u8; 32],
+            total_fees_paid_x: 0,
+            total_fees_paid_y: 0,
+            take_profit_bins: 0,
+            strategy: STRATEGY_BID_ASK,
+            last_harvest_slot: 0,
+            referrer: Pubkey::default(),
+            priority_slots_override: 0,
+            position_kind,
+            fee_delegate: Pubkey::default(),
+            owner_counted: false,
+            fee_bps_override: None,
+        }
+    }
+
+    /// Slots the authorized bot keeps exclusive harvest / close rights after its last
+    /// action. Positions without an override (or created before the field) use config's.
+    pub fn effective_priority_slots(&self, config_priority_slots: u64) -> u64 {
+        if self.priority_slots_override == 0 {
+            config_priority_slots
+        } else {
+            self.priority_slots_override
+        }
+    }
+
+    /// Protocol fee for this position: its override, never above the current
+    /// config.fee_bps (a later fee cut applies to discounted positions too).
+    pub fn effective_fee_bps(&self, config_fee_bps: u16) -> u16 {
+        self.fee_bps_override.map_or(config_fee_bps, |bps| bps.min(config_fee_bps))
+    }
+
+    /// Checks against whoever Position.owner is now (transfer_position moves it):
+    /// the user_close signer, and the claim_fees token accounts the fees land in.
+    pub fn is_owner(&self, signer: &Pubkey) -> bool {
+        *signer == self.owner
+    }
+
+    /// transfer_position: rewrite the owner in place, returning the previous one.
+    pub fn transfer_to(&mut self, new_owner: Pubkey) -> Result<Pubkey> {
+        require!(new_owner != Pubkey::default(), CoreError::InvalidNewOwner);
+        require!(new_owner != self.owner, CoreError::InvalidNewOwner);
+        Ok(core::mem::replace(&mut self.owner, new_owner))
+    }
+
+    /// claim_fees signer check: the owner, or the fee delegate if one is set.
+    pub fn can_claim_fees(&self, signer: &Pubkey) -> bool {
+        *signer == self.owner
+            || (self.fee_delegate != Pubkey::default() && *signer == self.fee_delegate)
+    }
+}
+
+#[account]
+pub struct Vault {
+    pub position: Pubkey,  // meteora_position this vault is bound to (1:1)
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const SIZE: usize = 8 + 32 + 1;
+}
+
+/// Per-user-per-pool counter for deterministic meteora position PDA derivation.
+/// Enables multiple positions per pool without requiring a keypair signer.
+#[account]
+pub struct PositionCounter {
+    pub count: u64,
+    pub bump: u8,
+}
+
+impl PositionCounter {
+    pub const SIZE: usize = 8 + 8 + 1;
+}
+
+/// Per-wallet open position count (["owner_stats", owner]) backing
+/// Config.max_positions_per_owner. Only positions with owner_counted set are in it.
+#[account]
+pub struct OwnerStats {
+    pub owner: Pubkey,
+    pub open_positions: u64,
+    pub bump: u8,
+}
+
+impl OwnerStats {
+    pub const SIZE: usize = 8 + 32 + 8 + 1;
+
+    /// Count one more open position, failing with TooManyPositions past the cap (0 = unlimited).
+    pub fn take_slot(&mut self, max_positions: u64) -> Result<()> {
+        let next = self.open_positions.checked_add(1).ok_or(CoreError::Overflow)?;
+        require!(max_positions == 0 || next <= max_positions, CoreError::TooManyPositions);
+        self.open_positions = next;
+        Ok(())
+    }
+}
+
+/// Rover authority PDA — owns rover (bribe) positions.
+/// Harvest proceeds accumulate here. sweep_rover splits SOL 60/40: 60% to revenue_dest (bridge_vault), 40% to Config.bot.
+#[account]
+pub struct RoverAuthority {
+    pub revenue_dest: Pubkey,              // Where monke share goes (bridge_vault PDA — 60% of swept SOL)
+    pub total_rover_positions: u64,        // Lifetime count
+    pub bump: u8,
+    pub pending_revenue_dest: Pubkey,      // Timelocked: proposed new revenue_dest
+    pub revenue_dest_change_at: i64,       // Timelocked: timestamp when pending can be applied (0 = none)
+    // Reserved space for future fields (avoids account reallocation post-deploy)
+    pub _reserved: [u8; 64],
+}
+
+impl RoverAuthority {
     pub const SIZE: usize = 8 + 32 + 8 + 1 + 32 + 8 + 64;
 }
 
-// ============ CONTEXTS ============
+/// Shared tip destination for a keeper collective. Owns ATAs that receive
+/// permissionless-harvest tips; the authority pays members out.
+#[account]
+pub struct KeeperPool {
+    pub authority: Pubkey,     // Pool admin — signs withdrawals
+    pub bump: u8,
+    pub created_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl KeeperPool {
+    pub const SIZE: usize = 8 + 32 + 1 + 8 + 32;
+}
+
+/// Per-pool quarantine flag. PDA seeds: ["pool_status", lb_pair]
+#[account]
+pub struct PoolStatus {
+    pub lb_pair: Pubkey,
+    pub blocked: bool,         // Gates new opens on lb_pair only
+    pub bump: u8,
+    pub min_position_amount: u64, // open_position_v2 minimum on this pool (0 = Config's)
+}
+
+impl PoolStatus {
+    pub const SIZE: usize = 8 + 32 + 1 + 1 + 8;
+    /// Layout before min_position_amount (migrate_pool_status grows these).
+    pub const LEGACY_SIZE: usize = 8 + 32 + 1 + 1;
+}
+
+/// Per-pool yield counters (["pool_metrics", lb_pair]), fed by harvest_bins /
+/// harvest_bins_multi / close_position once init_pool_metrics has run. Amounts are
+/// in each position's output token (X for buys, Y for sells), summed as base units.
+#[account]
+pub struct PoolMetrics {
+    pub lb_pair: Pubkey,
+    pub lifetime_harvested: u64,  // Output delivered to owners by harvests and bot closes
+    pub lifetime_fees: u64,       // Protocol fee taken on that output (incl. tip / referral)
+    pub position_count: u64,      // Positions closed through close_position
+    pub bump: u8,
+}
+
+impl PoolMetrics {
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+}
+
+// ============ CONTEXTS ============
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Config::SIZE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Token-2022 compatible open_position. All CPI via V2 variants.
+/// Permissionless bin array setup before an open — bin arrays in remaining_accounts
+#[derive(Accounts)]
+pub struct EnsureBinArrays<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Meteora pool — must be owned by the DLMM program; the bin array PDAs
+    /// are derived from it
+    #[account(constraint = *lb_pair.owner == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidPool)]
+    pub lb_pair: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: DLMM program
+    #[account(constraint = dlmm_program.key() == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub dlmm_program: AccountInfo<'info>,
+}
+
+/// Single-signer: meteora_position is a PDA (signed via invoke_signed), not a keypair.
+#[derive(Accounts)]
+#[instruction(amount: u64, min_bin_id: i32, max_bin_id: i32, side: Side)]
+pub struct OpenPositionV2<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: Validated by Meteora CPI
+    #[account(mut)]
+    pub lb_pair: AccountInfo<'info>,
+
+    /// CHECK: PoolStatus PDA for lb_pair — may be uninitialized (= not blocked).
+    /// Address enforced by seeds; blocked flag checked in handler.
+    #[account(seeds = [b"pool_status", lb_pair.key().as_ref()], bump)]
+    pub pool_status: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PositionCounter::SIZE,
+        seeds = [b"pos_counter", user.key().as_ref(), lb_pair.key().as_ref()],
+        bump
+    )]
+    pub position_counter: Account<'info, PositionCounter>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = OwnerStats::SIZE,
+        seeds = [b"owner_stats", user.key().as_ref()],
+        bump
+    )]
+    pub owner_stats: Box<Account<'info, OwnerStats>>,
+
+    /// CHECK: PDA signed via invoke_signed — replaces the old keypair Signer
+    #[account(
+        mut,
+        seeds = [b"meteora_pos", user.key().as_ref(), lb_pair.key().as_ref(), &position_counter.count.to_le_bytes()],
+        bump
+    )]
+    pub meteora_position: UncheckedAccount<'info>,
+
+    /// CHECK: Bitmap extension (pass DLMM program ID if none).
+    pub bin_array_bitmap_ext: AccountInfo<'info>,
+
+    /// CHECK: Pool reserve X
+    #[account(mut)]
+    pub reserve_x: AccountInfo<'info>,
+
+    /// CHECK: Pool reserve Y
+    #[account(mut)]
+    pub reserve_y: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Position::SIZE,
+        seeds = [b"position", meteora_position.key().as_ref()],
+        bump
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Vault::SIZE,
+        seeds = [b"vault", meteora_position.key().as_ref()],
+        bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: User's deposit token account (Token-2022 compatible). Validated in handler.
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Vault's token X account. Validated in handler.
+    #[account(mut)]
+    pub vault_token_x: AccountInfo<'info>,
+
+    /// CHECK: Vault's token Y account. Validated in handler.
+    #[account(mut)]
+    pub vault_token_y: AccountInfo<'info>,
+
+    /// CHECK: Token X program — must be on config.allowed_token_programs
+    #[account(constraint = config.is_token_program_allowed(token_x_program.key) @ CoreError::InvalidProgram)]
+    pub token_x_program: AccountInfo<'info>,
+
+    /// CHECK: Token Y program — must be on config.allowed_token_programs
+    #[account(constraint = config.is_token_program_allowed(token_y_program.key) @ CoreError::InvalidProgram)]
+    pub token_y_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Bin array lower — Meteora validates via CPI
+    #[account(mut)]
+    pub bin_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array upper — Meteora validates via CPI
+    #[account(mut)]
+    pub bin_array_upper: UncheckedAccount<'info>,
+
+    /// CHECK: Meteora event authority — validated in handler body
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Meteora DLMM program — validated in handler body
+    pub dlmm_program: UncheckedAccount<'info>,
+
+    /// CHECK: Token X mint — passed through to Meteora CPI
+    pub token_x_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token Y mint — passed through to Meteora CPI
+    pub token_y_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Bin array between lower and upper. Only required when [min_bin_id, max_bin_id]
+    /// spans three bin arrays; PDA checked in handler, Meteora validates contents via CPI.
+    #[account(mut)]
+    pub bin_array_middle: Option<UncheckedAccount<'info>>,
+
+    /// config.authority co-signing a fee_bps_override. Only required when one is set.
+    pub fee_authority: Option<Signer<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut)]
+    pub bot: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+        close = owner
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"vault", position.meteora_position.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Owner's OwnerStats PDA — decremented in handler when position.owner_counted
+    /// (may be uninitialized for positions that aren't counted).
+    #[account(mut, seeds = [b"owner_stats", position.owner.as_ref()], bump)]
+    pub owner_stats: UncheckedAccount<'info>,
+
+    /// CHECK: Position owner
+    #[account(mut, constraint = owner.key() == position.owner @ CoreError::Unauthorized)]
+    pub owner: AccountInfo<'info>,
+
+    // --- Meteora ---
+
+    /// CHECK: Meteora position
+    #[account(mut, constraint = meteora_position.key() == position.meteora_position @ CoreError::InvalidPosition)]
+    pub meteora_position: AccountInfo<'info>,
+
+    /// CHECK: DLMM pool
+    #[account(mut, constraint = lb_pair.key() == position.lb_pair @ CoreError::InvalidPool)]
+    pub lb_pair: AccountInfo<'info>,
+
+    /// CHECK: PoolMetrics PDA for lb_pair — may be uninitialized (metrics off).
+    /// Address enforced by seeds; updated in handler when initialized.
+    #[account(mut, seeds = [b"pool_metrics", lb_pair.key().as_ref()], bump)]
+    pub pool_metrics: UncheckedAccount<'info>,
+
+    /// CHECK: Bitmap ext — writable only when real account exists
+    pub bin_array_bitmap_ext: AccountInfo<'info>,
+
+    /// CHECK: Bin array lower
+    #[account(mut)]
+    pub bin_array_lower: AccountInfo<'info>,
+
+    /// CHECK: Bin array upper
+    #[account(mut)]
+    pub bin_array_upper: AccountInfo<'info>,
+
+    /// CHECK: Reserve X
+    #[account(mut)]
+    pub reserve_x: AccountInfo<'info>,
+
+    /// CHECK: Reserve Y
+    #[account(mut)]
+    pub reserve_y: AccountInfo<'info>,
+
+    /// CHECK: Token X mint — passed through to Meteora CPI
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Token Y mint — passed through to Meteora CPI
+    pub token_y_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: DLMM program
+    #[account(constraint = dlmm_program.key() == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub dlmm_program: AccountInfo<'info>,
+
+    // --- Token accounts (ownership validated) ---
+
+    #[account(mut, constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = owner_token_x.owner == position.owner @ CoreError::InvalidTokenOwner)]
+    pub owner_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = owner_token_y.owner == position.owner @ CoreError::InvalidTokenOwner)]
+    pub owner_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    // --- Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40) ---
+    #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
+    pub rover_authority: Box<Account<'info, RoverAuthority>>,
+
+    #[account(
+        mut,
+        constraint = rover_fee_token_x.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_x.mint == token_x_mint.key() @ CoreError::RoverFeeMintMismatch,
+    )]
+    pub rover_fee_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = rover_fee_token_y.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_y.mint == token_y_mint.key() @ CoreError::RoverFeeMintMismatch,
+    )]
+    pub rover_fee_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    /// CHECK: Token X program — must be SPL Token or Token-2022
+    #[account(constraint = config.is_token_program_accepted(token_x_program.key) @ CoreError::InvalidProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    /// CHECK: Token Y program — must be SPL Token or Token-2022
+    #[account(constraint = config.is_token_program_accepted(token_y_program.key) @ CoreError::InvalidProgram)]
+    pub token_y_program: AccountInfo<'info>,
+
+    /// CHECK: SPL Memo program (required for Token-2022 V2 CPI)
+    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub memo_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BotHarvest<'info> {
+    #[account(mut)]
+    pub bot: Signer<'info>,
+
+    // NOTE: config is mut for last_bot_harvest_slot heartbeat update.
+    // Bot authorization moved to instruction body (permissionless fallback).
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        seeds = [b"vault", position.meteora_position.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Position owner
+    #[account(mut, constraint = owner.key() == position.owner @ CoreError::Unauthorized)]
+    pub owner: AccountInfo<'info>,
+
+    // --- Meteora ---
+
+    /// CHECK: Meteora position
+    #[account(mut, constraint = meteora_position.key() == position.meteora_position @ CoreError::InvalidPosition)]
+    pub meteora_position: AccountInfo<'info>,
+
+    /// CHECK: DLMM pool
+    #[account(mut, constraint = lb_pair.key() == position.lb_pair @ CoreError::InvalidPool)]
+    pub lb_pair: AccountInfo<'info>,
+
+    /// CHECK: PoolMetrics PDA for lb_pair — may be uninitialized (metrics off).
+    /// Address enforced by seeds; updated in handler when initialized.
+    #[account(mut, seeds = [b"pool_metrics", lb_pair.key().as_ref()], bump)]
+    pub pool_metrics: UncheckedAccount<'info>,
+
+    /// CHECK: Bitmap ext — writable only when real account exists
+    pub bin_array_bitmap_ext: AccountInfo<'info>,
+
+    /// CHECK: Bin array lower
+    #[account(mut)]
+    pub bin_array_lower: AccountInfo<'info>,
+
+    /// CHECK: Bin array upper
+    #[account(mut)]
+    pub bin_array_upper: AccountInfo<'info>,
+
+    /// CHECK: Reserve X
+    #[account(mut)]
+    pub reserve_x: AccountInfo<'info>,
+
+    /// CHECK: Reserve Y
+    #[account(mut)]
+    pub reserve_y: AccountInfo<'info>,
+
+    /// CHECK: Token X mint — passed through to Meteora CPI
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Token Y mint — passed through to Meteora CPI
+    pub token_y_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: DLMM program
+    #[account(constraint = dlmm_program.key() == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub dlmm_program: AccountInfo<'info>,
+
+    // --- Token accounts (ownership validated) ---
+
+    #[account(mut, constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = owner_token_x.owner == position.owner @ CoreError::InvalidTokenOwner)]
+    pub owner_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = owner_token_y.owner == position.owner @ CoreError::InvalidTokenOwner)]
+    pub owner_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    // --- Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40) ---
+    #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
+    pub rover_authority: Box<Account<'info, RoverAuthority>>,
 
     #[account(
-        init,
-        payer = authority,
-        space = Config::SIZE,
-        seeds = [b"config"],
-        bump
+        mut,
+        constraint = rover_fee_token_x.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_x.mint == token_x_mint.key() @ CoreError::RoverFeeMintMismatch,
     )]
-    pub config: Account<'info, Config>,
+    pub rover_fee_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        constraint = rover_fee_token_y.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_y.mint == token_y_mint.key() @ CoreError::RoverFeeMintMismatch,
+    )]
+    pub rover_fee_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    /// CHECK: Token X program — must be SPL Token or Token-2022
+    #[account(constraint = config.is_token_program_accepted(token_x_program.key) @ CoreError::InvalidProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    /// CHECK: Token Y program — must be SPL Token or Token-2022
+    #[account(constraint = config.is_token_program_accepted(token_y_program.key) @ CoreError::InvalidProgram)]
+    pub token_y_program: AccountInfo<'info>,
+
+    /// CHECK: SPL Memo program (required for Token-2022 V2 CPI)
+    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub memo_program: AccountInfo<'info>,
 }
 
-/// Token-2022 compatible open_position. All CPI via V2 variants.
-/// Single-signer: meteora_position is a PDA (signed via invoke_signed), not a keypair.
+/// Pool-level accounts for harvest_bins_multi. Per-position accounts come in
+/// remaining_accounts — see harvest_bins_multi for the group layout.
 #[derive(Accounts)]
-#[instruction(amount: u64, min_bin_id: i32, max_bin_id: i32, side: Side)]
-pub struct OpenPositionV2<'info> {
+pub struct HarvestBinsMulti<'info> {
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub bot: Signer<'info>,
 
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    // config is mut for the heartbeat and harvest counters
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
     pub config: Box<Account<'info, Config>>,
 
-    /// CHECK: Validated by Meteora CPI
-    #[account(mut)]
+    /// CHECK: DLMM pool — every position in the batch must be on it (checked per entry)
+    #[account(mut, constraint = *lb_pair.owner == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidPool)]
     pub lb_pair: AccountInfo<'info>,
 
-    #[account(
-        init_if_needed,
-        payer = user,
-        space = PositionCounter::SIZE,
-        seeds = [b"pos_counter", user.key().as_ref(), lb_pair.key().as_ref()],
-        bump
-    )]
-    pub position_counter: Account<'info, PositionCounter>,
-
-    /// CHECK: PDA signed via invoke_signed — replaces the old keypair Signer
-    #[account(
-        mut,
-        seeds = [b"meteora_pos", user.key().as_ref(), lb_pair.key().as_ref(), &position_counter.count.to_le_bytes()],
-        bump
-    )]
-    pub meteora_position: UncheckedAccount<'info>,
+    /// CHECK: PoolMetrics PDA for lb_pair — may be uninitialized (metrics off).
+    /// Address enforced by seeds; updated in handler when initialized.
+    #[account(mut, seeds = [b"pool_metrics", lb_pair.key().as_ref()], bump)]
+    pub pool_metrics: UncheckedAccount<'info>,
 
-    /// CHECK: Bitmap extension (pass DLMM program ID if none).
+    /// CHECK: Bitmap ext — writable only when real account exists
     pub bin_array_bitmap_ext: AccountInfo<'info>,
 
-    /// CHECK: Pool reserve X
+    /// CHECK: Reserve X
     #[account(mut)]
     pub reserve_x: AccountInfo<'info>,
 
-    /// CHECK: Pool reserve Y
+    /// CHECK: Reserve Y
     #[account(mut)]
     pub reserve_y: AccountInfo<'info>,
 
+    /// CHECK: Token X mint — passed through to Meteora CPI
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Token Y mint — passed through to Meteora CPI
+    pub token_y_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: DLMM program
+    #[account(constraint = dlmm_program.key() == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub dlmm_program: AccountInfo<'info>,
+
+    #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
+    pub rover_authority: Box<Account<'info, RoverAuthority>>,
+
     #[account(
-        init,
-        payer = user,
-        space = Position::SIZE,
-        seeds = [b"position", meteora_position.key().as_ref()],
-        bump
+        mut,
+        constraint = rover_fee_token_x.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_x.mint == token_x_mint.key() @ CoreError::RoverFeeMintMismatch,
     )]
-    pub position: Box<Account<'info, Position>>,
+    pub rover_fee_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
     #[account(
-        init,
-        payer = user,
-        space = Vault::SIZE,
-        seeds = [b"vault", meteora_position.key().as_ref()],
-        bump
+        mut,
+        constraint = rover_fee_token_y.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_y.mint == token_y_mint.key() @ CoreError::RoverFeeMintMismatch,
     )]
-    pub vault: Box<Account<'info, Vault>>,
-
-    /// CHECK: User's deposit token account (Token-2022 compatible). Validated in handler.
-    #[account(mut)]
-    pub user_token_account: AccountInfo<'info>,
-
-    /// CHECK: Vault's token X account. Validated in handler.
-    #[account(mut)]
-    pub vault_token_x: AccountInfo<'info>,
-
-    /// CHECK: Vault's token Y account. Validated in handler.
-    #[account(mut)]
-    pub vault_token_y: AccountInfo<'info>,
+    pub rover_fee_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    /// CHECK: Token X program — SPL Token or Token-2022
-    #[account(constraint = *token_x_program.key == anchor_spl::token::ID || *token_x_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    /// CHECK: Token X program — must be SPL Token or Token-2022
+    #[account(constraint = config.is_token_program_accepted(token_x_program.key) @ CoreError::InvalidProgram)]
     pub token_x_program: AccountInfo<'info>,
-
-    /// CHECK: Token Y program — SPL Token or Token-2022
-    #[account(constraint = *token_y_program.key == anchor_spl::token::ID || *token_y_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    /// CHECK: Token Y program — must be SPL Token or Token-2022
+    #[account(constraint = config.is_token_program_accepted(token_y_program.key) @ CoreError::InvalidProgram)]
     pub token_y_program: AccountInfo<'info>,
 
-    pub system_program: Program<'info, System>,
-
-    /// CHECK: Bin array lower — Meteora validates via CPI
-    #[account(mut)]
-    pub bin_array_lower: UncheckedAccount<'info>,
-
-    /// CHECK: Bin array upper — Meteora validates via CPI
-    #[account(mut)]
-    pub bin_array_upper: UncheckedAccount<'info>,
-
-    /// CHECK: Meteora event authority — validated by Meteora CPI
-    pub event_authority: UncheckedAccount<'info>,
-
-    /// CHECK: Meteora DLMM program — validated in handler body
-    pub dlmm_program: UncheckedAccount<'info>,
-
-    /// CHECK: Token X mint — passed through to Meteora CPI
-    pub token_x_mint: UncheckedAccount<'info>,
-
-    /// CHECK: Token Y mint — passed through to Meteora CPI
-    pub token_y_mint: UncheckedAccount<'info>,
+    /// CHECK: SPL Memo program (required for Token-2022 V2 CPI)
+    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub memo_program: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ClosePosition<'info> {
+pub struct UserClose<'info> {
     #[account(mut)]
-    pub bot: Signer<'info>,
+    pub user: Signer<'info>,
 
-    #[account(
-        mut,
-        seeds = [b"config"],
-        bump = config.bump,
-    )]
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
         mut,
         seeds = [b"position", position.meteora_position.as_ref()],
         bump = position.bump,
-        close = owner
+        constraint = position.is_owner(user.key) @ CoreError::Unauthorized,
+        close = user
     )]
     pub position: Box<Account<'info, Position>>,
 
     #[account(
         mut,
-        close = owner,
+        close = user,
         seeds = [b"vault", position.meteora_position.as_ref()],
         bump = vault.bump
     )]
     pub vault: Box<Account<'info, Vault>>,
 
-    /// CHECK: Position owner
-    #[account(mut, constraint = owner.key() == position.owner @ CoreError::Unauthorized)]
-    pub owner: AccountInfo<'info>,
+    /// CHECK: Owner's OwnerStats PDA — decremented in handler when position.owner_counted
+    /// (may be uninitialized for positions that aren't counted).
+    #[account(mut, seeds = [b"owner_stats", position.owner.as_ref()], bump)]
+    pub owner_stats: UncheckedAccount<'info>,
 
     // --- Meteora ---
 
@@ -2159,6 +7084,11 @@ pub struct ClosePosition<'info> {
     #[account(mut, constraint = lb_pair.key() == position.lb_pair @ CoreError::InvalidPool)]
     pub lb_pair: AccountInfo<'info>,
 
+    /// CHECK: PoolMetrics PDA for lb_pair — may be uninitialized (metrics off).
+    /// Address enforced by seeds; updated in handler when initialized.
+    #[account(mut, seeds = [b"pool_metrics", lb_pair.key().as_ref()], bump)]
+    pub pool_metrics: UncheckedAccount<'info>,
+
     /// CHECK: Bitmap ext — writable only when real account exists
     pub bin_array_bitmap_ext: AccountInfo<'info>,
 
@@ -2184,13 +7114,14 @@ pub struct ClosePosition<'info> {
     pub token_y_mint: UncheckedAccount<'info>,
 
     /// CHECK: Event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
     pub event_authority: AccountInfo<'info>,
 
     /// CHECK: DLMM program
     #[account(constraint = dlmm_program.key() == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidProgram)]
     pub dlmm_program: AccountInfo<'info>,
 
-    // --- Token accounts (ownership validated) ---
+    // --- Token accounts ---
 
     #[account(mut, constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
@@ -2198,27 +7129,35 @@ pub struct ClosePosition<'info> {
     #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = owner_token_x.owner == position.owner @ CoreError::InvalidTokenOwner)]
-    pub owner_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+    #[account(mut, constraint = user_token_x.owner == user.key() @ CoreError::InvalidTokenOwner)]
+    pub user_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = owner_token_y.owner == position.owner @ CoreError::InvalidTokenOwner)]
-    pub owner_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+    #[account(mut, constraint = user_token_y.owner == user.key() @ CoreError::InvalidTokenOwner)]
+    pub user_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
     // --- Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40) ---
     #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
     pub rover_authority: Box<Account<'info, RoverAuthority>>,
 
-    #[account(mut, constraint = rover_fee_token_x.owner == rover_authority.key() @ CoreError::InvalidTokenOwner)]
+    #[account(
+        mut,
+        constraint = rover_fee_token_x.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_x.mint == token_x_mint.key() @ CoreError::RoverFeeMintMismatch,
+    )]
     pub rover_fee_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = rover_fee_token_y.owner == rover_authority.key() @ CoreError::InvalidTokenOwner)]
+    #[account(
+        mut,
+        constraint = rover_fee_token_y.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_token_y.mint == token_y_mint.key() @ CoreError::RoverFeeMintMismatch,
+    )]
     pub rover_fee_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
     /// CHECK: Token X program — must be SPL Token or Token-2022
-    #[account(constraint = *token_x_program.key == anchor_spl::token::ID || *token_x_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    #[account(constraint = config.is_token_program_accepted(token_x_program.key) @ CoreError::InvalidProgram)]
     pub token_x_program: AccountInfo<'info>,
     /// CHECK: Token Y program — must be SPL Token or Token-2022
-    #[account(constraint = *token_y_program.key == anchor_spl::token::ID || *token_y_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    #[account(constraint = config.is_token_program_accepted(token_y_program.key) @ CoreError::InvalidProgram)]
     pub token_y_program: AccountInfo<'info>,
 
     /// CHECK: SPL Memo program (required for Token-2022 V2 CPI)
@@ -2229,23 +7168,133 @@ pub struct ClosePosition<'info> {
 }
 
 #[derive(Accounts)]
-pub struct BotHarvest<'info> {
-    #[account(mut)]
-    pub bot: Signer<'info>,
+pub struct RescueVaultDust<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+        constraint = position.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        seeds = [b"vault", position.meteora_position.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Token X mint — transfer_checked validates it against the token accounts
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Token Y mint — transfer_checked validates it against the token accounts
+    pub token_y_mint: UncheckedAccount<'info>,
 
-    // NOTE: config is mut for last_bot_harvest_slot heartbeat update.
-    // Bot authorization moved to instruction body (permissionless fallback).
     #[account(
         mut,
-        seeds = [b"config"],
-        bump = config.bump,
+        constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner,
+        constraint = vault_token_x.mint == token_x_mint.key() @ CoreError::InvalidTokenOwner,
+    )]
+    pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner,
+        constraint = vault_token_y.mint == token_y_mint.key() @ CoreError::InvalidTokenOwner,
     )]
+    pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = owner_token_x.owner == owner.key() @ CoreError::InvalidTokenOwner)]
+    pub owner_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = owner_token_y.owner == owner.key() @ CoreError::InvalidTokenOwner)]
+    pub owner_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    /// CHECK: Token X program — must be SPL Token or Token-2022
+    #[account(constraint = *token_x_program.key == anchor_spl::token::ID || *token_x_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub token_x_program: AccountInfo<'info>,
+    /// CHECK: Token Y program — must be SPL Token or Token-2022
+    #[account(constraint = *token_y_program.key == anchor_spl::token::ID || *token_y_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub token_y_program: AccountInfo<'info>,
+
+    /// CHECK: SPL Memo program (memo-required destination accounts)
+    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub memo_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct TransferPosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
-        mut,
+        mut,
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+        constraint = position.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    /// CHECK: Current owner's OwnerStats PDA — decremented in handler when position.owner_counted
+    #[account(mut, seeds = [b"owner_stats", owner.key().as_ref()], bump)]
+    pub owner_stats: UncheckedAccount<'info>,
+
+    /// CHECK: New owner's OwnerStats PDA — created if needed and incremented in handler
+    /// when position.owner_counted
+    #[account(mut, seeds = [b"owner_stats", new_owner.as_ref()], bump)]
+    pub new_owner_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Position PDA in any historical layout — program owner, discriminator,
+    /// and PDA seeds validated in handler.
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct QuotePositionTax<'info> {
+    #[account(
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
+}
+
+#[derive(Accounts)]
+pub struct SetPositionLabel<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Position PDA — read raw so pre-label layouts can be reallocated.
+    /// Program owner, discriminator, owner field, and PDA seeds validated in handler.
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFees<'info> {
+    /// Position owner or its fee_delegate
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
         seeds = [b"position", position.meteora_position.as_ref()],
         bump = position.bump,
+        constraint = position.can_claim_fees(user.key) @ CoreError::Unauthorized
     )]
     pub position: Box<Account<'info, Position>>,
 
@@ -2255,10 +7304,6 @@ pub struct BotHarvest<'info> {
     )]
     pub vault: Box<Account<'info, Vault>>,
 
-    /// CHECK: Position owner
-    #[account(mut, constraint = owner.key() == position.owner @ CoreError::Unauthorized)]
-    pub owner: AccountInfo<'info>,
-
     // --- Meteora ---
 
     /// CHECK: Meteora position
@@ -2269,9 +7314,6 @@ pub struct BotHarvest<'info> {
     #[account(mut, constraint = lb_pair.key() == position.lb_pair @ CoreError::InvalidPool)]
     pub lb_pair: AccountInfo<'info>,
 
-    /// CHECK: Bitmap ext — writable only when real account exists
-    pub bin_array_bitmap_ext: AccountInfo<'info>,
-
     /// CHECK: Bin array lower
     #[account(mut)]
     pub bin_array_lower: AccountInfo<'info>,
@@ -2294,13 +7336,14 @@ pub struct BotHarvest<'info> {
     pub token_y_mint: UncheckedAccount<'info>,
 
     /// CHECK: Event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
     pub event_authority: AccountInfo<'info>,
 
     /// CHECK: DLMM program
     #[account(constraint = dlmm_program.key() == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidProgram)]
     pub dlmm_program: AccountInfo<'info>,
 
-    // --- Token accounts (ownership validated) ---
+    // --- Token accounts ---
 
     #[account(mut, constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
@@ -2308,21 +7351,12 @@ pub struct BotHarvest<'info> {
     #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = owner_token_x.owner == position.owner @ CoreError::InvalidTokenOwner)]
-    pub owner_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
-
-    #[account(mut, constraint = owner_token_y.owner == position.owner @ CoreError::InvalidTokenOwner)]
-    pub owner_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
-
-    // --- Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40) ---
-    #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
-    pub rover_authority: Box<Account<'info, RoverAuthority>>,
-
-    #[account(mut, constraint = rover_fee_token_x.owner == rover_authority.key() @ CoreError::InvalidTokenOwner)]
-    pub rover_fee_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+    // Fees go to the owner's accounts even when the fee_delegate signs
+    #[account(mut, constraint = position.is_owner(&user_token_x.owner) @ CoreError::InvalidTokenOwner)]
+    pub user_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = rover_fee_token_y.owner == rover_authority.key() @ CoreError::InvalidTokenOwner)]
-    pub rover_fee_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+    #[account(mut, constraint = position.is_owner(&user_token_y.owner) @ CoreError::InvalidTokenOwner)]
+    pub user_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
     /// CHECK: Token X program — must be SPL Token or Token-2022
     #[account(constraint = *token_x_program.key == anchor_spl::token::ID || *token_x_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
@@ -2336,26 +7370,21 @@ pub struct BotHarvest<'info> {
     pub memo_program: AccountInfo<'info>,
 }
 
+/// claim_fees_compound — ClaimFees plus the bitmap extension for the re-add CPI.
 #[derive(Accounts)]
-pub struct UserClose<'info> {
+pub struct ClaimFeesCompound<'info> {
+    /// Position owner or its fee_delegate
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
-    pub config: Box<Account<'info, Config>>,
-
     #[account(
-        mut,
         seeds = [b"position", position.meteora_position.as_ref()],
         bump = position.bump,
-        constraint = position.owner == user.key() @ CoreError::Unauthorized,
-        close = user
+        constraint = position.can_claim_fees(user.key) @ CoreError::Unauthorized
     )]
     pub position: Box<Account<'info, Position>>,
 
     #[account(
-        mut,
-        close = user,
         seeds = [b"vault", position.meteora_position.as_ref()],
         bump = vault.bump
     )]
@@ -2396,6 +7425,7 @@ pub struct UserClose<'info> {
     pub token_y_mint: UncheckedAccount<'info>,
 
     /// CHECK: Event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
     pub event_authority: AccountInfo<'info>,
 
     /// CHECK: DLMM program
@@ -2410,22 +7440,13 @@ pub struct UserClose<'info> {
     #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = user_token_x.owner == user.key() @ CoreError::InvalidTokenOwner)]
+    // Uncompounded fees go to the owner's accounts even when the fee_delegate signs
+    #[account(mut, constraint = position.is_owner(&user_token_x.owner) @ CoreError::InvalidTokenOwner)]
     pub user_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = user_token_y.owner == user.key() @ CoreError::InvalidTokenOwner)]
+    #[account(mut, constraint = position.is_owner(&user_token_y.owner) @ CoreError::InvalidTokenOwner)]
     pub user_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    // --- Fee routing: all fees → rover_authority ATAs (sweep_rover splits 60/40) ---
-    #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
-    pub rover_authority: Box<Account<'info, RoverAuthority>>,
-
-    #[account(mut, constraint = rover_fee_token_x.owner == rover_authority.key() @ CoreError::InvalidTokenOwner)]
-    pub rover_fee_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
-
-    #[account(mut, constraint = rover_fee_token_y.owner == rover_authority.key() @ CoreError::InvalidTokenOwner)]
-    pub rover_fee_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
-
     /// CHECK: Token X program — must be SPL Token or Token-2022
     #[account(constraint = *token_x_program.key == anchor_spl::token::ID || *token_x_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
     pub token_x_program: AccountInfo<'info>,
@@ -2436,15 +7457,29 @@ pub struct UserClose<'info> {
     /// CHECK: SPL Memo program (required for Token-2022 V2 CPI)
     #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
     pub memo_program: AccountInfo<'info>,
+}
 
-    pub system_program: Program<'info, System>,
+#[derive(Accounts)]
+pub struct SetFeeDelegate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+        constraint = position.owner == owner.key() @ CoreError::Unauthorized
+    )]
+    pub position: Box<Account<'info, Position>>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimFees<'info> {
+pub struct ClaimReward<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     #[account(
         seeds = [b"position", position.meteora_position.as_ref()],
         bump = position.bump,
@@ -2476,20 +7511,15 @@ pub struct ClaimFees<'info> {
     #[account(mut)]
     pub bin_array_upper: AccountInfo<'info>,
 
-    /// CHECK: Reserve X
-    #[account(mut)]
-    pub reserve_x: AccountInfo<'info>,
-
-    /// CHECK: Reserve Y
+    /// CHECK: Pool reward vault for reward_index — Meteora validates via CPI
     #[account(mut)]
-    pub reserve_y: AccountInfo<'info>,
+    pub reward_vault: AccountInfo<'info>,
 
-    /// CHECK: Token X mint — passed through to Meteora CPI
-    pub token_x_mint: UncheckedAccount<'info>,
-    /// CHECK: Token Y mint — passed through to Meteora CPI
-    pub token_y_mint: UncheckedAccount<'info>,
+    /// CHECK: Reward mint — Meteora validates against the pool's reward_info
+    pub reward_mint: UncheckedAccount<'info>,
 
     /// CHECK: Event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
     pub event_authority: AccountInfo<'info>,
 
     /// CHECK: DLMM program
@@ -2498,110 +7528,355 @@ pub struct ClaimFees<'info> {
 
     // --- Token accounts ---
 
+    #[account(
+        mut,
+        constraint = vault_reward_token.owner == vault.key() @ CoreError::InvalidTokenOwner,
+        constraint = vault_reward_token.mint == reward_mint.key() @ CoreError::InvalidTokenOwner,
+    )]
+    pub vault_reward_token: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_reward_token.owner == user.key() @ CoreError::InvalidTokenOwner,
+        constraint = user_reward_token.mint == reward_mint.key() @ CoreError::InvalidTokenOwner,
+    )]
+    pub user_reward_token: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
+    pub rover_authority: Box<Account<'info, RoverAuthority>>,
+
+    #[account(
+        mut,
+        constraint = rover_fee_reward.owner == rover_authority.key() @ CoreError::InvalidTokenOwner,
+        constraint = rover_fee_reward.mint == reward_mint.key() @ CoreError::RoverFeeMintMismatch,
+    )]
+    pub rover_fee_reward: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    /// CHECK: Reward token program — must be SPL Token or Token-2022
+    #[account(constraint = config.is_token_program_accepted(reward_token_program.key) @ CoreError::InvalidProgram)]
+    pub reward_token_program: AccountInfo<'info>,
+
+    /// CHECK: SPL Memo program (required for Token-2022 V2 CPI)
+    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub memo_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdminOnly<'info> {
+    #[account(constraint = authority.key() == config.authority @ CoreError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+/// Permissionless fee application — anyone can apply after timelock expires
+#[derive(Accounts)]
+pub struct ApplyFee<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+/// Emergency close — permissionless after 24hr timelock.
+/// Closes Position + Vault PDAs without Meteora CPI.
+/// Transfers any remaining vault tokens to position owner before closing.
+#[derive(Accounts)]
+pub struct ApplyEmergencyClose<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = caller,
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+        constraint = position.key() == config.pending_emergency_close @ CoreError::InvalidPosition
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        mut,
+        close = caller,
+        seeds = [b"vault", position.meteora_position.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Owner's OwnerStats PDA — decremented in handler when position.owner_counted
+    /// (may be uninitialized for positions that aren't counted).
+    #[account(mut, seeds = [b"owner_stats", position.owner.as_ref()], bump)]
+    pub owner_stats: UncheckedAccount<'info>,
+
+    /// CHECK: Position owner — receives any remaining vault tokens
+    #[account(constraint = owner.key() == position.owner @ CoreError::Unauthorized)]
+    pub owner: AccountInfo<'info>,
+
     #[account(mut, constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
     #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = user_token_x.owner == user.key() @ CoreError::InvalidTokenOwner)]
-    pub user_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+    #[account(mut, constraint = owner_token_x.owner == position.owner @ CoreError::InvalidTokenOwner)]
+    pub owner_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = user_token_y.owner == user.key() @ CoreError::InvalidTokenOwner)]
-    pub user_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+    #[account(mut, constraint = owner_token_y.owner == position.owner @ CoreError::InvalidTokenOwner)]
+    pub owner_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    /// CHECK: Token X mint
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Token Y mint
+    pub token_y_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token X program
+    #[account(constraint = config.is_token_program_accepted(token_x_program.key) @ CoreError::InvalidProgram)]
+    pub token_x_program: AccountInfo<'info>,
+
+    /// CHECK: Token Y program
+    #[account(constraint = config.is_token_program_accepted(token_y_program.key) @ CoreError::InvalidProgram)]
+    pub token_y_program: AccountInfo<'info>,
+
+    /// CHECK: SPL Memo program
+    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub memo_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Orphan vault reclaim — Vault whose Position PDA is gone. Closes the Vault and
+/// its token accounts to the caller; leftover tokens need config.authority.
+#[derive(Accounts)]
+pub struct ReclaimOrphanVault<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = caller,
+        seeds = [b"vault", vault.position.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Position PDA paired with the vault — must be absent (empty or not
+    /// owned by this program), checked in handler.
+    #[account(seeds = [b"position", vault.position.as_ref()], bump)]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+
+    /// CHECK: Receives leftover token X — only used when the caller is config.authority
+    #[account(mut)]
+    pub destination_token_x: AccountInfo<'info>,
+
+    /// CHECK: Receives leftover token Y — only used when the caller is config.authority
+    #[account(mut)]
+    pub destination_token_y: AccountInfo<'info>,
+
+    /// CHECK: Token X mint — must match vault_token_x
+    #[account(constraint = token_x_mint.key() == vault_token_x.mint @ CoreError::InvalidTokenOwner)]
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Token Y mint — must match vault_token_y
+    #[account(constraint = token_y_mint.key() == vault_token_y.mint @ CoreError::InvalidTokenOwner)]
+    pub token_y_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token X program
+    #[account(constraint = config.is_token_program_accepted(token_x_program.key) @ CoreError::InvalidProgram)]
+    pub token_x_program: AccountInfo<'info>,
+
+    /// CHECK: Token Y program
+    #[account(constraint = config.is_token_program_accepted(token_y_program.key) @ CoreError::InvalidProgram)]
+    pub token_y_program: AccountInfo<'info>,
+
+    /// CHECK: SPL Memo program
+    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub memo_program: AccountInfo<'info>,
+}
+
+/// Read-only harvest CU quote — no accounts are mutated
+#[derive(Accounts)]
+pub struct QuoteHarvestCost<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    /// CHECK: Token X mint — only data length is read (extension detection)
+    pub token_x_mint: UncheckedAccount<'info>,
+    /// CHECK: Token Y mint — only data length is read (extension detection)
+    pub token_y_mint: UncheckedAccount<'info>,
 
     /// CHECK: Token X program — must be SPL Token or Token-2022
-    #[account(constraint = *token_x_program.key == anchor_spl::token::ID || *token_x_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    #[account(constraint = config.is_token_program_accepted(token_x_program.key) @ CoreError::InvalidProgram)]
     pub token_x_program: AccountInfo<'info>,
     /// CHECK: Token Y program — must be SPL Token or Token-2022
-    #[account(constraint = *token_y_program.key == anchor_spl::token::ID || *token_y_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    #[account(constraint = config.is_token_program_accepted(token_y_program.key) @ CoreError::InvalidProgram)]
     pub token_y_program: AccountInfo<'info>,
+}
+
+/// Read-only bot health quote — no accounts are mutated
+#[derive(Accounts)]
+pub struct BotHeartbeat<'info> {
+    /// Must be config.bot — checked in handler (Config::record_heartbeat)
+    pub bot: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+}
+
+#[derive(Accounts)]
+pub struct QuoteBotHealth<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+}
+
+/// Read-only config counters — no accounts are mutated
+#[derive(Accounts)]
+pub struct ConfigStats<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+}
+
+#[derive(Accounts)]
+pub struct InitPoolMetrics<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Meteora pool — must be owned by the DLMM program
+    #[account(constraint = *lb_pair.owner == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidPool)]
+    pub lb_pair: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PoolMetrics::SIZE,
+        seeds = [b"pool_metrics", lb_pair.key().as_ref()],
+        bump
+    )]
+    pub pool_metrics: Account<'info, PoolMetrics>,
+
+    pub system_program: Program<'info, System>,
+}
 
-    /// CHECK: SPL Memo program (required for Token-2022 V2 CPI)
-    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
-    pub memo_program: AccountInfo<'info>,
+/// Read-only pool counters — no accounts are mutated
+#[derive(Accounts)]
+pub struct PoolStats<'info> {
+    #[account(seeds = [b"pool_metrics", pool_metrics.lb_pair.as_ref()], bump = pool_metrics.bump)]
+    pub pool_metrics: Account<'info, PoolMetrics>,
 }
 
+/// Read-only vault binding check — no accounts are mutated
 #[derive(Accounts)]
-pub struct AdminOnly<'info> {
-    #[account(constraint = authority.key() == config.authority @ CoreError::Unauthorized)]
-    pub authority: Signer<'info>,
+pub struct VerifyVaultBinding<'info> {
+    #[account(
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
 
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [b"vault", position.meteora_position.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
 }
 
-/// Permissionless fee application — anyone can apply after timelock expires
+/// Read-only LP fee quote — no accounts are mutated
 #[derive(Accounts)]
-pub struct ApplyFee<'info> {
-    pub caller: Signer<'info>,
+pub struct QuoteLpFees<'info> {
+    #[account(
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
 
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
+    /// CHECK: Meteora PositionV2 — bound to our Position and owned by the DLMM program
+    #[account(
+        constraint = meteora_position.key() == position.meteora_position @ CoreError::InvalidPositionData,
+        constraint = *meteora_position.owner == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidPositionData,
+    )]
+    pub meteora_position: UncheckedAccount<'info>,
 }
 
-/// Emergency close — permissionless after 24hr timelock.
-/// Closes Position + Vault PDAs without Meteora CPI.
-/// Transfers any remaining vault tokens to position owner before closing.
+/// Read-only close quote — no accounts are mutated
 #[derive(Accounts)]
-pub struct ApplyEmergencyClose<'info> {
-    #[account(mut)]
-    pub caller: Signer<'info>,
-
-    #[account(mut, seeds = [b"config"], bump = config.bump)]
+pub struct PreviewClose<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
-        mut,
-        close = caller,
         seeds = [b"position", position.meteora_position.as_ref()],
         bump = position.bump,
-        constraint = position.key() == config.pending_emergency_close @ CoreError::InvalidPosition
     )]
     pub position: Box<Account<'info, Position>>,
 
     #[account(
-        mut,
-        close = caller,
         seeds = [b"vault", position.meteora_position.as_ref()],
-        bump = vault.bump
+        bump = vault.bump,
     )]
     pub vault: Box<Account<'info, Vault>>,
 
-    /// CHECK: Position owner — receives any remaining vault tokens
-    #[account(constraint = owner.key() == position.owner @ CoreError::Unauthorized)]
-    pub owner: AccountInfo<'info>,
-
-    #[account(mut, constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    #[account(constraint = vault_token_x.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
 
-    #[account(mut, constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
+    #[account(constraint = vault_token_y.owner == vault.key() @ CoreError::InvalidTokenOwner)]
     pub vault_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+}
 
-    #[account(mut, constraint = owner_token_x.owner == position.owner @ CoreError::InvalidTokenOwner)]
-    pub owner_token_x: Box<InterfaceAccount<'info, ITokenAccount>>,
+/// Read-only position status — no accounts are mutated
+#[derive(Accounts)]
+pub struct PositionStatus<'info> {
+    #[account(
+        seeds = [b"position", position.meteora_position.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
 
-    #[account(mut, constraint = owner_token_y.owner == position.owner @ CoreError::InvalidTokenOwner)]
-    pub owner_token_y: Box<InterfaceAccount<'info, ITokenAccount>>,
+    /// CHECK: Meteora PositionV2 — bound to our Position and owned by the DLMM program
+    #[account(
+        constraint = meteora_position.key() == position.meteora_position @ CoreError::InvalidPositionData,
+        constraint = *meteora_position.owner == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidPositionData,
+    )]
+    pub meteora_position: UncheckedAccount<'info>,
 
-    /// CHECK: Token X mint
-    pub token_x_mint: UncheckedAccount<'info>,
-    /// CHECK: Token Y mint
-    pub token_y_mint: UncheckedAccount<'info>,
+    /// CHECK: Position's pool — only activeId is read (length-checked)
+    #[account(constraint = lb_pair.key() == position.lb_pair @ CoreError::InvalidPool)]
+    pub lb_pair: UncheckedAccount<'info>,
+}
 
-    /// CHECK: Token X program
-    #[account(constraint = *token_x_program.key == anchor_spl::token::ID || *token_x_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
-    pub token_x_program: AccountInfo<'info>,
+/// Read-only health check — no accounts are mutated
+#[derive(Accounts)]
+pub struct HealthCheck<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
 
-    /// CHECK: Token Y program
-    #[account(constraint = *token_y_program.key == anchor_spl::token::ID || *token_y_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
-    pub token_y_program: AccountInfo<'info>,
+    #[account(seeds = [b"rover_authority"], bump = rover_authority.bump)]
+    pub rover_authority: Account<'info, RoverAuthority>,
 
-    /// CHECK: SPL Memo program
-    #[account(constraint = memo_program.key() == SPL_MEMO_PROGRAM_ID @ CoreError::InvalidProgram)]
-    pub memo_program: AccountInfo<'info>,
+    /// CHECK: monke_bananas MonkeState PDA — owner, seeds, and discriminator validated in handler
+    pub monke_state: AccountInfo<'info>,
 
-    pub system_program: Program<'info, System>,
+    /// CHECK: monke_bananas dist_pool — matched against monke_state.dist_pool in handler
+    pub dist_pool: AccountInfo<'info>,
+
+    /// CHECK: monke_bananas program_vault PDA — derivation validated in handler
+    pub program_vault: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
@@ -2664,6 +7939,11 @@ pub struct OpenRoverPosition<'info> {
     #[account(mut)]
     pub lb_pair: AccountInfo<'info>,
 
+    /// CHECK: PoolStatus PDA for lb_pair — may be uninitialized (= not blocked).
+    /// Address enforced by seeds; blocked flag checked in handler.
+    #[account(seeds = [b"pool_status", lb_pair.key().as_ref()], bump)]
+    pub pool_status: UncheckedAccount<'info>,
+
     /// New position keypair (frontend generates)
     #[account(mut)]
     pub meteora_position: Signer<'info>,
@@ -2725,10 +8005,112 @@ pub struct OpenRoverPosition<'info> {
     /// CHECK: Token Y mint
     pub token_y_mint: AccountInfo<'info>,
 
-    /// CHECK: Token X program — SPL Token or Token-2022
+    /// CHECK: Token X program — must be on config.allowed_token_programs
+    #[account(constraint = config.is_token_program_allowed(token_x_program.key) @ CoreError::InvalidProgram)]
+    pub token_x_program: AccountInfo<'info>,
+
+    /// CHECK: Token Y program — must be on config.allowed_token_programs
+    #[account(constraint = config.is_token_program_allowed(token_y_program.key) @ CoreError::InvalidProgram)]
+    pub token_y_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // event_authority, dlmm_program, memo_program passed via remaining_accounts
+    // to fit within BPF 4KB stack frame with 2 init accounts
+}
+
+#[derive(Accounts)]
+#[instruction(lamports: u64, bin_step: u16)]
+pub struct OpenRoverPositionSol<'info> {
+    /// Anyone can deposit native SOL as a buy-side rover bribe
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [b"rover_authority"],
+        bump = rover_authority.bump
+    )]
+    pub rover_authority: Box<Account<'info, RoverAuthority>>,
+
+    // --- Meteora accounts ---
+
+    /// CHECK: Validated by Meteora CPI
+    #[account(mut)]
+    pub lb_pair: AccountInfo<'info>,
+
+    /// CHECK: PoolStatus PDA for lb_pair — may be uninitialized (= not blocked).
+    /// Address enforced by seeds; blocked flag checked in handler.
+    #[account(seeds = [b"pool_status", lb_pair.key().as_ref()], bump)]
+    pub pool_status: UncheckedAccount<'info>,
+
+    /// New position keypair (frontend generates)
+    #[account(mut)]
+    pub meteora_position: Signer<'info>,
+
+    /// CHECK: Bitmap extension — writable only when real account exists
+    pub bin_array_bitmap_ext: AccountInfo<'info>,
+
+    /// CHECK: Pool reserve X
+    #[account(mut)]
+    pub reserve_x: AccountInfo<'info>,
+
+    /// CHECK: Pool reserve Y
+    #[account(mut)]
+    pub reserve_y: AccountInfo<'info>,
+
+    /// CHECK: Bin array lower
+    #[account(mut)]
+    pub bin_array_lower: AccountInfo<'info>,
+
+    /// CHECK: Bin array upper
+    #[account(mut)]
+    pub bin_array_upper: AccountInfo<'info>,
+
+    // --- monke.army accounts ---
+
+    #[account(
+        init,
+        payer = depositor,
+        space = Position::SIZE,
+        seeds = [b"position", meteora_position.key().as_ref()],
+        bump
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = Vault::SIZE,
+        seeds = [b"vault", meteora_position.key().as_ref()],
+        bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Vault token X account. Validated in handler.
+    #[account(mut)]
+    pub vault_token_x: AccountInfo<'info>,
+
+    /// CHECK: Vault wSOL account — receives the wrapped deposit. Owner + mint validated in handler.
+    #[account(mut)]
+    pub vault_token_y: AccountInfo<'info>,
+
+    /// CHECK: Token X mint
+    pub token_x_mint: AccountInfo<'info>,
+
+    /// CHECK: Token Y mint — must be wSOL (SOL-quoted pool)
+    #[account(constraint = *token_y_mint.key == WSOL_MINT @ CoreError::NotSolPool)]
+    pub token_y_mint: AccountInfo<'info>,
+
+    /// CHECK: Token X program — must be on config.allowed_token_programs
+    #[account(constraint = config.is_token_program_allowed(token_x_program.key) @ CoreError::InvalidProgram)]
     pub token_x_program: AccountInfo<'info>,
 
-    /// CHECK: Token Y program — SPL Token or Token-2022
+    /// CHECK: Token Y program — classic SPL Token (wSOL; sync_native is SPL-only)
+    #[account(constraint = *token_y_program.key == anchor_spl::token::ID @ CoreError::InvalidProgram)]
     pub token_y_program: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
@@ -2737,6 +8119,35 @@ pub struct OpenRoverPosition<'info> {
     // to fit within BPF 4KB stack frame with 2 init accounts
 }
 
+#[derive(Accounts)]
+pub struct OpenRoverBatch<'info> {
+    /// Anyone can deposit tokens as a rover bribe
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [b"rover_authority"],
+        bump = rover_authority.bump
+    )]
+    pub rover_authority: Box<Account<'info, RoverAuthority>>,
+
+    /// CHECK: Meteora event authority
+    #[account(constraint = is_event_authority(event_authority.key) @ CoreError::InvalidEventAuthority)]
+    pub event_authority: AccountInfo<'info>,
+
+    /// CHECK: Meteora DLMM program
+    #[account(constraint = *dlmm_program.key == METEORA_DLMM_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub dlmm_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Per-pool accounts via remaining_accounts — see open_rover_batch for the stride layout
+}
+
 #[derive(Accounts)]
 // Timelocked propose/apply for rover revenue_dest
 pub struct UpdateRoverDistPool<'info> {
@@ -2789,18 +8200,116 @@ pub struct SweepRover<'info> {
         constraint = revenue_dest.key() == rover_authority.revenue_dest @ CoreError::InvalidPool,
         constraint = !revenue_dest.executable @ CoreError::InvalidDistPool
     )]
-    pub revenue_dest: AccountInfo<'info>,
+    pub revenue_dest: AccountInfo<'info>,
+
+    /// CHECK: Operator destination — bot keypair from Config (40% to operations)
+    #[account(
+        mut,
+        constraint = bot_dest.key() == config.bot @ CoreError::InvalidBot,
+        constraint = !bot_dest.executable @ CoreError::InvalidBot
+    )]
+    pub bot_dest: AccountInfo<'info>,
+}
+
+/// Close a token account owned by rover_authority. Permissionless.
+#[derive(Accounts)]
+pub struct SetPoolBlocked<'info> {
+    #[account(mut, constraint = authority.key() == config.authority @ CoreError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Meteora pool being (un)blocked — only its key is used
+    pub lb_pair: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PoolStatus::SIZE,
+        seeds = [b"pool_status", lb_pair.key().as_ref()],
+        bump
+    )]
+    pub pool_status: Account<'info, PoolStatus>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePoolStatus<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Meteora pool — only its key is used
+    pub lb_pair: UncheckedAccount<'info>,
+
+    /// CHECK: PoolStatus PDA in any historical layout — owner and discriminator
+    /// validated in handler.
+    #[account(mut, seeds = [b"pool_status", lb_pair.key().as_ref()], bump)]
+    pub pool_status: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfigSpace<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Config PDA in an older, shorter layout — owner and discriminator
+    /// validated in handler.
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterKeeperPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = KeeperPool::SIZE,
+        seeds = [b"keeper_pool", authority.key().as_ref()],
+        bump
+    )]
+    pub keeper_pool: Account<'info, KeeperPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawKeeperPool<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"keeper_pool", authority.key().as_ref()],
+        bump = keeper_pool.bump,
+        has_one = authority @ CoreError::Unauthorized,
+    )]
+    pub keeper_pool: Account<'info, KeeperPool>,
 
-    /// CHECK: Operator destination — bot keypair from Config (40% to operations)
     #[account(
         mut,
-        constraint = bot_dest.key() == config.bot @ CoreError::InvalidBot,
-        constraint = !bot_dest.executable @ CoreError::InvalidBot
+        constraint = pool_token_account.owner == keeper_pool.key() @ CoreError::InvalidTokenOwner,
+        constraint = pool_token_account.mint == mint.key() @ CoreError::InvalidTokenOwner,
     )]
-    pub bot_dest: AccountInfo<'info>,
+    pub pool_token_account: InterfaceAccount<'info, ITokenAccount>,
+
+    #[account(mut, constraint = destination.mint == mint.key() @ CoreError::InvalidTokenOwner)]
+    pub destination: InterfaceAccount<'info, ITokenAccount>,
+
+    /// CHECK: Token mint — matched against both token accounts; decimals read in handler
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: SPL Token or Token-2022
+    #[account(constraint = *token_program.key == anchor_spl::token::ID || *token_program.key == TOKEN_2022_PROGRAM_ID @ CoreError::InvalidProgram)]
+    pub token_program: AccountInfo<'info>,
 }
 
-/// Close a token account owned by rover_authority. Permissionless.
 /// Lamports (balance + rent) always go to rover_authority itself — no extraction possible.
 #[derive(Accounts)]
 pub struct CloseRoverTokenAccount<'info> {
@@ -2879,8 +8388,8 @@ pub struct OpenFeeRover<'info> {
     #[account(init, payer = bot, space = Vault::SIZE, seeds = [b"vault", meteora_position.key().as_ref()], bump)]
     pub vault: Box<Account<'info, Vault>>,
 
-    /// Source: rover_authority's token account (accumulated fee tokens)
-    #[account(mut, constraint = rover_token_account.owner == rover_authority.key() @ CoreError::InvalidTokenOwner)]
+    /// Source: any rover_authority token account (accumulated fee tokens). Owner and mint checked in handler.
+    #[account(mut)]
     pub rover_token_account: Box<InterfaceAccount<'info, ITokenAccount>>,
 
     /// CHECK: Vault token X account. Validated in handler.
@@ -2897,10 +8406,12 @@ pub struct OpenFeeRover<'info> {
     /// CHECK: Token Y mint
     pub token_y_mint: AccountInfo<'info>,
 
-    /// CHECK: Token X program — SPL Token or Token-2022
+    /// CHECK: Token X program — must be on config.allowed_token_programs
+    #[account(constraint = config.is_token_program_allowed(token_x_program.key) @ CoreError::InvalidProgram)]
     pub token_x_program: AccountInfo<'info>,
 
-    /// CHECK: Token Y program — SPL Token or Token-2022
+    /// CHECK: Token Y program — must be on config.allowed_token_programs
+    #[account(constraint = config.is_token_program_allowed(token_y_program.key) @ CoreError::InvalidProgram)]
     pub token_y_program: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
@@ -2922,9 +8433,35 @@ pub struct RoverOpenedEvent {
     pub bin_step: u16,         // Needed to compute price range
     pub min_bin_id: i32,
     pub max_bin_id: i32,
+    pub native_sol_amount: u64, // Lamports wrapped for a native-SOL buy-side rover (0 otherwise)
+    pub side: Side,             // Sell = token X above active_id, Buy = token Y below
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PositionStatusEvent {
+    pub position: Pubkey,
+    pub meteora_position: Pubkey,
+    pub is_empty: bool,            // No liquidity left — close_position_empty applies
+    pub bins_with_liquidity: u16,
+    pub active_id: i32,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub active_vs_range: i8,       // -1 below range, 0 inside, 1 above
+}
+
+#[event]
+pub struct RoverRangeTruncatedEvent {
+    pub lb_pair: Pubkey,
+    pub meteora_position: Pubkey,
+    pub side: Side,
+    pub bin_step: u16,
+    pub requested_width: i32,      // Bins needed for full 2x coverage
+    pub placed_width: i32,         // Bins actually opened (MAX_POSITION_WIDTH)
+    pub uncovered_min_bin_id: i32, // Gap the position does not cover
+    pub uncovered_max_bin_id: i32,
+}
+
 #[event]
 pub struct RoverSweptEvent {
     pub amount: u64,
@@ -2935,6 +8472,38 @@ pub struct RoverSweptEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PoolBlockedEvent {
+    pub lb_pair: Pubkey,
+    pub blocked: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolMinPositionAmountEvent {
+    pub lb_pair: Pubkey,
+    pub min_position_amount: u64,  // 0 = no override (Config's minimum applies)
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeeperPoolRegisteredEvent {
+    pub keeper_pool: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeeperPoolWithdrawEvent {
+    pub keeper_pool: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 // ============ ERRORS ============
 
 #[error_code]
@@ -2951,13 +8520,13 @@ pub enum CoreError {
     PositionTooWide,
     #[msg("Bin ID outside position range")]
     BinOutOfPositionRange,
-    #[msg("Invalid slippage (must be 0-20)")]
+    #[msg("Invalid slippage (must be 1-20)")]
     InvalidSlippage,
     #[msg("Fee too high (max 10%)")]
     FeeTooHigh,
     #[msg("No bin IDs provided")]
     NoBinsProvided,
-    #[msg("Too many bins (max 70 per call)")]
+    #[msg("Too many bins (exceeds config.max_bins_per_harvest, max 70 per call)")]
     TooManyBins,
     #[msg("Bin IDs must be contiguous (no gaps)")]
     NonContiguousBins,
@@ -3003,5 +8572,1340 @@ pub enum CoreError {
     InvalidMintData,
     #[msg("Invalid bot destination")]
     InvalidBot,
+    #[msg("max_bins_per_harvest must be between 1 and 70")]
+    InvalidMaxBinsPerHarvest,
+    #[msg("Token program allowlist must hold 1-4 distinct, non-default program IDs")]
+    InvalidTokenProgramAllowlist,
+    #[msg("Position narrower than config.min_position_width — see log for the minimum")]
+    PositionTooNarrow,
+    #[msg("min_position_width must be between 1 and 70")]
+    InvalidMinPositionWidth,
+    #[msg("min_position_amount must be at least 1")]
+    InvalidMinPositionAmount,
+    #[msg("PoolStatus account is not a valid monke.army PoolStatus PDA")]
+    InvalidPoolStatus,
+    #[msg("fee_bps_override must not exceed config.fee_bps")]
+    InvalidFeeOverride,
+    #[msg("max_pause_seconds must be between 1 and 30 days")]
+    InvalidMaxPauseSeconds,
+    #[msg("Position PDA still exists — vault is not orphaned")]
+    VaultNotOrphaned,
+    #[msg("Active bin drifted past max_drift from expected_active_id — see log")]
+    ActiveBinDrifted,
+    #[msg("Health check: config PDA or bump mismatch")]
+    HealthConfigMismatch,
+    #[msg("Health check: rover_authority PDA or bump mismatch")]
+    HealthRoverMismatch,
+    #[msg("Health check: monke_state is not the monke_bananas state PDA")]
+    HealthMonkeStateMismatch,
+    #[msg("Health check: dist_pool is unset in state or differs from state.dist_pool")]
+    HealthDistPoolMismatch,
+    #[msg("Health check: program_vault does not derive from monke_bananas")]
+    HealthProgramVaultMismatch,
+    #[msg("Health check: revenue_dest is neither dist_pool nor bridge_vault")]
+    HealthRevenueDestMismatch,
+    #[msg("sweep_rover lamport accounting mismatch — rover_authority not left at rent or destinations off")]
+    SweepInvariantViolated,
+    #[msg("Rover batch: 1-4 entries, remaining_accounts must be entries × 16")]
+    InvalidRoverBatch,
+    #[msg("Rover batch: position/vault PDA mismatch, already funded, or missing signer")]
+    RoverBatchAccountMismatch,
+    #[msg("Revenue dest change pending — apply or cancel it before sweeping")]
+    RevenueDestPending,
+    #[msg("Meteora position account does not match or is not a PositionV2")]
+    InvalidPositionData,
+    #[msg("Fee change nonce mismatch — the pending change was replaced or cancelled")]
+    StaleFeeChange,
+    #[msg("Native SOL rover requires a SOL-quoted pool (token Y = wSOL)")]
+    NotSolPool,
+    #[msg("Rover fee token account mint does not match the pool token mint")]
+    RoverFeeMintMismatch,
+    #[msg("Position account is not a valid monke.army Position PDA")]
+    InvalidPositionAccount,
+    #[msg("Label must be UTF-8 followed only by zero padding")]
+    InvalidLabel,
+    #[msg("Vault is not bound to this position's Meteora position")]
+    VaultBindingMismatch,
+    #[msg("Keeper ATA must be owned by the caller or a registered KeeperPool (pass it after the tip ATAs)")]
+    KeeperAtaOwnerMismatch,
+    #[msg("bps_to_remove must be between 1 and 10000")]
+    InvalidBpsToRemove,
+    #[msg("Range spans three bin arrays — pass the middle bin array PDA as bin_array_middle")]
+    MissingBinArrayMiddle,
+    #[msg("Take-profit not reached: active_id below max_bin_id + take_profit_bins")]
+    TakeProfitNotReached,
+    #[msg("Invalid strategy (0 = Spot, 1 = Curve, 2 = BidAsk)")]
+    InvalidStrategy,
+    #[msg("reward_index must be 0 or 1 (DLMM pools have two reward slots)")]
+    InvalidRewardIndex,
+    #[msg("Close output below min_x_out / min_y_out")]
+    SlippageExceeded,
+    #[msg("Position was harvested too recently — harvest cooldown active")]
+    HarvestCooldownActive,
+    #[msg("Harvest cooldown exceeds maximum (9000 slots / ~1 hour)")]
+    HarvestCooldownExceedsMax,
+    #[msg("New owner must be non-default and differ from the current owner")]
+    InvalidNewOwner,
+    #[msg("Min sweep exceeds maximum (1 SOL)")]
+    MinSweepExceedsMax,
+    #[msg("Referrer cannot be the position owner")]
+    InvalidReferrer,
+    #[msg("Referred position: pass the referrer's ATA for the fee mint as the last remaining account before any hook accounts")]
+    MissingReferrerAta,
+    #[msg("Pool is blocked for new positions")]
+    PoolBlocked,
+    #[msg("Fewer remaining accounts than hook_x_len + hook_y_len")]
+    MissingHookAccounts,
+    #[msg("tip_split_bps must be at most 10000")]
+    InvalidTipSplit,
+    #[msg("Second tip ATA duplicates the first")]
+    DuplicateKeeperAta,
+    #[msg("No fully converted bins with liquidity left to harvest")]
+    NothingConverted,
+    #[msg("Config layout version unsupported (run migrate_config)")]
+    ConfigVersionUnsupported,
+    #[msg("Bin arrays must be the lb_pair's bin array PDAs covering the range, in ascending order")]
+    InvalidBinArray,
+    #[msg("user_close_unwrap needs a WSOL-quoted pool (token_y_mint = WSOL)")]
+    UnwrapNotWsol,
+    #[msg("user_token_y must be a temporary WSOL account holding only this close's output")]
+    UnwrapAccountNotEmpty,
+    #[msg("Event authority is not the DLMM __event_authority PDA")]
+    InvalidEventAuthority,
+    #[msg("Token mint does not match the lb_pair's token_x_mint / token_y_mint")]
+    MintMismatch,
+    #[msg("Harvest batch: 1-4 entries, remaining_accounts must match entries × 9 plus extras")]
+    InvalidHarvestBatch,
+    #[msg("Harvest batch: position/vault PDA or meteora_position mismatch")]
+    HarvestBatchAccountMismatch,
+    #[msg("Owner already holds max_positions_per_owner open positions")]
+    TooManyPositions,
+    #[msg("OwnerStats account is not initialized or belongs to another owner")]
+    InvalidOwnerStats,
+
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn zeroed_config() -> Config {
+        let data = vec![0u8; Config::SIZE];
+        Config::try_deserialize_unchecked(&mut &data[..]).unwrap()
+    }
+
+    fn pool_status_pda(lb_pair: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"pool_status", lb_pair.as_ref()], &crate::ID).0
+    }
+
+    fn pool_status_data(lb_pair: Pubkey, blocked: bool) -> Vec<u8> {
+        let mut data = Vec::new();
+        PoolStatus { lb_pair, blocked, bump: 255, min_position_amount: 0 }
+            .try_serialize(&mut data)
+            .unwrap();
+        data
+    }
+
+    // ---- fee timelock races (propose / apply / cancel) ----
+
+    const T0: i64 = 1_700_000_000;
+
+    #[test]
+    fn apply_after_timelock_lands_the_proposed_fee() {
+        let mut config = zeroed_config();
+        config.fee_bps = 30;
+        config.propose_fee_change(50, T0).unwrap();
+        let nonce = config.fee_change_nonce;
+        assert_eq!(
+            config.apply_fee_change(nonce, T0 + 86_399).unwrap_err(),
+            error!(CoreError::FeeTimelockNotExpired)
+        );
+        assert_eq!(config.apply_fee_change(nonce, T0 + 86_400).unwrap(), 30);
+        assert_eq!(config.fee_bps, 50);
+        assert_eq!(config.fee_change_at, 0);
+    }
+
+    #[test]
+    fn second_apply_in_a_race_reverts() {
+        let mut config = zeroed_config();
+        config.propose_fee_change(50, T0).unwrap();
+        let nonce = config.fee_change_nonce;
+        config.apply_fee_change(nonce, T0 + 86_400).unwrap();
+        assert_eq!(
+            config.apply_fee_change(nonce, T0 + 86_400).unwrap_err(),
+            error!(CoreError::NoPendingFeeChange)
+        );
+        assert_eq!(config.fee_bps, 50);
+    }
+
+    #[test]
+    fn apply_then_cancel_keeps_the_applied_fee() {
+        let mut config = zeroed_config();
+        config.propose_fee_change(50, T0).unwrap();
+        let nonce = config.fee_change_nonce;
+        config.apply_fee_change(nonce, T0 + 86_400).unwrap();
+        assert_eq!(config.cancel_fee_change().unwrap_err(), error!(CoreError::NoPendingFeeChange));
+        assert_eq!(config.fee_bps, 50);
+    }
+
+    #[test]
+    fn cancel_then_apply_reverts() {
+        let mut config = zeroed_config();
+        config.fee_bps = 30;
+        config.propose_fee_change(50, T0).unwrap();
+        let nonce = config.fee_change_nonce;
+        config.cancel_fee_change().unwrap();
+        assert_eq!(
+            config.apply_fee_change(nonce, T0 + 86_400).unwrap_err(),
+            error!(CoreError::NoPendingFeeChange)
+        );
+        assert_eq!(config.fee_bps, 30);
+    }
+
+    #[test]
+    fn stale_apply_cannot_land_a_later_proposal() {
+        let mut config = zeroed_config();
+        config.fee_bps = 30;
+        config.propose_fee_change(50, T0).unwrap();
+        let stale = config.fee_change_nonce;
+        // Cancelled and re-proposed: the stale signer never saw 100 bps
+        config.cancel_fee_change().unwrap();
+        config.propose_fee_change(100, T0).unwrap();
+        assert_eq!(
+            config.apply_fee_change(stale, T0 + 86_400).unwrap_err(),
+            error!(CoreError::StaleFeeChange)
+        );
+        assert_eq!(config.fee_bps, 30);
+        let current = config.fee_change_nonce;
+        assert_eq!(config.apply_fee_change(current, T0 + 86_400).unwrap(), 30);
+        assert_eq!(config.fee_bps, 100);
+    }
+
+    #[test]
+    fn propose_over_pending_restarts_the_clock() {
+        let mut config = zeroed_config();
+        config.propose_fee_change(50, T0).unwrap();
+        let first = config.fee_change_nonce;
+        config.propose_fee_change(60, T0 + 3_600).unwrap();
+        assert_eq!(config.fee_change_nonce, first + 1);
+        assert_eq!(
+            config.apply_fee_change(first, T0 + 86_400).unwrap_err(),
+            error!(CoreError::StaleFeeChange)
+        );
+        assert_eq!(
+            config.apply_fee_change(first + 1, T0 + 86_400).unwrap_err(),
+            error!(CoreError::FeeTimelockNotExpired)
+        );
+        config.apply_fee_change(first + 1, T0 + 3_600 + 86_400).unwrap();
+        assert_eq!(config.fee_bps, 60);
+    }
+
+    // ---- keeper_tip_bps / priority_slots timelocks ----
+
+    #[test]
+    fn keeper_tip_apply_before_timelock_reverts() {
+        let mut config = zeroed_config();
+        config.keeper_tip_bps = 1000;
+        config.propose_keeper_tip_change(2000, T0).unwrap();
+        assert_eq!(
+            config.apply_keeper_tip_change(T0 + PARAM_TIMELOCK_SECONDS - 1).unwrap_err(),
+            error!(CoreError::FeeTimelockNotExpired)
+        );
+        assert_eq!(config.keeper_tip_bps, 1000);
+        assert_eq!(config.apply_keeper_tip_change(T0 + PARAM_TIMELOCK_SECONDS).unwrap(), 1000);
+        assert_eq!((config.keeper_tip_bps, config.keeper_tip_change_at), (2000, 0));
+        assert_eq!(
+            config.apply_keeper_tip_change(T0 + PARAM_TIMELOCK_SECONDS).unwrap_err(),
+            error!(CoreError::NoPendingFeeChange)
+        );
+    }
+
+    #[test]
+    fn priority_slots_apply_before_timelock_reverts() {
+        let mut config = zeroed_config();
+        config.priority_slots = 100;
+        config.propose_priority_slots_change(500, T0).unwrap();
+        // Re-proposing restarts the clock
+        config.propose_priority_slots_change(600, T0 + 3_600).unwrap();
+        assert_eq!(
+            config.apply_priority_slots_change(T0 + PARAM_TIMELOCK_SECONDS).unwrap_err(),
+            error!(CoreError::FeeTimelockNotExpired)
+        );
+        assert_eq!(config.priority_slots, 100);
+        assert_eq!(config.apply_priority_slots_change(T0 + 3_600 + PARAM_TIMELOCK_SECONDS).unwrap(), 100);
+        assert_eq!(config.priority_slots, 600);
+    }
+
+    // ---- harvest_bins / harvest_bins_multi checks ----
+
+    fn position_over(min_bin_id: i32, max_bin_id: i32) -> Position {
+        let mut position = Position::try_deserialize_unchecked(&mut &vec![0u8; Position::SIZE][..]).unwrap();
+        position.min_bin_id = min_bin_id;
+        position.max_bin_id = max_bin_id;
+        position
+    }
+
+    #[test]
+    fn harvest_requires_a_contiguous_range() {
+        let config = zeroed_config();
+        let position = position_over(-10, 10);
+        assert_eq!(check_harvest(&config, &position, &[3, 1, 2], 10_000, true, 0).unwrap(), (1, 3));
+        assert_eq!(
+            check_harvest(&config, &position, &[1, 3], 10_000, true, 0).unwrap_err(),
+            error!(CoreError::NonContiguousBins)
+        );
+        // Duplicates can't pad out a gap
+        assert_eq!(
+            check_harvest(&config, &position, &[1, 1, 3], 10_000, true, 0).unwrap_err(),
+            error!(CoreError::NonContiguousBins)
+        );
+        assert_eq!(
+            check_harvest(&config, &position, &[9, 10, 11], 10_000, true, 0).unwrap_err(),
+            error!(CoreError::BinOutOfPositionRange)
+        );
+    }
+
+    #[test]
+    fn harvest_range_span_overflow_is_an_error() {
+        let config = zeroed_config();
+        let position = position_over(i32::MIN, i32::MAX);
+        assert_eq!(
+            check_harvest(&config, &position, &[i32::MIN, i32::MAX], 10_000, true, 0).unwrap_err(),
+            error!(CoreError::Overflow)
+        );
+    }
+
+    #[test]
+    fn close_and_harvest_windows_unlock_independently() {
+        let mut config = zeroed_config();
+        config.max_bins_per_harvest = 10;
+        config.priority_slots = 150;
+        config.close_priority_slots = 600;
+        config.last_bot_harvest_slot = 1_000;
+        config.last_bot_close_slot = 1_000;
+        let position = position_over(0, 5);
+        let stale: Error = error!(CoreError::BotNotStale);
+
+        // Harvest unlocks after priority_slots while closes stay with the bot
+        assert_eq!(check_harvest(&config, &position, &[0], 10_000, false, 1_150).unwrap_err(), stale);
+        assert!(check_harvest(&config, &position, &[0], 10_000, false, 1_151).is_ok());
+        assert_eq!(require_close_unlocked(&config, &position, 1_151).unwrap_err(), stale);
+        assert_eq!(require_close_unlocked(&config, &position, 1_600).unwrap_err(), stale);
+        assert!(require_close_unlocked(&config, &position, 1_601).is_ok());
+
+        // A shorter close window doesn't move the harvest one
+        config.close_priority_slots = 50;
+        assert!(require_close_unlocked(&config, &position, 1_051).is_ok());
+        assert_eq!(check_harvest(&config, &position, &[0], 10_000, false, 1_051).unwrap_err(), stale);
+
+        // Unset, closes share priority_slots
+        config.close_priority_slots = 0;
+        assert_eq!(require_close_unlocked(&config, &position, 1_150).unwrap_err(), stale);
+        assert!(require_close_unlocked(&config, &position, 1_151).is_ok());
+    }
+
+    #[test]
+    fn position_override_replaces_the_harvest_staleness_window() {
+        let mut config = zeroed_config();
+        config.max_bins_per_harvest = 10;
+        config.priority_slots = 150;
+        config.last_bot_harvest_slot = 1_000;
+        let default_window = position_over(0, 5);
+        let mut whale = position_over(0, 5);
+        whale.priority_slots_override = 600;
+        let stale: Error = error!(CoreError::BotNotStale);
+
+        // The default position unlocks after config.priority_slots, the whale only after its own
+        assert!(check_harvest(&config, &default_window, &[0], 10_000, false, 1_151).is_ok());
+        assert_eq!(check_harvest(&config, &whale, &[0], 10_000, false, 1_151).unwrap_err(), stale);
+        assert_eq!(check_harvest(&config, &whale, &[0], 10_000, false, 1_600).unwrap_err(), stale);
+        assert!(check_harvest(&config, &whale, &[0], 10_000, false, 1_601).is_ok());
+
+        // A shorter override unlocks before the default
+        whale.priority_slots_override = 50;
+        assert!(check_harvest(&config, &whale, &[0], 10_000, false, 1_051).is_ok());
+        assert_eq!(check_harvest(&config, &default_window, &[0], 10_000, false, 1_051).unwrap_err(), stale);
+    }
+
+    #[test]
+    fn position_override_replaces_the_close_staleness_window() {
+        let mut config = zeroed_config();
+        config.priority_slots = 150;
+        config.close_priority_slots = 300;
+        config.last_bot_close_slot = 1_000;
+        let default_window = position_over(0, 5);
+        let mut whale = position_over(0, 5);
+        whale.priority_slots_override = 600;
+        let stale: Error = error!(CoreError::BotNotStale);
+
+        // The override stands in for close_priority_slots, not just priority_slots
+        assert!(require_close_unlocked(&config, &default_window, 1_301).is_ok());
+        assert_eq!(require_close_unlocked(&config, &whale, 1_301).unwrap_err(), stale);
+        assert_eq!(require_close_unlocked(&config, &whale, 1_600).unwrap_err(), stale);
+        assert!(require_close_unlocked(&config, &whale, 1_601).is_ok());
+
+        whale.priority_slots_override = 50;
+        assert!(require_close_unlocked(&config, &whale, 1_051).is_ok());
+        assert_eq!(require_close_unlocked(&config, &default_window, 1_051).unwrap_err(), stale);
+    }
+
+    // ---- keeper tips ----
+
+    #[test]
+    fn dust_harvest_still_pays_the_keeper_floor() {
+        // 20_000 lamports converted at 1% -> a 200 fee; 10% of that is a 20 tip
+        let fee = 200u64;
+        assert_eq!(keeper_tip_for(fee, 1_000, 0).unwrap(), 20);
+        // The floor lifts it, and never past the fee
+        assert_eq!(keeper_tip_for(fee, 1_000, 150).unwrap(), 150);
+        assert_eq!(keeper_tip_for(fee, 1_000, 200).unwrap(), 200);
+        // A fee that can't cover the floor falls back to the percentage tip
+        assert_eq!(keeper_tip_for(fee, 1_000, 201).unwrap(), 20);
+        // A larger percentage tip wins over the floor
+        assert_eq!(keeper_tip_for(10_000, 1_000, 150).unwrap(), 1_000);
+        // Floor-only config (keeper_tip_bps = 0) still tips
+        assert_eq!(keeper_tip_for(fee, 0, 150).unwrap(), 150);
+    }
+
+    #[test]
+    fn wrong_mint_keeper_ata_fails_before_removal() {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token_2022::spl_token_2022::state::{Account, AccountState};
+        let (token_x_mint, token_y_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let keeper = Pubkey::new_unique();
+        let ata_data = |mint: Pubkey| {
+            let mut data = vec![0u8; Account::LEN];
+            Account { mint, owner: keeper, state: AccountState::Initialized, ..Account::default() }
+                .pack_into_slice(&mut data);
+            data
+        };
+        let (x_key, y_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (mut x_data, mut y_data) = (ata_data(token_x_mint), ata_data(token_y_mint));
+        let (mut x_lamports, mut y_lamports) = (1u64, 1u64);
+        let token_program = anchor_spl::token::ID;
+        let x_ata = AccountInfo::new(&x_key, false, true, &mut x_lamports, &mut x_data, &token_program, false, 0);
+        let y_ata = AccountInfo::new(&y_key, false, true, &mut y_lamports, &mut y_data, &token_program, false, 0);
+        let fee_destinations = [Pubkey::new_unique(); 4];
+
+        // A Sell converts into Y, so an X-mint keeper ATA is rejected up front
+        assert_eq!(
+            precheck_tip_atas(std::slice::from_ref(&x_ata), &token_y_mint, 0, &fee_destinations).unwrap_err(),
+            error!(CoreError::MissingKeeperAta)
+        );
+        assert!(precheck_tip_atas(std::slice::from_ref(&y_ata), &token_y_mint, 0, &fee_destinations).is_ok());
+        // A wrong-mint second tip ATA is caught too, as is a missing one
+        assert_eq!(
+            precheck_tip_atas(&[y_ata.clone(), x_ata], &token_y_mint, 5_000, &fee_destinations).unwrap_err(),
+            error!(CoreError::MissingKeeperAta)
+        );
+        assert_eq!(
+            precheck_tip_atas(std::slice::from_ref(&y_ata), &token_y_mint, 5_000, &fee_destinations).unwrap_err(),
+            error!(CoreError::MissingKeeperAta)
+        );
+        // A fee destination passed as the tip ATA is rejected
+        assert!(precheck_tip_atas(&[y_ata], &token_y_mint, 0, &[y_key]).is_err());
+        // No tip ATA at all is left to the post-CPI check
+        assert!(precheck_tip_atas(&[], &token_y_mint, 0, &fee_destinations).is_ok());
+    }
+
+    #[test]
+    fn second_tip_ata_must_belong_to_the_caller_or_the_keeper_pool() {
+        let (caller, relayer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(require_tip_ata_owner(&caller, &caller, None).is_ok());
+        // A relayer's ATA with no KeeperPool behind it is rejected
+        assert_eq!(
+            require_tip_ata_owner(&relayer, &caller, None).unwrap_err(),
+            error!(CoreError::KeeperAtaOwnerMismatch)
+        );
+
+        let pool_key = Pubkey::new_unique();
+        let mut pool_data = Vec::new();
+        KeeperPool { authority: relayer, bump: 255, created_at: 0, _reserved: [0u8; 32] }
+            .try_serialize(&mut pool_data)
+            .unwrap();
+        let mut pool_lamports = 1u64;
+        let pool = AccountInfo::new(&pool_key, false, false, &mut pool_lamports, &mut pool_data, &crate::ID, false, 0);
+        assert!(require_tip_ata_owner(&pool_key, &caller, Some(&pool)).is_ok());
+        // The pool only vouches for ATAs it owns
+        assert_eq!(
+            require_tip_ata_owner(&relayer, &caller, Some(&pool)).unwrap_err(),
+            error!(CoreError::KeeperAtaOwnerMismatch)
+        );
+    }
+
+    // ---- Token-2022 memo detection ----
+
+    /// Token-2022 account bytes: base account, AccountType::Account, then TLV entries.
+    fn t22_account_data(extensions: &[(u16, &[u8])]) -> Vec<u8> {
+        let mut data = vec![0u8; T22_ACCOUNT_TLV_START - 1];
+        data.push(2); // AccountType::Account
+        for (ext_type, value) in extensions {
+            data.extend_from_slice(&ext_type.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    fn requires_memo(owner: &Pubkey, mut data: Vec<u8>) -> bool {
+        let (key, mut lamports) = (Pubkey::new_unique(), 1u64);
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, owner, false, 0);
+        destination_requires_memo(&info)
+    }
+
+    #[test]
+    fn memo_is_required_only_where_memo_transfer_is_on() {
+        const IMMUTABLE_OWNER: u16 = 7;
+        let t22 = TOKEN_2022_PROGRAM_ID;
+        // SPL Token accounts and Token-2022 accounts without extensions never need one
+        assert!(!requires_memo(&anchor_spl::token::ID, vec![0u8; 165]));
+        assert!(!requires_memo(&t22, vec![0u8; 165]));
+        assert!(!requires_memo(&t22, t22_account_data(&[(IMMUTABLE_OWNER, &[])])));
+
+        // MemoTransfer found behind another extension, enabled or not
+        let enabled = t22_account_data(&[(IMMUTABLE_OWNER, &[]), (T22_EXTENSION_MEMO_TRANSFER, &[1])]);
+        assert!(requires_memo(&t22, enabled));
+        let disabled = t22_account_data(&[(IMMUTABLE_OWNER, &[]), (T22_EXTENSION_MEMO_TRANSFER, &[0])]);
+        assert!(!requires_memo(&t22, disabled));
+
+        // Unknown owners are treated as needing one
+        assert!(requires_memo(&Pubkey::new_unique(), vec![0u8; 165]));
+    }
 
+    #[test]
+    fn malformed_tlv_never_panics() {
+        let t22 = TOKEN_2022_PROGRAM_ID;
+        // MemoTransfer header whose value is cut off: fail safe and send the memo
+        let mut truncated = t22_account_data(&[(T22_EXTENSION_MEMO_TRANSFER, &[1])]);
+        truncated.pop();
+        assert_eq!(find_t22_extension(&truncated, T22_EXTENSION_MEMO_TRANSFER), Ok(Some(&[][..])));
+        assert!(requires_memo(&t22, truncated));
+
+        // A length running past the end stops the walk instead of reading out of bounds
+        let mut overrun = t22_account_data(&[(7, &[])]);
+        overrun[T22_ACCOUNT_TLV_START + 2..T22_ACCOUNT_TLV_START + 4].copy_from_slice(&u16::MAX.to_le_bytes());
+        assert_eq!(find_t22_extension(&overrun, T22_EXTENSION_MEMO_TRANSFER), Ok(None));
+        // A partial header is ignored too
+        let mut partial = t22_account_data(&[]);
+        partial.extend_from_slice(&T22_EXTENSION_MEMO_TRANSFER.to_le_bytes());
+        assert_eq!(find_t22_extension(&partial, T22_EXTENSION_MEMO_TRANSFER), Ok(None));
+    }
+
+    // ---- config layout ----
+
+    #[test]
+    fn deployed_config_grows_once_to_the_full_layout() {
+        let mut deployed = zeroed_config();
+        deployed.bot = Pubkey::new_unique();
+        deployed.fee_bps = 30;
+        deployed.paused = true;
+        let mut data = Vec::new();
+        deployed.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Config::SIZE);
+        assert_eq!(Config::SIZE, Config::DEPLOYED_SIZE + Config::EXTENSION_SIZE);
+
+        // What migrate_config_space sees: the 313-byte account, zero-extended by grow_account
+        data.truncate(Config::DEPLOYED_SIZE);
+        data.resize(Config::SIZE, 0);
+        let mut config = Config::try_deserialize(&mut &data[..]).unwrap();
+        config.init_extension(T0).unwrap();
+
+        assert_eq!((config.bot, config.fee_bps), (deployed.bot, 30));
+        assert_eq!(config.config_version, CONFIG_VERSION);
+        assert_eq!(
+            config.allowed_token_programs,
+            [anchor_spl::token::ID, TOKEN_2022_PROGRAM_ID, Pubkey::default(), Pubkey::default()]
+        );
+        assert_eq!(config.max_pause_seconds, DEFAULT_MAX_PAUSE_SECONDS);
+        assert_eq!(config.pause_expiry_ts, T0 + DEFAULT_MAX_PAUSE_SECONDS);
+        assert_eq!(config._reserved_ext, [0u8; 80]);
+    }
+
+    #[test]
+    fn unmigrated_config_fails_require_version() {
+        let mut config = zeroed_config();
+        assert_eq!(config.config_version, 0);
+        assert_eq!(config.require_version().unwrap_err(), error!(CoreError::ConfigVersionUnsupported));
+
+        config.config_version = MIN_SUPPORTED_CONFIG_VERSION;
+        config.require_version().unwrap();
+        config.config_version = CONFIG_VERSION;
+        config.require_version().unwrap();
+    }
+
+    /// Byte offsets of the serialized Config that `mutate` changes.
+    fn changed_offsets(mutate: impl Fn(&mut Config)) -> Vec<usize> {
+        let mut config = zeroed_config();
+        let mut before = Vec::new();
+        config.try_serialize(&mut before).unwrap();
+        mutate(&mut config);
+        let mut after = Vec::new();
+        config.try_serialize(&mut after).unwrap();
+        assert_eq!(after.len(), Config::SIZE);
+        (0..after.len()).filter(|&i| before[i] != after[i]).collect()
+    }
+
+    #[test]
+    fn heartbeat_slot_sits_inside_the_extension() {
+        let offsets = changed_offsets(|c| c.last_bot_heartbeat_slot = u64::MAX);
+        assert_eq!(offsets.len(), 8);
+        // Past the allowlist and pause fields, before the reserved tail: no new growth step
+        let start = Config::DEPLOYED_SIZE + 32 * MAX_ALLOWED_TOKEN_PROGRAMS + 8 + 8;
+        assert_eq!(offsets, (start..start + 8).collect::<Vec<_>>());
+        assert!(start + 8 <= Config::SIZE - 80);
+    }
+
+    #[test]
+    fn close_priority_slots_sit_inside_the_extension() {
+        let offsets = changed_offsets(|c| c.close_priority_slots = u64::MAX);
+        // Right after the heartbeat slot; the 80-byte tail is all that's left
+        let start = Config::DEPLOYED_SIZE + 32 * MAX_ALLOWED_TOKEN_PROGRAMS + 8 + 8 + 8;
+        assert_eq!(offsets, (start..start + 8).collect::<Vec<_>>());
+        assert_eq!(start + 8, Config::SIZE - 80);
+
+        // Grown from the deployed layout it reads 0 and shares the harvest window
+        let mut config = zeroed_config();
+        config.init_extension(T0).unwrap();
+        config.priority_slots = 100;
+        assert_eq!(config.effective_close_priority_slots(), 100);
+    }
+
+    // ---- harvest telemetry ----
+
+    #[test]
+    fn heartbeat_rejects_anyone_but_the_bot() {
+        let mut config = zeroed_config();
+        config.bot = Pubkey::new_unique();
+        config.last_bot_harvest_slot = 500;
+
+        assert_eq!(
+            config.record_heartbeat(&Pubkey::new_unique(), 1_000).unwrap_err(),
+            error!(CoreError::Unauthorized)
+        );
+        assert_eq!(config.last_bot_heartbeat_slot, 0);
+
+        let bot = config.bot;
+        config.record_heartbeat(&bot, 1_000).unwrap();
+        assert_eq!(config.last_bot_heartbeat_slot, 1_000);
+        // Idle-but-alive doesn't touch the work slots keepers unlock on
+        assert_eq!(config.last_bot_harvest_slot, 500);
+    }
+
+    #[test]
+    fn harvest_counters_advance_on_their_own_path() {
+        let mut config = zeroed_config();
+        config.record_harvest(1_000, true).unwrap();
+        assert_eq!((config.bot_harvest_count, config.permissionless_harvest_count), (1, 0));
+        config.record_harvest(250, false).unwrap();
+        config.record_harvest(0, false).unwrap();
+        assert_eq!((config.bot_harvest_count, config.permissionless_harvest_count), (1, 2));
+        assert_eq!(config.total_harvested, 1_250);
+    }
+
+    // ---- pool metrics ----
+
+    fn read_pool_metrics(info: &AccountInfo) -> PoolMetrics {
+        PoolMetrics::try_deserialize(&mut &info.try_borrow_data().unwrap()[..]).unwrap()
+    }
+
+    #[test]
+    fn harvests_add_to_the_pools_lifetime_figures() {
+        let lb_pair = Pubkey::new_unique();
+        let key = Pubkey::find_program_address(&[b"pool_metrics", lb_pair.as_ref()], &crate::ID).0;
+        let mut data = Vec::new();
+        PoolMetrics { lb_pair, lifetime_harvested: 0, lifetime_fees: 0, position_count: 0, bump: 255 }
+            .try_serialize(&mut data)
+            .unwrap();
+        let mut lamports = 1u64;
+        let metrics = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+
+        record_pool_metrics(&metrics, 9_970, 30, false).unwrap();
+        record_pool_metrics(&metrics, 4_985, 15, false).unwrap();
+        let after = read_pool_metrics(&metrics);
+        assert_eq!((after.lifetime_harvested, after.lifetime_fees, after.position_count), (14_955, 45, 0));
+        // Only closes count positions
+        record_pool_metrics(&metrics, 1_000, 3, true).unwrap();
+        let after = read_pool_metrics(&metrics);
+        assert_eq!((after.lifetime_harvested, after.lifetime_fees, after.position_count), (15_955, 48, 1));
+        assert_eq!(after.lb_pair, lb_pair);
+
+        // Saturates instead of reverting the harvest
+        record_pool_metrics(&metrics, u64::MAX, u64::MAX, false).unwrap();
+        let after = read_pool_metrics(&metrics);
+        assert_eq!((after.lifetime_harvested, after.lifetime_fees), (u64::MAX, u64::MAX));
+
+        // Pools that never ran init_pool_metrics are skipped
+        let mut empty: Vec<u8> = Vec::new();
+        let mut lamports = 0u64;
+        let system = anchor_lang::system_program::ID;
+        let uninit = AccountInfo::new(&key, false, true, &mut lamports, &mut empty, &system, false, 0);
+        assert!(record_pool_metrics(&uninit, 1, 1, true).is_ok());
+    }
+
+    // ---- LP fee quote ----
+
+    /// A Meteora PositionV2 for bins [lower_bin_id, lower_bin_id + 69] with
+    /// `fees` = [(bin offset, fee_x_pending, fee_y_pending)] settled into it.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn meteora_position_fixture(lb_pair: Pubkey, owner: Pubkey, lower_bin_id: i32, fees: &[(usize, u64, u64)]) -> Vec<u8> {
+        let mut data = vec![0u8; POSITION_LOWER_BIN_OFFSET + 8];
+        data[8..40].copy_from_slice(lb_pair.as_ref());
+        data[40..72].copy_from_slice(owner.as_ref());
+        data[POSITION_LOWER_BIN_OFFSET..POSITION_LOWER_BIN_OFFSET + 4].copy_from_slice(&lower_bin_id.to_le_bytes());
+        data[POSITION_LOWER_BIN_OFFSET + 4..].copy_from_slice(&(lower_bin_id + 69).to_le_bytes());
+        for &(bin, x, y) in fees {
+            let fee_info = POSITION_FEE_INFOS_OFFSET + bin * POSITION_FEE_INFO_LEN;
+            // fee_*_per_token_complete checkpoints: never part of the quote
+            data[fee_info..fee_info + 32].fill(0xff);
+            data[fee_info + FEE_INFO_X_PENDING_OFFSET..fee_info + FEE_INFO_X_PENDING_OFFSET + 8]
+                .copy_from_slice(&x.to_le_bytes());
+            data[fee_info + FEE_INFO_Y_PENDING_OFFSET..fee_info + FEE_INFO_Y_PENDING_OFFSET + 8]
+                .copy_from_slice(&y.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn quote_lp_fees_reads_a_fixture_position() {
+        let (key, vault) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = meteora_position_fixture(
+            Pubkey::new_unique(),
+            vault,
+            -35,
+            &[(0, 1_500, 0), (34, 250, 7_000), (69, 0, 3)],
+        );
+        let mut lamports = 1u64;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &METEORA_DLMM_PROGRAM_ID, false, 0);
+        assert_eq!(quote_position_fees(&info).unwrap(), (1_750, 7_003));
+
+        let mut empty = meteora_position_fixture(Pubkey::new_unique(), vault, 0, &[]);
+        let mut lamports = 1u64;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut empty, &METEORA_DLMM_PROGRAM_ID, false, 0);
+        assert_eq!(quote_position_fees(&info).unwrap(), (0, 0));
+
+        let mut short = vec![0u8; POSITION_LOWER_BIN_OFFSET - 1];
+        let mut lamports = 1u64;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut short, &METEORA_DLMM_PROGRAM_ID, false, 0);
+        assert_eq!(quote_position_fees(&info).unwrap_err(), error!(CoreError::InvalidPositionData));
+    }
+
+    #[test]
+    fn lp_fee_preview_matches_what_the_claim_records() {
+        let (key, vault) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lb_pair = Pubkey::new_unique();
+        let mut data = meteora_position_fixture(lb_pair, vault, 0, &[(2, 4_000, 900), (3, 1, 0)]);
+        let mut lamports = 1u64;
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &METEORA_DLMM_PROGRAM_ID, false, 0);
+
+        let preview = quote_position_fees(&info).unwrap();
+        let return_data = lp_fees_return_data(preview.0, preview.1);
+        let decoded = (
+            u64::from_le_bytes(return_data[0..8].try_into().unwrap()),
+            u64::from_le_bytes(return_data[8..16].try_into().unwrap()),
+        );
+        assert_eq!(decoded, (4_001, 900));
+        assert_eq!(claim_fees_quote(&info), decoded);
+
+        // claim_fee2 pays the settled fees out and zeroes them: nothing left to preview
+        let claimed = meteora_position_fixture(lb_pair, vault, 0, &[]);
+        info.try_borrow_mut_data().unwrap().copy_from_slice(&claimed);
+        assert_eq!(quote_position_fees(&info).unwrap(), (0, 0));
+
+        // An unreadable layout fails the preview but quotes 0 on the claim
+        let mut short = vec![0u8; 64];
+        let mut lamports = 1u64;
+        let bad = AccountInfo::new(&key, false, true, &mut lamports, &mut short, &METEORA_DLMM_PROGRAM_ID, false, 0);
+        assert!(quote_position_fees(&bad).is_err());
+        assert_eq!(claim_fees_quote(&bad), (0, 0));
+    }
+
+    #[test]
+    fn compounding_fees_splits_only_the_claim() {
+        // claim_fee2 moved (5_000, 300) in with the range above the active bin: X goes
+        // back in, Y can't be added there and is paid out to the owner.
+        let (x_before, y_before) = (7_000u64, 50u64);
+        let (x_claimed, y_claimed) = (5_000u64, 300u64);
+        let (x_in, y_in, x_out, y_out) = fee_compound_amounts(10, 12, 5, x_claimed, y_claimed).unwrap();
+        assert_eq!((x_in, y_in, x_out, y_out), (5_000, 0, 0, 300));
+        // Whatever the vault held before the claim is still there afterwards
+        assert_eq!(x_before + x_claimed - x_in - x_out, x_before);
+        assert_eq!(y_before + y_claimed - y_in - y_out, y_before);
+
+        // Range below the active bin compounds Y
+        assert_eq!(fee_compound_amounts(-12, -10, 0, 40, 3_000).unwrap(), (0, 3_000, 40, 0));
+
+        // A claim under 1_000 per bin is paid out rather than compounded
+        assert_eq!(fee_compound_amounts(10, 12, 5, 2_999, 0).unwrap(), (0, 0, 2_999, 0));
+        // A range straddling the active bin takes neither; both are paid out
+        assert_eq!(fee_compound_amounts(4, 6, 5, 5_000, 5_000).unwrap(), (0, 0, 5_000, 5_000));
+    }
+
+    #[test]
+    fn reward_claim_pays_out_only_the_claimed_delta() {
+        // 4_000 already sat in the vault's reward account; claim_reward2 added 10_000
+        let (claimed, fee, to_owner) = reward_claim_split(4_000, 14_000, 30).unwrap();
+        assert_eq!((claimed, fee, to_owner), (10_000, 30, 9_970));
+        assert_eq!(14_000 - fee - to_owner, 4_000);
+
+        // Nothing claimed: nothing moves, whatever the balance
+        assert_eq!(reward_claim_split(4_000, 4_000, 30).unwrap(), (0, 0, 0));
+    }
+
+    // ---- fee delegate ----
+
+    #[test]
+    fn fee_delegate_claims_into_the_owners_accounts() {
+        let (owner, delegate) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut position = position_over(-10, 10);
+        position.owner = owner;
+        position.fee_delegate = delegate;
+
+        // Either may sign claim_fees; the fee token accounts must still be the owner's
+        assert!(position.can_claim_fees(&owner));
+        assert!(position.can_claim_fees(&delegate));
+        assert!(position.is_owner(&owner));
+        assert!(!position.is_owner(&delegate));
+    }
+
+    #[test]
+    fn unset_or_replaced_fee_delegate_cannot_claim() {
+        let (owner, delegate) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut position = position_over(-10, 10);
+        position.owner = owner;
+
+        // No delegate set: the Pubkey::default() sentinel never authorizes anyone
+        assert!(!position.can_claim_fees(&delegate));
+        assert!(!position.can_claim_fees(&Pubkey::default()));
+
+        // The owner rotates the delegate: the old key loses the right
+        position.fee_delegate = delegate;
+        assert!(position.can_claim_fees(&delegate));
+        position.fee_delegate = Pubkey::new_unique();
+        assert!(!position.can_claim_fees(&delegate));
+        assert!(!position.can_claim_fees(&Pubkey::new_unique()));
+        assert!(position.can_claim_fees(&owner));
+    }
+
+    // ---- sweep_rover ----
+
+    #[test]
+    fn dust_sweep_reverts_for_anyone_but_the_bot() {
+        let mut config = zeroed_config();
+        config.bot = Pubkey::new_unique();
+        config.min_sweep_lamports = 10_000_000;
+        let keeper = Pubkey::new_unique();
+        let nothing: Error = error!(CoreError::NothingToSweep);
+
+        assert_eq!(config.require_sweepable(9_999_999, &keeper).unwrap_err(), nothing);
+        assert!(config.require_sweepable(9_999_999, &config.bot).is_ok());
+        assert!(config.require_sweepable(10_000_000, &keeper).is_ok());
+        // Nothing above rent is never sweepable, even for the bot
+        assert_eq!(config.require_sweepable(0, &config.bot).unwrap_err(), nothing);
+    }
+
+    #[test]
+    fn sweep_leaves_the_rover_at_rent_and_moves_exactly_sweepable() {
+        let (rover_key, revenue_key, bot_key) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let system = anchor_lang::system_program::ID;
+        let rent = 1_000_000u64;
+        let (mut rover_lamports, mut revenue_lamports, mut bot_lamports) = (rent + 1_000_001, 5u64, 7u64);
+        let (mut rover_data, mut revenue_data, mut bot_data) = (Vec::new(), Vec::new(), Vec::new());
+        let rover = AccountInfo::new(&rover_key, false, true, &mut rover_lamports, &mut rover_data, &crate::ID, false, 0);
+        let revenue = AccountInfo::new(&revenue_key, false, true, &mut revenue_lamports, &mut revenue_data, &system, false, 0);
+        let bot = AccountInfo::new(&bot_key, false, true, &mut bot_lamports, &mut bot_data, &system, false, 0);
+
+        assert_eq!(move_swept_lamports(&rover, &revenue, &bot, 1_000_001, rent).unwrap(), (600_000, 400_001));
+        assert_eq!(rover.lamports(), rent);
+        assert_eq!((revenue.lamports(), bot.lamports()), (600_005, 400_008));
+
+        // One account as both destinations still receives the whole sweep
+        **rover.try_borrow_mut_lamports().unwrap() = rent + 500;
+        assert!(move_swept_lamports(&rover, &revenue, &revenue, 500, rent).is_ok());
+        assert_eq!((rover.lamports(), revenue.lamports()), (rent, 600_505));
+
+        // A sweepable that disagrees with the balance above rent trips the invariant
+        **rover.try_borrow_mut_lamports().unwrap() = rent + 500;
+        assert_eq!(
+            move_swept_lamports(&rover, &revenue, &bot, 400, rent).unwrap_err(),
+            error!(CoreError::SweepInvariantViolated)
+        );
+    }
+
+    // ---- transfer_position ----
+
+    #[test]
+    fn transferred_position_closes_for_the_new_owner_only() {
+        let (old_owner, new_owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut position = position_over(-10, 10);
+        position.owner = old_owner;
+
+        assert_eq!(position.transfer_to(new_owner).unwrap(), old_owner);
+        // user_close's signer constraint follows the stored owner
+        assert!(position.is_owner(&new_owner));
+        assert!(!position.is_owner(&old_owner));
+
+        let invalid: Error = error!(CoreError::InvalidNewOwner);
+        assert_eq!(position.transfer_to(new_owner).unwrap_err(), invalid);
+        assert_eq!(position.transfer_to(Pubkey::default()).unwrap_err(), invalid);
+        assert_eq!(position.owner, new_owner);
+    }
+
+    // ---- orphan vault reclaim ----
+
+    #[test]
+    fn orphaned_vault_can_be_reclaimed() {
+        let mut config = zeroed_config();
+        config.authority = Pubkey::new_unique();
+        let caller = Pubkey::new_unique();
+        let position_key = Pubkey::new_unique();
+
+        // Position PDA closed: no data, back to the system program
+        let mut lamports = 0u64;
+        let mut empty: Vec<u8> = Vec::new();
+        let system = anchor_lang::system_program::ID;
+        let gone = AccountInfo::new(&position_key, false, false, &mut lamports, &mut empty, &system, false, 0);
+        assert!(require_orphan_reclaim(&gone, 0, 0, &caller, &config).is_ok());
+        // Leftover tokens: only the admin may sweep them
+        assert_eq!(
+            require_orphan_reclaim(&gone, 0, 5, &caller, &config).unwrap_err(),
+            error!(CoreError::Unauthorized)
+        );
+        assert!(require_orphan_reclaim(&gone, 7, 5, &config.authority, &config).is_ok());
+
+        // A live Position pairs with the vault: never reclaimable, not even by the admin
+        let mut data = vec![0u8; Position::SIZE];
+        data[..8].copy_from_slice(Position::DISCRIMINATOR);
+        let mut lamports = 1u64;
+        let live = AccountInfo::new(&position_key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+        for caller in [caller, config.authority] {
+            assert_eq!(
+                require_orphan_reclaim(&live, 0, 0, &caller, &config).unwrap_err(),
+                error!(CoreError::VaultNotOrphaned)
+            );
+        }
+    }
+
+    // ---- per-owner position cap ----
+
+    fn owner_stats_data(stats: &OwnerStats) -> Vec<u8> {
+        let mut data = Vec::new();
+        stats.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn closing_frees_a_slot_under_the_cap() {
+        let owner = Pubkey::new_unique();
+        let mut stats = OwnerStats { owner, open_positions: 0, bump: 255 };
+        stats.take_slot(2).unwrap();
+        stats.take_slot(2).unwrap();
+        assert_eq!(stats.take_slot(2).unwrap_err(), error!(CoreError::TooManyPositions));
+        assert_eq!(stats.open_positions, 2);
+
+        let mut position = position_over(-10, 10);
+        position.owner = owner;
+        position.owner_counted = true;
+        let stats_key = Pubkey::find_program_address(&[b"owner_stats", owner.as_ref()], &crate::ID).0;
+        let mut lamports = 1u64;
+        let mut data = owner_stats_data(&stats);
+        let info = AccountInfo::new(&stats_key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+        release_owner_slot(&info, &position).unwrap();
+
+        let mut stats = OwnerStats::try_deserialize(&mut &info.try_borrow_data().unwrap()[..]).unwrap();
+        assert_eq!(stats.open_positions, 1);
+        stats.take_slot(2).unwrap();
+        // 0 = unlimited
+        stats.take_slot(0).unwrap();
+    }
+
+    #[test]
+    fn owner_slot_release_checks_the_stats_owner() {
+        let owner = Pubkey::new_unique();
+        let mut position = position_over(-10, 10);
+        position.owner = owner;
+        let stats_key = Pubkey::new_unique();
+        let mut lamports = 1u64;
+        let mut data = owner_stats_data(&OwnerStats { owner: Pubkey::new_unique(), open_positions: 1, bump: 255 });
+        let info = AccountInfo::new(&stats_key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+
+        // Positions opened before the cap never took a slot: nothing to release
+        assert!(release_owner_slot(&info, &position).is_ok());
+        position.owner_counted = true;
+        assert_eq!(release_owner_slot(&info, &position).unwrap_err(), error!(CoreError::InvalidOwnerStats));
+    }
+
+    #[test]
+    fn transferred_slot_counts_against_the_recipients_cap() {
+        let recipient = Pubkey::new_unique();
+        let (stats_key, bump) = Pubkey::find_program_address(&[b"owner_stats", recipient.as_ref()], &crate::ID);
+        let mut lamports = 1u64;
+        // bump 0 stored: initialization is read from the account, not the bump field
+        let mut data = owner_stats_data(&OwnerStats { owner: recipient, open_positions: 1, bump: 0 });
+        let info = AccountInfo::new(&stats_key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+        let (payer_key, system) = (Pubkey::new_unique(), anchor_lang::system_program::ID);
+        let (mut payer_lamports, mut payer_data) = (1u64, Vec::new());
+        let payer = AccountInfo::new(&payer_key, true, true, &mut payer_lamports, &mut payer_data, &system, false, 0);
+        let (mut program_lamports, mut program_data) = (1u64, Vec::new());
+        let loader = Pubkey::new_unique();
+        let system_program =
+            AccountInfo::new(&system, false, false, &mut program_lamports, &mut program_data, &loader, true, 0);
+
+        // Existing stats are incremented, not reset
+        take_owner_slot(&info, &recipient, bump, 2, &payer, &system_program).unwrap();
+        let stats = OwnerStats::try_deserialize(&mut &info.try_borrow_data().unwrap()[..]).unwrap();
+        assert_eq!((stats.owner, stats.open_positions), (recipient, 2));
+        assert_eq!(
+            take_owner_slot(&info, &recipient, bump, 2, &payer, &system_program).unwrap_err(),
+            error!(CoreError::TooManyPositions)
+        );
+        // Another wallet's stats at this address are rejected
+        assert_eq!(
+            take_owner_slot(&info, &Pubkey::new_unique(), bump, 0, &payer, &system_program).unwrap_err(),
+            error!(CoreError::InvalidOwnerStats)
+        );
+    }
+
+    // ---- active bin drift ----
+
+    #[test]
+    fn drift_past_tolerance_reverts_the_open() {
+        assert!(require_active_bin_within_drift(105, 100, 5).is_ok());
+        assert!(require_active_bin_within_drift(95, 100, 5).is_ok());
+        for active_id in [106, 94] {
+            assert_eq!(
+                require_active_bin_within_drift(active_id, 100, 5).unwrap_err(),
+                error!(CoreError::ActiveBinDrifted)
+            );
+        }
+        // 0 means the quoted bin exactly
+        assert!(require_active_bin_within_drift(100, 100, 0).is_ok());
+        assert!(require_active_bin_within_drift(101, 100, 0).is_err());
+        // Negative max_drift falls back to the generous default
+        assert!(require_active_bin_within_drift(100 + DEFAULT_MAX_ACTIVE_BIN_DRIFT, 100, -1).is_ok());
+        assert_eq!(
+            require_active_bin_within_drift(101 + DEFAULT_MAX_ACTIVE_BIN_DRIFT, 100, -1).unwrap_err(),
+            error!(CoreError::ActiveBinDrifted)
+        );
+        // Opposite extremes don't overflow
+        assert!(require_active_bin_within_drift(i32::MAX, i32::MIN, i32::MAX).is_err());
+    }
+
+    // ---- position fee override ----
+
+    #[test]
+    fn fee_override_reduces_the_harvest_fee() {
+        let mut config = zeroed_config();
+        config.fee_bps = 30;
+        config.authority = Pubkey::new_unique();
+        let mut position = position_over(0, 10);
+        position.side = Side::Sell;
+
+        position.fee_bps_override = resolve_fee_bps_override(10, &config, Some(config.authority)).unwrap();
+        let bps = position.effective_fee_bps(config.fee_bps);
+        assert_eq!(converted_side_fee(position.side, bps, 5_000, 1_000_000).unwrap(), (0, 1_000));
+        assert_eq!(converted_side_fee(position.side, config.fee_bps, 5_000, 1_000_000).unwrap(), (0, 3_000));
+
+        // Zero-fee promotion
+        position.fee_bps_override = resolve_fee_bps_override(0, &config, Some(config.authority)).unwrap();
+        assert_eq!(position.effective_fee_bps(config.fee_bps), 0);
+        // A later global cut below the override still applies
+        position.fee_bps_override = Some(10);
+        assert_eq!(position.effective_fee_bps(5), 5);
+        // The sentinel keeps the global fee
+        assert_eq!(resolve_fee_bps_override(FEE_BPS_OVERRIDE_NONE, &config, None).unwrap(), None);
+    }
+
+    #[test]
+    fn fee_override_above_the_global_fee_reverts() {
+        let mut config = zeroed_config();
+        config.fee_bps = 30;
+        config.authority = Pubkey::new_unique();
+        assert_eq!(
+            resolve_fee_bps_override(31, &config, Some(config.authority)).unwrap_err(),
+            error!(CoreError::InvalidFeeOverride)
+        );
+        assert_eq!(resolve_fee_bps_override(30, &config, Some(config.authority)).unwrap(), Some(30));
+        // Lowering it still needs the admin's signature
+        assert_eq!(resolve_fee_bps_override(10, &config, None).unwrap_err(), error!(CoreError::Unauthorized));
+        assert_eq!(
+            resolve_fee_bps_override(10, &config, Some(Pubkey::new_unique())).unwrap_err(),
+            error!(CoreError::Unauthorized)
+        );
+    }
+
+    // ---- minimum position amount ----
+
+    #[test]
+    fn pool_minimum_overrides_the_global_one() {
+        let pool = Pubkey::new_unique();
+        let status_key = pool_status_pda(&pool);
+        let mut config = zeroed_config();
+        let system = anchor_lang::system_program::ID;
+
+        // Never-set PoolStatus: the global minimum (pre-field configs fall back to the constant)
+        let (mut lamports, mut empty) = (0u64, Vec::new());
+        let unset = AccountInfo::new(&status_key, false, false, &mut lamports, &mut empty, &system, false, 0);
+        assert_eq!(min_position_amount(&config, &unset).unwrap(), MIN_POSITION_AMOUNT);
+        config.min_position_amount = 1_000_000;
+        assert_eq!(min_position_amount(&config, &unset).unwrap(), 1_000_000);
+
+        // Pool override, and an override of 0 deferring to Config
+        for (override_amount, expected) in [(50u64, 50u64), (0, 1_000_000)] {
+            let mut data = Vec::new();
+            PoolStatus { lb_pair: pool, blocked: false, bump: 255, min_position_amount: override_amount }
+                .try_serialize(&mut data)
+                .unwrap();
+            let mut lamports = 1u64;
+            let info = AccountInfo::new(&status_key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+            assert_eq!(min_position_amount(&config, &info).unwrap(), expected);
+        }
+
+        // A pre-override PoolStatus reads as no override
+        let mut data = pool_status_data(pool, false);
+        data.truncate(PoolStatus::LEGACY_SIZE);
+        let mut lamports = 1u64;
+        let legacy = AccountInfo::new(&status_key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+        assert_eq!(min_position_amount(&config, &legacy).unwrap(), 1_000_000);
+    }
+
+    // ---- pool mint check ----
+
+    #[test]
+    fn mismatched_mints_revert_the_open() {
+        let (token_x_mint, token_y_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![0u8; LB_PAIR_TOKEN_Y_MINT_OFFSET + 32];
+        data[LB_PAIR_TOKEN_X_MINT_OFFSET..LB_PAIR_TOKEN_X_MINT_OFFSET + 32].copy_from_slice(token_x_mint.as_ref());
+        data[LB_PAIR_TOKEN_Y_MINT_OFFSET..].copy_from_slice(token_y_mint.as_ref());
+        let (key, mut lamports) = (Pubkey::new_unique(), 1u64);
+        let lb_pair = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &METEORA_DLMM_PROGRAM_ID, false, 0);
+
+        assert!(require_lb_pair_mints(&lb_pair, &token_x_mint, &token_y_mint).is_ok());
+        let other = Pubkey::new_unique();
+        for (x, y) in [(other, token_y_mint), (token_x_mint, other), (token_y_mint, token_x_mint)] {
+            assert_eq!(require_lb_pair_mints(&lb_pair, &x, &y).unwrap_err(), error!(CoreError::MintMismatch));
+        }
+
+        // Too short to hold token_y_mint
+        let mut short = vec![0u8; LB_PAIR_TOKEN_Y_MINT_OFFSET];
+        let (key, mut lamports) = (Pubkey::new_unique(), 1u64);
+        let truncated = AccountInfo::new(&key, false, false, &mut lamports, &mut short, &METEORA_DLMM_PROGRAM_ID, false, 0);
+        assert_eq!(
+            require_lb_pair_mints(&truncated, &token_x_mint, &token_y_mint).unwrap_err(),
+            error!(CoreError::InvalidPool)
+        );
+    }
+
+    // ---- pause expiry ----
+
+    #[test]
+    fn expired_pause_allows_a_new_open() {
+        let mut config = zeroed_config();
+        config.max_pause_seconds = DEFAULT_MAX_PAUSE_SECONDS;
+        config.pause_at(T0).unwrap();
+        assert!(config.deposits_paused(T0));
+        assert!(config.deposits_paused(T0 + DEFAULT_MAX_PAUSE_SECONDS));
+        // Admin key lost: the flag stays set but deposits reopen
+        assert!(!config.deposits_paused(T0 + DEFAULT_MAX_PAUSE_SECONDS + 1));
+        assert!(config.paused);
+
+        // Re-pausing extends the window from the new pause
+        config.pause_at(T0 + 86_400).unwrap();
+        assert!(config.deposits_paused(T0 + DEFAULT_MAX_PAUSE_SECONDS + 1));
+        assert!(!config.deposits_paused(T0 + 86_400 + DEFAULT_MAX_PAUSE_SECONDS + 1));
+
+        // max_pause_seconds is capped, and 0 falls back to the default
+        config.max_pause_seconds = i64::MAX;
+        config.pause_at(T0).unwrap();
+        assert_eq!(config.pause_expiry_ts, T0 + MAX_PAUSE_SECONDS);
+        config.max_pause_seconds = 0;
+        config.pause_at(T0).unwrap();
+        assert_eq!(config.pause_expiry_ts, T0 + DEFAULT_MAX_PAUSE_SECONDS);
+    }
+
+    // ---- per-pool pause list ----
+
+    #[test]
+    fn blocked_pool_reverts_while_another_stays_open() {
+        let (pool_a, pool_b, pool_c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (status_a, status_b, status_c) = (pool_status_pda(&pool_a), pool_status_pda(&pool_b), pool_status_pda(&pool_c));
+        let (mut lamports_a, mut lamports_b, mut lamports_c) = (1u64, 0u64, 1u64);
+        let mut data_a = pool_status_data(pool_a, true);
+        let mut data_c = pool_status_data(pool_c, false);
+        let mut data_b: Vec<u8> = Vec::new();
+        let system = anchor_lang::system_program::ID;
+        // A: blocked, B: never set (uninitialized PDA), C: set then released
+        let blocked = AccountInfo::new(&status_a, false, false, &mut lamports_a, &mut data_a, &crate::ID, false, 0);
+        let never_set = AccountInfo::new(&status_b, false, false, &mut lamports_b, &mut data_b, &system, false, 0);
+        let released = AccountInfo::new(&status_c, false, false, &mut lamports_c, &mut data_c, &crate::ID, false, 0);
+
+        assert_eq!(require_pool_open(&blocked).unwrap_err(), error!(CoreError::PoolBlocked));
+        assert!(require_pool_open(&never_set).is_ok());
+        assert!(require_pool_open(&released).is_ok());
+    }
+
+    #[test]
+    fn legacy_size_pool_status_still_blocks() {
+        let pool = Pubkey::new_unique();
+        let status = pool_status_pda(&pool);
+        let mut lamports = 1u64;
+        let mut data = pool_status_data(pool, true);
+        data.truncate(PoolStatus::LEGACY_SIZE);
+        let info = AccountInfo::new(&status, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+        assert_eq!(require_pool_open(&info).unwrap_err(), error!(CoreError::PoolBlocked));
+    }
+
+    #[test]
+    fn rover_batch_checks_each_pools_status() {
+        let (pool_a, pool_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (status_a, status_b) = (pool_status_pda(&pool_a), pool_status_pda(&pool_b));
+        let (mut pool_lamports_a, mut pool_lamports_b) = (1u64, 1u64);
+        let (mut pool_data_a, mut pool_data_b) = (Vec::new(), Vec::new());
+        let (mut lamports_a, mut lamports_b) = (1u64, 0u64);
+        let mut data_a = pool_status_data(pool_a, true);
+        let mut data_b: Vec<u8> = Vec::new();
+        let dlmm = METEORA_DLMM_PROGRAM_ID;
+        let system = anchor_lang::system_program::ID;
+        let lb_pair_a = AccountInfo::new(&pool_a, false, true, &mut pool_lamports_a, &mut pool_data_a, &dlmm, false, 0);
+        let lb_pair_b = AccountInfo::new(&pool_b, false, true, &mut pool_lamports_b, &mut pool_data_b, &dlmm, false, 0);
+        let blocked = AccountInfo::new(&status_a, false, false, &mut lamports_a, &mut data_a, &crate::ID, false, 0);
+        let open = AccountInfo::new(&status_b, false, false, &mut lamports_b, &mut data_b, &system, false, 0);
+
+        assert_eq!(require_batch_pool_open(&lb_pair_a, &blocked).unwrap_err(), error!(CoreError::PoolBlocked));
+        assert!(require_batch_pool_open(&lb_pair_b, &open).is_ok());
+        // Pool B's entry can't dodge a block by passing another pool's (open) status
+        assert_eq!(
+            require_batch_pool_open(&lb_pair_a, &open).unwrap_err(),
+            error!(CoreError::RoverBatchAccountMismatch)
+        );
+    }
+
+    // ---- open strategy ----
+
+    #[test]
+    fn each_strategy_serializes_its_meteora_discriminant() {
+        // StrategyType byte follows min_bin_id / max_bin_id in the CPI payload
+        for (strategy, strategy_type, discriminant) in [
+            (STRATEGY_SPOT, StrategyType::SpotImBalanced, 6u8),
+            (STRATEGY_CURVE, StrategyType::CurveImBalanced, 7u8),
+            (STRATEGY_BID_ASK, StrategyType::BidAskImBalanced, 8u8),
+        ] {
+            let params = strategy_parameters_for(strategy, -35, 34).unwrap();
+            assert_eq!(params.strategy_type, strategy_type);
+            assert_eq!((params.min_bin_id, params.max_bin_id), (-35, 34));
+            let bytes = params.try_to_vec().unwrap();
+            assert_eq!(bytes[8], discriminant);
+        }
+        assert_eq!(strategy_parameters_for(3, -35, 34).unwrap_err(), error!(CoreError::InvalidStrategy));
+    }
+
+    // ---- open bin arrays ----
+
+    #[test]
+    fn open_across_two_array_boundaries_takes_the_middle_array() {
+        let lb_pair = Pubkey::new_unique();
+        let (lower_key, upper_key) = (bin_array_pda(&lb_pair, 0).0, bin_array_pda(&lb_pair, 2).0);
+        let (middle_key, wrong_key) = (bin_array_pda(&lb_pair, 1).0, Pubkey::new_unique());
+        let (mut l0, mut l1, mut l2, mut l3) = (1u64, 1u64, 1u64, 1u64);
+        let (mut d0, mut d1, mut d2, mut d3) = (vec![0u8], vec![0u8], vec![0u8], vec![0u8]);
+        let dlmm = METEORA_DLMM_PROGRAM_ID;
+        let lower = AccountInfo::new(&lower_key, false, true, &mut l0, &mut d0, &dlmm, false, 0);
+        let middle = AccountInfo::new(&middle_key, false, true, &mut l1, &mut d1, &dlmm, false, 0);
+        let upper = AccountInfo::new(&upper_key, false, true, &mut l2, &mut d2, &dlmm, false, 0);
+        let wrong = AccountInfo::new(&wrong_key, false, true, &mut l3, &mut d3, &dlmm, false, 0);
+
+        // 69 sits in array 0 and 140 in array 2: the range needs all three
+        let arrays = open_bin_arrays(&lb_pair, 69, 140, lower.clone(), Some(middle.clone()), upper.clone()).unwrap();
+        let keys: Vec<Pubkey> = arrays.iter().map(|a| a.key()).collect();
+        assert_eq!(keys, vec![lower_key, middle_key, upper_key]);
+
+        assert_eq!(
+            open_bin_arrays(&lb_pair, 69, 140, lower.clone(), None, upper.clone()).unwrap_err(),
+            error!(CoreError::MissingBinArrayMiddle)
+        );
+        assert_eq!(
+            open_bin_arrays(&lb_pair, 69, 140, lower.clone(), Some(wrong), upper.clone()).unwrap_err(),
+            error!(CoreError::MissingBinArrayMiddle)
+        );
+
+        // One boundary: lower and upper suffice and a passed middle is ignored
+        let arrays = open_bin_arrays(&lb_pair, 60, 100, lower.clone(), Some(middle.clone()), upper.clone()).unwrap();
+        assert_eq!(arrays.len(), 2);
+        // Three boundaries is more than one open can cover
+        assert_eq!(
+            open_bin_arrays(&lb_pair, 69, 210, lower, Some(middle), upper).unwrap_err(),
+            error!(CoreError::PositionTooWide)
+        );
+    }
+
+    // ---- position kind ----
+
+    #[test]
+    fn each_open_path_stamps_its_position_kind() {
+        let (user, rover_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (lb_pair, meteora_position) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let open = |kind, owner, side| {
+            Position::opened(kind, owner, lb_pair, meteora_position, side, (-10, 10), 1_000_000, T0, 255)
+        };
+        // open_position_v2, open_rover_position (and _sol / _batch), open_fee_rover
+        for (position, kind) in [
+            (open(POSITION_KIND_USER, user, Side::Buy), 0u8),
+            (open(POSITION_KIND_ROVER, rover_authority, Side::Buy), 1u8),
+            (open(POSITION_KIND_FEE_ROVER, rover_authority, Side::Sell), 2u8),
+        ] {
+            let mut data = Vec::new();
+            position.try_serialize(&mut data).unwrap();
+            let stored = Position::try_deserialize(&mut &data[..]).unwrap();
+            assert_eq!(stored.position_kind, kind);
+            assert_eq!((stored.strategy, stored.owner_counted), (STRATEGY_BID_ASK, false));
+        }
+        // Positions from before the field read as user positions
+        assert_eq!(position_over(-10, 10).position_kind, POSITION_KIND_USER);
+    }
+
+    // ---- rover range ----
+
+    #[test]
+    fn rover_range_sits_on_its_side_of_the_active_bin() {
+        // Sell: bins from just above the price upward
+        assert_eq!(rover_bin_range(100, 70, Side::Sell).unwrap(), (101, 170));
+        // Buy: the same width ending just below the price, never touching the active bin
+        assert_eq!(rover_bin_range(100, 70, Side::Buy).unwrap(), (30, 99));
+        // One-bin rovers sit right next to the active bin either way
+        assert_eq!(rover_bin_range(-5, 1, Side::Sell).unwrap(), (-4, -4));
+        assert_eq!(rover_bin_range(-5, 1, Side::Buy).unwrap(), (-6, -6));
+    }
+
+    // ---- fee rover source ----
+
+    fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> ITokenAccount {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token_2022::spl_token_2022::state::{Account, AccountState};
+        let mut data = vec![0u8; Account::LEN];
+        Account { mint, owner, amount, state: AccountState::Initialized, ..Account::default() }.pack_into_slice(&mut data);
+        ITokenAccount::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn fee_rover_drains_a_non_canonical_rover_ata() {
+        let rover_authority = Pubkey::find_program_address(&[b"rover_authority"], &crate::ID).0;
+        let token_x_mint = Pubkey::new_unique();
+        let canonical = anchor_spl::associated_token::get_associated_token_address(&rover_authority, &token_x_mint);
+        // A replaced rover_fee_token_x: rover-owned, right mint, but not the canonical ATA
+        let stray_address = Pubkey::new_unique();
+        assert_ne!(stray_address, canonical);
+        let stray = token_account(token_x_mint, rover_authority, 1_000);
+        assert!(require_rover_fee_source(&stray, &rover_authority, &token_x_mint).is_ok());
+
+        let foreign = token_account(token_x_mint, Pubkey::new_unique(), 1_000);
+        assert_eq!(
+            require_rover_fee_source(&foreign, &rover_authority, &token_x_mint).unwrap_err(),
+            error!(CoreError::InvalidTokenOwner)
+        );
+        let wrong_mint = token_account(Pubkey::new_unique(), rover_authority, 1_000);
+        assert_eq!(
+            require_rover_fee_source(&wrong_mint, &rover_authority, &token_x_mint).unwrap_err(),
+            error!(CoreError::RoverFeeMintMismatch)
+        );
+    }
 }
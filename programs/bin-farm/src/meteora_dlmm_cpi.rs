@@ -3,8 +3,9 @@
 // Meteora DLMM CPI module for monke.army
 // Program: LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo
 //
-// All CPI calls use V2 variants (Token-2022 compatible).
-// V1 functions removed — see git history for reference.
+// CPI calls use V2 variants (Token-2022 compatible). Harvest / close dispatch
+// remove-liquidity and claim-fee to the V1 instructions for plain SPL Token
+// pools (see *_auto below) to skip the memo / hook overhead.
 //
 // Discriminators verified against carbon-meteora-dlmm-decoder v0.12.0
 // and ref/dlmm-sdk/idls/dlmm.json.
@@ -29,6 +30,9 @@ pub const SPL_MEMO_PROGRAM_ID: Pubkey =
 pub const BINS_PER_ARRAY: i32 = 70;
 pub const MAX_POSITION_WIDTH: i32 = 70;
 
+/// DLMM pools carry at most two reward (LM) slots.
+pub const NUM_REWARDS: u64 = 2;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // DISCRIMINATORS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -38,7 +42,11 @@ pub mod disc {
     pub const ADD_LIQ_BY_STRATEGY2: [u8; 8]   = [0x03, 0xdd, 0x95, 0xda, 0x6f, 0x8d, 0x76, 0xd5];
     pub const REMOVE_LIQ_BY_RANGE2: [u8; 8]   = [0xcc, 0x02, 0xc3, 0x91, 0x35, 0x91, 0x91, 0xcd];
     pub const CLAIM_FEE2: [u8; 8]             = [0x70, 0xbf, 0x65, 0xab, 0x1c, 0x90, 0x7f, 0xbb];
+    pub const REMOVE_LIQ_BY_RANGE: [u8; 8]    = [0x1a, 0x52, 0x66, 0x98, 0xf0, 0x4a, 0x69, 0x1a];
+    pub const CLAIM_FEE: [u8; 8]              = [0xa9, 0x20, 0x4f, 0x89, 0x88, 0xe8, 0x46, 0x89];
     pub const CLOSE_POSITION2: [u8; 8]        = [0xae, 0x5a, 0x23, 0x73, 0xba, 0x28, 0x93, 0xe2];
+    pub const CLOSE_POSITION_IF_EMPTY: [u8; 8] = [0x3b, 0x7c, 0xd4, 0x76, 0x5b, 0x98, 0x6e, 0x9d];
+    pub const CLAIM_REWARD2: [u8; 8]          = [0xbe, 0x03, 0x7f, 0x77, 0xb2, 0x57, 0x9d, 0xb7];
     pub const INITIALIZE_BIN_ARRAY: [u8; 8]   = [0x23, 0x56, 0x13, 0xb9, 0x4e, 0xd4, 0x4b, 0xd3];
 }
 
@@ -77,6 +85,16 @@ impl StrategyParameters {
         }
     }
 
+    /// Curve distribution (liquidity concentrated toward active_id), one side zeroed.
+    pub fn curve_imbalanced(min_bin_id: i32, max_bin_id: i32) -> Self {
+        Self {
+            min_bin_id,
+            max_bin_id,
+            strategy_type: StrategyType::CurveImBalanced,
+            parameteres: [0u8; 64],
+        }
+    }
+
     /// BidAsk distribution via V2 two-sided CPI with one side zeroed.
     /// parameteres[0] = 1 favors X side (sell-side rover: deposit token X, amount_y = 0).
     pub fn bid_ask_imbalanced(min_bin_id: i32, max_bin_id: i32) -> Self {
@@ -122,14 +140,48 @@ pub struct RemainingAccountsInfo {
 impl RemainingAccountsInfo {
     pub fn none() -> Self { Self { slices: vec![] } }
 
-    pub fn empty_hooks() -> Self {
+    pub fn empty_hooks() -> Self { Self::with_hooks(0, 0) }
+
+    /// Transfer-hook slices for Token-2022 mints with a hook: `x_len` accounts for
+    /// token X, then `y_len` for token Y. Meteora consumes these slices from the front
+    /// of remaining_accounts before reading bin arrays (see hooks_then_bin_arrays).
+    pub fn with_hooks(x_len: u8, y_len: u8) -> Self {
         Self {
             slices: vec![
-                RemainingAccountsSlice { accounts_type: AccountsType::TransferHookX, length: 0 },
-                RemainingAccountsSlice { accounts_type: AccountsType::TransferHookY, length: 0 },
+                RemainingAccountsSlice { accounts_type: AccountsType::TransferHookX, length: x_len },
+                RemainingAccountsSlice { accounts_type: AccountsType::TransferHookY, length: y_len },
             ],
         }
     }
+
+    pub fn has_hook_accounts(&self) -> bool {
+        self.slices.iter().any(|s| s.length > 0)
+    }
+}
+
+/// Split the trailing transfer-hook run off a bin-farm instruction's remaining_accounts.
+/// Layout: [instruction extras (keeper ATA, referrer ATA, ...), hook X (x_len), hook Y (y_len)].
+/// Returns (extras, hook_accounts), or None if fewer than x_len + y_len were passed.
+pub fn split_hook_accounts<'a, 'info>(
+    remaining: &'a [AccountInfo<'info>],
+    x_len: u8,
+    y_len: u8,
+) -> Option<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    let hook_len = (x_len as usize).checked_add(y_len as usize)?;
+    let split = remaining.len().checked_sub(hook_len)?;
+    Some(remaining.split_at(split))
+}
+
+/// remaining_accounts for a V2 liquidity / fee CPI: hook accounts first, in
+/// RemainingAccountsInfo slice order, then the bin arrays.
+pub fn hooks_then_bin_arrays<'info>(
+    hook_accounts: &[AccountInfo<'info>],
+    bin_arrays: &[AccountInfo<'info>],
+) -> Vec<AccountInfo<'info>> {
+    let mut all = Vec::with_capacity(hook_accounts.len().saturating_add(bin_arrays.len()));
+    all.extend_from_slice(hook_accounts);
+    all.extend_from_slice(bin_arrays);
+    all
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -140,6 +192,12 @@ pub fn event_authority() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"__event_authority"], &METEORA_DLMM_PROGRAM_ID)
 }
 
+/// True if `key` is DLMM's `__event_authority` PDA. Checked before CPIs so a
+/// wrong account fails with a clear error instead of an opaque Meteora one.
+pub fn is_event_authority(key: &Pubkey) -> bool {
+    *key == event_authority().0
+}
+
 pub fn bin_array_pda(lb_pair: &Pubkey, index: i64) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[b"bin_array", lb_pair.as_ref(), &index.to_le_bytes()],
@@ -155,6 +213,106 @@ pub fn bin_id_to_array_index(bin_id: i32) -> i64 {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// ACCOUNT LAYOUT READERS
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// PositionV2 (zero-copy, ref/dlmm-sdk/idls/dlmm.json):
+//   0     discriminator            [u8; 8]
+//   8     lb_pair                  Pubkey
+//   40    owner                    Pubkey
+//   72    liquidity_shares         [u128; 70]           1120 bytes
+//   1192  reward_infos             [UserRewardInfo; 70] 48 bytes each
+//   4552  fee_infos                [FeeInfo; 70]        48 bytes each
+//   7912  lower_bin_id             i32
+//   7916  upper_bin_id             i32
+//
+// FeeInfo: fee_x_per_token_complete u128 (+0), fee_y_per_token_complete u128 (+16),
+//          fee_x_pending u64 (+32), fee_y_pending u64 (+40)
+
+pub const POSITION_LIQUIDITY_SHARES_OFFSET: usize = 72;
+pub const POSITION_LIQUIDITY_SHARE_LEN: usize = 16;
+pub const POSITION_FEE_INFOS_OFFSET: usize = 4552;
+pub const POSITION_FEE_INFO_LEN: usize = 48;
+pub const POSITION_LOWER_BIN_OFFSET: usize = 7912;
+pub const FEE_INFO_X_PENDING_OFFSET: usize = 32;
+pub const FEE_INFO_Y_PENDING_OFFSET: usize = 40;
+
+/// Sum fee_x_pending / fee_y_pending across every bin of a PositionV2.
+/// Reflects fees settled into the position at its last update — fees accrued
+/// in the bins since then are not included, so this is a lower bound.
+/// Returns None if the account is too short to be a PositionV2.
+pub fn read_position_pending_fees(data: &[u8]) -> Option<(u64, u64)> {
+    if data.len() < POSITION_LOWER_BIN_OFFSET {
+        return None;
+    }
+    let mut x_fees: u64 = 0;
+    let mut y_fees: u64 = 0;
+    for i in 0..MAX_POSITION_WIDTH as usize {
+        let base = POSITION_FEE_INFOS_OFFSET.checked_add(i.checked_mul(POSITION_FEE_INFO_LEN)?)?;
+        let x_at = base.checked_add(FEE_INFO_X_PENDING_OFFSET)?;
+        let y_at = base.checked_add(FEE_INFO_Y_PENDING_OFFSET)?;
+        let x = u64::from_le_bytes(data.get(x_at..x_at.checked_add(8)?)?.try_into().ok()?);
+        let y = u64::from_le_bytes(data.get(y_at..y_at.checked_add(8)?)?.try_into().ok()?);
+        x_fees = x_fees.saturating_add(x);
+        y_fees = y_fees.saturating_add(y);
+    }
+    Some((x_fees, y_fees))
+}
+
+/// Count bins of a PositionV2 with non-zero liquidity_shares. 0 = empty position
+/// (close_position_if_empty will succeed). None if the account is too short.
+pub fn read_position_liquidity_bins(data: &[u8]) -> Option<u16> {
+    if data.len() < POSITION_FEE_INFOS_OFFSET {
+        return None;
+    }
+    let mut bins: u16 = 0;
+    for i in 0..MAX_POSITION_WIDTH as usize {
+        let at = POSITION_LIQUIDITY_SHARES_OFFSET.checked_add(i.checked_mul(POSITION_LIQUIDITY_SHARE_LEN)?)?;
+        let share = u128::from_le_bytes(data.get(at..at.checked_add(POSITION_LIQUIDITY_SHARE_LEN)?)?.try_into().ok()?);
+        if share > 0 {
+            bins = bins.saturating_add(1);
+        }
+    }
+    Some(bins)
+}
+
+/// liquidity_shares of a PositionV2 at `bin_id`. Index = bin_id - lower_bin_id.
+/// None if the account is too short or the bin is outside the position's 70 slots.
+pub fn read_position_bin_share(data: &[u8], bin_id: i32) -> Option<u128> {
+    let lower_end = POSITION_LOWER_BIN_OFFSET.checked_add(4)?;
+    let lower_bin_id = i32::from_le_bytes(data.get(POSITION_LOWER_BIN_OFFSET..lower_end)?.try_into().ok()?);
+    let index = bin_id.checked_sub(lower_bin_id)?;
+    if !(0..MAX_POSITION_WIDTH).contains(&index) {
+        return None;
+    }
+    let at = POSITION_LIQUIDITY_SHARES_OFFSET.checked_add((index as usize).checked_mul(POSITION_LIQUIDITY_SHARE_LEN)?)?;
+    Some(u128::from_le_bytes(data.get(at..at.checked_add(POSITION_LIQUIDITY_SHARE_LEN)?)?.try_into().ok()?))
+}
+
+// LbPair (zero-copy, ref/dlmm-sdk/idls/dlmm.json):
+//   0     discriminator            [u8; 8]
+//   8     parameters               StaticParameters     32 bytes
+//   40    v_parameters             VariableParameters   32 bytes
+//   72    bump_seed / bin_step_seed / pair_type          4 bytes
+//   76    active_id                i32
+//   80    bin_step                 u16
+//   82    status .. creator_pool_on_off_control          6 bytes
+//   88    token_x_mint             Pubkey
+//   120   token_y_mint             Pubkey
+
+pub const LB_PAIR_TOKEN_X_MINT_OFFSET: usize = 88;
+pub const LB_PAIR_TOKEN_Y_MINT_OFFSET: usize = 120;
+
+/// (token_x_mint, token_y_mint) of an LbPair. None if the account is too short.
+pub fn read_lb_pair_mints(data: &[u8]) -> Option<(Pubkey, Pubkey)> {
+    let x_end = LB_PAIR_TOKEN_X_MINT_OFFSET.checked_add(32)?;
+    let y_end = LB_PAIR_TOKEN_Y_MINT_OFFSET.checked_add(32)?;
+    let x = Pubkey::try_from(data.get(LB_PAIR_TOKEN_X_MINT_OFFSET..x_end)?).ok()?;
+    let y = Pubkey::try_from(data.get(LB_PAIR_TOKEN_Y_MINT_OFFSET..y_end)?).ok()?;
+    Some((x, y))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // HELPERS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -167,6 +325,12 @@ fn bitmap_meta(account: &AccountInfo) -> AccountMeta {
     }
 }
 
+/// remaining_accounts keep the caller's writability: bin arrays arrive writable,
+/// transfer-hook programs and extra-account-meta lists usually don't.
+fn remaining_meta(account: &AccountInfo) -> AccountMeta {
+    AccountMeta { pubkey: account.key(), is_signer: false, is_writable: account.is_writable }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CPI INSTRUCTIONS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -205,7 +369,7 @@ pub fn initialize_position2<'info>(
 /// Fixed accounts (14): position(m), lb_pair(m), bitmap_ext(opt), user_token_x(m), user_token_y(m),
 ///   reserve_x(m), reserve_y(m), token_x_mint, token_y_mint, sender(s),
 ///   token_x_prog, token_y_prog, event_auth, program
-/// + remaining_accounts for bin arrays
+/// + remaining_accounts: transfer hook accounts (per RemainingAccountsInfo), then bin arrays
 pub fn add_liquidity_by_strategy2<'info>(
     accounts: &[AccountInfo<'info>; 14],
     params: LiquidityParameterByStrategy,
@@ -234,7 +398,7 @@ pub fn add_liquidity_by_strategy2<'info>(
         AccountMeta::new_readonly(accounts[12].key(), false),  // event_authority
         AccountMeta::new_readonly(accounts[13].key(), false),  // program
     ];
-    for a in remaining_accounts { metas.push(AccountMeta::new(a.key(), false)); }
+    for a in remaining_accounts { metas.push(remaining_meta(a)); }
     let mut all: Vec<AccountInfo<'info>> = accounts.to_vec();
     all.extend_from_slice(remaining_accounts);
     invoke_signed(&Instruction { program_id: METEORA_DLMM_PROGRAM_ID, accounts: metas, data }, &all, signer_seeds)?;
@@ -245,7 +409,7 @@ pub fn add_liquidity_by_strategy2<'info>(
 /// Accounts (15): position(m), lb_pair(m), bitmap_ext(m), user_token_x(m), user_token_y(m),
 ///   reserve_x(m), reserve_y(m), token_x_mint, token_y_mint, sender(s),
 ///   token_x_prog, token_y_prog, memo_prog, event_auth, program
-///   + remaining (bin_arrays, transfer hooks)
+///   + remaining (transfer hooks per RemainingAccountsInfo, then bin_arrays)
 pub fn remove_liquidity_by_range2<'info>(
     accounts: &[AccountInfo<'info>; 15],
     from_bin_id: i32,
@@ -279,7 +443,7 @@ pub fn remove_liquidity_by_range2<'info>(
         AccountMeta::new_readonly(accounts[13].key(), false),
         AccountMeta::new_readonly(accounts[14].key(), false),
     ];
-    for a in remaining_accounts { metas.push(AccountMeta::new(a.key(), false)); }
+    for a in remaining_accounts { metas.push(remaining_meta(a)); }
     let mut all: Vec<AccountInfo<'info>> = accounts.to_vec();
     all.extend_from_slice(remaining_accounts);
     invoke_signed(&Instruction { program_id: METEORA_DLMM_PROGRAM_ID, accounts: metas, data }, &all, signer_seeds)?;
@@ -290,7 +454,7 @@ pub fn remove_liquidity_by_range2<'info>(
 /// Accounts (14): lb_pair(m), position(m), sender(s), reserve_x(m), reserve_y(m),
 ///   user_token_x(m), user_token_y(m), token_x_mint, token_y_mint,
 ///   token_prog_x, token_prog_y, memo_prog, event_auth, program
-///   + remaining (bin_arrays, transfer hooks)
+///   + remaining (transfer hooks per RemainingAccountsInfo, then bin_arrays)
 pub fn claim_fee2<'info>(
     accounts: &[AccountInfo<'info>; 14],
     min_bin_id: i32,
@@ -321,7 +485,216 @@ pub fn claim_fee2<'info>(
         AccountMeta::new_readonly(accounts[12].key(), false),
         AccountMeta::new_readonly(accounts[13].key(), false),
     ];
-    for a in remaining_accounts { metas.push(AccountMeta::new(a.key(), false)); }
+    for a in remaining_accounts { metas.push(remaining_meta(a)); }
+    let mut all: Vec<AccountInfo<'info>> = accounts.to_vec();
+    all.extend_from_slice(remaining_accounts);
+    invoke_signed(&Instruction { program_id: METEORA_DLMM_PROGRAM_ID, accounts: metas, data }, &all, signer_seeds)?;
+    Ok(())
+}
+
+/// V1 remove liquidity — bin arrays as named accounts, no memo / hook support.
+/// Accounts (16): position(m), lb_pair(m), bitmap_ext(m), user_token_x(m), user_token_y(m),
+///   reserve_x(m), reserve_y(m), token_x_mint, token_y_mint, bin_array_lower(m),
+///   bin_array_upper(m), sender(s), token_x_prog, token_y_prog, event_auth, program
+pub fn remove_liquidity_by_range<'info>(
+    accounts: &[AccountInfo<'info>; 16],
+    from_bin_id: i32,
+    to_bin_id: i32,
+    bps_to_remove: u16,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = Vec::with_capacity(18);
+    data.extend_from_slice(&disc::REMOVE_LIQ_BY_RANGE);
+    data.extend_from_slice(&from_bin_id.to_le_bytes());
+    data.extend_from_slice(&to_bin_id.to_le_bytes());
+    data.extend_from_slice(&bps_to_remove.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: METEORA_DLMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts[0].key(), false),
+            AccountMeta::new(accounts[1].key(), false),
+            bitmap_meta(&accounts[2]),
+            AccountMeta::new(accounts[3].key(), false),
+            AccountMeta::new(accounts[4].key(), false),
+            AccountMeta::new(accounts[5].key(), false),
+            AccountMeta::new(accounts[6].key(), false),
+            AccountMeta::new_readonly(accounts[7].key(), false),
+            AccountMeta::new_readonly(accounts[8].key(), false),
+            AccountMeta::new(accounts[9].key(), false),
+            AccountMeta::new(accounts[10].key(), false),
+            AccountMeta::new_readonly(accounts[11].key(), true),
+            AccountMeta::new_readonly(accounts[12].key(), false),
+            AccountMeta::new_readonly(accounts[13].key(), false),
+            AccountMeta::new_readonly(accounts[14].key(), false),
+            AccountMeta::new_readonly(accounts[15].key(), false),
+        ],
+        data,
+    };
+    invoke_signed(&ix, accounts, signer_seeds)?;
+    Ok(())
+}
+
+/// V1 claim — one token program for both sides, no memo / hook support.
+/// Accounts (14): lb_pair(m), position(m), bin_array_lower(m), bin_array_upper(m),
+///   sender(s), reserve_x(m), reserve_y(m), user_token_x(m), user_token_y(m),
+///   token_x_mint, token_y_mint, token_prog, event_auth, program
+pub fn claim_fee<'info>(
+    accounts: &[AccountInfo<'info>; 14],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: METEORA_DLMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts[0].key(), false),
+            AccountMeta::new(accounts[1].key(), false),
+            AccountMeta::new(accounts[2].key(), false),
+            AccountMeta::new(accounts[3].key(), false),
+            AccountMeta::new_readonly(accounts[4].key(), true),
+            AccountMeta::new(accounts[5].key(), false),
+            AccountMeta::new(accounts[6].key(), false),
+            AccountMeta::new(accounts[7].key(), false),
+            AccountMeta::new(accounts[8].key(), false),
+            AccountMeta::new_readonly(accounts[9].key(), false),
+            AccountMeta::new_readonly(accounts[10].key(), false),
+            AccountMeta::new_readonly(accounts[11].key(), false),
+            AccountMeta::new_readonly(accounts[12].key(), false),
+            AccountMeta::new_readonly(accounts[13].key(), false),
+        ],
+        data: disc::CLAIM_FEE.to_vec(),
+    };
+    invoke_signed(&ix, accounts, signer_seeds)?;
+    Ok(())
+}
+
+/// True when a V2 liquidity / fee CPI can go through V1 instead: both token programs
+/// are classic SPL Token, no transfer-hook accounts, and exactly the lower / upper
+/// bin arrays (V1 has no slot for a third).
+pub fn v1_eligible(
+    token_x_program: &Pubkey,
+    token_y_program: &Pubkey,
+    remaining_accounts_info: &RemainingAccountsInfo,
+    remaining_accounts: &[AccountInfo],
+) -> bool {
+    *token_x_program == anchor_spl::token::ID
+        && *token_y_program == anchor_spl::token::ID
+        && !remaining_accounts_info.has_hook_accounts()
+        && remaining_accounts.len() == 2
+}
+
+/// remove_liquidity_by_range2 arguments, dispatched to V1 when v1_eligible.
+/// Same account list as remove_liquidity_by_range2; memo_prog is dropped on V1.
+pub fn remove_liquidity_by_range_auto<'info>(
+    accounts: &[AccountInfo<'info>; 15],
+    from_bin_id: i32,
+    to_bin_id: i32,
+    bps_to_remove: u16,
+    remaining_accounts_info: RemainingAccountsInfo,
+    signer_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if !v1_eligible(accounts[10].key, accounts[11].key, &remaining_accounts_info, remaining_accounts) {
+        return remove_liquidity_by_range2(
+            accounts, from_bin_id, to_bin_id, bps_to_remove,
+            remaining_accounts_info, signer_seeds, remaining_accounts,
+        );
+    }
+    remove_liquidity_by_range(
+        &[
+            accounts[0].clone(),
+            accounts[1].clone(),
+            accounts[2].clone(),
+            accounts[3].clone(),
+            accounts[4].clone(),
+            accounts[5].clone(),
+            accounts[6].clone(),
+            accounts[7].clone(),
+            accounts[8].clone(),
+            remaining_accounts[0].clone(),
+            remaining_accounts[1].clone(),
+            accounts[9].clone(),
+            accounts[10].clone(),
+            accounts[11].clone(),
+            accounts[13].clone(),
+            accounts[14].clone(),
+        ],
+        from_bin_id,
+        to_bin_id,
+        bps_to_remove,
+        signer_seeds,
+    )
+}
+
+/// claim_fee2 arguments, dispatched to V1 when v1_eligible. V1 claims over the
+/// whole position, so min / max bin ids only matter on the V2 path.
+pub fn claim_fee_auto<'info>(
+    accounts: &[AccountInfo<'info>; 14],
+    min_bin_id: i32,
+    max_bin_id: i32,
+    remaining_accounts_info: RemainingAccountsInfo,
+    signer_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if !v1_eligible(accounts[9].key, accounts[10].key, &remaining_accounts_info, remaining_accounts) {
+        return claim_fee2(
+            accounts, min_bin_id, max_bin_id,
+            remaining_accounts_info, signer_seeds, remaining_accounts,
+        );
+    }
+    claim_fee(
+        &[
+            accounts[0].clone(),
+            accounts[1].clone(),
+            remaining_accounts[0].clone(),
+            remaining_accounts[1].clone(),
+            accounts[2].clone(),
+            accounts[3].clone(),
+            accounts[4].clone(),
+            accounts[5].clone(),
+            accounts[6].clone(),
+            accounts[7].clone(),
+            accounts[8].clone(),
+            accounts[9].clone(),
+            accounts[12].clone(),
+            accounts[13].clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// V2 reward claim (LM / farm emissions) — one reward index per call.
+/// Accounts (10): lb_pair(m), position(m), sender(s), reward_vault(m), reward_mint,
+///   user_token_account(m), token_program, memo_prog, event_auth, program
+///   + remaining (transfer hooks per RemainingAccountsInfo, then bin_arrays)
+pub fn claim_reward2<'info>(
+    accounts: &[AccountInfo<'info>; 10],
+    reward_index: u64,
+    min_bin_id: i32,
+    max_bin_id: i32,
+    remaining_accounts_info: RemainingAccountsInfo,
+    signer_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(&disc::CLAIM_REWARD2);
+    data.extend_from_slice(&reward_index.to_le_bytes());
+    data.extend_from_slice(&min_bin_id.to_le_bytes());
+    data.extend_from_slice(&max_bin_id.to_le_bytes());
+    remaining_accounts_info.serialize(&mut data)?;
+
+    let mut metas = vec![
+        AccountMeta::new(accounts[0].key(), false),
+        AccountMeta::new(accounts[1].key(), false),
+        AccountMeta::new_readonly(accounts[2].key(), true),
+        AccountMeta::new(accounts[3].key(), false),
+        AccountMeta::new_readonly(accounts[4].key(), false),
+        AccountMeta::new(accounts[5].key(), false),
+        AccountMeta::new_readonly(accounts[6].key(), false),
+        AccountMeta::new_readonly(accounts[7].key(), false),
+        AccountMeta::new_readonly(accounts[8].key(), false),
+        AccountMeta::new_readonly(accounts[9].key(), false),
+    ];
+    for a in remaining_accounts { metas.push(remaining_meta(a)); }
     let mut all: Vec<AccountInfo<'info>> = accounts.to_vec();
     all.extend_from_slice(remaining_accounts);
     invoke_signed(&Instruction { program_id: METEORA_DLMM_PROGRAM_ID, accounts: metas, data }, &all, signer_seeds)?;
@@ -349,6 +722,28 @@ pub fn close_position2<'info>(
     Ok(())
 }
 
+/// Close only if the position holds no liquidity — Meteora reverts otherwise.
+/// Same account list as close_position2; there is no separate V2 variant.
+/// Accounts (5): position(m), sender(s), rent_receiver(m), event_auth, program
+pub fn close_position_if_empty<'info>(
+    accounts: &[AccountInfo<'info>; 5],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: METEORA_DLMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts[0].key(), false),
+            AccountMeta::new_readonly(accounts[1].key(), true),
+            AccountMeta::new(accounts[2].key(), false),
+            AccountMeta::new_readonly(accounts[3].key(), false),
+            AccountMeta::new_readonly(accounts[4].key(), false),
+        ],
+        data: disc::CLOSE_POSITION_IF_EMPTY.to_vec(),
+    };
+    invoke_signed(&ix, accounts, signer_seeds)?;
+    Ok(())
+}
+
 /// Accounts: lb_pair, bin_array(m), funder(ms), system_program
 pub fn initialize_bin_array<'info>(
     accounts: &[AccountInfo<'info>; 4],
@@ -372,3 +767,145 @@ pub fn initialize_bin_array<'info>(
     invoke_signed(&ix, accounts, signer_seeds)?;
     Ok(())
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+
+    const TOKEN_2022: Pubkey = solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PE6ZxwkKa8Z5B5");
+
+    fn put_u64(data: &mut [u8], at: usize, v: u64) {
+        data[at..at + 8].copy_from_slice(&v.to_le_bytes());
+    }
+
+    fn put_u128(data: &mut [u8], at: usize, v: u128) {
+        data[at..at + 16].copy_from_slice(&v.to_le_bytes());
+    }
+
+    fn fee_info_at(bin: usize) -> usize {
+        POSITION_FEE_INFOS_OFFSET + bin * POSITION_FEE_INFO_LEN
+    }
+
+    fn share_at(bin: usize) -> usize {
+        POSITION_LIQUIDITY_SHARES_OFFSET + bin * POSITION_LIQUIDITY_SHARE_LEN
+    }
+
+    /// Zeroed PositionV2 up to and including lower_bin_id / upper_bin_id.
+    fn position_fixture(lower_bin_id: i32) -> Vec<u8> {
+        let mut data = vec![0u8; POSITION_LOWER_BIN_OFFSET + 8];
+        data[POSITION_LOWER_BIN_OFFSET..POSITION_LOWER_BIN_OFFSET + 4]
+            .copy_from_slice(&lower_bin_id.to_le_bytes());
+        data[POSITION_LOWER_BIN_OFFSET + 4..POSITION_LOWER_BIN_OFFSET + 8]
+            .copy_from_slice(&(lower_bin_id + MAX_POSITION_WIDTH - 1).to_le_bytes());
+        data
+    }
+
+    // ---- v1_eligible ----
+
+    #[test]
+    fn v1_eligible_only_for_plain_spl_two_bin_arrays() {
+        let spl = anchor_spl::token::ID;
+        let (lower, upper, extra) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (mut l0, mut l1, mut l2) = (0u64, 0u64, 0u64);
+        let (mut d0, mut d1, mut d2) = (vec![], vec![], vec![]);
+        let bin_arrays = [
+            AccountInfo::new(&lower, false, true, &mut l0, &mut d0, &METEORA_DLMM_PROGRAM_ID, false, 0),
+            AccountInfo::new(&upper, false, true, &mut l1, &mut d1, &METEORA_DLMM_PROGRAM_ID, false, 0),
+            AccountInfo::new(&extra, false, true, &mut l2, &mut d2, &METEORA_DLMM_PROGRAM_ID, false, 0),
+        ];
+        let two = &bin_arrays[..2];
+
+        assert!(v1_eligible(&spl, &spl, &RemainingAccountsInfo::empty_hooks(), two));
+        assert!(v1_eligible(&spl, &spl, &RemainingAccountsInfo::none(), two));
+        assert!(!v1_eligible(&TOKEN_2022, &spl, &RemainingAccountsInfo::empty_hooks(), two));
+        assert!(!v1_eligible(&spl, &TOKEN_2022, &RemainingAccountsInfo::empty_hooks(), two));
+        assert!(!v1_eligible(&spl, &spl, &RemainingAccountsInfo::with_hooks(1, 0), two));
+        assert!(!v1_eligible(&spl, &spl, &RemainingAccountsInfo::with_hooks(0, 2), two));
+        assert!(!v1_eligible(&spl, &spl, &RemainingAccountsInfo::empty_hooks(), &bin_arrays));
+        assert!(!v1_eligible(&spl, &spl, &RemainingAccountsInfo::empty_hooks(), &bin_arrays[..1]));
+    }
+
+    // ---- PositionV2 decoders ----
+
+    #[test]
+    fn pending_fees_sum_every_bin() {
+        let mut data = position_fixture(0);
+        put_u64(&mut data, fee_info_at(0) + FEE_INFO_X_PENDING_OFFSET, 5);
+        put_u64(&mut data, fee_info_at(69) + FEE_INFO_X_PENDING_OFFSET, 7);
+        put_u64(&mut data, fee_info_at(3) + FEE_INFO_Y_PENDING_OFFSET, 11);
+        // fee_*_per_token_complete must not be read as pending
+        put_u128(&mut data, fee_info_at(3), u128::MAX);
+        assert_eq!(read_position_pending_fees(&data), Some((12, 11)));
+    }
+
+    #[test]
+    fn pending_fees_saturate_and_reject_short_accounts() {
+        let mut data = position_fixture(0);
+        put_u64(&mut data, fee_info_at(1) + FEE_INFO_X_PENDING_OFFSET, u64::MAX);
+        put_u64(&mut data, fee_info_at(2) + FEE_INFO_X_PENDING_OFFSET, 1);
+        assert_eq!(read_position_pending_fees(&data), Some((u64::MAX, 0)));
+        assert_eq!(read_position_pending_fees(&data[..POSITION_LOWER_BIN_OFFSET - 1]), None);
+    }
+
+    #[test]
+    fn liquidity_bins_count_non_zero_shares() {
+        let mut data = position_fixture(0);
+        assert_eq!(read_position_liquidity_bins(&data), Some(0));
+        put_u128(&mut data, share_at(0), 1);
+        put_u128(&mut data, share_at(69), u128::MAX);
+        assert_eq!(read_position_liquidity_bins(&data), Some(2));
+        assert_eq!(read_position_liquidity_bins(&data[..POSITION_FEE_INFOS_OFFSET - 1]), None);
+    }
+
+    #[test]
+    fn bin_share_indexes_from_lower_bin_id() {
+        let mut data = position_fixture(-10);
+        put_u128(&mut data, share_at(0), 3);
+        put_u128(&mut data, share_at(5), 42);
+        put_u128(&mut data, share_at(69), 9);
+        assert_eq!(read_position_bin_share(&data, -10), Some(3));
+        assert_eq!(read_position_bin_share(&data, -5), Some(42));
+        assert_eq!(read_position_bin_share(&data, 59), Some(9));
+        assert_eq!(read_position_bin_share(&data, -4), Some(0));
+        // Outside the 70 slots
+        assert_eq!(read_position_bin_share(&data, -11), None);
+        assert_eq!(read_position_bin_share(&data, 60), None);
+        assert_eq!(read_position_bin_share(&data[..POSITION_LOWER_BIN_OFFSET + 3], -10), None);
+    }
+
+    // ---- LbPair decoder ----
+
+    #[test]
+    fn lb_pair_mints_read_at_fixed_offsets() {
+        let (x, y) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![0u8; LB_PAIR_TOKEN_Y_MINT_OFFSET + 32];
+        data[LB_PAIR_TOKEN_X_MINT_OFFSET..LB_PAIR_TOKEN_X_MINT_OFFSET + 32].copy_from_slice(x.as_ref());
+        data[LB_PAIR_TOKEN_Y_MINT_OFFSET..LB_PAIR_TOKEN_Y_MINT_OFFSET + 32].copy_from_slice(y.as_ref());
+        // active_id sits before the mints and must not shift them
+        data[76..80].copy_from_slice(&(-123i32).to_le_bytes());
+        assert_eq!(read_lb_pair_mints(&data), Some((x, y)));
+        assert_eq!(read_lb_pair_mints(&data[..LB_PAIR_TOKEN_Y_MINT_OFFSET + 31]), None);
+    }
+
+    #[test]
+    fn bin_array_index_rounds_toward_negative_infinity() {
+        assert_eq!(bin_id_to_array_index(0), 0);
+        assert_eq!(bin_id_to_array_index(69), 0);
+        assert_eq!(bin_id_to_array_index(70), 1);
+        assert_eq!(bin_id_to_array_index(-1), -1);
+        assert_eq!(bin_id_to_array_index(-70), -1);
+        assert_eq!(bin_id_to_array_index(-71), -2);
+    }
+
+    #[test]
+    fn only_dlmm_event_authority_passes() {
+        assert!(is_event_authority(&event_authority().0));
+        assert!(!is_event_authority(&Pubkey::new_unique()));
+        assert!(!is_event_authority(&Pubkey::default()));
+        assert!(!is_event_authority(&METEORA_DLMM_PROGRAM_ID));
+        // Same seed under bin-farm's own program id: a plausible wrong account
+        let own = Pubkey::find_program_address(&[b"__event_authority"], &crate::ID).0;
+        assert!(!is_event_authority(&own));
+    }
+}
@@ -10,6 +10,8 @@
 //   Keeper calls deposit_sol once per distribution. Updates global accumulator. O(1).
 //   Holders call claim whenever they want. O(1).
 //   Weight changes (new burns) settle pending rewards before incrementing.
+//   Optional SPL reward token runs a second accumulator over the same weights
+//   (deposit_token / claim_token), independent of the SOL one.
 //
 // SMB Gen2 collection: SMBtHCCC6RYRutFEPb4gZqeBLUZbMNhRKaMKZZLHi7W (2x weight)
 // SMB Gen3 collection: 8Rt3Ayqth4DAiPnW9MDFi63TiQJHmohfTWLMQFHi4KZH (1x weight)
@@ -41,17 +43,64 @@ declare_id!("myA2F4S7trnQUiksrrB1prR3k95d8znEXZXwHkZw5ZH");
 /// accumulated_sol_per_share is stored as a u128 scaled by this factor.
 pub const PRECISION: u128 = 1_000_000_000_000; // 1e12
 
-/// Minimum SOL to trigger deposit (0.01 SOL)
+/// Default minimum SOL to trigger deposit (0.01 SOL). Tunable via set_min_deposit.
 pub const MIN_DEPOSIT_LAMPORTS: u64 = 10_000_000;
 
-/// Burn amount per feed_monke call: exactly 1,000,000 tokens (in base units).
-/// Assumes $BANANAS has some number of decimals — this constant should be
-/// adjusted to reflect 1M human-readable tokens in base units.
+/// Upper bound for the admin-set deposit floor (10 SOL) — keeps set_min_deposit
+/// from effectively disabling distributions.
+pub const MAX_MIN_DEPOSIT_LAMPORTS: u64 = 10_000_000_000;
+
+/// Whole $BANANAS burned per feed at initialize: 1,000,000 tokens. initialize scales
+/// it by the mint's decimals into MonkeState.bananas_per_feed:
 /// For a 6-decimal token: 1_000_000 * 1_000_000 = 1_000_000_000_000
 /// For a 9-decimal token: 1_000_000 * 1_000_000_000 = 1_000_000_000_000_000
-/// Set at initialization via `bananas_decimals` or hardcode after token launch.
+pub const FEED_TOKENS: u64 = 1_000_000;
+
+/// Burn amount for state created before bananas_per_feed existed (6-decimal mint,
+/// which initialize used to require).
 pub const BANANAS_PER_FEED: u64 = 1_000_000_000_000; // 1M tokens with 6 decimals
 
+/// set_bananas_per_feed bounds, in whole tokens (scaled by the mint's decimals).
+pub const MIN_FEED_TOKENS: u64 = 1;
+pub const MAX_FEED_TOKENS: u64 = 10_000_000;
+
+/// Longest unverified-collection window set_allow_unverified_until can open (7 days).
+pub const MAX_UNVERIFIED_WINDOW_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Initial per-feed weights written by initialize. Tunable via update_weights.
+pub const DEFAULT_GEN2_WEIGHT: u64 = 2;
+pub const DEFAULT_GEN3_WEIGHT: u64 = 1;
+
+/// Upper bound for update_weights — caps how far one feed can outweigh another.
+pub const MAX_WEIGHT_MULTIPLIER: u64 = 10;
+
+/// Upper bound for set_decay_params — at most half the weight lost per epoch.
+pub const MAX_DECAY_BPS_PER_EPOCH: u16 = 5000;
+
+/// Shortest decay epoch set_decay_params accepts (1 day).
+pub const MIN_DECAY_EPOCH_SECONDS: u32 = 86_400;
+
+/// Max NFTs per feed_monke_batch call (compute budget: metadata parse + settlement per NFT).
+pub const MAX_FEED_BATCH: usize = 8;
+
+/// remaining_accounts per NFT in feed_monke_batch:
+/// [nft_mint, nft_metadata, user_nft_account, monke_burn (writable)]
+pub const FEED_BATCH_STRIDE: usize = 4;
+
+/// Max dead monkes per compost_monke_batch call.
+pub const MAX_COMPOST_BATCH: usize = 10;
+
+/// Max NFTs per claim_all call (compute budget: two account loads + settlement per NFT).
+pub const MAX_CLAIM_ALL_BATCH: usize = 10;
+
+/// remaining_accounts per NFT in claim_all:
+/// [monke_burn (writable), nft_mint, user_nft_account]
+pub const CLAIM_ALL_STRIDE: usize = 3;
+
+/// Upper bound for set_compost_bounty (0.01 SOL per composted monke). The bounty is
+/// also limited to vault surplus above sol_liability, so it can never touch claims.
+pub const MAX_COMPOST_BOUNTY_LAMPORTS: u64 = 10_000_000;
+
 /// Metaplex Token Metadata program ID (mainnet)
 pub const MPL_TOKEN_METADATA_ID: Pubkey = anchor_lang::solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 
@@ -83,8 +132,9 @@ pub mod monke_bananas {
         smb_collection: Pubkey,
         smb_gen3_collection: Pubkey,
     ) -> Result<()> {
-        // Validate $BANANAS mint has 6 decimals (BANANAS_PER_FEED assumes this)
-        require!(ctx.accounts.bananas_mint.decimals == 6, MonkeError::InvalidMint);
+        // 1M whole $BANANAS in base units, whatever the mint's decimals
+        let bananas_per_feed = tokens_to_base_units(FEED_TOKENS, ctx.accounts.bananas_mint.decimals)
+            .ok_or(MonkeError::InvalidMint)?;
 
         let state = &mut ctx.accounts.state;
         state.authority = ctx.accounts.authority.key();
@@ -102,10 +152,24 @@ pub mod monke_bananas {
         state.total_bananas_burned = 0;
         state.paused = false;
         state.pegged_mint = Pubkey::default();
-        state._reserved = [0u8; 32];
+        state.pending_dist_pool = Pubkey::default();
+        state.pending_dist_pool_bump = 0;
+        state.dist_pool_change_at = 0;
+        state.sol_liability = 0;
+        state.min_deposit_lamports = MIN_DEPOSIT_LAMPORTS;
+        state.gen2_paused = false;
+        state.gen3_paused = false;
+        state.gen2_weight = DEFAULT_GEN2_WEIGHT;
+        state.gen3_weight = DEFAULT_GEN3_WEIGHT;
+        state.decay_bps_per_epoch = 0;
+        state.epoch_len_seconds = 0;
+        state.bananas_per_feed = bananas_per_feed;
+        state.allow_unverified_until = 0;
+        state.liability_initialized = true; // fresh vault: sol_liability = 0 is exact
+        state.decay_start_ts = 0;
 
         msg!("monke_bananas initialized");
-        msg!("BANANAS mint: {}", state.bananas_mint);
+        msg!("BANANAS mint: {} ({} per feed)", state.bananas_mint, bananas_per_feed);
         msg!("SMB Gen2 collection: {}", smb_collection);
         msg!("SMB Gen3 collection: {}", smb_gen3_collection);
         msg!("Dist pool: {}", dist_pool);
@@ -123,13 +187,20 @@ pub mod monke_bananas {
         require!(!state.paused, MonkeError::Paused);
 
         // 1. Validate NFT is from SMB Gen2 or Gen3 collection.
-        //    Returns weight multiplier: 2 for Gen2, 1 for Gen3.
-        let weight_multiplier = validate_collection_and_weight(
+        //    Returns the configured weight multiplier for the matched collection.
+        //    Registry collections need their CollectionConfig passed in.
+        let (weight_multiplier, generation) = validate_collection_and_weight(
             &ctx.accounts.nft_metadata,
             &ctx.accounts.nft_mint.key(),
-            &state.smb_collection,
-            &state.smb_gen3_collection,
+            state,
+            ctx.accounts.collection_config.as_deref(),
         )?;
+        // Per-collection pause (global `paused` above already halts both)
+        match generation {
+            SmbGen::Gen2 => require!(!state.gen2_paused, MonkeError::CollectionPaused),
+            SmbGen::Gen3 => require!(!state.gen3_paused, MonkeError::CollectionPaused),
+            SmbGen::Registered => {} // `enabled` already checked
+        }
 
         // 2. Validate caller holds the NFT
         require!(
@@ -141,7 +212,8 @@ pub mod monke_bananas {
             MonkeError::NotNftHolder
         );
 
-        // 3. Burn exactly BANANAS_PER_FEED $BANANAS
+        // 3. Burn exactly state.bananas_per_feed $BANANAS
+        let bananas_per_feed = state.effective_bananas_per_feed();
         let burn_cpi = Burn {
             mint: ctx.accounts.bananas_mint.to_account_info(),
             from: ctx.accounts.user_bananas_account.to_account_info(),
@@ -149,48 +221,31 @@ pub mod monke_bananas {
         };
         burn(
             CpiContext::new(ctx.accounts.token_program.to_account_info(), burn_cpi),
-            BANANAS_PER_FEED,
+            bananas_per_feed,
         )?;
 
         // 4. MasterChef settlement + weight increment.
         //    reward_debt is stored in the same PRECISION-scaled units as
         //    (weight * accumulated_sol_per_share). All math stays in that scale
         //    until the final claim division to avoid precision loss.
+        //    Steps 5-6 (weight increment, reward_debt rebase) live in settle_feed.
+        //    Any decay owed since the last interaction is applied first.
+        let now = Clock::get()?.unix_timestamp;
+        let state = &mut ctx.accounts.state;
         let monke_burn = &mut ctx.accounts.monke_burn;
-        let accumulated = state.accumulated_sol_per_share;
-
-        if monke_burn.share_weight == 0 {
-            // First burn — initialize the PDA fields
-            monke_burn.nft_mint = ctx.accounts.nft_mint.key();
-            monke_burn.first_fed_at = Clock::get()?.unix_timestamp;
-            monke_burn.claimed_sol = 0;
-            monke_burn.reward_debt = 0;
-        }
-
-        // Calculate pending rewards at current weight (PRECISION-scaled)
-        let pending_scaled = (monke_burn.share_weight as u128)
-            .checked_mul(accumulated).ok_or(MonkeError::Overflow)?
-            .checked_sub(monke_burn.reward_debt).unwrap_or(0);
-
-        // 5. Increment weight (Gen2 = +2, Gen3 = +1)
-        monke_burn.share_weight = monke_burn.share_weight
-            .checked_add(weight_multiplier).ok_or(MonkeError::Overflow)?;
-
-        // 6. Update reward_debt for new weight, preserving pending rewards.
-        //    new_debt = new_weight * accumulated - pending_scaled
-        //    This ensures the pending amount earned before this burn is still
-        //    claimable, while the new weight unit starts earning from now.
-        let new_entitled = (monke_burn.share_weight as u128)
-            .checked_mul(accumulated).ok_or(MonkeError::Overflow)?;
-        monke_burn.reward_debt = new_entitled
-            .checked_sub(pending_scaled).unwrap_or(0);
+        settle_decay(monke_burn, state, now)?;
+        let pending = settle_feed(
+            monke_burn,
+            &ctx.accounts.nft_mint.key(),
+            state,
+            weight_multiplier,
+        )?;
+        monke_burn.last_interaction_ts = now;
 
         // 7. Update global state
-        let state = &mut ctx.accounts.state;
-        state.total_share_weight = state.total_share_weight
-            .checked_add(weight_multiplier).ok_or(MonkeError::Overflow)?;
+        add_fed_weight(state, weight_multiplier, pending)?;
         state.total_bananas_burned = state.total_bananas_burned
-            .checked_add(BANANAS_PER_FEED).ok_or(MonkeError::Overflow)?;
+            .checked_add(bananas_per_feed).ok_or(MonkeError::Overflow)?;
 
         emit!(FeedEvent {
             user: ctx.accounts.user.key(),
@@ -206,7 +261,124 @@ pub mod monke_bananas {
         Ok(())
     }
 
-    /// Feed a gooseswtf pixel goose. Burns BANANAS_PER_FEED and increments weight by 1.
+    /// Feed up to MAX_FEED_BATCH SMB NFTs in one transaction. One burn CPI for
+    /// state.bananas_per_feed × n, then the same validation + MasterChef settlement as
+    /// feed_monke per NFT. One FeedEvent per NFT so indexers see n feeds.
+    ///
+    /// remaining_accounts: n × FEED_BATCH_STRIDE, per NFT in order:
+    ///   [0] nft_mint
+    ///   [1] nft_metadata (Metaplex PDA, validated by validate_collection_and_weight)
+    ///   [2] user_nft_account (SPL token account, owner = user, amount = 1)
+    ///   [3] monke_burn PDA ["monke_burn", nft_mint] — writable; created (user pays) if missing
+    /// The same mint may not appear twice in one batch.
+    pub fn feed_monke_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FeedMonkeBatch<'info>>,
+    ) -> Result<()> {
+        let state = &ctx.accounts.state;
+        require!(!state.paused, MonkeError::Paused);
+
+        let rem = ctx.remaining_accounts;
+        require!(
+            !rem.is_empty() && rem.len().is_multiple_of(FEED_BATCH_STRIDE),
+            MonkeError::InvalidFeedBatch
+        );
+        let count = rem.len() / FEED_BATCH_STRIDE;
+        require!(count <= MAX_FEED_BATCH, MonkeError::InvalidFeedBatch);
+
+        let total_burn = state.effective_bananas_per_feed()
+            .checked_mul(count as u64).ok_or(MonkeError::Overflow)?;
+        burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.bananas_mint.to_account_info(),
+                    from: ctx.accounts.user_bananas_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            total_burn,
+        )?;
+
+        let user_key = ctx.accounts.user.key();
+        let state = &mut ctx.accounts.state;
+        let window_open = state.distribution_open;
+        let mut added_weight: u64 = 0;
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(count);
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        for accs in rem.chunks(FEED_BATCH_STRIDE) {
+            let (nft_mint, nft_metadata, user_nft_info, monke_burn_info) =
+                (&accs[0], &accs[1], &accs[2], &accs[3]);
+            let mint_key = nft_mint.key();
+            require!(!seen.contains(&mint_key), MonkeError::InvalidFeedBatch);
+            seen.push(mint_key);
+
+            // Legacy collections only — registry NFTs go through feed_monke
+            let (weight_multiplier, generation) = validate_collection_and_weight(
+                nft_metadata,
+                &mint_key,
+                state,
+                None,
+            )?;
+            match generation {
+                SmbGen::Gen2 => require!(!state.gen2_paused, MonkeError::CollectionPaused),
+                SmbGen::Gen3 => require!(!state.gen3_paused, MonkeError::CollectionPaused),
+                SmbGen::Registered => return err!(MonkeError::InvalidCollection),
+            }
+
+            let user_nft_account = Account::<TokenAccount>::try_from(user_nft_info)?;
+            require!(user_nft_account.mint == mint_key, MonkeError::InvalidNftMint);
+            require!(user_nft_account.owner == user_key, MonkeError::NotNftHolder);
+            require!(user_nft_account.amount == 1, MonkeError::NotNftHolder);
+
+            let (expected_burn, burn_bump) = Pubkey::find_program_address(
+                &[b"monke_burn", mint_key.as_ref()],
+                &crate::ID,
+            );
+            require!(monke_burn_info.key() == expected_burn, MonkeError::InvalidFeedBatch);
+            if monke_burn_info.data_is_empty() {
+                create_monke_burn(
+                    monke_burn_info,
+                    &ctx.accounts.user.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    &mint_key,
+                    burn_bump,
+                )?;
+            }
+
+            let mut monke_burn = Account::<MonkeBurn>::try_from(monke_burn_info)?;
+            settle_decay(&mut monke_burn, state, timestamp)?;
+            settle_feed(&mut monke_burn, &mint_key, state, weight_multiplier)?;
+            monke_burn.last_interaction_ts = timestamp;
+            monke_burn.exit(&crate::ID)?;
+
+            added_weight = added_weight
+                .checked_add(weight_multiplier).ok_or(MonkeError::Overflow)?;
+            let total_weight = if window_open {
+                state.total_share_weight
+            } else {
+                state.total_share_weight.checked_add(added_weight).ok_or(MonkeError::Overflow)?
+            };
+
+            emit!(FeedEvent {
+                user: user_key,
+                nft_mint: mint_key,
+                new_weight: monke_burn.share_weight,
+                total_weight,
+                timestamp,
+            });
+        }
+
+        add_fed_weight(state, added_weight, window_open)?;
+        state.total_bananas_burned = state.total_bananas_burned
+            .checked_add(total_burn).ok_or(MonkeError::Overflow)?;
+
+        msg!("Batch fed {} monkes: +{} weight, total_weight={}",
+            count, added_weight, state.total_share_weight);
+        Ok(())
+    }
+
+    /// Feed a gooseswtf pixel goose. Burns state.bananas_per_feed and increments weight by 1.
     /// On first feed (share_weight == 0), GooseDAO Core membership is required.
     /// On subsequent feeds, membership is not checked (once in, always in).
     pub fn feed_goose(ctx: Context<FeedGoose>) -> Result<()> {
@@ -217,18 +389,20 @@ pub mod monke_bananas {
         validate_goose_pixel_collection(
             &ctx.accounts.goose_nft_metadata,
             &ctx.accounts.goose_nft_mint.key(),
+            state,
         )?;
 
         // 2. Once-in-always-in gate: only check GooseDAO membership on first feed
         let monke_burn = &ctx.accounts.monke_burn;
-        if monke_burn.share_weight == 0 {
+        if monke_burn.share_weight == 0 && monke_burn.pending_weight == 0 {
             validate_goose_dao_membership(
                 &ctx.accounts.goose_dao_asset,
                 &ctx.accounts.user.key(),
             )?;
         }
 
-        // 3. Burn exactly BANANAS_PER_FEED $BANANAS
+        // 3. Burn exactly state.bananas_per_feed $BANANAS
+        let bananas_per_feed = state.effective_bananas_per_feed();
         let burn_cpi = Burn {
             mint: ctx.accounts.bananas_mint.to_account_info(),
             from: ctx.accounts.user_bananas_account.to_account_info(),
@@ -236,39 +410,27 @@ pub mod monke_bananas {
         };
         burn(
             CpiContext::new(ctx.accounts.token_program.to_account_info(), burn_cpi),
-            BANANAS_PER_FEED,
+            bananas_per_feed,
         )?;
 
         // 4. MasterChef settlement + weight increment (identical to feed_monke)
+        let now = Clock::get()?.unix_timestamp;
+        let state = &mut ctx.accounts.state;
         let monke_burn = &mut ctx.accounts.monke_burn;
-        let accumulated = state.accumulated_sol_per_share;
-
-        if monke_burn.share_weight == 0 {
-            monke_burn.nft_mint = ctx.accounts.goose_nft_mint.key();
-            monke_burn.first_fed_at = Clock::get()?.unix_timestamp;
-            monke_burn.claimed_sol = 0;
-            monke_burn.reward_debt = 0;
-        }
-
-        let pending_scaled = (monke_burn.share_weight as u128)
-            .checked_mul(accumulated).ok_or(MonkeError::Overflow)?
-            .checked_sub(monke_burn.reward_debt).unwrap_or(0);
-
         let weight_increment: u64 = 1;
-        monke_burn.share_weight = monke_burn.share_weight
-            .checked_add(weight_increment).ok_or(MonkeError::Overflow)?;
-
-        let new_entitled = (monke_burn.share_weight as u128)
-            .checked_mul(accumulated).ok_or(MonkeError::Overflow)?;
-        monke_burn.reward_debt = new_entitled
-            .checked_sub(pending_scaled).unwrap_or(0);
+        settle_decay(monke_burn, state, now)?;
+        let pending = settle_feed(
+            monke_burn,
+            &ctx.accounts.goose_nft_mint.key(),
+            state,
+            weight_increment,
+        )?;
+        monke_burn.last_interaction_ts = now;
 
         // 5. Update global state
-        let state = &mut ctx.accounts.state;
-        state.total_share_weight = state.total_share_weight
-            .checked_add(weight_increment).ok_or(MonkeError::Overflow)?;
+        add_fed_weight(state, weight_increment, pending)?;
         state.total_bananas_burned = state.total_bananas_burned
-            .checked_add(BANANAS_PER_FEED).ok_or(MonkeError::Overflow)?;
+            .checked_add(bananas_per_feed).ok_or(MonkeError::Overflow)?;
 
         emit!(FeedEvent {
             user: ctx.accounts.user.key(),
@@ -288,42 +450,124 @@ pub mod monke_bananas {
     /// Updates the global accumulator so all monke holders can claim their share.
     /// Typically called by the keeper on Saturday, but anyone can call anytime.
     pub fn deposit_sol(ctx: Context<DepositSol>) -> Result<()> {
-        let state = &ctx.accounts.state;
-        require!(state.total_share_weight > 0, MonkeError::NoMonkes);
-
-        // Calculate distributable SOL from dist_pool (minus rent-exempt minimum)
-        let pool_balance = ctx.accounts.dist_pool.lamports();
-        let rent = Rent::get()?.minimum_balance(0);
-        let distributable = pool_balance.saturating_sub(rent);
-        require!(distributable >= MIN_DEPOSIT_LAMPORTS, MonkeError::NothingToDeposit);
+        let distributable = execute_deposit_sol(
+            &mut ctx.accounts.state,
+            &ctx.accounts.dist_pool,
+            &ctx.accounts.program_vault,
+            &ctx.accounts.system_program,
+        )?;
 
-        // Direct lamport manipulation instead of system_instruction::transfer.
-        // dist_pool is program-owned (PDA of this program), not system-owned.
-        // system_instruction::transfer requires system-owned source — would fail at runtime.
-        **ctx.accounts.dist_pool.try_borrow_mut_lamports()? -= distributable;
-        **ctx.accounts.program_vault.try_borrow_mut_lamports()? += distributable;
+        msg!("Deposited {} lamports, accumulator={}",
+            distributable, ctx.accounts.state.accumulated_sol_per_share);
 
-        // Update accumulator: add (deposit * PRECISION / total_weight)
-        let increment = (distributable as u128)
-            .checked_mul(PRECISION).ok_or(MonkeError::Overflow)?
-            .checked_div(state.total_share_weight as u128).ok_or(MonkeError::Overflow)?;
+        Ok(())
+    }
 
+    /// Open a snapshot distribution (front-running mitigation). Authority only.
+    ///
+    /// deposit_sol credits whatever weight exists at deposit time, so a feed landed just
+    /// before a known large deposit earns a full share of it. While a window is open,
+    /// feeds park their weight in MonkeBurn.pending_weight instead of total_share_weight,
+    /// so deposits made inside the window (deposit_sol or deposit_sol_snapshot) only pay
+    /// the weight frozen at begin_distribution, less anything defed/composted since.
+    /// deposit_sol_snapshot closes the window; parked weight then earns from that point
+    /// once activate_pending_weight folds it in.
+    ///
+    /// Tradeoffs: honest feeders inside the window also miss that distribution, and the
+    /// window only helps if it opens before the deposit is predictable — a feed before
+    /// begin_distribution still counts. Keep windows short; cancel_distribution ends one
+    /// without a deposit.
+    pub fn begin_distribution(ctx: Context<BeginDistribution>) -> Result<()> {
         let state = &mut ctx.accounts.state;
-        state.accumulated_sol_per_share = state.accumulated_sol_per_share
-            .checked_add(increment).ok_or(MonkeError::Overflow)?;
-        state.total_sol_distributed = state.total_sol_distributed
-            .checked_add(distributable).ok_or(MonkeError::Overflow)?;
+        require!(!state.distribution_open, MonkeError::DistributionAlreadyOpen);
+        let epoch = state.distribution_epoch
+            .checked_add(1).ok_or(MonkeError::Overflow)?;
+        state.distribution_epoch = epoch;
+        state.distribution_open = true;
+        state.pending_share_weight = 0;
+
+        let distribution = &mut ctx.accounts.distribution;
+        distribution.epoch = epoch;
+        distribution.snapshot_weight = state.total_share_weight;
+        distribution.snapshot_acc = state.accumulated_sol_per_share;
+        distribution.opened_at = Clock::get()?.unix_timestamp;
+        distribution.closed = false;
+        distribution.bump = ctx.bumps.distribution;
+
+        emit!(DistributionBegunEvent {
+            epoch,
+            snapshot_weight: distribution.snapshot_weight,
+            snapshot_acc: distribution.snapshot_acc,
+            authority: ctx.accounts.authority.key(),
+            timestamp: distribution.opened_at,
+        });
 
-        emit!(DepositEvent {
-            amount: distributable,
-            total_distributed: state.total_sol_distributed,
-            accumulator: state.accumulated_sol_per_share,
-            total_share_weight: state.total_share_weight,
+        msg!("Distribution {} opened: snapshot weight={}", epoch, distribution.snapshot_weight);
+        Ok(())
+    }
+
+    /// deposit_sol against the frozen snapshot weight, then close the window.
+    /// Permissionless, like deposit_sol.
+    pub fn deposit_sol_snapshot(ctx: Context<DepositSolSnapshot>) -> Result<()> {
+        require!(ctx.accounts.state.distribution_open, MonkeError::NoOpenDistribution);
+
+        let distributable = execute_deposit_sol(
+            &mut ctx.accounts.state,
+            &ctx.accounts.dist_pool,
+            &ctx.accounts.program_vault,
+            &ctx.accounts.system_program,
+        )?;
+        close_distribution(&mut ctx.accounts.state, &mut ctx.accounts.distribution, distributable)?;
+
+        msg!("Snapshot deposit {}: {} lamports, accumulator={}",
+            ctx.accounts.distribution.epoch, distributable, ctx.accounts.state.accumulated_sol_per_share);
+        Ok(())
+    }
+
+    /// Close the open window without a deposit (e.g. snapshot weight was zero).
+    /// Authority only — a permissionless close would let a front-runner's parked
+    /// weight start earning before the deposit it was waiting on.
+    pub fn cancel_distribution(ctx: Context<CancelDistribution>) -> Result<()> {
+        require!(ctx.accounts.state.distribution_open, MonkeError::NoOpenDistribution);
+        close_distribution(&mut ctx.accounts.state, &mut ctx.accounts.distribution, 0)?;
+        msg!("Distribution {} cancelled", ctx.accounts.distribution.epoch);
+        Ok(())
+    }
+
+    /// Permissionless: fold a monke's pending_weight into share_weight once its window
+    /// has closed. The parked weight earns from the window's close:
+    /// reward_debt += pending * acc_after (same for the token accumulator).
+    /// total_share_weight already includes it (close_distribution).
+    pub fn activate_pending_weight(ctx: Context<ActivatePendingWeight>) -> Result<()> {
+        let distribution = &ctx.accounts.distribution;
+        require!(distribution.closed, MonkeError::DistributionNotClosed);
+
+        let monke_burn = &mut ctx.accounts.monke_burn;
+        let pending = monke_burn.pending_weight;
+        require!(pending > 0, MonkeError::NoPendingWeight);
+
+        monke_burn.reward_debt = monke_burn.reward_debt
+            .checked_add(
+                (pending as u128).checked_mul(distribution.acc_after).ok_or(MonkeError::Overflow)?,
+            ).ok_or(MonkeError::Overflow)?;
+        monke_burn.token_reward_debt = monke_burn.token_reward_debt
+            .checked_add(
+                (pending as u128).checked_mul(distribution.token_acc_after).ok_or(MonkeError::Overflow)?,
+            ).ok_or(MonkeError::Overflow)?;
+        monke_burn.share_weight = monke_burn.share_weight
+            .checked_add(pending).ok_or(MonkeError::Overflow)?;
+        monke_burn.pending_weight = 0;
+
+        emit!(PendingWeightActivatedEvent {
+            nft_mint: monke_burn.nft_mint,
+            epoch: distribution.epoch,
+            weight_added: pending,
+            new_weight: monke_burn.share_weight,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("Deposited {} lamports, accumulator={}", distributable, state.accumulated_sol_per_share);
-
+        msg!("Activated {} pending weight for monke {} (now {})",
+            pending, monke_burn.nft_mint, monke_burn.share_weight);
         Ok(())
     }
 
@@ -338,17 +582,26 @@ pub mod monke_bananas {
         require!(distributable >= MIN_DEPOSIT_LAMPORTS, MonkeError::NothingToDeposit);
 
         // CPI token::transfer from dist_pool ATA → program_vault ATA
-        // dist_pool PDA signs as the token account authority
+        // dist_pool signs as the token account authority: via seeds when it is a
+        // dist_pool PDA, otherwise it signed this transaction
         let bump = state.dist_pool_bump;
+        let dist_pool = &ctx.accounts.dist_pool;
+        let source = dist_pool_source(&dist_pool.key(), dist_pool.owner, dist_pool.is_signer, bump)?;
+        let bump_seed = [bump];
+        let pda_seeds: &[&[u8]] = &[b"dist_pool", &bump_seed];
+        let signer_seeds: &[&[&[u8]]] = match source {
+            DistPoolSource::SelfSigned => &[],
+            DistPoolSource::ProgramOwned | DistPoolSource::ProgramSigned => &[pda_seeds],
+        };
         token::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 Transfer {
                     from: ctx.accounts.dist_pool_pegged_ata.to_account_info(),
                     to: ctx.accounts.program_vault_pegged_ata.to_account_info(),
-                    authority: ctx.accounts.dist_pool.to_account_info(),
+                    authority: dist_pool.to_account_info(),
                 },
-                &[&[b"dist_pool", &[bump]]],
+                signer_seeds,
             ),
             distributable,
         )?;
@@ -377,8 +630,90 @@ pub mod monke_bananas {
         Ok(())
     }
 
+    /// Deposit `amount` of the configured reward token into the program vault's token
+    /// account and advance accumulated_token_per_share. Permissionless — the DAO (or a
+    /// partner) funds it from any account it controls. The SOL accumulator is untouched.
+    pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+        credit_token_deposit(&mut ctx.accounts.state, amount)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.depositor_token_account.to_account_info(),
+                    to: ctx.accounts.program_vault_token_ata.to_account_info(),
+                    authority: ctx.accounts.depositor.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let state = &ctx.accounts.state;
+        emit!(TokenDepositEvent {
+            depositor: ctx.accounts.depositor.key(),
+            mint: state.reward_token_mint,
+            amount,
+            total_distributed: state.total_token_distributed,
+            accumulator: state.accumulated_token_per_share,
+            total_share_weight: state.total_share_weight,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Deposited {} reward tokens, accumulator={}", amount, state.accumulated_token_per_share);
+        Ok(())
+    }
+
+    /// Sweep SOL sent directly to program_vault into the accumulator. Permissionless.
+    /// Donations that bypass dist_pool -> deposit_sol raise the vault balance without
+    /// moving accumulated_sol_per_share, so nobody can claim them. Surplus is the vault
+    /// balance above rent + sol_liability (deposited SOL not yet claimed).
+    /// Requires liability_initialized (see init_sol_liability).
+    pub fn reconcile_vault_surplus(ctx: Context<ReconcileVaultSurplus>) -> Result<()> {
+        let available = ctx.accounts.program_vault.lamports()
+            .saturating_sub(rent_floor(&ctx.accounts.program_vault)?);
+        let state = &mut ctx.accounts.state;
+        let surplus = credit_vault_surplus(state, available)?;
+
+        emit!(SurplusReconciledEvent {
+            surplus,
+            accumulator: state.accumulated_sol_per_share,
+            total_share_weight: state.total_share_weight,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Reconciled {} lamports of vault surplus, accumulator={}", surplus, state.accumulated_sol_per_share);
+        Ok(())
+    }
+
+    /// One-time admin step for state that predates sol_liability: it reads 0 from the
+    /// old reserved bytes, which would make every owed lamport in program_vault look
+    /// like surplus.
+    /// Sets sol_liability to the vault balance above rent — everything in the vault is
+    /// treated as owed — and sets liability_initialized, which unlocks
+    /// reconcile_vault_surplus and the compost bounty.
+    pub fn init_sol_liability(ctx: Context<InitSolLiability>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        require!(!state.liability_initialized, MonkeError::LiabilityAlreadyInitialized);
+
+        let vault_lamports = ctx.accounts.program_vault.lamports();
+        let sol_liability = vault_lamports.saturating_sub(rent_floor(&ctx.accounts.program_vault)?);
+        state.sol_liability = sol_liability;
+        state.liability_initialized = true;
+
+        emit!(SolLiabilityInitializedEvent {
+            sol_liability,
+            vault_lamports,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("sol_liability initialized: {} lamports (vault {})", sol_liability, vault_lamports);
+        Ok(())
+    }
+
     /// Claim accumulated SOL for a monke. Caller must hold the SMB Gen2 NFT.
-    /// Always works even when paused — holders can never be locked out.
+    /// Always works even when paused — holders can never be locked out, including
+    /// by a MonkeBurn nobody has migrated (grown in place, caller pays the rent).
     pub fn claim(ctx: Context<Claim>) -> Result<()> {
         // Validate NFT ownership: caller holds the token (balance = 1)
         require!(
@@ -390,47 +725,102 @@ pub mod monke_bananas {
             MonkeError::NotNftHolder
         );
 
-        let monke_burn = &ctx.accounts.monke_burn;
-        let state = &ctx.accounts.state;
+        let mut monke_burn = load_monke_burn(
+            &ctx.accounts.monke_burn,
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let owed = execute_claim(
+            &mut ctx.accounts.state,
+            &mut monke_burn,
+            &ctx.accounts.program_vault,
+            &ctx.accounts.user.to_account_info(),
+        )?;
+        store_monke_burn(&ctx.accounts.monke_burn, &monke_burn)?;
 
-        // MasterChef formula: owed = (weight * accumulator - reward_debt) / PRECISION
-        // Subtraction happens in PRECISION-scaled units first, then one division.
-        // This avoids precision loss from separate divisions.
-        let pending_scaled = (monke_burn.share_weight as u128)
-            .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?
-            .checked_sub(monke_burn.reward_debt).unwrap_or(0);
+        emit!(ClaimEvent {
+            user: ctx.accounts.user.key(),
+            nft_mint: monke_burn.nft_mint,
+            amount: owed,
+            total_claimed: monke_burn.claimed_sol,
+            share_weight: monke_burn.share_weight,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        let owed = pending_scaled
-            .checked_div(PRECISION).unwrap_or(0) as u64;
+        msg!("Claimed {} lamports for monke {}", owed, monke_burn.nft_mint);
 
-        require!(owed > 0, MonkeError::NothingToClaim);
+        Ok(())
+    }
 
-        // Direct lamport manipulation instead of system_instruction::transfer.
-        // program_vault is program-owned (PDA), not system-owned.
-        // Check rent-exempt minimum and sufficient balance
-        let rent_minimum = Rent::get()?.minimum_balance(0);
-        let vault_lamports = ctx.accounts.program_vault.lamports();
+    /// Claim only `amount` of the owed SOL (tax-lot management, custodians). The rest
+    /// stays pending: reward_debt advances by exactly amount * PRECISION instead of
+    /// resetting to weight * accumulator, so two partial claims summing to the owed
+    /// amount leave the same zero pending and reward_debt a full claim would (see
+    /// settle_partial_claim). Same NFT-holder check
+    /// and rent-exempt vault guard as claim. Decay owed settles first, as in claim,
+    /// so claiming in small pieces never keeps a monke at its undecayed weight.
+    pub fn claim_partial(ctx: Context<Claim>, amount: u64) -> Result<()> {
+        require!(
+            ctx.accounts.user_nft_account.amount == 1,
+            MonkeError::NotNftHolder
+        );
         require!(
-            vault_lamports >= owed.checked_add(rent_minimum).ok_or(MonkeError::Overflow)?,
-            MonkeError::InsufficientVaultBalance
+            ctx.accounts.user_nft_account.owner == ctx.accounts.user.key(),
+            MonkeError::NotNftHolder
         );
 
-        **ctx.accounts.program_vault.try_borrow_mut_lamports()? -= owed;
-        **ctx.accounts.user.try_borrow_mut_lamports()? += owed;
+        let mut monke_burn = load_monke_burn(
+            &ctx.accounts.monke_burn,
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let now = Clock::get()?.unix_timestamp;
+        let owed = settle_partial_claim(&mut ctx.accounts.state, &mut monke_burn, amount, now)?;
+        store_monke_burn(&ctx.accounts.monke_burn, &monke_burn)?;
+
+        pay_from_vault(
+            &ctx.accounts.program_vault,
+            &ctx.accounts.user.to_account_info(),
+            amount,
+        )?;
 
-        // Update reward_debt and claimed_sol.
-        // Set reward_debt = weight * accumulator so next claim starts from zero pending.
-        // We subtract (owed * PRECISION) remainder to avoid rounding dust accumulation:
-        // reward_debt = weight * accumulator - (pending_scaled - owed * PRECISION)
-        // Simplified: reward_debt = weight * accumulator (standard MasterChef reset)
-        let monke_burn = &mut ctx.accounts.monke_burn;
-        monke_burn.reward_debt = (monke_burn.share_weight as u128)
-            .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?;
-        monke_burn.claimed_sol = monke_burn.claimed_sol
-            .checked_add(owed).ok_or(MonkeError::Overflow)?;
+        emit!(PartialClaimEvent {
+            user: ctx.accounts.user.key(),
+            nft_mint: monke_burn.nft_mint,
+            amount,
+            remaining: owed.checked_sub(amount).ok_or(MonkeError::Overflow)?,
+            total_claimed: monke_burn.claimed_sol,
+            timestamp: now,
+        });
 
-        emit!(ClaimEvent {
+        msg!("Partially claimed {} of {} lamports for monke {}", amount, owed, monke_burn.nft_mint);
+        Ok(())
+    }
+
+    /// Same as claim, but the owed SOL goes to `recipient` (treasury, custodial wallet)
+    /// instead of the signer. NFT ownership is still checked against the signer.
+    /// Recipient may be any non-executable account (system wallet or program-owned PDA);
+    /// lamports are credited directly like claim. A legacy MonkeBurn is grown in place
+    /// first, the signer paying the rent, as in claim.
+    pub fn claim_to(ctx: Context<ClaimTo>) -> Result<()> {
+        require!(!ctx.accounts.recipient.executable, MonkeError::InvalidRecipient);
+
+        let mut monke_burn = load_monke_burn(
+            &ctx.accounts.monke_burn,
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let owed = execute_claim(
+            &mut ctx.accounts.state,
+            &mut monke_burn,
+            &ctx.accounts.program_vault,
+            &ctx.accounts.recipient,
+        )?;
+        store_monke_burn(&ctx.accounts.monke_burn, &monke_burn)?;
+
+        emit!(ClaimToEvent {
             user: ctx.accounts.user.key(),
+            recipient: ctx.accounts.recipient.key(),
             nft_mint: monke_burn.nft_mint,
             amount: owed,
             total_claimed: monke_burn.claimed_sol,
@@ -438,8 +828,192 @@ pub mod monke_bananas {
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("Claimed {} lamports for monke {}", owed, monke_burn.nft_mint);
+        msg!("Claimed {} lamports for monke {} to {}",
+            owed, monke_burn.nft_mint, ctx.accounts.recipient.key());
+        Ok(())
+    }
+
+    /// Claim for up to MAX_CLAIM_ALL_BATCH monkes in one call: same settlement as claim
+    /// per NFT, then one program_vault transfer for the total. One ClaimEvent per paid
+    /// NFT plus a ClaimAllEvent summary. Not gated by pause, like claim.
+    /// NFTs the caller no longer holds (or that have nothing pending) are skipped, so a
+    /// transferred monke doesn't abort the batch. Malformed entries (wrong PDA, mint
+    /// mismatch, duplicates) still fail the whole call, as does a zero total.
+    /// Legacy MonkeBurns are grown in place (load_monke_burn), the caller paying the rent.
+    ///
+    /// remaining_accounts: n × CLAIM_ALL_STRIDE, per NFT in order:
+    ///   [0] monke_burn PDA ["monke_burn", nft_mint] — writable
+    ///   [1] nft_mint
+    ///   [2] user_nft_account (SPL token account for nft_mint; may be closed)
+    pub fn claim_all<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAll<'info>>,
+    ) -> Result<()> {
+        let rem = ctx.remaining_accounts;
+        require!(
+            !rem.is_empty() && rem.len().is_multiple_of(CLAIM_ALL_STRIDE),
+            MonkeError::InvalidClaimAllBatch
+        );
+        let count = rem.len() / CLAIM_ALL_STRIDE;
+        require!(count <= MAX_CLAIM_ALL_BATCH, MonkeError::InvalidClaimAllBatch);
+
+        let user_key = ctx.accounts.user.key();
+        let user_info = ctx.accounts.user.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let state = &mut ctx.accounts.state;
+        let timestamp = Clock::get()?.unix_timestamp;
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(count);
+        let mut total: u64 = 0;
+        let mut claimed: u32 = 0;
+
+        for accs in rem.chunks(CLAIM_ALL_STRIDE) {
+            let (burn_info, mint_info, user_nft_info) = (&accs[0], &accs[1], &accs[2]);
+            let mint_key = mint_info.key();
+            require!(!seen.contains(&mint_key), MonkeError::InvalidClaimAllBatch);
+            seen.push(mint_key);
+
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"monke_burn", mint_key.as_ref()],
+                &crate::ID,
+            );
+            require!(burn_info.key() == expected, MonkeError::InvalidClaimAllBatch);
+            let mut monke_burn = load_monke_burn(burn_info, &user_info, &system_program)?;
+            require!(monke_burn.nft_mint == mint_key, MonkeError::InvalidNftMint);
+
+            // Not held any more (sold, or the token account was closed): skip
+            if user_nft_info.data_is_empty() {
+                msg!("Skipping monke {}: token account closed", mint_key);
+                continue;
+            }
+            let user_nft_account = Account::<TokenAccount>::try_from(user_nft_info)?;
+            require!(user_nft_account.mint == mint_key, MonkeError::InvalidNftMint);
+            if user_nft_account.owner != user_key || user_nft_account.amount != 1 {
+                msg!("Skipping monke {}: not held by caller", mint_key);
+                continue;
+            }
+
+            if pending_owed(&monke_burn, state, timestamp)? == 0 {
+                continue;
+            }
+            let owed = settle_claim(state, &mut monke_burn, timestamp)?;
+            store_monke_burn(burn_info, &monke_burn)?;
+
+            total = total.checked_add(owed).ok_or(MonkeError::Overflow)?;
+            claimed = claimed.checked_add(1).ok_or(MonkeError::Overflow)?;
+
+            emit!(ClaimEvent {
+                user: user_key,
+                nft_mint: mint_key,
+                amount: owed,
+                total_claimed: monke_burn.claimed_sol,
+                share_weight: monke_burn.share_weight,
+                timestamp,
+            });
+        }
+
+        require!(total > 0, MonkeError::NothingToClaim);
+        pay_from_vault(
+            &ctx.accounts.program_vault,
+            &ctx.accounts.user.to_account_info(),
+            total,
+        )?;
+
+        emit!(ClaimAllEvent {
+            user: user_key,
+            nfts_claimed: claimed,
+            nfts_skipped: (count as u32).saturating_sub(claimed),
+            total_amount: total,
+            timestamp,
+        });
+
+        msg!("Claimed {} lamports across {} of {} monkes", total, claimed, count);
+        Ok(())
+    }
+
+    /// Unfeed: drop `weight` from a monke you hold (conviction signal, no $BANANAS back).
+    /// Pending SOL is settled first into unclaimed_sol, so rewards earned before the
+    /// defeed stay claimable — the next claim pays them out; nothing leaves the vault
+    /// here. Decay owed settles with it, so `weight` is checked against the decayed
+    /// weight. The remaining weight then earns from the current accumulator
+    /// (reward_debt = weight * acc).
+    /// Pending reward tokens must be claimed first (claim_token) — a lower weight
+    /// can't carry them forward through token_reward_debt.
+    /// Not gated by pause, like claim.
+    pub fn defeed_monke(ctx: Context<DefeedMonke>, weight: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            pending_token_owed(&ctx.accounts.monke_burn, &ctx.accounts.state, now)? == 0,
+            MonkeError::UnclaimedRewardToken
+        );
+
+        let settled = settle_defeed(
+            &mut ctx.accounts.state,
+            &mut ctx.accounts.monke_burn,
+            weight,
+            now,
+        )?;
+        let monke_burn = &ctx.accounts.monke_burn;
+        let state = &ctx.accounts.state;
+
+        emit!(DefeedEvent {
+            user: ctx.accounts.user.key(),
+            nft_mint: monke_burn.nft_mint,
+            weight_removed: weight,
+            new_weight: monke_burn.share_weight,
+            total_weight: state.total_share_weight,
+            settled_sol: settled,
+            timestamp: now,
+        });
+
+        msg!("Defed monke {}: -{} weight (now {}), {} lamports held for claim",
+            monke_burn.nft_mint, weight, monke_burn.share_weight, settled);
+        Ok(())
+    }
+
+    /// Read-only: theoretical SOL owed to a monke vs. what program_vault can pay right
+    /// now (balance minus rent). claim is all-or-nothing, so can_claim_now is false when
+    /// the vault is short — wait for the next deposit_sol instead of eating a revert.
+    pub fn quote_claimable_now(ctx: Context<QuoteClaimable>) -> Result<()> {
+        let monke_burn = &ctx.accounts.monke_burn;
+        let state = &ctx.accounts.state;
+
+        let theoretical = pending_owed(monke_burn, state, Clock::get()?.unix_timestamp)?;
+
+        let rent_minimum = rent_floor(&ctx.accounts.program_vault)?;
+        let available = ctx.accounts.program_vault.lamports().saturating_sub(rent_minimum);
+        let payable = core::cmp::min(theoretical, available);
+
+        emit!(ClaimableEvent {
+            nft_mint: monke_burn.nft_mint,
+            theoretical,
+            payable,
+            can_claim_now: theoretical > 0 && payable == theoretical,
+        });
+
+        msg!("Claimable: theoretical={} payable={}", theoretical, payable);
+        Ok(())
+    }
+
+    /// Read-only: pending reward for any MonkeBurn, using exactly the formula `claim`
+    /// pays out with (shared pending_owed helper). No NFT ownership required, so
+    /// indexers can query arbitrary mints. Ignores program_vault liquidity — see
+    /// quote_claimable_now for that. Emits PendingRewardsEvent and sets the amount
+    /// (u64 LE) as return data for simulateTransaction callers.
+    pub fn pending_rewards(ctx: Context<PendingRewards>) -> Result<()> {
+        let monke_burn = &ctx.accounts.monke_burn;
+        let now = Clock::get()?.unix_timestamp;
+        let pending = pending_owed(monke_burn, &ctx.accounts.state, now)?;
+        let (effective_weight, _) = decay_due(monke_burn, &ctx.accounts.state, now)?;
+
+        anchor_lang::solana_program::program::set_return_data(&pending.to_le_bytes());
+
+        emit!(PendingRewardsEvent {
+            nft_mint: monke_burn.nft_mint,
+            share_weight: monke_burn.share_weight,
+            effective_weight,
+            pending,
+        });
 
+        msg!("Pending rewards: {} lamports", pending);
         Ok(())
     }
 
@@ -459,9 +1033,9 @@ pub mod monke_bananas {
         let state = &ctx.accounts.state;
         require!(state.pegged_mint != Pubkey::default(), MonkeError::PeggedNotConfigured);
 
-        let pending_scaled = (monke_burn.share_weight as u128)
-            .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?
-            .checked_sub(monke_burn.reward_debt).unwrap_or(0);
+        let pending_scaled = sol_pending_scaled(
+            monke_burn, monke_burn.share_weight, state.accumulated_sol_per_share,
+        )?;
 
         let owed = pending_scaled
             .checked_div(PRECISION).unwrap_or(0) as u64;
@@ -502,25 +1076,65 @@ pub mod monke_bananas {
         Ok(())
     }
 
-    // ─── ADMIN ───
-
-    pub fn pause(ctx: Context<AdminOnly>) -> Result<()> {
-        ctx.accounts.state.paused = true;
-        msg!("monke_bananas paused");
-        Ok(())
-    }
+    /// Claim accumulated reward tokens for a monke. Independent of claim — SOL owed
+    /// is left alone. Pays at the decayed weight like claim; the decayed share of the
+    /// pending tokens is forfeited. Always works even when paused.
+    pub fn claim_token(ctx: Context<ClaimToken>) -> Result<()> {
+        let state = &ctx.accounts.state;
+        require!(state.reward_token_mint != Pubkey::default(), MonkeError::RewardTokenNotConfigured);
 
-    pub fn unpause(ctx: Context<AdminOnly>) -> Result<()> {
-        ctx.accounts.state.paused = false;
-        msg!("monke_bananas unpaused");
-        Ok(())
-    }
+        let now = Clock::get()?.unix_timestamp;
+        let owed = settle_token_claim(state, &mut ctx.accounts.monke_burn, now)?;
+        require!(owed > 0, MonkeError::NothingToClaim);
 
-    pub fn transfer_authority(
-        ctx: Context<AdminOnly>,
-        new_authority: Pubkey,
-    ) -> Result<()> {
-        ctx.accounts.state.pending_authority = new_authority;
+        let bump = state.program_vault_bump;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.program_vault_token_ata.to_account_info(),
+                    to: ctx.accounts.user_token_ata.to_account_info(),
+                    authority: ctx.accounts.program_vault.to_account_info(),
+                },
+                &[&[b"program_vault", &[bump]]],
+            ),
+            owed,
+        )?;
+
+        let monke_burn = &ctx.accounts.monke_burn;
+        emit!(TokenClaimEvent {
+            user: ctx.accounts.user.key(),
+            nft_mint: monke_burn.nft_mint,
+            mint: state.reward_token_mint,
+            amount: owed,
+            total_claimed: monke_burn.claimed_token,
+            share_weight: monke_burn.share_weight,
+            timestamp: now,
+        });
+
+        msg!("Claimed {} reward tokens for monke {}", owed, monke_burn.nft_mint);
+        Ok(())
+    }
+
+    // ─── ADMIN ───
+
+    pub fn pause(ctx: Context<AdminOnly>) -> Result<()> {
+        ctx.accounts.state.paused = true;
+        msg!("monke_bananas paused");
+        Ok(())
+    }
+
+    pub fn unpause(ctx: Context<AdminOnly>) -> Result<()> {
+        ctx.accounts.state.paused = false;
+        msg!("monke_bananas unpaused");
+        Ok(())
+    }
+
+    pub fn transfer_authority(
+        ctx: Context<AdminOnly>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.state.pending_authority = new_authority;
         msg!("Authority transfer proposed to {}", new_authority);
         Ok(())
     }
@@ -535,16 +1149,319 @@ pub mod monke_bananas {
 
     /// Set the $PEGGED mint address. Admin-only. Called once during migration
     /// from raw SOL distribution to $PEGGED LST distribution.
+    /// Tune the deposit_sol floor as SOL price moves. Smaller floors mean more
+    /// frequent, smaller distributions. Rent-minimum subtraction is unchanged.
+    pub fn set_min_deposit(ctx: Context<AdminOnly>, min_deposit_lamports: u64) -> Result<()> {
+        require!(
+            min_deposit_lamports > 0 && min_deposit_lamports <= MAX_MIN_DEPOSIT_LAMPORTS,
+            MonkeError::InvalidMinDeposit
+        );
+        let state = &mut ctx.accounts.state;
+        let old_min_deposit_lamports = state.effective_min_deposit();
+        state.min_deposit_lamports = min_deposit_lamports;
+
+        emit!(MinDepositUpdatedEvent {
+            old_min_deposit_lamports,
+            new_min_deposit_lamports: min_deposit_lamports,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Min deposit set: {} → {} lamports", old_min_deposit_lamports, min_deposit_lamports);
+        Ok(())
+    }
+
+    /// Pause feed_monke for one SMB collection while the other keeps feeding.
+    /// Global `paused` still overrides both. claim is never gated by these flags.
+    pub fn set_collection_pause(
+        ctx: Context<AdminOnly>,
+        gen2_paused: bool,
+        gen3_paused: bool,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        state.gen2_paused = gen2_paused;
+        state.gen3_paused = gen3_paused;
+
+        emit!(CollectionPauseEvent {
+            gen2_paused,
+            gen3_paused,
+            global_paused: state.paused,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Collection pause: gen2={} gen3={}", gen2_paused, gen3_paused);
+        Ok(())
+    }
+
+    /// Re-tune per-feed weight for Gen2 / Gen3. Only future feeds use the new
+    /// multipliers — existing MonkeBurn weights are left untouched.
+    /// Bounded to 1..=MAX_WEIGHT_MULTIPLIER so a single feed can't mint runaway weight.
+    pub fn update_weights(
+        ctx: Context<AdminOnly>,
+        gen2_weight: u64,
+        gen3_weight: u64,
+    ) -> Result<()> {
+        require!(
+            (1..=MAX_WEIGHT_MULTIPLIER).contains(&gen2_weight)
+                && (1..=MAX_WEIGHT_MULTIPLIER).contains(&gen3_weight),
+            MonkeError::InvalidWeight
+        );
+        let state = &mut ctx.accounts.state;
+        let old_gen2_weight = state.effective_gen2_weight();
+        let old_gen3_weight = state.effective_gen3_weight();
+        state.gen2_weight = gen2_weight;
+        state.gen3_weight = gen3_weight;
+
+        emit!(WeightsUpdatedEvent {
+            old_gen2_weight,
+            old_gen3_weight,
+            new_gen2_weight: gen2_weight,
+            new_gen3_weight: gen3_weight,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Weights set: gen2 {} → {}, gen3 {} → {}",
+            old_gen2_weight, gen2_weight, old_gen3_weight, gen3_weight);
+        Ok(())
+    }
+
+    /// Configure lazy weight decay: each monke loses `decay_bps_per_epoch` of its
+    /// weight per `epoch_len_seconds` since its last feed (see settle_decay).
+    /// 0 for either turns decay off. Turning it on counts from each monke's
+    /// last_interaction_ts, i.e. time since its last feed — not from now. Monkes
+    /// that haven't fed since last_interaction_ts existed count from decay_start_ts,
+    /// stamped here when decay turns on.
+    pub fn set_decay_params(
+        ctx: Context<AdminOnly>,
+        decay_bps_per_epoch: u16,
+        epoch_len_seconds: u32,
+    ) -> Result<()> {
+        require!(decay_bps_per_epoch <= MAX_DECAY_BPS_PER_EPOCH, MonkeError::InvalidDecayParams);
+        require!(
+            decay_bps_per_epoch == 0 || epoch_len_seconds >= MIN_DECAY_EPOCH_SECONDS,
+            MonkeError::InvalidDecayParams
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let state = &mut ctx.accounts.state;
+        let was_enabled = state.decay_enabled();
+        state.decay_bps_per_epoch = decay_bps_per_epoch;
+        state.epoch_len_seconds = epoch_len_seconds;
+        if state.decay_enabled() && (!was_enabled || state.decay_start_ts == 0) {
+            state.decay_start_ts = now;
+        }
+
+        emit!(DecayParamsSetEvent {
+            decay_bps_per_epoch,
+            epoch_len_seconds,
+            authority: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+
+        msg!("Decay set: {} bps per {}s epoch", decay_bps_per_epoch, epoch_len_seconds);
+        Ok(())
+    }
+
+    /// Create or update a registry entry making another collection feedable.
+    /// feed_monke checks the legacy Gen2/Gen3 collections first, then the
+    /// CollectionConfig passed by the caller. `enabled = false` blocks new feeds
+    /// only; claims and existing weight are unaffected.
+    pub fn register_collection(
+        ctx: Context<RegisterCollection>,
+        collection: Pubkey,
+        weight: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        let bump = ctx.bumps.collection_config;
+        ctx.accounts.collection_config.register(collection, weight, enabled, bump)?;
+
+        emit!(CollectionRegisteredEvent {
+            collection,
+            weight,
+            enabled,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Collection {} registered: weight={} enabled={}", collection, weight, enabled);
+        Ok(())
+    }
+
+    /// Lamports paid to the caller per composted monke, on top of the MonkeBurn rent
+    /// refund. 0 turns the bounty off. Capped at MAX_COMPOST_BOUNTY_LAMPORTS, and each
+    /// payout must fit in program_vault surplus (see pay_compost_bounty).
+    pub fn set_compost_bounty(ctx: Context<AdminOnly>, compost_bounty_lamports: u64) -> Result<()> {
+        require!(
+            compost_bounty_lamports <= MAX_COMPOST_BOUNTY_LAMPORTS,
+            MonkeError::InvalidCompostBounty
+        );
+        let state = &mut ctx.accounts.state;
+        let old_compost_bounty_lamports = state.compost_bounty_lamports;
+        state.compost_bounty_lamports = compost_bounty_lamports;
+
+        emit!(CompostBountyUpdatedEvent {
+            old_compost_bounty_lamports,
+            new_compost_bounty_lamports: compost_bounty_lamports,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Compost bounty set: {} → {} lamports", old_compost_bounty_lamports, compost_bounty_lamports);
+        Ok(())
+    }
+
+    /// Per-feed burn in whole tokens (MIN_FEED_TOKENS..=MAX_FEED_TOKENS), scaled by
+    /// the $BANANAS mint's decimals. Applies to the next feed; existing weight is untouched.
+    pub fn set_bananas_per_feed(ctx: Context<SetBananasPerFeed>, feed_tokens: u64) -> Result<()> {
+        let new_bananas_per_feed = bounded_feed_burn(feed_tokens, ctx.accounts.bananas_mint.decimals)?;
+        let state = &mut ctx.accounts.state;
+        let old_bananas_per_feed = state.effective_bananas_per_feed();
+        state.bananas_per_feed = new_bananas_per_feed;
+
+        emit!(BananasPerFeedUpdatedEvent {
+            old_bananas_per_feed,
+            new_bananas_per_feed,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Bananas per feed set: {} → {}", old_bananas_per_feed, new_bananas_per_feed);
+        Ok(())
+    }
+
+    /// Collection migration window: until `allow_unverified_until`, an NFT whose
+    /// metadata collection matches Gen2 / Gen3 / its registered collection / the pixel
+    /// goose collection is accepted even with `verified == false`
+    /// (UnverifiedCollectionAcceptedEvent on every use).
+    /// At most MAX_UNVERIFIED_WINDOW_SECONDS ahead; 0 closes the window immediately.
+    pub fn set_allow_unverified_until(ctx: Context<AdminOnly>, allow_unverified_until: i64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        if allow_unverified_until != 0 {
+            let max_until = now.checked_add(MAX_UNVERIFIED_WINDOW_SECONDS)
+                .ok_or(MonkeError::Overflow)?;
+            require!(
+                allow_unverified_until > now && allow_unverified_until <= max_until,
+                MonkeError::InvalidUnverifiedWindow
+            );
+        }
+        let state = &mut ctx.accounts.state;
+        let old_allow_unverified_until = state.allow_unverified_until;
+        state.allow_unverified_until = allow_unverified_until;
+
+        emit!(UnverifiedWindowSetEvent {
+            old_allow_unverified_until,
+            new_allow_unverified_until: allow_unverified_until,
+            authority: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+
+        msg!("Unverified collection window: {} → {}", old_allow_unverified_until, allow_unverified_until);
+        Ok(())
+    }
+
     pub fn set_pegged_mint(ctx: Context<AdminOnly>, pegged_mint: Pubkey) -> Result<()> {
         ctx.accounts.state.pegged_mint = pegged_mint;
         msg!("Pegged mint set to {}", pegged_mint);
         Ok(())
     }
 
+    /// Set the SPL reward token for deposit_token / claim_token. Only while nothing
+    /// has been distributed — token_reward_debt is denominated in this mint, so it
+    /// can't change once the accumulator has moved.
+    pub fn set_reward_token_mint(ctx: Context<AdminOnly>, reward_token_mint: Pubkey) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        require!(state.accumulated_token_per_share == 0, MonkeError::RewardTokenLocked);
+        let old_mint = state.reward_token_mint;
+        state.reward_token_mint = reward_token_mint;
+
+        emit!(RewardTokenMintSetEvent {
+            old_mint,
+            new_mint: reward_token_mint,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Reward token mint set to {}", reward_token_mint);
+        Ok(())
+    }
+
+    /// dist_pool changes use a 24-hour timelock (mirrors bin-farm's revenue_dest).
+    /// Step 1: propose_dist_pool sets pending_dist_pool + bump and dist_pool_change_at.
+    /// Step 2: apply_dist_pool (permissionless) applies it after the delay.
+    /// deposit_sol pulls from dist_pool, so holders get a day to see where revenue will come from.
+    /// Any target is accepted here; deposits check it (dist_pool_source): a
+    /// [b"dist_pool", new_dist_pool_bump] PDA is signed for by this program, any other
+    /// system-owned account (e.g. a new rover authority or a multisig) signs the
+    /// deposit itself. new_dist_pool_bump is ignored for the latter.
+    pub fn propose_dist_pool(
+        ctx: Context<AdminOnly>,
+        new_dist_pool: Pubkey,
+        new_dist_pool_bump: u8,
+    ) -> Result<()> {
+        require!(new_dist_pool != Pubkey::default(), MonkeError::InvalidDistPool);
+        let state = &mut ctx.accounts.state;
+        state.pending_dist_pool = new_dist_pool;
+        state.pending_dist_pool_bump = new_dist_pool_bump;
+        state.dist_pool_change_at = Clock::get()?.unix_timestamp
+            .checked_add(86_400).ok_or(MonkeError::Overflow)?; // 24 hours
+
+        emit!(DistPoolChangeProposedEvent {
+            current_dist_pool: state.dist_pool,
+            new_dist_pool,
+            effective_at: state.dist_pool_change_at,
+        });
+
+        msg!("dist_pool change proposed: {}, effective at {}", new_dist_pool, state.dist_pool_change_at);
+        Ok(())
+    }
+
+    /// Apply a previously proposed dist_pool change. Permissionless after 24hr.
+    pub fn apply_dist_pool(ctx: Context<ApplyDistPool>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        require!(state.dist_pool_change_at > 0, MonkeError::NoPendingDistPoolChange);
+        require!(
+            Clock::get()?.unix_timestamp >= state.dist_pool_change_at,
+            MonkeError::DistPoolTimelockNotExpired
+        );
+
+        let old_dist_pool = state.dist_pool;
+        state.dist_pool = state.pending_dist_pool;
+        state.dist_pool_bump = state.pending_dist_pool_bump;
+        state.pending_dist_pool = Pubkey::default();
+        state.pending_dist_pool_bump = 0;
+        state.dist_pool_change_at = 0;
+
+        emit!(DistPoolAppliedEvent {
+            old_dist_pool,
+            new_dist_pool: state.dist_pool,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("dist_pool applied: {} -> {}", old_dist_pool, state.dist_pool);
+        Ok(())
+    }
+
+    /// Cancel a pending dist_pool change. Admin only.
+    pub fn cancel_pending_dist_pool(ctx: Context<AdminOnly>) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        require!(state.dist_pool_change_at > 0, MonkeError::NoPendingDistPoolChange);
+        emit!(DistPoolChangeCancelledEvent {
+            cancelled_dist_pool: state.pending_dist_pool,
+            was_effective_at: state.dist_pool_change_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        state.pending_dist_pool = Pubkey::default();
+        state.pending_dist_pool_bump = 0;
+        state.dist_pool_change_at = 0;
+        msg!("Pending dist_pool change cancelled");
+        Ok(())
+    }
+
     /// Compost a dead monke — clean up MonkeBurn PDA for a burned SMB Gen2 NFT.
     /// Permissionless. Anyone can call for any NFT whose mint supply == 0.
-    /// Unclaimed SOL returns to program_vault (redistributed to living monkes).
-    /// Caller receives rent refund as incentive.
+    /// Unclaimed SOL is released from sol_liability, so reconcile_vault_surplus
+    /// redistributes it to living monkes. Caller receives rent refund as incentive.
     pub fn compost_monke(ctx: Context<CompostMonke>) -> Result<()> {
         // Verify the NFT has been burned (supply == 0)
         require!(ctx.accounts.nft_mint.supply == 0, MonkeError::NftNotBurned);
@@ -552,55 +1469,1083 @@ pub mod monke_bananas {
         let state = &mut ctx.accounts.state;
         let burn = &ctx.accounts.monke_burn;
 
-        // Compute unclaimed SOL for this dead monke
-        // unwrap_or(0) consistent with claim/feed_monke — prevents
-        // un-compostable monkes if reward_debt slightly exceeds weight * accumulator due to rounding
-        let pending_scaled = (burn.share_weight as u128)
-            .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?
-            .checked_sub(burn.reward_debt).unwrap_or(0);
-        // checked_div to comply with #![deny(clippy::integer_arithmetic)]
-        let unclaimed = (pending_scaled.checked_div(PRECISION).unwrap_or(0)) as u64;
+        // Remove the dead weight and release its unclaimed SOL from sol_liability:
+        // the lamports stay in program_vault as surplus until reconcile_vault_surplus
+        // credits them to the remaining weight.
+        let unclaimed = remove_monke(state, burn)?;
 
-        // Unclaimed SOL for this dead monke stays in program_vault as surplus.
-        // It is NOT redistributed via the accumulator. It implicitly subsidizes
-        // future claims because the vault balance exceeds what the accumulator tracks.
-        // We remove the dead weight so future deposit_sol increments are larger per share.
-
-        // Subtract dead weight from global total
-        state.total_share_weight = state.total_share_weight
-            .checked_sub(burn.share_weight).ok_or(MonkeError::Overflow)?;
+        let bounty_paid = pay_compost_bounty(
+            state,
+            &ctx.accounts.program_vault,
+            &ctx.accounts.caller.to_account_info(),
+        )?;
 
         emit!(CompostEvent {
             nft_mint: burn.nft_mint,
             weight_removed: burn.share_weight,
-            unclaimed_sol_absorbed: unclaimed, // SOL stays in program_vault as surplus, boosting future claims
+            unclaimed_sol_released: unclaimed,
+            new_total_weight: state.total_share_weight,
+            bounty_paid,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Composted dead monke: weight={} unclaimed={} bounty={}", burn.share_weight, unclaimed, bounty_paid);
+        // MonkeBurn PDA closed by Anchor `close = caller` constraint — rent to caller
+        Ok(())
+    }
+
+    /// Compost up to MAX_COMPOST_BATCH dead monkes in one call. Same accounting as
+    /// compost_monke per entry; each composted PDA's rent (and the compost bounty,
+    /// if set) goes to the caller.
+    /// Entries whose mint still has supply are skipped (logged), so one live NFT
+    /// doesn't abort the batch. Malformed entries (wrong PDA, mint mismatch,
+    /// duplicates) still fail the whole call.
+    ///
+    /// remaining_accounts: n × [monke_burn (writable), nft_mint]
+    pub fn compost_monke_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CompostMonkeBatch<'info>>,
+    ) -> Result<()> {
+        let rem = ctx.remaining_accounts;
+        require!(
+            !rem.is_empty() && rem.len().is_multiple_of(2),
+            MonkeError::InvalidCompostBatch
+        );
+        require!(rem.len() / 2 <= MAX_COMPOST_BATCH, MonkeError::InvalidCompostBatch);
+
+        let caller = ctx.accounts.caller.to_account_info();
+        let state = &mut ctx.accounts.state;
+        let timestamp = Clock::get()?.unix_timestamp;
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(rem.len() / 2);
+        let mut composted: u32 = 0;
+
+        for pair in rem.chunks(2) {
+            let (burn_info, mint_info) = (&pair[0], &pair[1]);
+            require!(!seen.contains(&burn_info.key()), MonkeError::InvalidCompostBatch);
+            seen.push(burn_info.key());
+
+            let burn = Account::<MonkeBurn>::try_from(burn_info)?;
+            require!(mint_info.key() == burn.nft_mint, MonkeError::InvalidNftMint);
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"monke_burn", burn.nft_mint.as_ref()],
+                &crate::ID,
+            );
+            require!(burn_info.key() == expected, MonkeError::InvalidCompostBatch);
+
+            let nft_mint = Account::<Mint>::try_from(mint_info)?;
+            if nft_mint.supply != 0 {
+                msg!("Skipping live monke {}", burn.nft_mint);
+                continue;
+            }
+
+            let unclaimed = remove_monke(state, &burn)?;
+            let bounty_paid = pay_compost_bounty(state, &ctx.accounts.program_vault, &caller)?;
+
+            emit!(CompostEvent {
+                nft_mint: burn.nft_mint,
+                weight_removed: burn.share_weight,
+                unclaimed_sol_released: unclaimed,
+                new_total_weight: state.total_share_weight,
+                bounty_paid,
+                timestamp,
+            });
+
+            burn.close(caller.clone())?;
+            composted = composted.checked_add(1).ok_or(MonkeError::Overflow)?;
+        }
+
+        msg!("Compost batch: {} composted, {} skipped",
+            composted, seen.len().saturating_sub(composted as usize));
+        Ok(())
+    }
+
+    /// Purge a MonkeBurn whose NFT no longer belongs to an approved collection
+    /// (e.g. after a collection change). Permissionless, rent to caller.
+    /// Re-runs the same collection checks as feed_monke / feed_goose against the
+    /// current state; only a clean collection mismatch on both counts as ineligible —
+    /// malformed or mismatched metadata reverts instead of purging.
+    /// Unclaimed SOL is released from sol_liability so reconcile_vault_surplus
+    /// can redistribute it to the remaining eligible holders.
+    pub fn purge_ineligible_monke(ctx: Context<PurgeIneligibleMonke>) -> Result<()> {
+        let state = &ctx.accounts.state;
+        let nft_mint = ctx.accounts.nft_mint.key();
+
+        let smb_check = validate_collection_and_weight(
+            &ctx.accounts.nft_metadata,
+            &nft_mint,
+            state,
+            None,
+        );
+        let goose_check = validate_goose_pixel_collection(&ctx.accounts.nft_metadata, &nft_mint, state);
+        let smb_mismatch = matches!(&smb_check, Err(e) if *e == MonkeError::InvalidCollection.into());
+        let goose_mismatch = matches!(&goose_check, Err(e) if *e == MonkeError::InvalidGooseCollection.into());
+        if !(smb_mismatch && goose_mismatch) {
+            smb_check?;
+            goose_check?;
+            return err!(MonkeError::MonkeStillEligible);
+        }
+
+        // Registry collections stay eligible even when disabled (disable only
+        // blocks new feeds). The caller must pass the registry PDA for the NFT's
+        // collection so an existing entry can't be hidden by omitting it.
+        let (collection_key, _) = read_collection_key(&ctx.accounts.nft_metadata, &nft_mint)?;
+        let (expected_config, _) = Pubkey::find_program_address(
+            &[b"collection", collection_key.as_ref()],
+            &crate::ID,
+        );
+        let config_info = &ctx.accounts.collection_config;
+        require!(config_info.key() == expected_config, MonkeError::InvalidCollectionConfig);
+        require!(
+            config_info.owner != &crate::ID || config_info.data_is_empty(),
+            MonkeError::MonkeStillEligible
+        );
+
+        let state = &mut ctx.accounts.state;
+        let burn = &ctx.accounts.monke_burn;
+        let unclaimed = remove_monke(state, burn)?;
+
+        emit!(PurgeEvent {
+            nft_mint: burn.nft_mint,
+            weight_removed: burn.share_weight,
+            unclaimed_sol_released: unclaimed,
             new_total_weight: state.total_share_weight,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("Composted dead monke: weight={} unclaimed={}", burn.share_weight, unclaimed);
+        msg!("Purged ineligible monke: weight={} unclaimed={}", burn.share_weight, unclaimed);
         // MonkeBurn PDA closed by Anchor `close = caller` constraint — rent to caller
         Ok(())
     }
+
+    /// Permissionless: grow MonkeState to MonkeState::SIZE after an upgrade appended
+    /// fields. New fields start zeroed (no reward token configured). Payer covers the
+    /// rent top-up. Every instruction that loads MonkeState fails until this runs.
+    pub fn migrate_state(ctx: Context<MigrateState>) -> Result<()> {
+        let info = ctx.accounts.state.to_account_info();
+        require!(*info.owner == crate::ID, MonkeError::InvalidMigrationAccount);
+        require!(
+            info.try_borrow_data()?.get(..8) == Some(MonkeState::DISCRIMINATOR),
+            MonkeError::InvalidMigrationAccount
+        );
+        let old_len = info.data_len();
+
+        grow_account(
+            &info,
+            MonkeState::SIZE,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        emit!(AccountMigratedEvent {
+            account: info.key(),
+            old_len: old_len as u32,
+            new_len: info.data_len() as u32,
+            payer: ctx.accounts.payer.key(),
+        });
+
+        msg!("MonkeState migrated: {} -> {} bytes", old_len, info.data_len());
+        Ok(())
+    }
+
+    /// Permissionless: grow a MonkeBurn created before token_reward_debt existed.
+    /// token_reward_debt starts at 0, which is exact — a legacy MonkeBurn can't change
+    /// weight until migrated, so it held its current weight for every token deposit.
+    /// last_interaction_ts starts at 0: decay counts from state.decay_start_ts until
+    /// the next feed (decay_clock). unclaimed_sol starts at 0 — only defeed_monke
+    /// credits it, and defeed needs a migrated MonkeBurn.
+    /// Payer covers the rent top-up. No-op if already current.
+    pub fn migrate_monke_burn(ctx: Context<MigrateMonkeBurn>) -> Result<()> {
+        let info = ctx.accounts.monke_burn.to_account_info();
+        require!(*info.owner == crate::ID, MonkeError::InvalidMigrationAccount);
+        let nft_mint = {
+            let data = info.try_borrow_data()?;
+            require!(
+                data.get(..8) == Some(MonkeBurn::DISCRIMINATOR),
+                MonkeError::InvalidMigrationAccount
+            );
+            let raw = data.get(8..40).ok_or(MonkeError::InvalidMigrationAccount)?;
+            Pubkey::try_from(raw).map_err(|_| MonkeError::InvalidMigrationAccount)?
+        };
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"monke_burn", nft_mint.as_ref()],
+            &crate::ID,
+        );
+        require!(info.key() == expected, MonkeError::InvalidMigrationAccount);
+        let old_len = info.data_len();
+
+        grow_account(
+            &info,
+            MonkeBurn::SIZE,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        emit!(AccountMigratedEvent {
+            account: info.key(),
+            old_len: old_len as u32,
+            new_len: info.data_len() as u32,
+            payer: ctx.accounts.payer.key(),
+        });
+
+        msg!("MonkeBurn {} migrated: {} -> {} bytes", nft_mint, old_len, info.data_len());
+        Ok(())
+    }
 }
 
 // ============ HELPERS ============
 
-/// Validate that an NFT belongs to the SMB Gen2 or Gen3 collection by deserializing
-/// its Metaplex metadata account and checking the collection field.
-/// Returns weight multiplier: 2 for Gen2, 1 for Gen3.
+/// reconcile_vault_surplus's accounting: credit program_vault's balance above rent
+/// (`available`) and sol_liability to the accumulator and return the amount.
+fn credit_vault_surplus(state: &mut MonkeState, available: u64) -> Result<u64> {
+    require!(state.liability_initialized, MonkeError::LiabilityNotInitialized);
+    require!(state.total_share_weight > 0, MonkeError::NoMonkes);
+
+    let surplus = available.saturating_sub(state.sol_liability);
+    require!(surplus > 0, MonkeError::NothingToReconcile);
+
+    let increment = (surplus as u128)
+        .checked_mul(PRECISION).ok_or(MonkeError::Overflow)?
+        .checked_div(state.total_share_weight as u128).ok_or(MonkeError::Overflow)?;
+    require!(increment > 0, MonkeError::NothingToReconcile);
+
+    state.accumulated_sol_per_share = state.accumulated_sol_per_share
+        .checked_add(increment).ok_or(MonkeError::Overflow)?;
+    state.total_sol_distributed = state.total_sol_distributed
+        .checked_add(surplus).ok_or(MonkeError::Overflow)?;
+    state.sol_liability = state.sol_liability
+        .checked_add(surplus).ok_or(MonkeError::Overflow)?;
+    Ok(surplus)
+}
+
+/// Drop a MonkeBurn that is being closed (compost, purge) from the global totals and
+/// release its unclaimed SOL from sol_liability. Returns the unclaimed lamports.
+/// Floored at 0 like claim — a reward_debt slightly above weight * accumulator from
+/// rounding can't make a monke un-closable.
+fn remove_monke(state: &mut MonkeState, monke_burn: &MonkeBurn) -> Result<u64> {
+    let pending_scaled = sol_pending_scaled(
+        monke_burn, monke_burn.share_weight, state.accumulated_sol_per_share,
+    )?;
+    let unclaimed = pending_scaled.checked_div(PRECISION).unwrap_or(0) as u64;
+
+    state.total_share_weight = state.total_share_weight
+        .checked_sub(monke_burn.share_weight).ok_or(MonkeError::Overflow)?;
+    remove_pending_weight(state, monke_burn)?;
+    state.sol_liability = state.sol_liability.saturating_sub(unclaimed);
+    Ok(unclaimed)
+}
+
+/// Shared body of deposit_sol / deposit_sol_snapshot: move dist_pool's balance above
+/// rent into program_vault and advance the accumulator over total_share_weight.
+fn execute_deposit_sol<'info>(
+    state: &mut MonkeState,
+    dist_pool: &AccountInfo<'info>,
+    program_vault: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    require!(state.total_share_weight > 0, MonkeError::NoMonkes);
+
+    // Calculate distributable SOL from dist_pool (minus rent-exempt minimum)
+    let pool_balance = dist_pool.lamports();
+    let rent = rent_floor(dist_pool)?;
+    let distributable = pool_balance.saturating_sub(rent);
+    require!(distributable >= state.effective_min_deposit(), MonkeError::NothingToDeposit);
+
+    match dist_pool_source(&dist_pool.key(), dist_pool.owner, dist_pool.is_signer, state.dist_pool_bump)? {
+        DistPoolSource::ProgramOwned => {
+            // Direct lamport manipulation instead of system_instruction::transfer.
+            // A program-owned dist_pool can't be the source of a system transfer.
+            let pool_after = pool_balance.checked_sub(distributable).ok_or(MonkeError::Overflow)?;
+            let vault_after = program_vault.lamports().checked_add(distributable).ok_or(MonkeError::Overflow)?;
+            **dist_pool.try_borrow_mut_lamports()? = pool_after;
+            **program_vault.try_borrow_mut_lamports()? = vault_after;
+        }
+        DistPoolSource::ProgramSigned => {
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: dist_pool.clone(),
+                        to: program_vault.clone(),
+                    },
+                    &[&[b"dist_pool", &[state.dist_pool_bump]]],
+                ),
+                distributable,
+            )?;
+        }
+        DistPoolSource::SelfSigned => {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: dist_pool.clone(),
+                        to: program_vault.clone(),
+                    },
+                ),
+                distributable,
+            )?;
+        }
+    }
+
+    credit_sol_deposit(state, distributable)?;
+
+    emit!(DepositEvent {
+        amount: distributable,
+        total_distributed: state.total_sol_distributed,
+        accumulator: state.accumulated_sol_per_share,
+        total_share_weight: state.total_share_weight,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(distributable)
+}
+
+/// How deposit_sol / deposit_pegged move funds out of state.dist_pool.
+#[derive(Debug, PartialEq, Eq)]
+enum DistPoolSource {
+    /// A [b"dist_pool", bump] PDA owned by this program: lamports are debited directly.
+    ProgramOwned,
+    /// A [b"dist_pool", bump] PDA still owned by the system program: this program signs.
+    ProgramSigned,
+    /// Any other system-owned account (set via propose_dist_pool): it signs the deposit.
+    SelfSigned,
+}
+
+/// Classify the dist_pool account a deposit pulls from. Program-owned accounts must be
+/// a dist_pool PDA, so program_vault / MonkeState can never stand in as the source.
+fn dist_pool_source(key: &Pubkey, owner: &Pubkey, is_signer: bool, bump: u8) -> Result<DistPoolSource> {
+    let is_pda = Pubkey::create_program_address(&[b"dist_pool", &[bump]], &crate::ID).ok() == Some(*key);
+    if *owner == crate::ID {
+        require!(is_pda, MonkeError::InvalidDistPool);
+        Ok(DistPoolSource::ProgramOwned)
+    } else if *owner == anchor_lang::system_program::ID {
+        if is_pda {
+            Ok(DistPoolSource::ProgramSigned)
+        } else {
+            require!(is_signer, MonkeError::DistPoolNotSigner);
+            Ok(DistPoolSource::SelfSigned)
+        }
+    } else {
+        err!(MonkeError::InvalidDistPool)
+    }
+}
+
+/// Update accumulator: add (deposit * PRECISION / total_weight). The SOL side only —
+/// accumulated_token_per_share is credited separately by credit_token_deposit.
+fn credit_sol_deposit(state: &mut MonkeState, amount: u64) -> Result<()> {
+    let increment = (amount as u128)
+        .checked_mul(PRECISION).ok_or(MonkeError::Overflow)?
+        .checked_div(state.total_share_weight as u128).ok_or(MonkeError::Overflow)?;
+
+    state.accumulated_sol_per_share = state.accumulated_sol_per_share
+        .checked_add(increment).ok_or(MonkeError::Overflow)?;
+    state.total_sol_distributed = state.total_sol_distributed
+        .checked_add(amount).ok_or(MonkeError::Overflow)?;
+    state.sol_liability = state.sol_liability
+        .checked_add(amount).ok_or(MonkeError::Overflow)?;
+    Ok(())
+}
+
+/// deposit_token's accounting: validate and credit `amount` reward tokens to
+/// accumulated_token_per_share over total_share_weight. The SOL accumulator is untouched.
+fn credit_token_deposit(state: &mut MonkeState, amount: u64) -> Result<()> {
+    require!(state.total_share_weight > 0, MonkeError::NoMonkes);
+    require!(state.reward_token_mint != Pubkey::default(), MonkeError::RewardTokenNotConfigured);
+    require!(amount > 0, MonkeError::NothingToDeposit);
+
+    let increment = (amount as u128)
+        .checked_mul(PRECISION).ok_or(MonkeError::Overflow)?
+        .checked_div(state.total_share_weight as u128).ok_or(MonkeError::Overflow)?;
+    // A zero increment would strand the tokens in the vault
+    require!(increment > 0, MonkeError::NothingToDeposit);
+
+    state.accumulated_token_per_share = state.accumulated_token_per_share
+        .checked_add(increment).ok_or(MonkeError::Overflow)?;
+    state.total_token_distributed = state.total_token_distributed
+        .checked_add(amount).ok_or(MonkeError::Overflow)?;
+    Ok(())
+}
+
+/// Shared payout for claim / claim_to. Caller has already validated NFT ownership.
+/// Moves `owed` lamports program_vault → recipient and resets reward_debt.
+fn execute_claim(
+    state: &mut MonkeState,
+    monke_burn: &mut MonkeBurn,
+    program_vault: &AccountInfo,
+    recipient: &AccountInfo,
+) -> Result<u64> {
+    let owed = settle_claim(state, monke_burn, Clock::get()?.unix_timestamp)?;
+
+    require!(owed > 0, MonkeError::NothingToClaim);
+
+    pay_from_vault(program_vault, recipient, owed)?;
+
+    Ok(owed)
+}
+
+/// Book a full claim against one MonkeBurn: settle decay owed, then reset
+/// reward_debt, empty unclaimed_sol, count claimed_sol and release sol_liability for
+/// everything pending at the decayed weight plus what defeed_monke held. Returns
+/// the lamports owed; paying them is the caller's job (claim_all pays once for the
+/// batch).
+fn settle_claim(state: &mut MonkeState, monke_burn: &mut MonkeBurn, now: i64) -> Result<u64> {
+    settle_decay(monke_burn, state, now)?;
+    let owed = pending_owed(monke_burn, state, now)?;
+
+    // Update reward_debt and claimed_sol.
+    // Set reward_debt = weight * accumulator so next claim starts from zero pending.
+    // We subtract (owed * PRECISION) remainder to avoid rounding dust accumulation:
+    // reward_debt = weight * accumulator - (pending_scaled - owed * PRECISION)
+    // Simplified: reward_debt = weight * accumulator (standard MasterChef reset)
+    monke_burn.reward_debt = (monke_burn.share_weight as u128)
+        .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?;
+    monke_burn.unclaimed_sol = 0;
+    monke_burn.claimed_sol = monke_burn.claimed_sol
+        .checked_add(owed).ok_or(MonkeError::Overflow)?;
+
+    // Claimed SOL leaves the tracked liability. Saturating: rounding dust means
+    // the sum of claims can never exceed deposits, but never revert a claim on it.
+    state.sol_liability = state.sol_liability.saturating_sub(owed);
+    Ok(owed)
+}
+
+/// claim_partial's booking: settle decay owed, then take `amount` out of what is
+/// pending — unclaimed_sol first, the rest by advancing reward_debt by exactly
+/// that remainder * PRECISION. The claim that takes the last owed lamport resets
+/// reward_debt to weight * accumulator instead, dropping the same sub-lamport dust
+/// settle_claim does, so the pieces end exactly where one full claim would. Returns
+/// the SOL owed before the claim; paying `amount` is the caller's job.
+fn settle_partial_claim(
+    state: &mut MonkeState,
+    monke_burn: &mut MonkeBurn,
+    amount: u64,
+    now: i64,
+) -> Result<u64> {
+    settle_decay(monke_burn, state, now)?;
+    let owed = pending_owed(monke_burn, state, now)?;
+    require!(amount > 0, MonkeError::NothingToClaim);
+    require!(amount <= owed, MonkeError::ClaimExceedsOwed);
+
+    let from_buffer = core::cmp::min(amount, monke_burn.unclaimed_sol);
+    let from_rewards = amount.checked_sub(from_buffer).ok_or(MonkeError::Overflow)?;
+    monke_burn.unclaimed_sol = monke_burn.unclaimed_sol
+        .checked_sub(from_buffer).ok_or(MonkeError::Overflow)?;
+    monke_burn.reward_debt = if amount == owed {
+        (monke_burn.share_weight as u128)
+            .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?
+    } else {
+        monke_burn.reward_debt
+            .checked_add((from_rewards as u128).checked_mul(PRECISION).ok_or(MonkeError::Overflow)?)
+            .ok_or(MonkeError::Overflow)?
+    };
+    monke_burn.claimed_sol = monke_burn.claimed_sol
+        .checked_add(amount).ok_or(MonkeError::Overflow)?;
+    state.sol_liability = state.sol_liability.saturating_sub(amount);
+    Ok(owed)
+}
+
+/// Book defeed_monke's weight drop against one MonkeBurn. Decay settles first, so
+/// `weight` is bounded by the decayed share_weight and total_share_weight loses the
+/// decay and the defed weight exactly once each. SOL pending at the decayed weight
+/// moves to unclaimed_sol for the next claim — still owed, so sol_liability and
+/// claimed_sol are untouched. Returns the lamports moved.
+fn settle_defeed(
+    state: &mut MonkeState,
+    monke_burn: &mut MonkeBurn,
+    weight: u64,
+    now: i64,
+) -> Result<u64> {
+    settle_decay(monke_burn, state, now)?;
+    let owed = sol_pending_scaled(monke_burn, monke_burn.share_weight, state.accumulated_sol_per_share)?
+        .checked_div(PRECISION).unwrap_or(0) as u64;
+    monke_burn.unclaimed_sol = monke_burn.unclaimed_sol
+        .checked_add(owed).ok_or(MonkeError::Overflow)?;
+
+    let current_weight = monke_burn.share_weight;
+    require!(weight > 0 && weight <= current_weight, MonkeError::InvalidDefeedWeight);
+    monke_burn.share_weight = current_weight
+        .checked_sub(weight).ok_or(MonkeError::Overflow)?;
+    monke_burn.reward_debt = (monke_burn.share_weight as u128)
+        .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?;
+    monke_burn.token_reward_debt = (monke_burn.share_weight as u128)
+        .checked_mul(state.accumulated_token_per_share).ok_or(MonkeError::Overflow)?;
+    state.total_share_weight = state.total_share_weight
+        .checked_sub(weight).ok_or(MonkeError::Overflow)?;
+    Ok(owed)
+}
+
+/// claim_token's booking: reset token_reward_debt and count claimed_token for the
+/// reward tokens pending at the decayed weight, which it returns. reward_debt and
+/// the SOL owed are left alone.
+fn settle_token_claim(state: &MonkeState, monke_burn: &mut MonkeBurn, now: i64) -> Result<u64> {
+    let owed = pending_token_owed(monke_burn, state, now)?;
+    monke_burn.token_reward_debt = (monke_burn.share_weight as u128)
+        .checked_mul(state.accumulated_token_per_share).ok_or(MonkeError::Overflow)?;
+    monke_burn.claimed_token = monke_burn.claimed_token
+        .checked_add(owed).ok_or(MonkeError::Overflow)?;
+    Ok(owed)
+}
+
+/// Rent-exempt minimum for a lamport-holding PDA (dist_pool, program_vault) at its
+/// current data length, so the floor stays right if the account ever carries data.
+fn rent_floor(account: &AccountInfo) -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(account.data_len()))
+}
+
+/// Move `amount` lamports program_vault → recipient, keeping the vault rent-exempt.
+/// Direct lamport manipulation instead of system_instruction::transfer:
+/// program_vault is program-owned (PDA), not system-owned.
+fn pay_from_vault(program_vault: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+    let rent_minimum = rent_floor(program_vault)?;
+    let vault_lamports = program_vault.lamports();
+    require!(
+        vault_lamports >= amount.checked_add(rent_minimum).ok_or(MonkeError::Overflow)?,
+        MonkeError::InsufficientVaultBalance
+    );
+
+    let vault_after = vault_lamports.checked_sub(amount).ok_or(MonkeError::Overflow)?;
+    let recipient_after = recipient.lamports().checked_add(amount).ok_or(MonkeError::Overflow)?;
+    **program_vault.try_borrow_mut_lamports()? = vault_after;
+    **recipient.try_borrow_mut_lamports()? = recipient_after;
+    Ok(())
+}
+
+/// Pay state.compost_bounty_lamports to the composter. The bounty comes only out of
+/// vault surplus (balance above rent and sol_liability), so it never eats into SOL
+/// owed to holders; if the surplus can't cover it the compost reverts with
+/// CompostBountyExceedsHeadroom. Returns the amount paid (0 when the bounty is off).
+/// A set bounty reverts with LiabilityNotInitialized until init_sol_liability has run.
+fn pay_compost_bounty(state: &MonkeState, program_vault: &AccountInfo, caller: &AccountInfo) -> Result<u64> {
+    if state.compost_bounty_lamports == 0 {
+        return Ok(0);
+    }
+    let bounty = compost_bounty_due(state, program_vault.lamports(), rent_floor(program_vault)?)?;
+    pay_from_vault(program_vault, caller, bounty)?;
+    Ok(bounty)
+}
+
+/// pay_compost_bounty's headroom check against a vault holding `vault_lamports`
+/// with rent-exempt minimum `rent_minimum`.
+fn compost_bounty_due(state: &MonkeState, vault_lamports: u64, rent_minimum: u64) -> Result<u64> {
+    let bounty = state.compost_bounty_lamports;
+    if bounty == 0 {
+        return Ok(0);
+    }
+    require!(state.liability_initialized, MonkeError::LiabilityNotInitialized);
+    let headroom = vault_lamports
+        .saturating_sub(rent_minimum)
+        .saturating_sub(state.sol_liability);
+    require!(bounty <= headroom, MonkeError::CompostBountyExceedsHeadroom);
+    Ok(bounty)
+}
+
+/// MasterChef settlement + weight increment for one feed.
+/// reward_debt is stored in the same PRECISION-scaled units as
+/// (weight * accumulated_sol_per_share). All math stays in that scale
+/// until the final claim division to avoid precision loss.
+/// While a snapshot distribution is open the weight is parked in pending_weight
+/// instead (returns true); the caller adds it to state.pending_share_weight.
+fn settle_feed(
+    monke_burn: &mut MonkeBurn,
+    nft_mint: &Pubkey,
+    state: &MonkeState,
+    weight_multiplier: u64,
+) -> Result<bool> {
+    let accumulated = state.accumulated_sol_per_share;
+    let accumulated_token = state.accumulated_token_per_share;
+    if monke_burn.share_weight == 0 && monke_burn.pending_weight == 0 {
+        // First burn — initialize the PDA fields
+        monke_burn.nft_mint = *nft_mint;
+        monke_burn.first_fed_at = Clock::get()?.unix_timestamp;
+        monke_burn.claimed_sol = 0;
+        monke_burn.reward_debt = 0;
+        monke_burn.claimed_token = 0;
+        monke_burn.token_reward_debt = 0;
+        monke_burn.pending_epoch = 0;
+    }
+
+    // Weight parked in an earlier window must be folded in first (activate_pending_weight)
+    let in_open_window = state.distribution_open
+        && monke_burn.pending_epoch == state.distribution_epoch;
+    require!(
+        monke_burn.pending_weight == 0 || in_open_window,
+        MonkeError::PendingWeightNotActivated
+    );
+    if state.distribution_open {
+        monke_burn.pending_weight = monke_burn.pending_weight
+            .checked_add(weight_multiplier).ok_or(MonkeError::Overflow)?;
+        monke_burn.pending_epoch = state.distribution_epoch;
+        return Ok(true);
+    }
+
+    // Calculate pending rewards at current weight (PRECISION-scaled)
+    let pending_scaled = sol_pending_scaled(monke_burn, monke_burn.share_weight, accumulated)?;
+    let pending_token_scaled = (monke_burn.share_weight as u128)
+        .checked_mul(accumulated_token).ok_or(MonkeError::Overflow)?
+        .saturating_sub(monke_burn.token_reward_debt);
+
+    // Increment weight (Gen2 = +2, Gen3 = +1)
+    monke_burn.share_weight = monke_burn.share_weight
+        .checked_add(weight_multiplier).ok_or(MonkeError::Overflow)?;
+
+    // Update reward_debt for new weight, preserving pending rewards.
+    //   new_debt = new_weight * accumulated - pending_scaled
+    // This ensures the pending amount earned before this burn is still
+    // claimable, while the new weight unit starts earning from now.
+    let new_entitled = (monke_burn.share_weight as u128)
+        .checked_mul(accumulated).ok_or(MonkeError::Overflow)?;
+    monke_burn.reward_debt = new_entitled.saturating_sub(pending_scaled);
+    monke_burn.token_reward_debt =
+        rebase_token_debt(monke_burn.share_weight, accumulated_token, pending_token_scaled)?;
+    Ok(false)
+}
+
+/// Start of a MonkeBurn's decay clock. last_interaction_ts is set by every feed;
+/// monkes that haven't fed since it existed (0 after migrate_monke_burn) count from
+/// when decay was turned on, or from their first feed if that is later.
+fn decay_clock(monke_burn: &MonkeBurn, state: &MonkeState) -> i64 {
+    if monke_burn.last_interaction_ts != 0 {
+        monke_burn.last_interaction_ts
+    } else {
+        monke_burn.first_fed_at.max(state.decay_start_ts)
+    }
+}
+
+/// Decay owed by a MonkeBurn right now: (effective weight, whole epochs elapsed).
+/// share_weight is multiplied by (1 - decay_bps/10_000)^epochs in PRECISION fixed
+/// point (square-and-multiply) and rounded to the nearest whole weight.
+fn decay_due(monke_burn: &MonkeBurn, state: &MonkeState, now: i64) -> Result<(u64, u64)> {
+    let weight = monke_burn.share_weight;
+    let clock = decay_clock(monke_burn, state);
+    if !state.decay_enabled() || weight == 0 || clock == 0 {
+        return Ok((weight, 0));
+    }
+    let elapsed = now.saturating_sub(clock).max(0) as u64;
+    let epochs = elapsed.checked_div(state.epoch_len_seconds as u64).ok_or(MonkeError::Overflow)?;
+    if epochs == 0 {
+        return Ok((weight, 0));
+    }
+
+    let mut base = (10_000u128)
+        .checked_sub(state.decay_bps_per_epoch as u128).ok_or(MonkeError::Overflow)?
+        .checked_mul(PRECISION).ok_or(MonkeError::Overflow)?
+        .checked_div(10_000).ok_or(MonkeError::Overflow)?;
+    let mut factor = PRECISION;
+    let mut exp = epochs;
+    while exp > 0 && factor > 0 {
+        if exp.checked_rem(2) == Some(1) {
+            factor = factor.checked_mul(base).ok_or(MonkeError::Overflow)?
+                .checked_div(PRECISION).ok_or(MonkeError::Overflow)?;
+        }
+        base = base.checked_mul(base).ok_or(MonkeError::Overflow)?
+            .checked_div(PRECISION).ok_or(MonkeError::Overflow)?;
+        exp = exp.checked_div(2).ok_or(MonkeError::Overflow)?;
+    }
+
+    let half = PRECISION.checked_div(2).ok_or(MonkeError::Overflow)?;
+    let effective = (weight as u128)
+        .checked_mul(factor).ok_or(MonkeError::Overflow)?
+        .checked_add(half).ok_or(MonkeError::Overflow)?
+        .checked_div(PRECISION).ok_or(MonkeError::Overflow)?;
+    Ok((effective as u64, epochs))
+}
+
+/// Rewards pending at `old_weight` (PRECISION-scaled) that survive decay to
+/// `new_weight`: unclaimed rewards decay with the weight that earned them.
+fn decay_pending(pending_scaled: u128, old_weight: u64, new_weight: u64) -> Result<u128> {
+    if new_weight >= old_weight {
+        return Ok(pending_scaled);
+    }
+    Ok(pending_scaled
+        .checked_mul(new_weight as u128).ok_or(MonkeError::Overflow)?
+        .checked_div(old_weight as u128).ok_or(MonkeError::Overflow)?)
+}
+
+/// Per-NFT lazy decay, applied when the MonkeBurn is touched (feeds, claims, defeed).
+/// The weight drops to decay_due's effective weight and unclaimed SOL and reward
+/// tokens shrink with it (decay_pending), so a monke earns at its effective weight
+/// whether or not anyone touches it — pending_owed / pending_token_owed report the
+/// same amounts before the decay is written. The decay clock advances by the whole
+/// epochs consumed. Weights are integers, so the clock only advances once decay
+/// actually removes a unit — smaller decay keeps accruing.
 ///
-/// Metaplex metadata layout (simplified — we parse from raw bytes):
-///   key(1) + update_authority(32) + mint(32) + name(4+32) + symbol(4+10) + uri(4+200)
-///   + seller_fee_basis_points(2) + creators_option(1) + [if Some: count(4) + creators(34*n)]
-///   + primary_sale_happened(1) + is_mutable(1)
-///   + collection_option(1) + [if Some: verified(1) + key(32)]
+/// The removed weight leaves total_share_weight and the forfeited SOL leaves
+/// sol_liability (reconcile_vault_surplus redistributes it). Between touches
+/// total_share_weight counts every monke at its stored weight, an upper bound on the
+/// decayed total, so deposits never credit more than program_vault holds.
+/// Returns the weight removed.
+fn settle_decay(monke_burn: &mut MonkeBurn, state: &mut MonkeState, now: i64) -> Result<u64> {
+    let (new_weight, epochs) = decay_due(monke_burn, state, now)?;
+    let old_weight = monke_burn.share_weight;
+    if new_weight >= old_weight {
+        return Ok(0);
+    }
+
+    let pending_scaled = sol_pending_scaled(monke_burn, old_weight, state.accumulated_sol_per_share)?;
+    let pending_token_scaled = (old_weight as u128)
+        .checked_mul(state.accumulated_token_per_share).ok_or(MonkeError::Overflow)?
+        .saturating_sub(monke_burn.token_reward_debt);
+    let kept = decay_pending(pending_scaled, old_weight, new_weight)?;
+    let kept_token = decay_pending(pending_token_scaled, old_weight, new_weight)?;
+
+    monke_burn.share_weight = new_weight;
+    monke_burn.reward_debt = (new_weight as u128)
+        .checked_mul(state.accumulated_sol_per_share).ok_or(MonkeError::Overflow)?
+        .checked_sub(kept).ok_or(MonkeError::Overflow)?;
+    monke_burn.token_reward_debt = (new_weight as u128)
+        .checked_mul(state.accumulated_token_per_share).ok_or(MonkeError::Overflow)?
+        .checked_sub(kept_token).ok_or(MonkeError::Overflow)?;
+    let consumed = (epochs as i64)
+        .checked_mul(state.epoch_len_seconds as i64).ok_or(MonkeError::Overflow)?;
+    monke_burn.last_interaction_ts = decay_clock(monke_burn, state)
+        .checked_add(consumed).ok_or(MonkeError::Overflow)?;
+
+    let removed = old_weight.checked_sub(new_weight).ok_or(MonkeError::Overflow)?;
+    let forfeited_sol = (pending_scaled.checked_div(PRECISION).unwrap_or(0) as u64)
+        .saturating_sub(kept.checked_div(PRECISION).unwrap_or(0) as u64);
+    state.total_share_weight = state.total_share_weight
+        .checked_sub(removed).ok_or(MonkeError::Overflow)?;
+    state.sol_liability = state.sol_liability.saturating_sub(forfeited_sol);
+
+    emit!(WeightDecayEvent {
+        nft_mint: monke_burn.nft_mint,
+        old_weight,
+        new_weight,
+        epochs,
+        forfeited_sol,
+        timestamp: now,
+    });
+    Ok(removed)
+}
+
+/// Add fed weight to the live total, or to pending_share_weight when settle_feed
+/// parked it for the open snapshot window.
+fn add_fed_weight(state: &mut MonkeState, weight: u64, pending: bool) -> Result<()> {
+    if pending {
+        state.pending_share_weight = state.pending_share_weight
+            .checked_add(weight).ok_or(MonkeError::Overflow)?;
+    } else {
+        state.total_share_weight = state.total_share_weight
+            .checked_add(weight).ok_or(MonkeError::Overflow)?;
+    }
+    Ok(())
+}
+
+/// Drop a closing MonkeBurn's unactivated pending_weight from whichever global
+/// total holds it: pending_share_weight while its window is open, otherwise
+/// total_share_weight (close_distribution already moved it there).
+fn remove_pending_weight(state: &mut MonkeState, monke_burn: &MonkeBurn) -> Result<()> {
+    if monke_burn.pending_weight == 0 {
+        return Ok(());
+    }
+    if state.distribution_open && monke_burn.pending_epoch == state.distribution_epoch {
+        state.pending_share_weight = state.pending_share_weight
+            .checked_sub(monke_burn.pending_weight).ok_or(MonkeError::Overflow)?;
+    } else {
+        state.total_share_weight = state.total_share_weight
+            .checked_sub(monke_burn.pending_weight).ok_or(MonkeError::Overflow)?;
+    }
+    Ok(())
+}
+
+/// End the open snapshot window: record the post-deposit accumulators and move the
+/// window's pending weight into total_share_weight so it earns from here on.
+fn close_distribution(
+    state: &mut MonkeState,
+    distribution: &mut Distribution,
+    amount: u64,
+) -> Result<()> {
+    distribution.closed = true;
+    distribution.amount = amount;
+    distribution.acc_after = state.accumulated_sol_per_share;
+    distribution.token_acc_after = state.accumulated_token_per_share;
+    distribution.late_weight = state.pending_share_weight;
+
+    state.total_share_weight = state.total_share_weight
+        .checked_add(state.pending_share_weight).ok_or(MonkeError::Overflow)?;
+    state.pending_share_weight = 0;
+    state.distribution_open = false;
+
+    emit!(DistributionClosedEvent {
+        epoch: distribution.epoch,
+        amount,
+        snapshot_weight: distribution.snapshot_weight,
+        late_weight: distribution.late_weight,
+        accumulator: distribution.acc_after,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// token_reward_debt for a new weight that keeps `pending_token_scaled` claimable
+/// (same rebase as reward_debt in settle_feed, against the token accumulator).
+fn rebase_token_debt(
+    new_weight: u64,
+    accumulated_token: u128,
+    pending_token_scaled: u128,
+) -> Result<u128> {
+    Ok((new_weight as u128)
+        .checked_mul(accumulated_token).ok_or(MonkeError::Overflow)?
+        .saturating_sub(pending_token_scaled))
+}
+
+/// Grow a program account to `size` (zero-filled), topping up rent from `payer`.
+/// No-op when already at least `size`. Used by migrate_state / migrate_monke_burn.
+fn grow_account<'info>(
+    info: &AccountInfo<'info>,
+    size: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if info.data_len() >= size {
+        return Ok(());
+    }
+    let required = Rent::get()?.minimum_balance(size)
+        .saturating_sub(info.lamports());
+    if required > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            required,
+        )?;
+    }
+    info.resize(size)?;
+    Ok(())
+}
+
+/// MonkeBurn for the SOL claim paths (claim, claim_partial, claim_to, claim_all). One
+/// created before token_reward_debt existed is grown in place first — the same zero-fill
+/// migrate_monke_burn does, `payer` covering the rent top-up — so a legacy account never
+/// blocks a claim. The caller writes it back with store_monke_burn.
+fn load_monke_burn<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<MonkeBurn> {
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    grow_account(info, MonkeBurn::SIZE, payer, system_program)?;
+    let data = info.try_borrow_data()?;
+    MonkeBurn::try_deserialize(&mut &data[..])
+}
+
+/// Write back a MonkeBurn loaded with load_monke_burn.
+fn store_monke_burn(info: &AccountInfo, monke_burn: &MonkeBurn) -> Result<()> {
+    monke_burn.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+}
+
+/// Create a MonkeBurn PDA for feed_monke_batch (the init_if_needed equivalent for
+/// accounts passed via remaining_accounts). Tops up rent if the address was pre-funded.
+fn create_monke_burn<'info>(
+    monke_burn_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    nft_mint: &Pubkey,
+    bump: u8,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"monke_burn", nft_mint.as_ref(), &[bump]];
+    let rent = Rent::get()?.minimum_balance(MonkeBurn::SIZE);
+    let current = monke_burn_info.lamports();
+    if current == 0 {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: monke_burn_info.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            MonkeBurn::SIZE as u64,
+            &crate::ID,
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(current);
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.clone(),
+                        to: monke_burn_info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        anchor_lang::system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Allocate { account_to_allocate: monke_burn_info.clone() },
+                &[seeds],
+            ),
+            MonkeBurn::SIZE as u64,
+        )?;
+        anchor_lang::system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Assign { account_to_assign: monke_burn_info.clone() },
+                &[seeds],
+            ),
+            &crate::ID,
+        )?;
+    }
+
+    MonkeBurn {
+        nft_mint: *nft_mint,
+        share_weight: 0,
+        reward_debt: 0,
+        claimed_sol: 0,
+        first_fed_at: 0,
+        token_reward_debt: 0,
+        claimed_token: 0,
+        pending_weight: 0,
+        pending_epoch: 0,
+        last_interaction_ts: 0,
+        unclaimed_sol: 0,
+    }
+    .try_serialize(&mut &mut monke_burn_info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// `tokens` whole tokens in base units for a mint with `decimals`; None on overflow.
+fn tokens_to_base_units(tokens: u64, decimals: u8) -> Option<u64> {
+    10u64.checked_pow(decimals as u32)?.checked_mul(tokens)
+}
+
+/// set_bananas_per_feed's burn: `feed_tokens` within MIN_FEED_TOKENS..=MAX_FEED_TOKENS,
+/// in base units for a mint with `decimals`.
+fn bounded_feed_burn(feed_tokens: u64, decimals: u8) -> Result<u64> {
+    require!(
+        (MIN_FEED_TOKENS..=MAX_FEED_TOKENS).contains(&feed_tokens),
+        MonkeError::InvalidBananasPerFeed
+    );
+    Ok(tokens_to_base_units(feed_tokens, decimals).ok_or(MonkeError::InvalidBananasPerFeed)?)
+}
+
+/// MasterChef formula: owed = (weight * accumulator - reward_debt) / PRECISION
+/// Subtraction happens in PRECISION-scaled units first, then one division.
+/// This avoids precision loss from separate divisions.
+/// Reports what claim pays at `now`: decay owed but not yet written is applied
+/// (decay_pending), so the amount is at the effective weight, plus whatever
+/// defeed_monke left in unclaimed_sol.
+fn pending_owed(monke_burn: &MonkeBurn, state: &MonkeState, now: i64) -> Result<u64> {
+    let (effective, _) = decay_due(monke_burn, state, now)?;
+    let pending_scaled = decay_pending(
+        sol_pending_scaled(monke_burn, monke_burn.share_weight, state.accumulated_sol_per_share)?,
+        monke_burn.share_weight,
+        effective,
+    )?;
+    let pending = pending_scaled.checked_div(PRECISION).unwrap_or(0) as u64;
+    Ok(pending.checked_add(monke_burn.unclaimed_sol).ok_or(MonkeError::Overflow)?)
+}
+
+/// weight * accumulator - reward_debt, floored at 0 (PRECISION-scaled). The floor
+/// only triggers when rounding leaves reward_debt above the product; behavior is
+/// unchanged, but RewardSaturationEvent records it so precision drift is visible.
+fn sol_pending_scaled(monke_burn: &MonkeBurn, weight: u64, accumulated: u128) -> Result<u128> {
+    let computed = (weight as u128)
+        .checked_mul(accumulated).ok_or(MonkeError::Overflow)?;
+    let (pending, saturation) = pending_or_saturation(monke_burn, computed);
+    if let Some(event) = saturation {
+        emit!(event);
+    }
+    Ok(pending)
+}
+
+/// `computed` - reward_debt, or 0 plus the RewardSaturationEvent to emit when
+/// reward_debt is the larger of the two.
+fn pending_or_saturation(monke_burn: &MonkeBurn, computed: u128) -> (u128, Option<RewardSaturationEvent>) {
+    match computed.checked_sub(monke_burn.reward_debt) {
+        Some(pending) => (pending, None),
+        None => (0, Some(RewardSaturationEvent {
+            nft_mint: monke_burn.nft_mint,
+            reward_debt: monke_burn.reward_debt,
+            computed,
+        })),
+    }
+}
+
+/// pending_owed against the reward token accumulator (token_reward_debt).
+fn pending_token_owed(monke_burn: &MonkeBurn, state: &MonkeState, now: i64) -> Result<u64> {
+    let (effective, _) = decay_due(monke_burn, state, now)?;
+    let pending_scaled = (monke_burn.share_weight as u128)
+        .checked_mul(state.accumulated_token_per_share).ok_or(MonkeError::Overflow)?
+        .saturating_sub(monke_burn.token_reward_debt);
+    let pending_scaled = decay_pending(pending_scaled, monke_burn.share_weight, effective)?;
+    Ok(pending_scaled.checked_div(PRECISION).unwrap_or(0) as u64)
+}
+
+/// Which collection an NFT matched in validate_collection_and_weight.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum SmbGen {
+    Gen2,
+    Gen3,
+    /// Matched a CollectionConfig from the registry (gated by its own `enabled` flag)
+    Registered,
+}
+
+/// Validate that an NFT belongs to the SMB Gen2 or Gen3 collection, or to the
+/// registered collection passed in `registered`.
+/// Returns the configured weight multiplier (state.gen2_weight / gen3_weight, or
+/// the registry weight) plus which collection matched.
+/// The two legacy collections are always checked first.
 fn validate_collection_and_weight(
     metadata_info: &AccountInfo,
     nft_mint: &Pubkey,
-    gen2_collection: &Pubkey,
-    gen3_collection: &Pubkey,
-) -> Result<u64> {
+    state: &MonkeState,
+    registered: Option<&CollectionConfig>,
+) -> Result<(u64, SmbGen)> {
+    let (collection_key, verified) = read_collection_key(metadata_info, nft_mint)?;
+
+    let matched = if collection_key == state.smb_collection {
+        (state.effective_gen2_weight(), SmbGen::Gen2)
+    } else if collection_key == state.smb_gen3_collection {
+        (state.effective_gen3_weight(), SmbGen::Gen3)
+    } else if let Some(config) = registered.filter(|c| c.collection == collection_key) {
+        require!(config.enabled, MonkeError::CollectionDisabled);
+        (config.weight, SmbGen::Registered)
+    } else {
+        return Err(MonkeError::InvalidCollection.into());
+    };
+
+    // Only a configured collection key reaches here, so the migration window can't
+    // admit arbitrary collections — just unverified members of known ones.
+    let now = Clock::get()?.unix_timestamp;
+    if require_collection_admitted(verified, state.allow_unverified_until, now)? {
+        emit!(UnverifiedCollectionAcceptedEvent {
+            nft_mint: *nft_mint,
+            collection: collection_key,
+            allow_unverified_until: state.allow_unverified_until,
+            timestamp: now,
+        });
+        msg!("Unverified collection accepted for {} (window until {})",
+            nft_mint, state.allow_unverified_until);
+    }
+    Ok(matched)
+}
+
+/// Verified collections always pass. An unverified member of a known collection
+/// passes only while `now` is before `allow_unverified_until` (0 = window never
+/// opened), else CollectionNotVerified. Returns true when the window admitted it.
+fn require_collection_admitted(verified: bool, allow_unverified_until: i64, now: i64) -> Result<bool> {
+    if verified {
+        return Ok(false);
+    }
+    require!(now < allow_unverified_until, MonkeError::CollectionNotVerified);
+    Ok(true)
+}
+
+/// Deserialize an NFT's Metaplex metadata account and return its collection key
+/// and verified flag. Fails with InvalidCollection if the metadata has no collection
+/// set. Layout walk lives in parse_metadata_collection.
+fn read_collection_key(
+    metadata_info: &AccountInfo,
+    nft_mint: &Pubkey,
+) -> Result<(Pubkey, bool)> {
     // Verify metadata account is owned by Metaplex Token Metadata program
     require!(
         metadata_info.owner == &MPL_TOKEN_METADATA_ID,
@@ -625,47 +2570,42 @@ fn validate_collection_and_weight(
         MonkeError::InvalidMetadata
     );
 
-    // Parse metadata to find collection field
+    parse_metadata_collection(&data)?.ok_or_else(|| MonkeError::InvalidCollection.into())
+}
+
+/// Walk raw Token Metadata bytes up to the collection field and return its key and
+/// verified flag (None if the metadata has no collection). Works for plain NFTs and pNFTs:
+///
+///   key(1) + update_authority(32) + mint(32) + name(4+n) + symbol(4+n) + uri(4+n)
+///   + seller_fee_basis_points(2) + creators: Option<Vec<Creator(34)>>
+///   + primary_sale_happened(1) + is_mutable(1)
+///   + edition_nonce: Option<u8> + token_standard: Option<u8>
+///   + collection: Option<{ verified(1) + key(32) }>
+///   + uses: Option<Uses> + collection_details: Option<..> + programmable_config: Option<..>
+///
+/// The pNFT-only fields (token_standard = ProgrammableNonFungible, programmable_config)
+/// either precede collection at a fixed 1-2 bytes or come after it, so only the
+/// option tags before collection need walking. Option tags other than 0/1 are
+/// rejected rather than guessed at.
+fn parse_metadata_collection(data: &[u8]) -> Result<Option<(Pubkey, bool)>> {
     // Skip: key(1) + update_authority(32) + mint(32) = 65 bytes
     let mut offset: usize = 65;
 
-    // name: 4-byte length prefix + data (padded to 32 bytes in practice, but use length)
-    require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-    let name_len = u32::from_le_bytes(
-        data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-    ) as usize;
-    offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?
-        .checked_add(name_len).ok_or(MonkeError::Overflow)?;
-
-    // symbol: 4-byte length prefix + data
-    require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-    let symbol_len = u32::from_le_bytes(
-        data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-    ) as usize;
-    offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?
-        .checked_add(symbol_len).ok_or(MonkeError::Overflow)?;
-
-    // uri: 4-byte length prefix + data
-    require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-    let uri_len = u32::from_le_bytes(
-        data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-    ) as usize;
-    offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?
-        .checked_add(uri_len).ok_or(MonkeError::Overflow)?;
+    // name, symbol, uri: 4-byte length prefix + data
+    for _ in 0..3 {
+        let len = read_borsh_u32(data, offset)? as usize;
+        offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?
+            .checked_add(len).ok_or(MonkeError::Overflow)?;
+    }
 
     // seller_fee_basis_points: 2 bytes
     offset = offset.checked_add(2).ok_or(MonkeError::Overflow)?;
 
     // creators: Option<Vec<Creator>>
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    let has_creators = data[offset] == 1;
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-
+    let (has_creators, after_tag) = read_option_tag(data, offset)?;
+    offset = after_tag;
     if has_creators {
-        require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-        let num_creators = u32::from_le_bytes(
-            data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-        ) as usize;
+        let num_creators = read_borsh_u32(data, offset)? as usize;
         offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?;
         // Each creator: address(32) + verified(1) + share(1) = 34 bytes
         offset = offset.checked_add(
@@ -673,56 +2613,61 @@ fn validate_collection_and_weight(
         ).ok_or(MonkeError::Overflow)?;
     }
 
-    // primary_sale_happened: 1 byte
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-    // is_mutable: 1 byte
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-
-    // edition_nonce: Option<u8>
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    if data[offset] == 1 {
-        offset = offset.checked_add(2).ok_or(MonkeError::Overflow)?;
-    } else {
-        offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-    }
+    // primary_sale_happened(1) + is_mutable(1)
+    offset = offset.checked_add(2).ok_or(MonkeError::Overflow)?;
 
-    // token_standard: Option<TokenStandard> (u8 enum)
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    if data[offset] == 1 {
-        offset = offset.checked_add(2).ok_or(MonkeError::Overflow)?;
-    } else {
-        offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
+    // edition_nonce: Option<u8>, token_standard: Option<TokenStandard> (u8 enum)
+    for _ in 0..2 {
+        let (is_some, after_tag) = read_option_tag(data, offset)?;
+        offset = if is_some {
+            after_tag.checked_add(1).ok_or(MonkeError::Overflow)?
+        } else {
+            after_tag
+        };
     }
 
     // collection: Option<Collection>
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    let has_collection = data[offset] == 1;
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-
-    require!(has_collection, MonkeError::InvalidCollection);
-
+    let (has_collection, after_tag) = read_option_tag(data, offset)?;
+    if !has_collection {
+        return Ok(None);
+    }
     // Collection: verified(1) + key(32)
-    require!(data.len() >= offset + 33, MonkeError::InvalidMetadata);
-    let _verified = data[offset] == 1;
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
+    let verified = match data.get(after_tag) {
+        Some(0) => false,
+        Some(1) => true,
+        _ => return err!(MonkeError::InvalidMetadata),
+    };
+    let key_start = after_tag.checked_add(1).ok_or(MonkeError::Overflow)?;
+    let key_end = key_start.checked_add(32).ok_or(MonkeError::Overflow)?;
+    let key_bytes = data.get(key_start..key_end).ok_or(MonkeError::InvalidMetadata)?;
+    let collection_key = Pubkey::try_from(key_bytes).map_err(|_| MonkeError::InvalidMetadata)?;
+    Ok(Some((collection_key, verified)))
+}
 
-    let collection_key = Pubkey::try_from(&data[offset..offset + 32])
-        .map_err(|_| MonkeError::InvalidMetadata)?;
+/// Borsh u32 (little-endian) at `offset`, bounds-checked.
+fn read_borsh_u32(data: &[u8], offset: usize) -> Result<u32> {
+    let end = offset.checked_add(4).ok_or(MonkeError::Overflow)?;
+    let bytes = data.get(offset..end).ok_or(MonkeError::InvalidMetadata)?;
+    Ok(u32::from_le_bytes(bytes.try_into().map_err(|_| MonkeError::InvalidMetadata)?))
+}
 
-    if collection_key == *gen2_collection {
-        Ok(1)
-    } else if collection_key == *gen3_collection {
-        Ok(1)
-    } else {
-        Err(MonkeError::InvalidCollection.into())
-    }
+/// Borsh Option tag at `offset`: (is_some, offset of the payload / next field).
+fn read_option_tag(data: &[u8], offset: usize) -> Result<(bool, usize)> {
+    let is_some = match data.get(offset) {
+        Some(0) => false,
+        Some(1) => true,
+        _ => return err!(MonkeError::InvalidMetadata),
+    };
+    Ok((is_some, offset.checked_add(1).ok_or(MonkeError::Overflow)?))
 }
 
 /// Validate that an NFT belongs to the gooseswtf pixel goose collection.
-/// Same Token Metadata parsing as validate_collection_and_weight (pNFTs use identical layout).
+/// Same Token Metadata parsing and unverified-collection window as
+/// validate_collection_and_weight (parse_metadata_collection, require_collection_admitted).
 fn validate_goose_pixel_collection(
     metadata_info: &AccountInfo,
     nft_mint: &Pubkey,
+    state: &MonkeState,
 ) -> Result<()> {
     require!(
         metadata_info.owner == &MPL_TOKEN_METADATA_ID,
@@ -745,88 +2690,28 @@ fn validate_goose_pixel_collection(
         MonkeError::InvalidMetadata
     );
 
-    let mut offset: usize = 65; // key(1) + update_authority(32) + mint(32)
-
-    // name: 4-byte length prefix + data
-    require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-    let name_len = u32::from_le_bytes(
-        data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-    ) as usize;
-    offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?
-        .checked_add(name_len).ok_or(MonkeError::Overflow)?;
-
-    // symbol: 4-byte length prefix + data
-    require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-    let symbol_len = u32::from_le_bytes(
-        data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-    ) as usize;
-    offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?
-        .checked_add(symbol_len).ok_or(MonkeError::Overflow)?;
-
-    // uri: 4-byte length prefix + data
-    require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-    let uri_len = u32::from_le_bytes(
-        data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-    ) as usize;
-    offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?
-        .checked_add(uri_len).ok_or(MonkeError::Overflow)?;
-
-    // seller_fee_basis_points: 2 bytes
-    offset = offset.checked_add(2).ok_or(MonkeError::Overflow)?;
-
-    // creators: Option<Vec<Creator>>
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    let has_creators = data[offset] == 1;
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-
-    if has_creators {
-        require!(data.len() > offset + 4, MonkeError::InvalidMetadata);
-        let num_creators = u32::from_le_bytes(
-            data[offset..offset + 4].try_into().map_err(|_| MonkeError::InvalidMetadata)?
-        ) as usize;
-        offset = offset.checked_add(4).ok_or(MonkeError::Overflow)?;
-        offset = offset.checked_add(
-            num_creators.checked_mul(34).ok_or(MonkeError::Overflow)?
-        ).ok_or(MonkeError::Overflow)?;
-    }
-
-    // primary_sale_happened: 1 byte
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-    // is_mutable: 1 byte
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-
-    // edition_nonce: Option<u8>
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    if data[offset] == 1 {
-        offset = offset.checked_add(2).ok_or(MonkeError::Overflow)?;
-    } else {
-        offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-    }
-
-    // token_standard: Option<TokenStandard>
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    if data[offset] == 1 {
-        offset = offset.checked_add(2).ok_or(MonkeError::Overflow)?;
-    } else {
-        offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
+    let now = Clock::get()?.unix_timestamp;
+    if goose_collection_admitted(&data, state.allow_unverified_until, now)? {
+        emit!(UnverifiedCollectionAcceptedEvent {
+            nft_mint: *nft_mint,
+            collection: GOOSE_PIXEL_COLLECTION,
+            allow_unverified_until: state.allow_unverified_until,
+            timestamp: now,
+        });
+        msg!("Unverified goose collection accepted for {} (window until {})",
+            nft_mint, state.allow_unverified_until);
     }
+    Ok(())
+}
 
-    // collection: Option<Collection>
-    require!(data.len() > offset, MonkeError::InvalidMetadata);
-    let has_collection = data[offset] == 1;
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-
-    require!(has_collection, MonkeError::InvalidGooseCollection);
-
-    require!(data.len() >= offset + 33, MonkeError::InvalidMetadata);
-    let _verified = data[offset] == 1;
-    offset = offset.checked_add(1).ok_or(MonkeError::Overflow)?;
-
-    let collection_key = Pubkey::try_from(&data[offset..offset + 32])
-        .map_err(|_| MonkeError::InvalidMetadata)?;
-
+/// Collection check on raw goose metadata: the collection must be
+/// GOOSE_PIXEL_COLLECTION (else InvalidGooseCollection), then the same verified
+/// rule as SMB. Returns true when the unverified window admitted it.
+fn goose_collection_admitted(data: &[u8], allow_unverified_until: i64, now: i64) -> Result<bool> {
+    let (collection_key, verified) = parse_metadata_collection(data)?
+        .ok_or(MonkeError::InvalidGooseCollection)?;
     require!(collection_key == GOOSE_PIXEL_COLLECTION, MonkeError::InvalidGooseCollection);
-    Ok(())
+    require_collection_admitted(verified, allow_unverified_until, now)
 }
 
 /// Validate GooseDAO membership by reading a Metaplex Core asset account.
@@ -881,7 +2766,31 @@ pub struct MonkeState {
     pub program_vault_bump: u8,              // PDA bump for program_vault
     pub dist_pool_bump: u8,                  // PDA bump for dist_pool
     pub pegged_mint: Pubkey,                 // $PEGGED mint (set via set_pegged_mint after migration)
-    pub _reserved: [u8; 32],                 // Reserved for future fields (avoids realloc)
+    // pending_dist_pool..dist_pool_change_at (41 bytes) overrun the old 32-byte
+    // _reserved: a pre-timelock MonkeState needs migrate_state before it loads
+    pub pending_dist_pool: Pubkey,           // Timelocked: proposed new dist_pool (default = none)
+    pub pending_dist_pool_bump: u8,          // Timelocked: PDA bump for pending_dist_pool (unused if not a PDA)
+    pub dist_pool_change_at: i64,            // Timelocked: timestamp when pending can be applied (0 = none)
+    pub sol_liability: u64,                  // SOL deposited into program_vault not yet claimed
+    pub min_deposit_lamports: u64,           // deposit_sol floor (0 = unset → MIN_DEPOSIT_LAMPORTS)
+    pub gen2_paused: bool,                   // Gates feed_monke for Gen2 only
+    pub gen3_paused: bool,                   // Gates feed_monke for Gen3 only
+    pub gen2_weight: u64,                    // Weight added per Gen2 feed (0 = unset → 1)
+    pub gen3_weight: u64,                    // Weight added per Gen3 feed (0 = unset → 1)
+    pub decay_bps_per_epoch: u16,            // Lazy weight decay per epoch (0 = off); was _reserved
+    pub epoch_len_seconds: u32,              // Decay epoch length (0 = off); was _reserved
+    // Appended by migrate_state (reserved bytes were too small for these)
+    pub reward_token_mint: Pubkey,           // SPL reward token (default = not configured)
+    pub accumulated_token_per_share: u128,   // Scaled by PRECISION, in reward token base units
+    pub total_token_distributed: u64,        // Lifetime reward tokens deposited (tracking)
+    pub distribution_epoch: u64,             // Last snapshot distribution opened (0 = never)
+    pub distribution_open: bool,             // Snapshot window open — feeds go to pending_weight
+    pub pending_share_weight: u64,           // Weight fed during the open window (not in total yet)
+    pub compost_bounty_lamports: u64,        // Paid from program_vault surplus per compost (0 = rent refund only)
+    pub bananas_per_feed: u64,               // Base units burned per feed (0 = unset → BANANAS_PER_FEED)
+    pub allow_unverified_until: i64,         // Accept unverified known collections before this ts (0 = never)
+    pub liability_initialized: bool,         // sol_liability is trustworthy (set by initialize / init_sol_liability)
+    pub decay_start_ts: i64,                 // When decay was turned on; decay clock for monkes not fed since (0 = never)
 }
 
 impl MonkeState {
@@ -901,7 +2810,64 @@ impl MonkeState {
         1 +  // program_vault_bump
         1 +  // dist_pool_bump
         32 + // pegged_mint
-        32;  // _reserved
+        32 + // pending_dist_pool
+        1 +  // pending_dist_pool_bump
+        8 +  // dist_pool_change_at
+        8 +  // sol_liability
+        8 +  // min_deposit_lamports
+        1 +  // gen2_paused
+        1 +  // gen3_paused
+        8 +  // gen2_weight
+        8 +  // gen3_weight
+        2 +  // decay_bps_per_epoch
+        4 +  // epoch_len_seconds
+        32 + // reward_token_mint
+        16 + // accumulated_token_per_share (u128)
+        8 +  // total_token_distributed
+        8 +  // distribution_epoch
+        1 +  // distribution_open
+        8 +  // pending_share_weight
+        8 +  // compost_bounty_lamports
+        8 +  // bananas_per_feed
+        8 +  // allow_unverified_until
+        1 +  // liability_initialized
+        8;   // decay_start_ts
+
+    /// Burn per feed. State created before bananas_per_feed existed reads 0 and keeps
+    /// the 6-decimal constant it was deployed with.
+    pub fn effective_bananas_per_feed(&self) -> u64 {
+        if self.bananas_per_feed == 0 {
+            BANANAS_PER_FEED
+        } else {
+            self.bananas_per_feed
+        }
+    }
+
+    /// Per-feed Gen2 weight. State created before gen2_weight existed reads 0 from the
+    /// reserved bytes and keeps the flat 1x weight it was deployed with.
+    pub fn effective_gen2_weight(&self) -> u64 {
+        self.gen2_weight.max(1)
+    }
+
+    /// Decay runs only when both knobs are set (state from before decay reads 0 for both).
+    pub fn decay_enabled(&self) -> bool {
+        self.decay_bps_per_epoch > 0 && self.epoch_len_seconds > 0
+    }
+
+    /// Per-feed Gen3 weight, same legacy fallback as effective_gen2_weight.
+    pub fn effective_gen3_weight(&self) -> u64 {
+        self.gen3_weight.max(1)
+    }
+
+    /// deposit_sol floor. State created before min_deposit_lamports existed reads 0
+    /// from the reserved bytes and falls back to the constant.
+    pub fn effective_min_deposit(&self) -> u64 {
+        if self.min_deposit_lamports == 0 {
+            MIN_DEPOSIT_LAMPORTS
+        } else {
+            self.min_deposit_lamports
+        }
+    }
 }
 
 #[account]
@@ -911,6 +2877,13 @@ pub struct MonkeBurn {
     pub reward_debt: u128,                   // MasterChef: weight * accumulated_sol_per_share at last interaction
     pub claimed_sol: u64,                    // Lifetime SOL claimed (tracking)
     pub first_fed_at: i64,                   // Timestamp of first burn
+    // Appended by migrate_monke_burn
+    pub token_reward_debt: u128,             // weight * accumulated_token_per_share at last interaction
+    pub claimed_token: u64,                  // Lifetime reward tokens claimed (tracking)
+    pub pending_weight: u64,                 // Fed during a snapshot window, not earning yet
+    pub pending_epoch: u64,                  // Distribution epoch pending_weight belongs to
+    pub last_interaction_ts: i64,            // Decay clock: last feed / applied decay step (0 = state.decay_start_ts)
+    pub unclaimed_sol: u64,                  // SOL settled by defeed_monke, paid out by the next claim
 }
 
 impl MonkeBurn {
@@ -919,7 +2892,66 @@ impl MonkeBurn {
         8 +  // share_weight
         16 + // reward_debt (u128)
         8 +  // claimed_sol
-        8;   // first_fed_at
+        8 +  // first_fed_at
+        16 + // token_reward_debt (u128)
+        8 +  // claimed_token
+        8 +  // pending_weight
+        8 +  // pending_epoch
+        8 +  // last_interaction_ts
+        8;   // unclaimed_sol
+}
+
+/// One snapshot distribution (begin_distribution → deposit_sol_snapshot).
+/// PDA seeds: ["distribution", epoch (u64 LE)]
+#[account]
+pub struct Distribution {
+    pub epoch: u64,
+    pub snapshot_weight: u64,                // total_share_weight at begin_distribution
+    pub snapshot_acc: u128,                  // accumulated_sol_per_share at begin_distribution
+    pub opened_at: i64,
+    pub closed: bool,
+    pub amount: u64,                         // SOL deposited on close (0 if cancelled)
+    pub acc_after: u128,                     // SOL accumulator at close — pending weight earns from here
+    pub token_acc_after: u128,               // Token accumulator at close
+    pub late_weight: u64,                    // Weight fed during the window
+    pub bump: u8,
+}
+
+impl Distribution {
+    pub const SIZE: usize = 8 + 8 + 8 + 16 + 8 + 1 + 8 + 16 + 16 + 8 + 1;
+}
+
+/// Registry entry for an additional feedable collection.
+/// PDA seeds: ["collection", collection]
+#[account]
+pub struct CollectionConfig {
+    pub collection: Pubkey,                  // Verified collection key on the NFT metadata
+    pub weight: u64,                         // Weight added per feed
+    pub enabled: bool,                       // Gates new feeds only (claim never gated)
+    pub bump: u8,
+}
+
+impl CollectionConfig {
+    pub const SIZE: usize = 8 +  // discriminator
+        32 + // collection
+        8 +  // weight
+        1 +  // enabled
+        1;   // bump
+
+    /// register_collection: set (or overwrite) the entry. Same weight bounds as
+    /// update_weights.
+    pub fn register(&mut self, collection: Pubkey, weight: u64, enabled: bool, bump: u8) -> Result<()> {
+        require!(collection != Pubkey::default(), MonkeError::InvalidCollection);
+        require!(
+            (1..=MAX_WEIGHT_MULTIPLIER).contains(&weight),
+            MonkeError::InvalidWeight
+        );
+        self.collection = collection;
+        self.weight = weight;
+        self.enabled = enabled;
+        self.bump = bump;
+        Ok(())
+    }
 }
 
 // ============ CONTEXTS ============
@@ -983,7 +3015,56 @@ pub struct FeedMonke<'info> {
         constraint = user_nft_account.owner == user.key() @ MonkeError::NotNftHolder,
         constraint = user_nft_account.amount == 1 @ MonkeError::NotNftHolder,
     )]
-    pub user_nft_account: Account<'info, TokenAccount>,
+    pub user_nft_account: Account<'info, TokenAccount>,
+
+    /// User's $BANANAS token account (will be burned from)
+    #[account(
+        mut,
+        constraint = user_bananas_account.mint == state.bananas_mint @ MonkeError::InvalidMint,
+        constraint = user_bananas_account.owner == user.key() @ MonkeError::NotTokenOwner,
+    )]
+    pub user_bananas_account: Account<'info, TokenAccount>,
+
+    /// $BANANAS mint (for burn CPI)
+    #[account(
+        mut,
+        constraint = bananas_mint.key() == state.bananas_mint @ MonkeError::InvalidMint
+    )]
+    pub bananas_mint: Account<'info, Mint>,
+
+    /// MonkeBurn PDA — created on first burn, incremented on subsequent burns
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = MonkeBurn::SIZE,
+        seeds = [b"monke_burn", nft_mint.key().as_ref()],
+        bump
+    )]
+    pub monke_burn: Account<'info, MonkeBurn>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// Registry entry for the NFT's collection. Only needed for collections
+    /// outside the legacy Gen2/Gen3 pair.
+    #[account(
+        seeds = [b"collection", collection_config.collection.as_ref()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Option<Account<'info, CollectionConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct FeedMonkeBatch<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
 
     /// User's $BANANAS token account (will be burned from)
     #[account(
@@ -1000,18 +3081,9 @@ pub struct FeedMonke<'info> {
     )]
     pub bananas_mint: Account<'info, Mint>,
 
-    /// MonkeBurn PDA — created on first burn, incremented on subsequent burns
-    #[account(
-        init_if_needed,
-        payer = user,
-        space = MonkeBurn::SIZE,
-        seeds = [b"monke_burn", nft_mint.key().as_ref()],
-        bump
-    )]
-    pub monke_burn: Account<'info, MonkeBurn>,
-
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    // remaining_accounts: n × [nft_mint, nft_metadata, user_nft_account, monke_burn] — see feed_monke_batch
 }
 
 #[derive(Accounts)]
@@ -1085,11 +3157,71 @@ pub struct DepositSol<'info> {
     )]
     pub state: Account<'info, MonkeState>,
 
-    /// CHECK: dist_pool PDA — SOL source
+    /// CHECK: dist_pool — SOL source. Validated against state.dist_pool
+    /// (changeable via propose_dist_pool / apply_dist_pool); a target that isn't a
+    /// dist_pool PDA must sign (dist_pool_source).
     #[account(
         mut,
-        seeds = [b"dist_pool"],
-        bump = state.dist_pool_bump
+        constraint = dist_pool.key() == state.dist_pool @ MonkeError::InvalidDistPool
+    )]
+    pub dist_pool: AccountInfo<'info>,
+
+    /// CHECK: program_vault PDA — SOL destination
+    #[account(
+        mut,
+        seeds = [b"program_vault"],
+        bump = state.program_vault_bump
+    )]
+    pub program_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BeginDistribution<'info> {
+    #[account(mut, constraint = authority.key() == state.authority @ MonkeError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Distribution::SIZE,
+        seeds = [b"distribution", state.distribution_epoch.checked_add(1).ok_or(MonkeError::Overflow)?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositSolSnapshot<'info> {
+    /// Anyone can call (permissionless, like deposit_sol)
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
+
+    /// The open window's Distribution
+    #[account(
+        mut,
+        seeds = [b"distribution", state.distribution_epoch.to_le_bytes().as_ref()],
+        bump = distribution.bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    /// CHECK: dist_pool — SOL source. Validated against state.dist_pool; a target
+    /// that isn't a dist_pool PDA must sign (dist_pool_source)
+    #[account(
+        mut,
+        constraint = dist_pool.key() == state.dist_pool @ MonkeError::InvalidDistPool
     )]
     pub dist_pool: AccountInfo<'info>,
 
@@ -1104,6 +3236,39 @@ pub struct DepositSol<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct CancelDistribution<'info> {
+    #[account(constraint = authority.key() == state.authority @ MonkeError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(
+        mut,
+        seeds = [b"distribution", state.distribution_epoch.to_le_bytes().as_ref()],
+        bump = distribution.bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+}
+
+#[derive(Accounts)]
+pub struct ActivatePendingWeight<'info> {
+    #[account(
+        mut,
+        seeds = [b"monke_burn", monke_burn.nft_mint.as_ref()],
+        bump
+    )]
+    pub monke_burn: Account<'info, MonkeBurn>,
+
+    /// Distribution the pending weight was fed under
+    #[account(
+        seeds = [b"distribution", monke_burn.pending_epoch.to_le_bytes().as_ref()],
+        bump = distribution.bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+}
+
 #[derive(Accounts)]
 pub struct DepositPegged<'info> {
     /// Anyone can call (permissionless — keeper calls weekly)
@@ -1116,22 +3281,229 @@ pub struct DepositPegged<'info> {
     )]
     pub state: Account<'info, MonkeState>,
 
-    /// CHECK: dist_pool PDA — authority for the dist_pool $PEGGED ATA
+    /// CHECK: dist_pool — authority for the dist_pool $PEGGED ATA. Validated against
+    /// state.dist_pool (changeable via propose_dist_pool / apply_dist_pool); a dist_pool
+    /// PDA is signed for with [b"dist_pool", state.dist_pool_bump], any other target
+    /// must sign the transaction (dist_pool_source).
+    #[account(
+        constraint = dist_pool.key() == state.dist_pool @ MonkeError::InvalidDistPool
+    )]
+    pub dist_pool: AccountInfo<'info>,
+
+    /// Dist pool's $PEGGED ATA — source (receives from bridge)
+    #[account(
+        mut,
+        constraint = dist_pool_pegged_ata.owner == dist_pool.key() @ MonkeError::InvalidTokenAccount,
+        constraint = dist_pool_pegged_ata.mint == state.pegged_mint @ MonkeError::InvalidMint,
+    )]
+    pub dist_pool_pegged_ata: Account<'info, TokenAccount>,
+
+    /// Program vault's $PEGGED ATA — destination
+    #[account(
+        mut,
+        constraint = program_vault_pegged_ata.owner == program_vault.key() @ MonkeError::InvalidTokenAccount,
+        constraint = program_vault_pegged_ata.mint == state.pegged_mint @ MonkeError::InvalidMint,
+    )]
+    pub program_vault_pegged_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: program_vault PDA — for ATA ownership validation
+    #[account(
+        seeds = [b"program_vault"],
+        bump = state.program_vault_bump
+    )]
+    pub program_vault: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
+
+    /// CHECK: MonkeBurn PDA for the held NFT, possibly in the layout from before
+    /// token_reward_debt — grown and deserialized in the handler (load_monke_burn).
+    #[account(
+        mut,
+        seeds = [b"monke_burn", user_nft_account.mint.as_ref()],
+        bump
+    )]
+    pub monke_burn: UncheckedAccount<'info>,
+
+    /// User's NFT token account — proves ownership (balance must be 1)
+    #[account(
+        constraint = user_nft_account.owner == user.key() @ MonkeError::NotNftHolder,
+        constraint = user_nft_account.amount == 1 @ MonkeError::NotNftHolder,
+    )]
+    pub user_nft_account: Account<'info, TokenAccount>,
+
+    /// CHECK: program_vault PDA — SOL source for claim
+    #[account(
+        mut,
+        seeds = [b"program_vault"],
+        bump = state.program_vault_bump
+    )]
+    pub program_vault: AccountInfo<'info>,
+
+    /// Pays the rent top-up when a legacy MonkeBurn is grown in place
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAll<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut, seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    /// CHECK: program_vault PDA — SOL source for the batch payout
+    #[account(mut, seeds = [b"program_vault"], bump = state.program_vault_bump)]
+    pub program_vault: AccountInfo<'info>,
+
+    /// Pays the rent top-up when a legacy MonkeBurn is grown in place
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: n × [monke_burn (writable), nft_mint, user_nft_account] — see claim_all
+}
+
+#[derive(Accounts)]
+pub struct DefeedMonke<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_burn", monke_burn.nft_mint.as_ref()],
+        bump
+    )]
+    pub monke_burn: Account<'info, MonkeBurn>,
+
+    /// User's NFT token account — proves ownership (balance must be 1)
+    #[account(
+        constraint = user_nft_account.mint == monke_burn.nft_mint @ MonkeError::InvalidNftMint,
+        constraint = user_nft_account.owner == user.key() @ MonkeError::NotNftHolder,
+        constraint = user_nft_account.amount == 1 @ MonkeError::NotNftHolder,
+    )]
+    pub user_nft_account: Account<'info, TokenAccount>,
+
+}
+
+#[derive(Accounts)]
+pub struct ClaimTo<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
+
+    /// CHECK: MonkeBurn PDA for the held NFT, possibly in the layout from before
+    /// token_reward_debt — grown and deserialized in the handler (load_monke_burn).
+    #[account(
+        mut,
+        seeds = [b"monke_burn", user_nft_account.mint.as_ref()],
+        bump
+    )]
+    pub monke_burn: UncheckedAccount<'info>,
+
+    /// User's NFT token account — proves ownership (balance must be 1)
+    #[account(
+        constraint = user_nft_account.owner == user.key() @ MonkeError::NotNftHolder,
+        constraint = user_nft_account.amount == 1 @ MonkeError::NotNftHolder,
+    )]
+    pub user_nft_account: Account<'info, TokenAccount>,
+
+    /// CHECK: program_vault PDA — SOL source for claim
+    #[account(
+        mut,
+        seeds = [b"program_vault"],
+        bump = state.program_vault_bump
+    )]
+    pub program_vault: AccountInfo<'info>,
+
+    /// CHECK: Any non-executable account — receives the owed lamports (checked in handler)
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+
+    /// Pays the rent top-up when a legacy MonkeBurn is grown in place
+    pub system_program: Program<'info, System>,
+}
+
+/// Read-only pending reward view — no accounts are mutated
+#[derive(Accounts)]
+pub struct PendingRewards<'info> {
+    #[account(seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(seeds = [b"monke_burn", monke_burn.nft_mint.as_ref()], bump)]
+    pub monke_burn: Account<'info, MonkeBurn>,
+}
+
+/// Read-only claim quote — no accounts are mutated
+#[derive(Accounts)]
+pub struct QuoteClaimable<'info> {
+    #[account(seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(seeds = [b"monke_burn", monke_burn.nft_mint.as_ref()], bump)]
+    pub monke_burn: Account<'info, MonkeBurn>,
+
+    /// CHECK: program_vault PDA — only the lamport balance is read
+    #[account(seeds = [b"program_vault"], bump = state.program_vault_bump)]
+    pub program_vault: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimPegged<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_burn", monke_burn.nft_mint.as_ref()],
+        bump
+    )]
+    pub monke_burn: Account<'info, MonkeBurn>,
+
+    /// User's NFT token account — proves ownership (balance must be 1)
     #[account(
-        seeds = [b"dist_pool"],
-        bump = state.dist_pool_bump
+        constraint = user_nft_account.mint == monke_burn.nft_mint @ MonkeError::InvalidNftMint,
+        constraint = user_nft_account.owner == user.key() @ MonkeError::NotNftHolder,
+        constraint = user_nft_account.amount == 1 @ MonkeError::NotNftHolder,
     )]
-    pub dist_pool: AccountInfo<'info>,
+    pub user_nft_account: Account<'info, TokenAccount>,
 
-    /// Dist pool's $PEGGED ATA — source (receives from bridge)
+    /// CHECK: program_vault PDA — authority for the vault $PEGGED ATA
     #[account(
-        mut,
-        constraint = dist_pool_pegged_ata.owner == dist_pool.key() @ MonkeError::InvalidTokenAccount,
-        constraint = dist_pool_pegged_ata.mint == state.pegged_mint @ MonkeError::InvalidMint,
+        seeds = [b"program_vault"],
+        bump = state.program_vault_bump
     )]
-    pub dist_pool_pegged_ata: Account<'info, TokenAccount>,
+    pub program_vault: AccountInfo<'info>,
 
-    /// Program vault's $PEGGED ATA — destination
+    /// Program vault's $PEGGED ATA — source of claim payout
     #[account(
         mut,
         constraint = program_vault_pegged_ata.owner == program_vault.key() @ MonkeError::InvalidTokenAccount,
@@ -1139,55 +3511,57 @@ pub struct DepositPegged<'info> {
     )]
     pub program_vault_pegged_ata: Account<'info, TokenAccount>,
 
-    /// CHECK: program_vault PDA — for ATA ownership validation
+    /// User's $PEGGED ATA — destination
     #[account(
-        seeds = [b"program_vault"],
-        bump = state.program_vault_bump
+        mut,
+        constraint = user_pegged_ata.owner == user.key() @ MonkeError::InvalidTokenAccount,
+        constraint = user_pegged_ata.mint == state.pegged_mint @ MonkeError::InvalidMint,
     )]
-    pub program_vault: AccountInfo<'info>,
+    pub user_pegged_ata: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct Claim<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
+pub struct DepositToken<'info> {
+    /// Anyone can fund a distribution
+    pub depositor: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"monke_state"],
         bump = state.state_bump
     )]
     pub state: Account<'info, MonkeState>,
 
+    /// Depositor's reward token account — source
     #[account(
         mut,
-        seeds = [b"monke_burn", monke_burn.nft_mint.as_ref()],
-        bump
+        constraint = depositor_token_account.owner == depositor.key() @ MonkeError::InvalidTokenAccount,
+        constraint = depositor_token_account.mint == state.reward_token_mint @ MonkeError::InvalidMint,
     )]
-    pub monke_burn: Account<'info, MonkeBurn>,
+    pub depositor_token_account: Account<'info, TokenAccount>,
 
-    /// User's NFT token account — proves ownership (balance must be 1)
+    /// Program vault's reward token ATA — destination
     #[account(
-        constraint = user_nft_account.mint == monke_burn.nft_mint @ MonkeError::InvalidNftMint,
-        constraint = user_nft_account.owner == user.key() @ MonkeError::NotNftHolder,
-        constraint = user_nft_account.amount == 1 @ MonkeError::NotNftHolder,
+        mut,
+        constraint = program_vault_token_ata.owner == program_vault.key() @ MonkeError::InvalidTokenAccount,
+        constraint = program_vault_token_ata.mint == state.reward_token_mint @ MonkeError::InvalidMint,
     )]
-    pub user_nft_account: Account<'info, TokenAccount>,
+    pub program_vault_token_ata: Account<'info, TokenAccount>,
 
-    /// CHECK: program_vault PDA — SOL source for claim
+    /// CHECK: program_vault PDA — for ATA ownership validation
     #[account(
-        mut,
         seeds = [b"program_vault"],
         bump = state.program_vault_bump
     )]
     pub program_vault: AccountInfo<'info>,
 
-    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimPegged<'info> {
+pub struct ClaimToken<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -1212,32 +3586,58 @@ pub struct ClaimPegged<'info> {
     )]
     pub user_nft_account: Account<'info, TokenAccount>,
 
-    /// CHECK: program_vault PDA — authority for the vault $PEGGED ATA
+    /// CHECK: program_vault PDA — authority for the vault reward token ATA
     #[account(
         seeds = [b"program_vault"],
         bump = state.program_vault_bump
     )]
     pub program_vault: AccountInfo<'info>,
 
-    /// Program vault's $PEGGED ATA — source of claim payout
+    /// Program vault's reward token ATA — source of claim payout
     #[account(
         mut,
-        constraint = program_vault_pegged_ata.owner == program_vault.key() @ MonkeError::InvalidTokenAccount,
-        constraint = program_vault_pegged_ata.mint == state.pegged_mint @ MonkeError::InvalidMint,
+        constraint = program_vault_token_ata.owner == program_vault.key() @ MonkeError::InvalidTokenAccount,
+        constraint = program_vault_token_ata.mint == state.reward_token_mint @ MonkeError::InvalidMint,
     )]
-    pub program_vault_pegged_ata: Account<'info, TokenAccount>,
+    pub program_vault_token_ata: Account<'info, TokenAccount>,
 
-    /// User's $PEGGED ATA — destination
+    /// User's reward token account — destination
     #[account(
         mut,
-        constraint = user_pegged_ata.owner == user.key() @ MonkeError::InvalidTokenAccount,
-        constraint = user_pegged_ata.mint == state.pegged_mint @ MonkeError::InvalidMint,
+        constraint = user_token_ata.owner == user.key() @ MonkeError::InvalidTokenAccount,
+        constraint = user_token_ata.mint == state.reward_token_mint @ MonkeError::InvalidMint,
     )]
-    pub user_pegged_ata: Account<'info, TokenAccount>,
+    pub user_token_ata: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: MonkeState PDA in any historical layout — read raw because a short
+    /// account can't deserialize. Program owner and discriminator checked in handler.
+    #[account(mut, seeds = [b"monke_state"], bump)]
+    pub state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateMonkeBurn<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: MonkeBurn PDA in any historical layout — program owner, discriminator,
+    /// and PDA seeds validated in handler.
+    #[account(mut)]
+    pub monke_burn: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct AdminOnly<'info> {
     #[account(constraint = authority.key() == state.authority @ MonkeError::Unauthorized)]
@@ -1247,6 +3647,40 @@ pub struct AdminOnly<'info> {
     pub state: Account<'info, MonkeState>,
 }
 
+#[derive(Accounts)]
+pub struct SetBananasPerFeed<'info> {
+    #[account(constraint = authority.key() == state.authority @ MonkeError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    /// Read for decimals only
+    #[account(address = state.bananas_mint @ MonkeError::InvalidMint)]
+    pub bananas_mint: Account<'info, Mint>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey)]
+pub struct RegisterCollection<'info> {
+    #[account(mut, constraint = authority.key() == state.authority @ MonkeError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CollectionConfig::SIZE,
+        seeds = [b"collection", collection.as_ref()],
+        bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct AcceptAuthority<'info> {
     #[account(
@@ -1259,6 +3693,70 @@ pub struct AcceptAuthority<'info> {
     pub state: Account<'info, MonkeState>,
 }
 
+#[derive(Accounts)]
+pub struct ReconcileVaultSurplus<'info> {
+    /// Anyone can call (permissionless)
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
+
+    /// CHECK: program_vault PDA — balance compared against sol_liability
+    #[account(
+        seeds = [b"program_vault"],
+        bump = state.program_vault_bump
+    )]
+    pub program_vault: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitSolLiability<'info> {
+    #[account(constraint = authority.key() == state.authority @ MonkeError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"monke_state"],
+        bump = state.state_bump
+    )]
+    pub state: Account<'info, MonkeState>,
+
+    /// CHECK: program_vault PDA — balance becomes the initial sol_liability
+    #[account(
+        seeds = [b"program_vault"],
+        bump = state.program_vault_bump
+    )]
+    pub program_vault: AccountInfo<'info>,
+}
+
+/// Permissionless apply for dist_pool after 24hr timelock
+#[derive(Accounts)]
+pub struct ApplyDistPool<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+}
+
+#[derive(Accounts)]
+pub struct CompostMonkeBatch<'info> {
+    /// Anyone can compost — gets rent refund per composted monke
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    /// CHECK: program_vault PDA — pays the compost bounty
+    #[account(mut, seeds = [b"program_vault"], bump = state.program_vault_bump)]
+    pub program_vault: AccountInfo<'info>,
+    // remaining_accounts: n × [monke_burn (writable), nft_mint] — see compost_monke_batch
+}
+
 #[derive(Accounts)]
 pub struct CompostMonke<'info> {
     /// Anyone can compost — gets rent refund as incentive
@@ -1276,30 +3774,205 @@ pub struct CompostMonke<'info> {
     )]
     pub monke_burn: Account<'info, MonkeBurn>,
 
-    /// NFT mint — verify supply == 0 (burned)
-    #[account(constraint = nft_mint.key() == monke_burn.nft_mint @ MonkeError::InvalidNftMint)]
-    pub nft_mint: Account<'info, Mint>,
+    /// NFT mint — verify supply == 0 (burned)
+    #[account(constraint = nft_mint.key() == monke_burn.nft_mint @ MonkeError::InvalidNftMint)]
+    pub nft_mint: Account<'info, Mint>,
+
+    /// CHECK: program_vault PDA — unclaimed SOL stays here; pays the compost bounty
+    #[account(mut, seeds = [b"program_vault"], bump = state.program_vault_bump)]
+    pub program_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurgeIneligibleMonke<'info> {
+    /// Anyone can purge — gets rent refund as incentive
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"monke_state"], bump = state.state_bump)]
+    pub state: Account<'info, MonkeState>,
+
+    #[account(
+        mut,
+        close = caller,
+        seeds = [b"monke_burn", monke_burn.nft_mint.as_ref()],
+        bump
+    )]
+    pub monke_burn: Account<'info, MonkeBurn>,
+
+    /// CHECK: NFT mint — bound to monke_burn
+    #[account(constraint = nft_mint.key() == monke_burn.nft_mint @ MonkeError::InvalidNftMint)]
+    pub nft_mint: AccountInfo<'info>,
+
+    /// CHECK: Metaplex metadata — PDA-of-mint and owner checked in the collection validators
+    pub nft_metadata: AccountInfo<'info>,
+
+    /// CHECK: CollectionConfig PDA for the NFT's collection (may be uninitialized).
+    /// Address derived from the metadata and checked in the handler.
+    pub collection_config: AccountInfo<'info>,
+}
+
+// ============ EVENTS ============
+
+#[event]
+pub struct FeedEvent {
+    pub user: Pubkey,
+    pub nft_mint: Pubkey,
+    pub new_weight: u64,
+    pub total_weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DepositEvent {
+    pub amount: u64,
+    pub total_distributed: u64,
+    pub accumulator: u128,
+    pub total_share_weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WeightDecayEvent {
+    pub nft_mint: Pubkey,
+    pub old_weight: u64,
+    pub new_weight: u64,
+    pub epochs: u64,           // Whole decay epochs applied
+    pub forfeited_sol: u64,    // Unclaimed SOL that decayed with the weight (released from sol_liability)
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DefeedEvent {
+    pub user: Pubkey,
+    pub nft_mint: Pubkey,
+    pub weight_removed: u64,
+    pub new_weight: u64,
+    pub total_weight: u64,
+    pub settled_sol: u64,      // Pending SOL moved to unclaimed_sol before the decrement
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimToEvent {
+    pub user: Pubkey,          // NFT holder who signed
+    pub recipient: Pubkey,     // Wallet that received the SOL
+    pub nft_mint: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub share_weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimEvent {
+    pub user: Pubkey,
+    pub nft_mint: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub share_weight: u64,
+    pub timestamp: i64,
+}
+
+/// reward_debt exceeded share_weight * accumulator, so pending was floored at 0.
+/// Emitted for monitoring only; both values are PRECISION-scaled.
+#[event]
+pub struct RewardSaturationEvent {
+    pub nft_mint: Pubkey,
+    pub reward_debt: u128,
+    pub computed: u128,
+}
+
+#[event]
+pub struct ClaimAllEvent {
+    pub user: Pubkey,
+    pub nfts_claimed: u32,     // NFTs paid (one ClaimEvent each)
+    pub nfts_skipped: u32,     // Not held by the caller, or nothing pending
+    pub total_amount: u64,     // Lamports paid in the single vault transfer
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PartialClaimEvent {
+    pub user: Pubkey,
+    pub nft_mint: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,        // Owed SOL left pending after this claim
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PendingRewardsEvent {
+    pub nft_mint: Pubkey,
+    pub share_weight: u64,
+    pub effective_weight: u64, // share_weight after decay owed since the decay clock (decay_clock)
+    pub pending: u64,          // Lamports claim would pay (ignoring vault liquidity)
+}
+
+#[event]
+pub struct ClaimableEvent {
+    pub nft_mint: Pubkey,
+    pub theoretical: u64,      // Accumulator entitlement
+    pub payable: u64,          // min(theoretical, program_vault balance - rent)
+    pub can_claim_now: bool,   // claim would succeed right now
+}
+
+#[event]
+pub struct PurgeEvent {
+    pub nft_mint: Pubkey,
+    pub weight_removed: u64,
+    /// Released from sol_liability — reconcile_vault_surplus redistributes it
+    pub unclaimed_sol_released: u64,
+    pub new_total_weight: u64,
+    pub timestamp: i64,
+}
 
-    /// CHECK: program_vault PDA — unclaimed SOL stays here
-    #[account(mut, seeds = [b"program_vault"], bump = state.program_vault_bump)]
-    pub program_vault: AccountInfo<'info>,
+#[event]
+pub struct CompostEvent {
+    pub nft_mint: Pubkey,
+    pub weight_removed: u64,
+    /// Released from sol_liability — reconcile_vault_surplus redistributes it
+    pub unclaimed_sol_released: u64,
+    pub new_total_weight: u64,
+    pub bounty_paid: u64,              // Compost bounty paid to the caller (0 = off)
+    pub timestamp: i64,
+}
 
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct DistributionBegunEvent {
+    pub epoch: u64,
+    pub snapshot_weight: u64,
+    pub snapshot_acc: u128,
+    pub authority: Pubkey,
+    pub timestamp: i64,
 }
 
-// ============ EVENTS ============
+#[event]
+pub struct DistributionClosedEvent {
+    pub epoch: u64,
+    pub amount: u64,           // 0 when cancelled
+    pub snapshot_weight: u64,
+    pub late_weight: u64,      // Fed during the window, excluded from this distribution
+    pub accumulator: u128,
+    pub timestamp: i64,
+}
 
 #[event]
-pub struct FeedEvent {
-    pub user: Pubkey,
+pub struct PendingWeightActivatedEvent {
     pub nft_mint: Pubkey,
+    pub epoch: u64,
+    pub weight_added: u64,
     pub new_weight: u64,
-    pub total_weight: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct DepositEvent {
+pub struct TokenDepositEvent {
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
     pub amount: u64,
     pub total_distributed: u64,
     pub accumulator: u128,
@@ -1308,9 +3981,10 @@ pub struct DepositEvent {
 }
 
 #[event]
-pub struct ClaimEvent {
+pub struct TokenClaimEvent {
     pub user: Pubkey,
     pub nft_mint: Pubkey,
+    pub mint: Pubkey,
     pub amount: u64,
     pub total_claimed: u64,
     pub share_weight: u64,
@@ -1318,12 +3992,131 @@ pub struct ClaimEvent {
 }
 
 #[event]
-pub struct CompostEvent {
+pub struct RewardTokenMintSetEvent {
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct SolLiabilityInitializedEvent {
+    pub sol_liability: u64,
+    pub vault_lamports: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SurplusReconciledEvent {
+    pub surplus: u64,
+    pub accumulator: u128,
+    pub total_share_weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionRegisteredEvent {
+    pub collection: Pubkey,
+    pub weight: u64,
+    pub enabled: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BananasPerFeedUpdatedEvent {
+    pub old_bananas_per_feed: u64,
+    pub new_bananas_per_feed: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnverifiedWindowSetEvent {
+    pub old_allow_unverified_until: i64,
+    pub new_allow_unverified_until: i64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnverifiedCollectionAcceptedEvent {
     pub nft_mint: Pubkey,
-    pub weight_removed: u64,
-    /// SOL stays in program_vault as surplus, boosting future claims for all holders
-    pub unclaimed_sol_absorbed: u64,
-    pub new_total_weight: u64,
+    pub collection: Pubkey,
+    pub allow_unverified_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompostBountyUpdatedEvent {
+    pub old_compost_bounty_lamports: u64,
+    pub new_compost_bounty_lamports: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DecayParamsSetEvent {
+    pub decay_bps_per_epoch: u16,
+    pub epoch_len_seconds: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WeightsUpdatedEvent {
+    pub old_gen2_weight: u64,
+    pub old_gen3_weight: u64,
+    pub new_gen2_weight: u64,
+    pub new_gen3_weight: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionPauseEvent {
+    pub gen2_paused: bool,
+    pub gen3_paused: bool,
+    pub global_paused: bool,   // Overrides both when set
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinDepositUpdatedEvent {
+    pub old_min_deposit_lamports: u64,
+    pub new_min_deposit_lamports: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistPoolChangeProposedEvent {
+    pub current_dist_pool: Pubkey,
+    pub new_dist_pool: Pubkey,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct DistPoolChangeCancelledEvent {
+    pub cancelled_dist_pool: Pubkey,
+    pub was_effective_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistPoolAppliedEvent {
+    pub old_dist_pool: Pubkey,
+    pub new_dist_pool: Pubkey,
     pub timestamp: i64,
 }
 
@@ -1393,4 +4186,881 @@ pub enum MonkeError {
 
     #[msg("Invalid Metaplex Core asset account")]
     InvalidCoreAsset,
+
+    #[msg("dist_pool cannot be the null address or does not match state")]
+    InvalidDistPool,
+
+    #[msg("No pending dist_pool change")]
+    NoPendingDistPoolChange,
+
+    #[msg("dist_pool timelock not expired (24 hours required)")]
+    DistPoolTimelockNotExpired,
+
+    #[msg("Nothing to reconcile (program_vault has no untracked surplus)")]
+    NothingToReconcile,
+
+    #[msg("NFT still belongs to an approved collection — nothing to purge")]
+    MonkeStillEligible,
+
+    #[msg("min_deposit_lamports must be between 1 lamport and 10 SOL")]
+    InvalidMinDeposit,
+
+    #[msg("Feeding is paused for this collection")]
+    CollectionPaused,
+
+    #[msg("Feed batch: 1-8 NFTs, remaining_accounts must be NFTs × 4 with matching MonkeBurn PDAs and no duplicate mints")]
+    InvalidFeedBatch,
+
+    #[msg("Compost batch: 1-10 [monke_burn, nft_mint] pairs, valid PDAs, no duplicates")]
+    InvalidCompostBatch,
+
+    #[msg("Claim-all batch: 1-10 [monke_burn, nft_mint, user_nft_account] groups, valid PDAs, no duplicate mints")]
+    InvalidClaimAllBatch,
+
+    #[msg("compost_bounty_lamports exceeds MAX_COMPOST_BOUNTY_LAMPORTS (0.01 SOL)")]
+    InvalidCompostBounty,
+
+    #[msg("bananas_per_feed must be 1 to 10M whole tokens")]
+    InvalidBananasPerFeed,
+
+    #[msg("Compost bounty exceeds program_vault surplus above sol_liability")]
+    CompostBountyExceedsHeadroom,
+
+    #[msg("Claim recipient cannot be an executable account")]
+    InvalidRecipient,
+
+    #[msg("Weight multiplier must be between 1 and MAX_WEIGHT_MULTIPLIER")]
+    InvalidWeight,
+
+    #[msg("Decay must be at most MAX_DECAY_BPS_PER_EPOCH with an epoch of at least MIN_DECAY_EPOCH_SECONDS")]
+    InvalidDecayParams,
+
+    #[msg("Defeed weight must be between 1 and the monke's current weight")]
+    InvalidDefeedWeight,
+
+    #[msg("Collection is registered but disabled for new feeds")]
+    CollectionDisabled,
+
+    #[msg("CollectionConfig account does not match the NFT's collection")]
+    InvalidCollectionConfig,
+
+    #[msg("allow_unverified_until must be 0 or in the future, at most MAX_UNVERIFIED_WINDOW_SECONDS ahead")]
+    InvalidUnverifiedWindow,
+
+    #[msg("sol_liability not initialized — admin must call init_sol_liability first")]
+    LiabilityNotInitialized,
+
+    #[msg("sol_liability already initialized")]
+    LiabilityAlreadyInitialized,
+
+    #[msg("dist_pool is not a dist_pool PDA and must sign the deposit")]
+    DistPoolNotSigner,
+
+    #[msg("Account is not a migratable monke_bananas account")]
+    InvalidMigrationAccount,
+
+    #[msg("Reward token mint not configured — call set_reward_token_mint first")]
+    RewardTokenNotConfigured,
+
+    #[msg("Reward token mint can't change after tokens have been distributed")]
+    RewardTokenLocked,
+
+    #[msg("Claim pending reward tokens before defeeding")]
+    UnclaimedRewardToken,
+
+    #[msg("A snapshot distribution is already open")]
+    DistributionAlreadyOpen,
+
+    #[msg("No snapshot distribution is open")]
+    NoOpenDistribution,
+
+    #[msg("Distribution window is still open")]
+    DistributionNotClosed,
+
+    #[msg("Monke has no pending weight to activate")]
+    NoPendingWeight,
+
+    #[msg("Activate pending weight from a closed distribution before feeding again")]
+    PendingWeightNotActivated,
+
+    #[msg("Partial claim amount exceeds owed SOL")]
+    ClaimExceedsOwed,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+
+    /// Token Metadata bytes up to and including the collection field.
+    fn metadata_fixture(
+        creators: usize,
+        edition_nonce: Option<u8>,
+        token_standard: Option<u8>,
+        collection: Option<(bool, Pubkey)>,
+    ) -> Vec<u8> {
+        let mut data = vec![4u8]; // Key::MetadataV1
+        data.extend_from_slice(Pubkey::new_unique().as_ref()); // update_authority
+        data.extend_from_slice(Pubkey::new_unique().as_ref()); // mint
+        for field in ["SMB #1355", "SMB", "https://arweave.net/monke.json"] {
+            data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            data.extend_from_slice(field.as_bytes());
+        }
+        data.extend_from_slice(&500u16.to_le_bytes()); // seller_fee_basis_points
+        if creators == 0 {
+            data.push(0);
+        } else {
+            data.push(1);
+            data.extend_from_slice(&(creators as u32).to_le_bytes());
+            for _ in 0..creators {
+                data.extend_from_slice(Pubkey::new_unique().as_ref());
+                data.extend_from_slice(&[1, 50]); // verified, share
+            }
+        }
+        data.extend_from_slice(&[1, 1]); // primary_sale_happened, is_mutable
+        for opt in [edition_nonce, token_standard] {
+            match opt {
+                Some(v) => data.extend_from_slice(&[1, v]),
+                None => data.push(0),
+            }
+        }
+        match collection {
+            Some((verified, key)) => {
+                data.extend_from_slice(&[1, verified as u8]);
+                data.extend_from_slice(key.as_ref());
+            }
+            None => data.push(0),
+        }
+        data.push(0); // uses: None
+        data
+    }
+
+    // ---- parse_metadata_collection ----
+
+    #[test]
+    fn parses_a_verified_collection() {
+        let collection = Pubkey::new_unique();
+        let data = metadata_fixture(2, Some(255), Some(0), Some((true, collection)));
+        assert_eq!(parse_metadata_collection(&data).unwrap(), Some((collection, true)));
+    }
+
+    #[test]
+    fn parses_an_unverified_pnft_collection() {
+        // pNFT: token_standard = ProgrammableNonFungible (4), no creators, no edition_nonce
+        let collection = Pubkey::new_unique();
+        let data = metadata_fixture(0, None, Some(4), Some((false, collection)));
+        assert_eq!(parse_metadata_collection(&data).unwrap(), Some((collection, false)));
+    }
+
+    #[test]
+    fn metadata_without_collection_is_none() {
+        let data = metadata_fixture(1, Some(254), None, None);
+        assert_eq!(parse_metadata_collection(&data).unwrap(), None);
+    }
+
+    #[test]
+    fn malformed_metadata_is_rejected() {
+        let collection = Pubkey::new_unique();
+        let data = metadata_fixture(1, None, None, Some((true, collection)));
+        let invalid: Error = error!(MonkeError::InvalidMetadata);
+
+        // Truncated inside the collection key
+        assert_eq!(parse_metadata_collection(&data[..data.len() - 2]).unwrap_err(), invalid);
+
+        // Option tag that is neither 0 nor 1 (the collection tag)
+        let mut bad_tag = data.clone();
+        let tag_at = bad_tag.len() - 1 - 32 - 1 - 1;
+        assert_eq!(bad_tag[tag_at], 1);
+        bad_tag[tag_at] = 2;
+        assert_eq!(parse_metadata_collection(&bad_tag).unwrap_err(), invalid);
+
+        // verified flag that is neither 0 nor 1
+        let mut bad_verified = data;
+        bad_verified[tag_at + 1] = 7;
+        assert_eq!(parse_metadata_collection(&bad_verified).unwrap_err(), invalid);
+    }
+
+    // ---- unverified-collection window ----
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn verified_collection_passes_without_a_window() {
+        let data = metadata_fixture(1, None, None, Some((true, Pubkey::new_unique())));
+        let (_, verified) = parse_metadata_collection(&data).unwrap().unwrap();
+        // Window never opened, open, and expired: no bypass either way
+        assert!(!require_collection_admitted(verified, 0, NOW).unwrap());
+        assert!(!require_collection_admitted(verified, NOW + 3_600, NOW).unwrap());
+        assert!(!require_collection_admitted(verified, NOW - 1, NOW).unwrap());
+    }
+
+    #[test]
+    fn unverified_collection_accepted_in_window() {
+        let data = metadata_fixture(1, None, None, Some((false, Pubkey::new_unique())));
+        let (_, verified) = parse_metadata_collection(&data).unwrap().unwrap();
+        assert!(require_collection_admitted(verified, NOW + 3_600, NOW).unwrap());
+        assert!(require_collection_admitted(verified, NOW + 1, NOW).unwrap());
+    }
+
+    #[test]
+    fn unverified_collection_rejected_after_window() {
+        let data = metadata_fixture(1, None, None, Some((false, Pubkey::new_unique())));
+        let (_, verified) = parse_metadata_collection(&data).unwrap().unwrap();
+        let not_verified: Error = error!(MonkeError::CollectionNotVerified);
+        // Expired exactly at the deadline and after it
+        assert_eq!(require_collection_admitted(verified, NOW, NOW).unwrap_err(), not_verified);
+        assert_eq!(require_collection_admitted(verified, NOW - 1, NOW).unwrap_err(), not_verified);
+        // Window never opened
+        assert_eq!(require_collection_admitted(verified, 0, NOW).unwrap_err(), not_verified);
+    }
+
+    #[test]
+    fn goose_collection_follows_the_same_window() {
+        let verified = metadata_fixture(1, None, None, Some((true, GOOSE_PIXEL_COLLECTION)));
+        let unverified = metadata_fixture(1, None, None, Some((false, GOOSE_PIXEL_COLLECTION)));
+        let not_verified: Error = error!(MonkeError::CollectionNotVerified);
+
+        assert!(!goose_collection_admitted(&verified, 0, NOW).unwrap());
+        assert!(goose_collection_admitted(&unverified, NOW + 3_600, NOW).unwrap());
+        assert_eq!(goose_collection_admitted(&unverified, NOW, NOW).unwrap_err(), not_verified);
+        assert_eq!(goose_collection_admitted(&unverified, 0, NOW).unwrap_err(), not_verified);
+
+        // The window never admits another collection
+        let other = metadata_fixture(1, None, None, Some((false, Pubkey::new_unique())));
+        assert_eq!(
+            goose_collection_admitted(&other, NOW + 3_600, NOW).unwrap_err(),
+            error!(MonkeError::InvalidGooseCollection)
+        );
+    }
+
+    // ---- collection registry ----
+
+    fn registered(collection: Pubkey, weight: u64, enabled: bool) -> CollectionConfig {
+        let zeroed = [0u8; CollectionConfig::SIZE];
+        let mut config = CollectionConfig::try_deserialize_unchecked(&mut &zeroed[..]).unwrap();
+        config.register(collection, weight, enabled, 255).unwrap();
+        config
+    }
+
+    /// Feeds `collection`'s NFT through validate_collection_and_weight with `config` passed.
+    fn feed_weight(collection: Pubkey, config: Option<&CollectionConfig>) -> Result<(u64, SmbGen)> {
+        anchor_lang::solana_program::program_stubs::set_syscall_stubs(Box::new(SysvarStubs));
+        let mut state = zeroed_state();
+        state.smb_collection = Pubkey::new_unique();
+        state.smb_gen3_collection = Pubkey::new_unique();
+        let nft_mint = Pubkey::new_unique();
+        let (metadata_key, _) = Pubkey::find_program_address(
+            &[b"metadata", MPL_TOKEN_METADATA_ID.as_ref(), nft_mint.as_ref()],
+            &MPL_TOKEN_METADATA_ID,
+        );
+        let mut data = metadata_fixture(1, None, None, Some((true, collection)));
+        let mut lamports = 1u64;
+        let owner = MPL_TOKEN_METADATA_ID;
+        let metadata = AccountInfo::new(&metadata_key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        validate_collection_and_weight(&metadata, &nft_mint, &state, config)
+    }
+
+    #[test]
+    fn registering_a_collection_bounds_its_weight() {
+        let collection = Pubkey::new_unique();
+        let config = registered(collection, 3, true);
+        assert_eq!((config.collection, config.weight, config.enabled, config.bump), (collection, 3, true, 255));
+
+        let mut config = registered(collection, 3, true);
+        for weight in [0, MAX_WEIGHT_MULTIPLIER + 1] {
+            assert_eq!(
+                config.register(collection, weight, true, 255).unwrap_err(),
+                error!(MonkeError::InvalidWeight)
+            );
+        }
+        assert_eq!(
+            config.register(Pubkey::default(), 3, true, 255).unwrap_err(),
+            error!(MonkeError::InvalidCollection)
+        );
+        // Re-registering overwrites the entry
+        config.register(collection, MAX_WEIGHT_MULTIPLIER, false, 255).unwrap();
+        assert_eq!((config.weight, config.enabled), (MAX_WEIGHT_MULTIPLIER, false));
+    }
+
+    #[test]
+    fn feed_takes_the_registered_collections_weight() {
+        let collection = Pubkey::new_unique();
+        let config = registered(collection, 4, true);
+        assert_eq!(feed_weight(collection, Some(&config)).unwrap(), (4, SmbGen::Registered));
+
+        // Without its registry entry, or with another collection's, the NFT isn't feedable
+        let invalid: Error = error!(MonkeError::InvalidCollection);
+        assert_eq!(feed_weight(collection, None).unwrap_err(), invalid);
+        let other = registered(Pubkey::new_unique(), 4, true);
+        assert_eq!(feed_weight(collection, Some(&other)).unwrap_err(), invalid);
+    }
+
+    #[test]
+    fn disabled_collection_rejects_feeds() {
+        let collection = Pubkey::new_unique();
+        let config = registered(collection, 4, false);
+        assert_eq!(feed_weight(collection, Some(&config)).unwrap_err(), error!(MonkeError::CollectionDisabled));
+    }
+
+    // ---- bananas_per_feed ----
+
+    #[test]
+    fn feed_burn_scales_with_6_and_9_decimal_mints() {
+        let mut state = zeroed_state();
+        // Pre-field state keeps the 6-decimal constant
+        assert_eq!(state.effective_bananas_per_feed(), BANANAS_PER_FEED);
+
+        // initialize: 1M whole tokens at the mint's own decimals
+        state.bananas_per_feed = tokens_to_base_units(FEED_TOKENS, 6).unwrap();
+        assert_eq!(state.effective_bananas_per_feed(), BANANAS_PER_FEED);
+        state.bananas_per_feed = tokens_to_base_units(FEED_TOKENS, 9).unwrap();
+        assert_eq!(state.effective_bananas_per_feed(), 1_000_000_000_000_000);
+
+        // A feed-amount halving on the 9-decimal mint
+        state.bananas_per_feed = bounded_feed_burn(500_000, 9).unwrap();
+        assert_eq!(state.effective_bananas_per_feed(), 500_000_000_000_000);
+        assert_eq!(bounded_feed_burn(500_000, 6).unwrap(), 500_000_000_000);
+    }
+
+    #[test]
+    fn feed_burn_outside_bounds_is_rejected() {
+        for tokens in [0, MAX_FEED_TOKENS + 1] {
+            assert_eq!(bounded_feed_burn(tokens, 6).unwrap_err(), error!(MonkeError::InvalidBananasPerFeed));
+        }
+        assert_eq!(bounded_feed_burn(MAX_FEED_TOKENS, 6).unwrap(), 10_000_000_000_000);
+        // In bounds but too many decimals to fit a u64
+        assert_eq!(bounded_feed_burn(MAX_FEED_TOKENS, 18).unwrap_err(), error!(MonkeError::InvalidBananasPerFeed));
+        assert_eq!(tokens_to_base_units(FEED_TOKENS, 20), None);
+    }
+
+    // ---- defeed_monke ----
+
+    fn zeroed_state() -> MonkeState {
+        MonkeState::try_deserialize_unchecked(&mut &vec![0u8; MonkeState::SIZE][..]).unwrap()
+    }
+
+    /// A fed monke with nothing pending at the state's current accumulators.
+    fn fed_burn(state: &MonkeState, weight: u64, last_interaction_ts: i64) -> MonkeBurn {
+        let mut burn =
+            MonkeBurn::try_deserialize_unchecked(&mut &vec![0u8; MonkeBurn::SIZE][..]).unwrap();
+        burn.nft_mint = Pubkey::new_unique();
+        burn.share_weight = weight;
+        burn.reward_debt = weight as u128 * state.accumulated_sol_per_share;
+        burn.token_reward_debt = weight as u128 * state.accumulated_token_per_share;
+        burn.last_interaction_ts = last_interaction_ts;
+        burn
+    }
+
+    /// Credit `lamports` over total_share_weight, as execute_deposit_sol does.
+    fn deposit(state: &mut MonkeState, lamports: u64) {
+        state.accumulated_sol_per_share +=
+            lamports as u128 * PRECISION / state.total_share_weight as u128;
+        state.sol_liability += lamports;
+    }
+
+    #[test]
+    fn defeed_holds_rewards_earned_before_it_for_claim() {
+        let mut state = zeroed_state();
+        state.total_share_weight = 10;
+        let mut burn = fed_burn(&state, 10, NOW);
+        deposit(&mut state, 50_000);
+
+        // Nothing is paid: the earned SOL moves to unclaimed_sol and stays owed
+        let settled = settle_defeed(&mut state, &mut burn, 4, NOW).unwrap();
+        assert_eq!(settled, 50_000);
+        assert_eq!((burn.unclaimed_sol, burn.claimed_sol), (50_000, 0));
+        assert_eq!(state.sol_liability, 50_000);
+        assert_eq!((burn.share_weight, state.total_share_weight), (6, 6));
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 50_000);
+
+        // The remaining weight earns on top of it from here on
+        deposit(&mut state, 60_000);
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 110_000);
+
+        // More than the remaining weight, or nothing, is rejected
+        let invalid: Error = error!(MonkeError::InvalidDefeedWeight);
+        assert_eq!(settle_defeed(&mut state.clone(), &mut burn.clone(), 7, NOW).unwrap_err(), invalid);
+        assert_eq!(settle_defeed(&mut state.clone(), &mut burn.clone(), 0, NOW).unwrap_err(), invalid);
+
+        // claim pays the held SOL with the new rewards and empties the buffer
+        assert_eq!(settle_claim(&mut state, &mut burn, NOW).unwrap(), 110_000);
+        assert_eq!((burn.unclaimed_sol, burn.claimed_sol), (0, 110_000));
+        assert_eq!(state.sol_liability, 0);
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 0);
+    }
+
+    #[test]
+    fn partial_claim_takes_defeed_held_sol_first() {
+        let mut state = zeroed_state();
+        state.total_share_weight = 10;
+        let mut burn = fed_burn(&state, 10, NOW);
+        deposit(&mut state, 50_000);
+        settle_defeed(&mut state, &mut burn, 5, NOW).unwrap();
+        deposit(&mut state, 10_000); // all to the remaining weight of 5
+
+        let debt = burn.reward_debt;
+        assert_eq!(settle_partial_claim(&mut state, &mut burn, 30_000, NOW).unwrap(), 60_000);
+        assert_eq!((burn.unclaimed_sol, burn.reward_debt), (20_000, debt));
+
+        assert_eq!(settle_partial_claim(&mut state, &mut burn, 25_000, NOW).unwrap(), 30_000);
+        assert_eq!(burn.unclaimed_sol, 0);
+        assert_eq!(burn.reward_debt, debt + 5_000 * PRECISION);
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 5_000);
+
+        assert_eq!(settle_partial_claim(&mut state, &mut burn, 5_000, NOW).unwrap(), 5_000);
+        assert_eq!(burn.claimed_sol, 60_000);
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 0);
+    }
+
+    #[test]
+    fn defeed_checks_weight_after_decay() {
+        let mut state = zeroed_state();
+        state.decay_bps_per_epoch = MAX_DECAY_BPS_PER_EPOCH; // halves per epoch
+        state.epoch_len_seconds = MIN_DECAY_EPOCH_SECONDS;
+        let epoch = MIN_DECAY_EPOCH_SECONDS as i64;
+        state.total_share_weight = 20;
+        let mut burn = fed_burn(&state, 10, NOW - epoch);
+        let other = fed_burn(&state, 10, NOW);
+        deposit(&mut state, 20_000);
+
+        // One epoch owed: 10 -> 5, so defeeding 6 is more than the monke has left
+        let mut over = burn.clone();
+        let mut over_state = state.clone();
+        assert_eq!(
+            settle_defeed(&mut over_state, &mut over, 6, NOW).unwrap_err(),
+            error!(MonkeError::InvalidDefeedWeight)
+        );
+
+        // Half the 10_000 earned decays with the weight; the rest is held for claim
+        let settled = settle_defeed(&mut state, &mut burn, 4, NOW).unwrap();
+        assert_eq!(settled, 5_000);
+        assert_eq!(burn.unclaimed_sol, 5_000);
+        assert_eq!(state.sol_liability, 15_000);
+        assert_eq!(burn.share_weight, 1);
+        assert_eq!(burn.last_interaction_ts, NOW);
+        // Decay and defeed each leave the total once: it still sums the per-NFT weights
+        assert_eq!(state.total_share_weight, burn.share_weight + other.share_weight);
+    }
+
+    // ---- pending_rewards ----
+
+    #[test]
+    fn pending_rewards_matches_what_claim_pays() {
+        let mut state = zeroed_state();
+        state.total_share_weight = 10;
+        let mut small = fed_burn(&state, 3, NOW);
+        let mut large = fed_burn(&state, 7, NOW);
+        credit_sol_deposit(&mut state, 1_001).unwrap();
+
+        let (small_view, large_view) =
+            (pending_owed(&small, &state, NOW).unwrap(), pending_owed(&large, &state, NOW).unwrap());
+        assert_eq!((small_view, large_view), (300, 700));
+        // Reading doesn't move the accumulator or the debt
+        assert_eq!(pending_owed(&small, &state, NOW).unwrap(), small_view);
+
+        assert_eq!(settle_claim(&mut state, &mut small, NOW).unwrap(), small_view);
+        assert_eq!(settle_claim(&mut state, &mut large, NOW).unwrap(), large_view);
+        assert_eq!(pending_owed(&small, &state, NOW).unwrap(), 0);
+
+        // A second deposit is reported and paid the same way
+        credit_sol_deposit(&mut state, 2_000).unwrap();
+        let view = pending_owed(&large, &state, NOW).unwrap();
+        assert_eq!(view, 1_400);
+        assert_eq!(settle_claim(&mut state, &mut large, NOW).unwrap(), view);
+    }
+
+    #[test]
+    fn reward_debt_above_the_product_fires_a_saturation_event() {
+        let mut state = zeroed_state();
+        state.total_share_weight = 3;
+        let mut burn = fed_burn(&state, 3, NOW);
+        credit_sol_deposit(&mut state, 1_000).unwrap();
+        let computed = 3 * state.accumulated_sol_per_share;
+
+        // Exactly at the product and below it: no event
+        burn.reward_debt = computed;
+        assert!(pending_or_saturation(&burn, computed).1.is_none());
+        burn.reward_debt = computed - 1;
+        let (pending, event) = pending_or_saturation(&burn, computed);
+        assert_eq!(pending, 1);
+        assert!(event.is_none());
+
+        // One unit of rounding over: pending floors at 0 and the event carries both values
+        burn.reward_debt = computed + 1;
+        let (pending, event) = pending_or_saturation(&burn, computed);
+        assert_eq!(pending, 0);
+        let event = event.unwrap();
+        assert_eq!((event.nft_mint, event.reward_debt, event.computed), (burn.nft_mint, computed + 1, computed));
+        // Behavior unchanged: nothing is owed
+        assert_eq!(sol_pending_scaled(&burn, 3, state.accumulated_sol_per_share).unwrap(), 0);
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 0);
+    }
+
+    // ---- claim_partial ----
+
+    #[test]
+    fn partial_claims_summing_to_owed_match_a_full_claim() {
+        let mut state = zeroed_state();
+        state.total_share_weight = 3;
+        let mut partial = fed_burn(&state, 3, NOW);
+        let mut full = partial.clone();
+        deposit(&mut state, 1_000); // 333 per weight, 999 owed after rounding
+        let mut full_state = state.clone();
+
+        assert_eq!(settle_partial_claim(&mut state, &mut partial, 400, NOW).unwrap(), 999);
+        assert_eq!(pending_owed(&partial, &state, NOW).unwrap(), 599);
+        assert_eq!(settle_partial_claim(&mut state, &mut partial, 599, NOW).unwrap(), 599);
+        assert_eq!(settle_claim(&mut full_state, &mut full, NOW).unwrap(), 999);
+
+        assert_eq!(pending_owed(&partial, &state, NOW).unwrap(), 0);
+        assert_eq!(partial.reward_debt, full.reward_debt);
+        assert_eq!(partial.claimed_sol, full.claimed_sol);
+        assert_eq!(state.sol_liability, full_state.sol_liability);
+    }
+
+    #[test]
+    fn partial_claim_over_owed_is_rejected() {
+        let mut state = zeroed_state();
+        state.total_share_weight = 2;
+        let mut burn = fed_burn(&state, 2, NOW);
+        deposit(&mut state, 1_000);
+        assert_eq!(
+            settle_partial_claim(&mut state, &mut burn, 1_001, NOW).unwrap_err(),
+            error!(MonkeError::ClaimExceedsOwed)
+        );
+        assert_eq!(
+            settle_partial_claim(&mut state, &mut burn, 0, NOW).unwrap_err(),
+            error!(MonkeError::NothingToClaim)
+        );
+    }
+
+    #[test]
+    fn partial_claims_settle_decay() {
+        let mut state = decaying_state(MAX_DECAY_BPS_PER_EPOCH);
+        state.total_share_weight = 8;
+        let mut burn = fed_burn(&state, 8, NOW);
+        deposit(&mut state, 8_000);
+
+        // A dust claim an epoch later still writes the decay
+        assert_eq!(settle_partial_claim(&mut state, &mut burn, 1, NOW + EPOCH).unwrap(), 4_000);
+        assert_eq!((burn.share_weight, state.total_share_weight), (4, 4));
+        assert_eq!(burn.last_interaction_ts, NOW + EPOCH);
+        assert_eq!(pending_owed(&burn, &state, NOW + EPOCH).unwrap(), 3_999);
+    }
+
+    // ---- claim_to ----
+
+    /// Rent and Clock for the claim path's Rent::get / Clock::get.
+    struct SysvarStubs;
+
+    impl anchor_lang::solana_program::program_stubs::SyscallStubs for SysvarStubs {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock { unix_timestamp: NOW, ..Clock::default() };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    /// MonkeBurn layout from before token_reward_debt.
+    const LEGACY_MONKE_BURN_SIZE: usize = 8 + 32 + 8 + 16 + 8 + 8;
+
+    /// The input-buffer framing AccountInfo::resize relies on: the original data
+    /// length in the 4 bytes before the key, the current one in the 8 bytes before
+    /// the data, and room behind the data to grow into.
+    #[repr(C, align(8))]
+    struct KeySlot {
+        original_data_len: u32,
+        key: Pubkey,
+    }
+
+    #[repr(C, align(8))]
+    struct DataSlot {
+        data_len: u64,
+        data: [u8; MonkeBurn::SIZE],
+    }
+
+    #[test]
+    fn claim_to_grows_a_legacy_burn() {
+        anchor_lang::solana_program::program_stubs::set_syscall_stubs(Box::new(SysvarStubs));
+        let mut state = zeroed_state();
+        state.total_share_weight = 10;
+        let burn = fed_burn(&state, 10, NOW);
+        deposit(&mut state, 50_000);
+
+        let mut current = Vec::new();
+        burn.try_serialize(&mut current).unwrap();
+        let legacy = LEGACY_MONKE_BURN_SIZE;
+        let key_slot = KeySlot { original_data_len: legacy as u32, key: Pubkey::new_unique() };
+        let mut data_slot = DataSlot { data_len: legacy as u64, data: [0xff; MonkeBurn::SIZE] };
+        data_slot.data[..legacy].copy_from_slice(&current[..legacy]);
+        // CPIs are no-ops off-chain, so start rent-exempt at the grown size
+        let mut burn_lamports = Rent::default().minimum_balance(MonkeBurn::SIZE);
+        let burn_info = AccountInfo::new(
+            &key_slot.key,
+            false,
+            true,
+            &mut burn_lamports,
+            &mut data_slot.data[..legacy],
+            &crate::ID,
+            false,
+            0,
+        );
+        let [user_key, recipient_key, vault_key] = [(); 3].map(|_| Pubkey::new_unique());
+        let (system, loader) = (anchor_lang::system_program::ID, Pubkey::new_unique());
+        let (mut user_lamports, mut recipient_lamports) = (1_000_000_000u64, 0u64);
+        let (mut vault_lamports, mut program_lamports) = (100_000_000u64, 1u64);
+        let (mut user_data, mut recipient_data) = (Vec::new(), Vec::new());
+        let (mut vault_data, mut program_data) = (Vec::new(), Vec::new());
+        let user = AccountInfo::new(&user_key, true, true, &mut user_lamports, &mut user_data, &system, false, 0);
+        let recipient = AccountInfo::new(
+            &recipient_key, false, true, &mut recipient_lamports, &mut recipient_data, &system, false, 0,
+        );
+        let vault =
+            AccountInfo::new(&vault_key, false, true, &mut vault_lamports, &mut vault_data, &crate::ID, false, 0);
+        let system_program =
+            AccountInfo::new(&system, false, false, &mut program_lamports, &mut program_data, &loader, true, 0);
+
+        // Same sequence as the handler: grow + load, settle and pay, write back
+        let mut loaded = load_monke_burn(&burn_info, &user, &system_program).unwrap();
+        assert_eq!(burn_info.data_len(), MonkeBurn::SIZE);
+        assert_eq!((loaded.token_reward_debt, loaded.last_interaction_ts), (0, 0));
+        assert_eq!(loaded.share_weight, 10);
+        let owed = execute_claim(&mut state, &mut loaded, &vault, &recipient).unwrap();
+        store_monke_burn(&burn_info, &loaded).unwrap();
+        assert_eq!((owed, recipient.lamports()), (50_000, 50_000));
+
+        let stored = MonkeBurn::try_deserialize(&mut &burn_info.data.borrow()[..]).unwrap();
+        assert_eq!((stored.claimed_sol, stored.reward_debt), (50_000, loaded.reward_debt));
+        assert_eq!(pending_owed(&stored, &state, NOW).unwrap(), 0);
+    }
+
+    // ---- reward token ----
+
+    #[test]
+    fn sol_and_token_accumulators_advance_independently() {
+        let mut state = zeroed_state();
+        state.reward_token_mint = Pubkey::new_unique();
+        state.total_share_weight = 4;
+        let mut burn = fed_burn(&state, 4, NOW);
+
+        credit_sol_deposit(&mut state, 4_000).unwrap();
+        assert_eq!(state.accumulated_token_per_share, 0);
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 4_000);
+        assert_eq!(pending_token_owed(&burn, &state, NOW).unwrap(), 0);
+
+        let sol_acc = state.accumulated_sol_per_share;
+        credit_token_deposit(&mut state, 400).unwrap();
+        assert_eq!(state.accumulated_sol_per_share, sol_acc);
+        assert_eq!((state.total_token_distributed, state.total_sol_distributed), (400, 4_000));
+        assert_eq!(pending_token_owed(&burn, &state, NOW).unwrap(), 400);
+
+        // Claiming one side leaves the other pending
+        assert_eq!(settle_token_claim(&state, &mut burn, NOW).unwrap(), 400);
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 4_000);
+        credit_token_deposit(&mut state, 800).unwrap();
+        assert_eq!(settle_claim(&mut state, &mut burn, NOW).unwrap(), 4_000);
+        assert_eq!(pending_token_owed(&burn, &state, NOW).unwrap(), 800);
+        assert_eq!((burn.claimed_sol, burn.claimed_token), (4_000, 400));
+    }
+
+    #[test]
+    fn token_deposit_requires_a_configured_mint_and_weight() {
+        let mut state = zeroed_state();
+        state.total_share_weight = 4;
+        assert_eq!(
+            credit_token_deposit(&mut state, 400).unwrap_err(),
+            error!(MonkeError::RewardTokenNotConfigured)
+        );
+        state.reward_token_mint = Pubkey::new_unique();
+        assert_eq!(credit_token_deposit(&mut state, 0).unwrap_err(), error!(MonkeError::NothingToDeposit));
+        state.total_share_weight = 0;
+        assert_eq!(credit_token_deposit(&mut state, 400).unwrap_err(), error!(MonkeError::NoMonkes));
+    }
+
+    // ---- compost ----
+
+    #[test]
+    fn composted_unclaimed_sol_is_reconciled_to_the_living() {
+        let mut state = zeroed_state();
+        state.liability_initialized = true;
+        state.total_share_weight = 4;
+        let dead = fed_burn(&state, 2, NOW);
+        let living = fed_burn(&state, 2, NOW);
+        deposit(&mut state, 4_000);
+        let vault_available = 4_000;
+
+        assert_eq!(remove_monke(&mut state, &dead).unwrap(), 2_000);
+        assert_eq!((state.total_share_weight, state.sol_liability), (2, 2_000));
+
+        // The dead monke's share is now surplus and goes to the remaining weight
+        assert_eq!(credit_vault_surplus(&mut state, vault_available).unwrap(), 2_000);
+        assert_eq!(state.sol_liability, vault_available);
+        assert_eq!(pending_owed(&living, &state, NOW).unwrap(), 4_000);
+        assert_eq!(
+            credit_vault_surplus(&mut state, vault_available).unwrap_err(),
+            error!(MonkeError::NothingToReconcile)
+        );
+    }
+
+    #[test]
+    fn compost_bounty_comes_out_of_vault_headroom() {
+        const RENT: u64 = 1_000_000;
+        let mut state = zeroed_state();
+        state.liability_initialized = true;
+        state.compost_bounty_lamports = 5_000;
+        state.total_share_weight = 4;
+        let dead = fed_burn(&state, 2, NOW);
+        deposit(&mut state, 4_000);
+        // Vault holds rent, the 4_000 owed, and 5_000 surplus
+        let vault = RENT + 4_000 + 5_000;
+
+        remove_monke(&mut state, &dead).unwrap();
+        assert_eq!(compost_bounty_due(&state, vault, RENT).unwrap(), 5_000);
+        // The dead monke's released 2_000 is headroom too
+        state.compost_bounty_lamports = 7_000;
+        assert_eq!(compost_bounty_due(&state, vault, RENT).unwrap(), 7_000);
+
+        // A bounty past the headroom reverts instead of eating owed SOL or rent
+        let exceeded: Error = error!(MonkeError::CompostBountyExceedsHeadroom);
+        state.compost_bounty_lamports = 7_001;
+        assert_eq!(compost_bounty_due(&state, vault, RENT).unwrap_err(), exceeded);
+        assert_eq!(compost_bounty_due(&state, RENT - 1, RENT).unwrap_err(), exceeded);
+
+        // Off: nothing paid, whatever the vault holds
+        state.compost_bounty_lamports = 0;
+        assert_eq!(compost_bounty_due(&state, 0, RENT).unwrap(), 0);
+    }
+
+    // ---- decay ----
+
+    const EPOCH: i64 = MIN_DECAY_EPOCH_SECONDS as i64;
+
+    fn decaying_state(decay_bps_per_epoch: u16) -> MonkeState {
+        let mut state = zeroed_state();
+        state.decay_bps_per_epoch = decay_bps_per_epoch;
+        state.epoch_len_seconds = MIN_DECAY_EPOCH_SECONDS;
+        state
+    }
+
+    #[test]
+    fn decay_compounds_over_epochs() {
+        let state = decaying_state(1_000); // 10% per epoch
+        let burn = fed_burn(&state, 1_000, NOW);
+        let due = |elapsed: i64| decay_due(&burn, &state, NOW + elapsed).unwrap();
+        assert_eq!(due(EPOCH - 1), (1_000, 0));
+        assert_eq!(due(EPOCH), (900, 1));
+        assert_eq!(due(3 * EPOCH + EPOCH / 2), (729, 3));
+        assert_eq!(due(10 * EPOCH), (349, 10)); // 1000 * 0.9^10 = 348.7
+        assert_eq!(due(1_000 * EPOCH).0, 0);
+    }
+
+    #[test]
+    fn untouched_monke_earns_at_effective_weight() {
+        let mut state = decaying_state(MAX_DECAY_BPS_PER_EPOCH); // halves per epoch
+        state.total_share_weight = 8;
+        let burn = fed_burn(&state, 8, NOW);
+        deposit(&mut state, 80_000);
+
+        assert_eq!(pending_owed(&burn, &state, NOW).unwrap(), 80_000);
+        assert_eq!(pending_owed(&burn, &state, NOW + EPOCH).unwrap(), 40_000);
+        assert_eq!(pending_owed(&burn, &state, NOW + 2 * EPOCH).unwrap(), 20_000);
+
+        // Claiming pays exactly what was reported and writes the decay
+        let (mut claimed, mut claim_state) = (burn.clone(), state.clone());
+        assert_eq!(settle_claim(&mut claim_state, &mut claimed, NOW + 2 * EPOCH).unwrap(), 20_000);
+        assert_eq!(claimed.share_weight, 2);
+        assert_eq!(claim_state.total_share_weight, 2);
+        // Paid and forfeited SOL both leave the liability
+        assert_eq!(claim_state.sol_liability, 0);
+    }
+
+    #[test]
+    fn decay_settled_per_epoch_matches_settled_at_once() {
+        let mut state = decaying_state(MAX_DECAY_BPS_PER_EPOCH);
+        state.total_share_weight = 8;
+        let mut stepped = fed_burn(&state, 8, NOW);
+        let mut at_once = stepped.clone();
+        let mut stepped_state = state.clone();
+
+        for epoch in 1..=3 {
+            settle_decay(&mut stepped, &mut stepped_state, NOW + epoch * EPOCH).unwrap();
+        }
+        assert_eq!(settle_decay(&mut at_once, &mut state, NOW + 3 * EPOCH).unwrap(), 7);
+
+        assert_eq!((stepped.share_weight, at_once.share_weight), (1, 1));
+        assert_eq!(stepped.last_interaction_ts, NOW + 3 * EPOCH);
+        assert_eq!(at_once.last_interaction_ts, NOW + 3 * EPOCH);
+        assert_eq!(stepped_state.total_share_weight, state.total_share_weight);
+    }
+
+    #[test]
+    fn unclaimed_reward_token_does_not_defer_decay() {
+        let mut state = decaying_state(MAX_DECAY_BPS_PER_EPOCH);
+        state.total_share_weight = 4;
+        let mut burn = fed_burn(&state, 4, NOW);
+        state.accumulated_token_per_share = 1_000 * PRECISION;
+        deposit(&mut state, 4_000);
+
+        assert_eq!(settle_claim(&mut state, &mut burn, NOW + EPOCH).unwrap(), 2_000);
+        assert_eq!((burn.share_weight, state.total_share_weight), (2, 2));
+        // The token rewards shrank with the weight instead of blocking it
+        assert_eq!(pending_token_owed(&burn, &state, NOW + EPOCH).unwrap(), 2_000);
+    }
+
+    #[test]
+    fn legacy_monke_decays_from_decay_start() {
+        let mut state = decaying_state(MAX_DECAY_BPS_PER_EPOCH);
+        state.decay_start_ts = NOW - 2 * EPOCH;
+        state.total_share_weight = 8;
+        // Migrated MonkeBurn: fed long before decay existed, never since
+        let mut burn = fed_burn(&state, 8, 0);
+        burn.first_fed_at = NOW - 100 * EPOCH;
+
+        assert_eq!(decay_due(&burn, &state, NOW).unwrap(), (2, 2));
+        assert_eq!(settle_decay(&mut burn, &mut state, NOW).unwrap(), 6);
+        assert_eq!(burn.last_interaction_ts, NOW);
+
+        // Fed after decay was turned on: counts from the first feed instead
+        let mut late = fed_burn(&state, 8, 0);
+        late.first_fed_at = NOW - EPOCH;
+        assert_eq!(decay_due(&late, &state, NOW).unwrap(), (4, 1));
+
+        // Decay off: nothing owed however old the monke is
+        state.decay_bps_per_epoch = 0;
+        assert_eq!(decay_due(&late, &state, NOW).unwrap(), (8, 0));
+    }
+
+    // ---- dist_pool targets ----
+
+    #[test]
+    fn dist_pool_can_move_to_a_signing_wallet() {
+        let (canonical, bump) = Pubkey::find_program_address(&[b"dist_pool"], &crate::ID);
+        let system = anchor_lang::system_program::ID;
+        assert_eq!(dist_pool_source(&canonical, &crate::ID, false, bump).unwrap(), DistPoolSource::ProgramOwned);
+        assert_eq!(dist_pool_source(&canonical, &system, false, bump).unwrap(), DistPoolSource::ProgramSigned);
+
+        // A new rover authority / multisig behind the timelock: fine once it signs
+        let wallet = Pubkey::new_unique();
+        assert_eq!(dist_pool_source(&wallet, &system, true, bump).unwrap(), DistPoolSource::SelfSigned);
+        assert_eq!(
+            dist_pool_source(&wallet, &system, false, bump).unwrap_err(),
+            error!(MonkeError::DistPoolNotSigner)
+        );
+
+        // Other program-owned accounts (program_vault, MonkeState) can't be drained
+        let (program_vault, _) = Pubkey::find_program_address(&[b"program_vault"], &crate::ID);
+        assert_eq!(
+            dist_pool_source(&program_vault, &crate::ID, true, bump).unwrap_err(),
+            error!(MonkeError::InvalidDistPool)
+        );
+        assert_eq!(
+            dist_pool_source(&wallet, &Pubkey::new_unique(), true, bump).unwrap_err(),
+            error!(MonkeError::InvalidDistPool)
+        );
+    }
 }
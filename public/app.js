@@ -486,6 +486,13 @@ function getRoverAuthorityPDA() {
   );
 }
 
+function getOwnerStatsPDA(owner) {
+  return solanaWeb3.PublicKey.findProgramAddressSync(
+    [new TextEncoder().encode('owner_stats'), owner.toBytes()],
+    new solanaWeb3.PublicKey(CONFIG.CORE_PROGRAM_ID)
+  );
+}
+
 /** PDA derivation — monke_bananas program */
 function getMonkeStatePDA() {
   return solanaWeb3.PublicKey.findProgramAddressSync(
@@ -2062,6 +2069,17 @@ async function createPosition() {
       maxBinId: maxBin,
       side: state.side === 'buy' ? Side.Buy : Side.Sell,
       maxActiveBinSlippage: slippage,
+      takeProfitBins: 0,
+      strategy: 0, // STRATEGY_SPOT
+      referrer: address(solanaWeb3.PublicKey.default.toBase58()), // no referrer
+      prioritySlotsOverride: 0n, // 0 = config.priority_slots
+      // No transfer-hook accounts (hook_x_len = hook_y_len = 0).
+      hookXLen: 0,
+      hookYLen: 0,
+      feeBpsOverride: 0xffff, // FEE_BPS_OVERRIDE_NONE
+      // Revert if the active bin moved past the on-chain default drift since the range was picked
+      expectedActiveId: state.activeBin,
+      maxDrift: -1,
     });
     const openWeb3Ix = kitIxToWeb3(openIx);
     if (bitmapExtWritable) {
@@ -2215,10 +2233,13 @@ async function closePosition(index) {
     ]);
 
     // --- Execute TX: compute budget + userClose only ---
+    // The user signs their own close, so the min-out guard is left off (0).
+    const [ownerStatsPDA] = getOwnerStatsPDA(user);
     const closeIx = await getUserCloseInstructionAsync({
       user: asSigner(user),
       position: address(pos.pubkey.toBase58()),
       vault: address(vaultPDA.toBase58()),
+      ownerStats: address(ownerStatsPDA.toBase58()),
       meteoraPosition: address(pos.meteoraPosition.toBase58()),
       lbPair: address(cpi.lbPair.toBase58()),
       binArrayBitmapExt: address(cpi.binArrayBitmapExt.toBase58()),
@@ -2239,6 +2260,8 @@ async function closePosition(index) {
       tokenXProgram: address(cpi.tokenXProgramId.toBase58()),
       tokenYProgram: address(cpi.tokenYProgramId.toBase58()),
       memoProgram: address(SPL_MEMO_PROGRAM_ID.toBase58()),
+      minXOut: 0n,
+      minYOut: 0n,
     });
     const closeWeb3Ix = kitIxToWeb3(closeIx);
     const bmExtWritable = !cpi.binArrayBitmapExt.equals(cpi.dlmmProgram);
@@ -2309,10 +2332,13 @@ async function closePositionDirect(pos) {
   ]);
 
   // --- Execute TX: compute budget + userClose only ---
+  // The user signs their own close, so the min-out guard is left off (0).
+  const [ownerStatsPDA] = getOwnerStatsPDA(user);
   const closeIx = await getUserCloseInstructionAsync({
     user: asSigner(user),
     position: address(pos.pubkey.toBase58()),
     vault: address(vaultPDA.toBase58()),
+    ownerStats: address(ownerStatsPDA.toBase58()),
     meteoraPosition: address(pos.meteoraPosition.toBase58()),
     lbPair: address(cpi.lbPair.toBase58()),
     binArrayBitmapExt: address(cpi.binArrayBitmapExt.toBase58()),
@@ -2333,6 +2359,8 @@ async function closePositionDirect(pos) {
     tokenXProgram: address(cpi.tokenXProgramId.toBase58()),
     tokenYProgram: address(cpi.tokenYProgramId.toBase58()),
     memoProgram: address(SPL_MEMO_PROGRAM_ID.toBase58()),
+    minXOut: 0n,
+    minYOut: 0n,
   });
   const ucWeb3Ix = kitIxToWeb3(closeIx);
   const ucBmWritable = !cpi.binArrayBitmapExt.equals(cpi.dlmmProgram);
@@ -2386,6 +2414,7 @@ async function claimFeesDirect(pos) {
   ]);
 
   // --- Execute TX: compute budget + claimFees only ---
+  // No transfer-hook accounts (hook_x_len = hook_y_len = 0).
   const claimFeesIx = getClaimFeesInstruction({
     user: asSigner(user),
     position: address(positionPDA.toBase58()),
@@ -2407,6 +2436,8 @@ async function claimFeesDirect(pos) {
     tokenXProgram: address(cpi.tokenXProgramId.toBase58()),
     tokenYProgram: address(cpi.tokenYProgramId.toBase58()),
     memoProgram: address(SPL_MEMO_PROGRAM_ID.toBase58()),
+    hookXLen: 0,
+    hookYLen: 0,
   });
   const tx = new solanaWeb3.Transaction();
   tx.add(solanaWeb3.ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }));
@@ -3450,6 +3481,10 @@ async function handleHarvestPosition(positionPDAStr, lbPairStr, ownerStr, side)
     tokenYProgram: address(cpi.tokenYProgramId.toBase58()),
     memoProgram: address(SPL_MEMO_PROGRAM_ID.toBase58()),
     binIds: binIds,
+    bpsToRemove: 10_000,
+    tipSplitBps: 0,
+    hookXLen: 0,
+    hookYLen: 0,
   });
   const harvestWeb3Ix = kitIxToWeb3(harvestIx);
   const hvBmWritable = !cpi.binArrayBitmapExt.equals(cpi.dlmmProgram);
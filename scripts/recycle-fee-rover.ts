@@ -162,11 +162,11 @@ async function main() {
     createAssociatedTokenAccountIdempotentInstruction(botKeypair.publicKey, vaultTokenY, vaultPDA, tokenYMint, tokenYProg),
   ];
 
-  // 10. Send open_fee_rover
+  // 10. Send open_fee_rover (max_active_bin_slippage 0 = on-chain default)
   console.log(`\n[SEND] open_fee_rover — amount=${balance}, binStep=${binStep}`);
   try {
     const sig = await program.methods
-      .openFeeRover(new BN(balance.toString()), binStep)
+      .openFeeRover(new BN(balance.toString()), binStep, 0)
       .accounts({
         bot: botKeypair.publicKey,
         config: configPDA,
@@ -20,7 +20,7 @@ import {
   Keypair,
   ComputeBudgetProgram,
 } from '@solana/web3.js';
-import { Program, AnchorProvider, Wallet } from '@coral-xyz/anchor';
+import { Program, AnchorProvider, Wallet, BN } from '@coral-xyz/anchor';
 import {
   getAssociatedTokenAddressSync,
   createAssociatedTokenAccountIdempotentInstruction,
@@ -91,6 +91,8 @@ async function main() {
   const [configPDA] = PublicKey.findProgramAddressSync([Buffer.from('config')], CORE);
   const [vaultPda] = PublicKey.findProgramAddressSync([Buffer.from('vault'), meteoraPosKey.toBuffer()], CORE);
   const [roverAuth] = PublicKey.findProgramAddressSync([Buffer.from('rover_authority')], CORE);
+  const [ownerStats] = PublicKey.findProgramAddressSync([Buffer.from('owner_stats'), owner.toBuffer()], CORE);
+  const [poolMetrics] = PublicKey.findProgramAddressSync([Buffer.from('pool_metrics'), lbPairKey.toBuffer()], CORE);
   const [eventAuth] = PublicKey.findProgramAddressSync([Buffer.from('__event_authority')], DLMM_PROGRAM);
 
   // Bin array bitmap extension — check if account exists
@@ -146,16 +148,19 @@ async function main() {
   ];
 
   try {
+    // Diagnostic close: no bin amounts are read here, so the min-out guard is off (0).
     const sig = await program.methods
-      .closePosition()
+      .closePosition(new BN(0), new BN(0), 0, 0)
       .accounts({
         bot: botKeypair.publicKey,
         config: configPDA,
         position: new PublicKey(POSITION_PDA),
         vault: vaultPda,
+        ownerStats,
         owner,
         meteoraPosition: meteoraPosKey,
         lbPair: lbPairKey,
+        poolMetrics,
         binArrayBitmapExt: bitmapExt,
         binArrayLower,
         binArrayUpper,
@@ -26,13 +26,13 @@ export const BIN_FARM_ERROR__INVALID_BIN_RANGE = 0x1773; // 6003
 export const BIN_FARM_ERROR__POSITION_TOO_WIDE = 0x1774; // 6004
 /** BinOutOfPositionRange: Bin ID outside position range */
 export const BIN_FARM_ERROR__BIN_OUT_OF_POSITION_RANGE = 0x1775; // 6005
-/** InvalidSlippage: Invalid slippage (must be 0-20) */
+/** InvalidSlippage: Invalid slippage (must be 1-20) */
 export const BIN_FARM_ERROR__INVALID_SLIPPAGE = 0x1776; // 6006
 /** FeeTooHigh: Fee too high (max 10%) */
 export const BIN_FARM_ERROR__FEE_TOO_HIGH = 0x1777; // 6007
 /** NoBinsProvided: No bin IDs provided */
 export const BIN_FARM_ERROR__NO_BINS_PROVIDED = 0x1778; // 6008
-/** TooManyBins: Too many bins (max 70 per call) */
+/** TooManyBins: Too many bins (exceeds config.max_bins_per_harvest, max 70 per call) */
 export const BIN_FARM_ERROR__TOO_MANY_BINS = 0x1779; // 6009
 /** NonContiguousBins: Bin IDs must be contiguous (no gaps) */
 export const BIN_FARM_ERROR__NON_CONTIGUOUS_BINS = 0x177a; // 6010
@@ -78,75 +78,295 @@ export const BIN_FARM_ERROR__EMERGENCY_CLOSE_TIMELOCK_NOT_EXPIRED = 0x178d; // 6
 export const BIN_FARM_ERROR__INVALID_MINT_DATA = 0x178e; // 6030
 /** InvalidBot: Invalid bot destination */
 export const BIN_FARM_ERROR__INVALID_BOT = 0x178f; // 6031
+/** InvalidMaxBinsPerHarvest: max_bins_per_harvest must be between 1 and 70 */
+export const BIN_FARM_ERROR__INVALID_MAX_BINS_PER_HARVEST = 0x1790; // 6032
+/** InvalidTokenProgramAllowlist: Token program allowlist must hold 1-4 distinct, non-default program IDs */
+export const BIN_FARM_ERROR__INVALID_TOKEN_PROGRAM_ALLOWLIST = 0x1791; // 6033
+/** PositionTooNarrow: Position narrower than config.min_position_width — see log for the minimum */
+export const BIN_FARM_ERROR__POSITION_TOO_NARROW = 0x1792; // 6034
+/** InvalidMinPositionWidth: min_position_width must be between 1 and 70 */
+export const BIN_FARM_ERROR__INVALID_MIN_POSITION_WIDTH = 0x1793; // 6035
+/** InvalidMinPositionAmount: min_position_amount must be at least 1 */
+export const BIN_FARM_ERROR__INVALID_MIN_POSITION_AMOUNT = 0x1794; // 6036
+/** InvalidPoolStatus: PoolStatus account is not a valid monke.army PoolStatus PDA */
+export const BIN_FARM_ERROR__INVALID_POOL_STATUS = 0x1795; // 6037
+/** InvalidFeeOverride: fee_bps_override must not exceed config.fee_bps */
+export const BIN_FARM_ERROR__INVALID_FEE_OVERRIDE = 0x1796; // 6038
+/** InvalidMaxPauseSeconds: max_pause_seconds must be between 1 and 30 days */
+export const BIN_FARM_ERROR__INVALID_MAX_PAUSE_SECONDS = 0x1797; // 6039
+/** VaultNotOrphaned: Position PDA still exists — vault is not orphaned */
+export const BIN_FARM_ERROR__VAULT_NOT_ORPHANED = 0x1798; // 6040
+/** ActiveBinDrifted: Active bin drifted past max_drift from expected_active_id — see log */
+export const BIN_FARM_ERROR__ACTIVE_BIN_DRIFTED = 0x1799; // 6041
+/** HealthConfigMismatch: Health check: config PDA or bump mismatch */
+export const BIN_FARM_ERROR__HEALTH_CONFIG_MISMATCH = 0x179a; // 6042
+/** HealthRoverMismatch: Health check: rover_authority PDA or bump mismatch */
+export const BIN_FARM_ERROR__HEALTH_ROVER_MISMATCH = 0x179b; // 6043
+/** HealthMonkeStateMismatch: Health check: monke_state is not the monke_bananas state PDA */
+export const BIN_FARM_ERROR__HEALTH_MONKE_STATE_MISMATCH = 0x179c; // 6044
+/** HealthDistPoolMismatch: Health check: dist_pool is unset in state or differs from state.dist_pool */
+export const BIN_FARM_ERROR__HEALTH_DIST_POOL_MISMATCH = 0x179d; // 6045
+/** HealthProgramVaultMismatch: Health check: program_vault does not derive from monke_bananas */
+export const BIN_FARM_ERROR__HEALTH_PROGRAM_VAULT_MISMATCH = 0x179e; // 6046
+/** HealthRevenueDestMismatch: Health check: revenue_dest is neither dist_pool nor bridge_vault */
+export const BIN_FARM_ERROR__HEALTH_REVENUE_DEST_MISMATCH = 0x179f; // 6047
+/** SweepInvariantViolated: sweep_rover lamport accounting mismatch — rover_authority not left at rent or destinations off */
+export const BIN_FARM_ERROR__SWEEP_INVARIANT_VIOLATED = 0x17a0; // 6048
+/** InvalidRoverBatch: Rover batch: 1-4 entries, remaining_accounts must be entries × 16 */
+export const BIN_FARM_ERROR__INVALID_ROVER_BATCH = 0x17a1; // 6049
+/** RoverBatchAccountMismatch: Rover batch: position/vault PDA mismatch, already funded, or missing signer */
+export const BIN_FARM_ERROR__ROVER_BATCH_ACCOUNT_MISMATCH = 0x17a2; // 6050
+/** RevenueDestPending: Revenue dest change pending — apply or cancel it before sweeping */
+export const BIN_FARM_ERROR__REVENUE_DEST_PENDING = 0x17a3; // 6051
+/** InvalidPositionData: Meteora position account does not match or is not a PositionV2 */
+export const BIN_FARM_ERROR__INVALID_POSITION_DATA = 0x17a4; // 6052
+/** StaleFeeChange: Fee change nonce mismatch — the pending change was replaced or cancelled */
+export const BIN_FARM_ERROR__STALE_FEE_CHANGE = 0x17a5; // 6053
+/** NotSolPool: Native SOL rover requires a SOL-quoted pool (token Y = wSOL) */
+export const BIN_FARM_ERROR__NOT_SOL_POOL = 0x17a6; // 6054
+/** RoverFeeMintMismatch: Rover fee token account mint does not match the pool token mint */
+export const BIN_FARM_ERROR__ROVER_FEE_MINT_MISMATCH = 0x17a7; // 6055
+/** InvalidPositionAccount: Position account is not a valid monke.army Position PDA */
+export const BIN_FARM_ERROR__INVALID_POSITION_ACCOUNT = 0x17a8; // 6056
+/** InvalidLabel: Label must be UTF-8 followed only by zero padding */
+export const BIN_FARM_ERROR__INVALID_LABEL = 0x17a9; // 6057
+/** VaultBindingMismatch: Vault is not bound to this position's Meteora position */
+export const BIN_FARM_ERROR__VAULT_BINDING_MISMATCH = 0x17aa; // 6058
+/** KeeperAtaOwnerMismatch: Keeper ATA must be owned by the caller or a registered KeeperPool (pass it after the tip ATAs) */
+export const BIN_FARM_ERROR__KEEPER_ATA_OWNER_MISMATCH = 0x17ab; // 6059
+/** InvalidBpsToRemove: bps_to_remove must be between 1 and 10000 */
+export const BIN_FARM_ERROR__INVALID_BPS_TO_REMOVE = 0x17ac; // 6060
+/** MissingBinArrayMiddle: Range spans three bin arrays — pass the middle bin array PDA as bin_array_middle */
+export const BIN_FARM_ERROR__MISSING_BIN_ARRAY_MIDDLE = 0x17ad; // 6061
+/** TakeProfitNotReached: Take-profit not reached: active_id below max_bin_id + take_profit_bins */
+export const BIN_FARM_ERROR__TAKE_PROFIT_NOT_REACHED = 0x17ae; // 6062
+/** InvalidStrategy: Invalid strategy (0 = Spot, 1 = Curve, 2 = BidAsk) */
+export const BIN_FARM_ERROR__INVALID_STRATEGY = 0x17af; // 6063
+/** InvalidRewardIndex: reward_index must be 0 or 1 (DLMM pools have two reward slots) */
+export const BIN_FARM_ERROR__INVALID_REWARD_INDEX = 0x17b0; // 6064
+/** SlippageExceeded: Close output below min_x_out / min_y_out */
+export const BIN_FARM_ERROR__SLIPPAGE_EXCEEDED = 0x17b1; // 6065
+/** HarvestCooldownActive: Position was harvested too recently — harvest cooldown active */
+export const BIN_FARM_ERROR__HARVEST_COOLDOWN_ACTIVE = 0x17b2; // 6066
+/** HarvestCooldownExceedsMax: Harvest cooldown exceeds maximum (9000 slots / ~1 hour) */
+export const BIN_FARM_ERROR__HARVEST_COOLDOWN_EXCEEDS_MAX = 0x17b3; // 6067
+/** InvalidNewOwner: New owner must be non-default and differ from the current owner */
+export const BIN_FARM_ERROR__INVALID_NEW_OWNER = 0x17b4; // 6068
+/** MinSweepExceedsMax: Min sweep exceeds maximum (1 SOL) */
+export const BIN_FARM_ERROR__MIN_SWEEP_EXCEEDS_MAX = 0x17b5; // 6069
+/** InvalidReferrer: Referrer cannot be the position owner */
+export const BIN_FARM_ERROR__INVALID_REFERRER = 0x17b6; // 6070
+/** MissingReferrerAta: Referred position: pass the referrer's ATA for the fee mint as the last remaining account before any hook accounts */
+export const BIN_FARM_ERROR__MISSING_REFERRER_ATA = 0x17b7; // 6071
+/** PoolBlocked: Pool is blocked for new positions */
+export const BIN_FARM_ERROR__POOL_BLOCKED = 0x17b8; // 6072
+/** MissingHookAccounts: Fewer remaining accounts than hook_x_len + hook_y_len */
+export const BIN_FARM_ERROR__MISSING_HOOK_ACCOUNTS = 0x17b9; // 6073
+/** InvalidTipSplit: tip_split_bps must be at most 10000 */
+export const BIN_FARM_ERROR__INVALID_TIP_SPLIT = 0x17ba; // 6074
+/** DuplicateKeeperAta: Second tip ATA duplicates the first */
+export const BIN_FARM_ERROR__DUPLICATE_KEEPER_ATA = 0x17bb; // 6075
+/** NothingConverted: No fully converted bins with liquidity left to harvest */
+export const BIN_FARM_ERROR__NOTHING_CONVERTED = 0x17bc; // 6076
+/** ConfigVersionUnsupported: Config layout version unsupported (run migrate_config) */
+export const BIN_FARM_ERROR__CONFIG_VERSION_UNSUPPORTED = 0x17bd; // 6077
+/** InvalidBinArray: Bin arrays must be the lb_pair's bin array PDAs covering the range, in ascending order */
+export const BIN_FARM_ERROR__INVALID_BIN_ARRAY = 0x17be; // 6078
+/** UnwrapNotWsol: user_close_unwrap needs a WSOL-quoted pool (token_y_mint = WSOL) */
+export const BIN_FARM_ERROR__UNWRAP_NOT_WSOL = 0x17bf; // 6079
+/** UnwrapAccountNotEmpty: user_token_y must be a temporary WSOL account holding only this close's output */
+export const BIN_FARM_ERROR__UNWRAP_ACCOUNT_NOT_EMPTY = 0x17c0; // 6080
+/** InvalidEventAuthority: Event authority is not the DLMM __event_authority PDA */
+export const BIN_FARM_ERROR__INVALID_EVENT_AUTHORITY = 0x17c1; // 6081
+/** MintMismatch: Token mint does not match the lb_pair's token_x_mint / token_y_mint */
+export const BIN_FARM_ERROR__MINT_MISMATCH = 0x17c2; // 6082
+/** InvalidHarvestBatch: Harvest batch: 1-4 entries, remaining_accounts must match entries × 9 plus extras */
+export const BIN_FARM_ERROR__INVALID_HARVEST_BATCH = 0x17c3; // 6083
+/** HarvestBatchAccountMismatch: Harvest batch: position/vault PDA or meteora_position mismatch */
+export const BIN_FARM_ERROR__HARVEST_BATCH_ACCOUNT_MISMATCH = 0x17c4; // 6084
+/** TooManyPositions: Owner already holds max_positions_per_owner open positions */
+export const BIN_FARM_ERROR__TOO_MANY_POSITIONS = 0x17c5; // 6085
+/** InvalidOwnerStats: OwnerStats account is not initialized or belongs to another owner */
+export const BIN_FARM_ERROR__INVALID_OWNER_STATS = 0x17c6; // 6086
 
 export type BinFarmError =
+  | typeof BIN_FARM_ERROR__ACTIVE_BIN_DRIFTED
   | typeof BIN_FARM_ERROR__BIN_OUT_OF_POSITION_RANGE
   | typeof BIN_FARM_ERROR__BOT_NOT_STALE
   | typeof BIN_FARM_ERROR__BOT_PAUSED
+  | typeof BIN_FARM_ERROR__CONFIG_VERSION_UNSUPPORTED
+  | typeof BIN_FARM_ERROR__DUPLICATE_KEEPER_ATA
   | typeof BIN_FARM_ERROR__EMERGENCY_CLOSE_TIMELOCK_NOT_EXPIRED
   | typeof BIN_FARM_ERROR__FEE_TIMELOCK_NOT_EXPIRED
   | typeof BIN_FARM_ERROR__FEE_TOO_HIGH
+  | typeof BIN_FARM_ERROR__HARVEST_BATCH_ACCOUNT_MISMATCH
+  | typeof BIN_FARM_ERROR__HARVEST_COOLDOWN_ACTIVE
+  | typeof BIN_FARM_ERROR__HARVEST_COOLDOWN_EXCEEDS_MAX
+  | typeof BIN_FARM_ERROR__HEALTH_CONFIG_MISMATCH
+  | typeof BIN_FARM_ERROR__HEALTH_DIST_POOL_MISMATCH
+  | typeof BIN_FARM_ERROR__HEALTH_MONKE_STATE_MISMATCH
+  | typeof BIN_FARM_ERROR__HEALTH_PROGRAM_VAULT_MISMATCH
+  | typeof BIN_FARM_ERROR__HEALTH_REVENUE_DEST_MISMATCH
+  | typeof BIN_FARM_ERROR__HEALTH_ROVER_MISMATCH
+  | typeof BIN_FARM_ERROR__INVALID_BIN_ARRAY
   | typeof BIN_FARM_ERROR__INVALID_BIN_RANGE
   | typeof BIN_FARM_ERROR__INVALID_BOT
+  | typeof BIN_FARM_ERROR__INVALID_BPS_TO_REMOVE
   | typeof BIN_FARM_ERROR__INVALID_DIST_POOL
+  | typeof BIN_FARM_ERROR__INVALID_EVENT_AUTHORITY
+  | typeof BIN_FARM_ERROR__INVALID_FEE_OVERRIDE
+  | typeof BIN_FARM_ERROR__INVALID_HARVEST_BATCH
+  | typeof BIN_FARM_ERROR__INVALID_LABEL
+  | typeof BIN_FARM_ERROR__INVALID_MAX_BINS_PER_HARVEST
+  | typeof BIN_FARM_ERROR__INVALID_MAX_PAUSE_SECONDS
+  | typeof BIN_FARM_ERROR__INVALID_MIN_POSITION_AMOUNT
+  | typeof BIN_FARM_ERROR__INVALID_MIN_POSITION_WIDTH
   | typeof BIN_FARM_ERROR__INVALID_MINT_DATA
+  | typeof BIN_FARM_ERROR__INVALID_NEW_OWNER
+  | typeof BIN_FARM_ERROR__INVALID_OWNER_STATS
   | typeof BIN_FARM_ERROR__INVALID_POOL
+  | typeof BIN_FARM_ERROR__INVALID_POOL_STATUS
   | typeof BIN_FARM_ERROR__INVALID_POSITION
+  | typeof BIN_FARM_ERROR__INVALID_POSITION_ACCOUNT
+  | typeof BIN_FARM_ERROR__INVALID_POSITION_DATA
   | typeof BIN_FARM_ERROR__INVALID_PROGRAM
+  | typeof BIN_FARM_ERROR__INVALID_REFERRER
+  | typeof BIN_FARM_ERROR__INVALID_REWARD_INDEX
+  | typeof BIN_FARM_ERROR__INVALID_ROVER_BATCH
   | typeof BIN_FARM_ERROR__INVALID_SLIPPAGE
+  | typeof BIN_FARM_ERROR__INVALID_STRATEGY
+  | typeof BIN_FARM_ERROR__INVALID_TIP_SPLIT
   | typeof BIN_FARM_ERROR__INVALID_TOKEN_OWNER
+  | typeof BIN_FARM_ERROR__INVALID_TOKEN_PROGRAM_ALLOWLIST
+  | typeof BIN_FARM_ERROR__KEEPER_ATA_OWNER_MISMATCH
+  | typeof BIN_FARM_ERROR__MIN_SWEEP_EXCEEDS_MAX
+  | typeof BIN_FARM_ERROR__MINT_MISMATCH
+  | typeof BIN_FARM_ERROR__MISSING_BIN_ARRAY_MIDDLE
+  | typeof BIN_FARM_ERROR__MISSING_HOOK_ACCOUNTS
   | typeof BIN_FARM_ERROR__MISSING_KEEPER_ATA
+  | typeof BIN_FARM_ERROR__MISSING_REFERRER_ATA
   | typeof BIN_FARM_ERROR__NO_BINS_PROVIDED
   | typeof BIN_FARM_ERROR__NON_CONTIGUOUS_BINS
   | typeof BIN_FARM_ERROR__NO_PENDING_AUTHORITY
   | typeof BIN_FARM_ERROR__NO_PENDING_EMERGENCY_CLOSE
   | typeof BIN_FARM_ERROR__NO_PENDING_FEE_CHANGE
+  | typeof BIN_FARM_ERROR__NOTHING_CONVERTED
   | typeof BIN_FARM_ERROR__NOTHING_TO_SWEEP
+  | typeof BIN_FARM_ERROR__NOT_SOL_POOL
   | typeof BIN_FARM_ERROR__OVERFLOW
   | typeof BIN_FARM_ERROR__PAUSED
+  | typeof BIN_FARM_ERROR__POOL_BLOCKED
+  | typeof BIN_FARM_ERROR__POSITION_TOO_NARROW
   | typeof BIN_FARM_ERROR__POSITION_TOO_SMALL
   | typeof BIN_FARM_ERROR__POSITION_TOO_WIDE
   | typeof BIN_FARM_ERROR__PRIORITY_SLOTS_EXCEED_MAX
+  | typeof BIN_FARM_ERROR__REVENUE_DEST_PENDING
+  | typeof BIN_FARM_ERROR__ROVER_BATCH_ACCOUNT_MISMATCH
   | typeof BIN_FARM_ERROR__ROVER_BIN_STEP_TOO_SMALL
   | typeof BIN_FARM_ERROR__ROVER_DEPOSIT_TOO_SMALL
+  | typeof BIN_FARM_ERROR__ROVER_FEE_MINT_MISMATCH
+  | typeof BIN_FARM_ERROR__SLIPPAGE_EXCEEDED
+  | typeof BIN_FARM_ERROR__STALE_FEE_CHANGE
+  | typeof BIN_FARM_ERROR__SWEEP_INVARIANT_VIOLATED
+  | typeof BIN_FARM_ERROR__TAKE_PROFIT_NOT_REACHED
   | typeof BIN_FARM_ERROR__TOO_MANY_BINS
+  | typeof BIN_FARM_ERROR__TOO_MANY_POSITIONS
   | typeof BIN_FARM_ERROR__UNAUTHORIZED
+  | typeof BIN_FARM_ERROR__UNWRAP_ACCOUNT_NOT_EMPTY
+  | typeof BIN_FARM_ERROR__UNWRAP_NOT_WSOL
+  | typeof BIN_FARM_ERROR__VAULT_BINDING_MISMATCH
+  | typeof BIN_FARM_ERROR__VAULT_NOT_ORPHANED
   | typeof BIN_FARM_ERROR__ZERO_AMOUNT;
 
 let binFarmErrorMessages: Record<BinFarmError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   binFarmErrorMessages = {
+    [BIN_FARM_ERROR__ACTIVE_BIN_DRIFTED]: `Active bin drifted past max_drift from expected_active_id — see log`,
     [BIN_FARM_ERROR__BIN_OUT_OF_POSITION_RANGE]: `Bin ID outside position range`,
     [BIN_FARM_ERROR__BOT_NOT_STALE]: `Bot is still active — permissionless harvest not yet available`,
     [BIN_FARM_ERROR__BOT_PAUSED]: `Bot close operations are paused`,
+    [BIN_FARM_ERROR__CONFIG_VERSION_UNSUPPORTED]: `Config layout version unsupported (run migrate_config)`,
+    [BIN_FARM_ERROR__DUPLICATE_KEEPER_ATA]: `Second tip ATA duplicates the first`,
     [BIN_FARM_ERROR__EMERGENCY_CLOSE_TIMELOCK_NOT_EXPIRED]: `Emergency close timelock not expired (24 hours required)`,
     [BIN_FARM_ERROR__FEE_TIMELOCK_NOT_EXPIRED]: `Fee timelock not expired (24 hours required)`,
     [BIN_FARM_ERROR__FEE_TOO_HIGH]: `Fee too high (max 10%)`,
+    [BIN_FARM_ERROR__HARVEST_BATCH_ACCOUNT_MISMATCH]: `Harvest batch: position/vault PDA or meteora_position mismatch`,
+    [BIN_FARM_ERROR__HARVEST_COOLDOWN_ACTIVE]: `Position was harvested too recently — harvest cooldown active`,
+    [BIN_FARM_ERROR__HARVEST_COOLDOWN_EXCEEDS_MAX]: `Harvest cooldown exceeds maximum (9000 slots / ~1 hour)`,
+    [BIN_FARM_ERROR__HEALTH_CONFIG_MISMATCH]: `Health check: config PDA or bump mismatch`,
+    [BIN_FARM_ERROR__HEALTH_DIST_POOL_MISMATCH]: `Health check: dist_pool is unset in state or differs from state.dist_pool`,
+    [BIN_FARM_ERROR__HEALTH_MONKE_STATE_MISMATCH]: `Health check: monke_state is not the monke_bananas state PDA`,
+    [BIN_FARM_ERROR__HEALTH_PROGRAM_VAULT_MISMATCH]: `Health check: program_vault does not derive from monke_bananas`,
+    [BIN_FARM_ERROR__HEALTH_REVENUE_DEST_MISMATCH]: `Health check: revenue_dest is neither dist_pool nor bridge_vault`,
+    [BIN_FARM_ERROR__HEALTH_ROVER_MISMATCH]: `Health check: rover_authority PDA or bump mismatch`,
+    [BIN_FARM_ERROR__INVALID_BIN_ARRAY]: `Bin arrays must be the lb_pair's bin array PDAs covering the range, in ascending order`,
     [BIN_FARM_ERROR__INVALID_BIN_RANGE]: `Invalid bin range (min must be <= max)`,
     [BIN_FARM_ERROR__INVALID_BOT]: `Invalid bot destination`,
+    [BIN_FARM_ERROR__INVALID_BPS_TO_REMOVE]: `bps_to_remove must be between 1 and 10000`,
     [BIN_FARM_ERROR__INVALID_DIST_POOL]: `dist_pool cannot be the null address`,
+    [BIN_FARM_ERROR__INVALID_EVENT_AUTHORITY]: `Event authority is not the DLMM __event_authority PDA`,
+    [BIN_FARM_ERROR__INVALID_FEE_OVERRIDE]: `fee_bps_override must not exceed config.fee_bps`,
+    [BIN_FARM_ERROR__INVALID_HARVEST_BATCH]: `Harvest batch: 1-4 entries, remaining_accounts must match entries × 9 plus extras`,
+    [BIN_FARM_ERROR__INVALID_LABEL]: `Label must be UTF-8 followed only by zero padding`,
+    [BIN_FARM_ERROR__INVALID_MAX_BINS_PER_HARVEST]: `max_bins_per_harvest must be between 1 and 70`,
+    [BIN_FARM_ERROR__INVALID_MAX_PAUSE_SECONDS]: `max_pause_seconds must be between 1 and 30 days`,
+    [BIN_FARM_ERROR__INVALID_MIN_POSITION_AMOUNT]: `min_position_amount must be at least 1`,
+    [BIN_FARM_ERROR__INVALID_MIN_POSITION_WIDTH]: `min_position_width must be between 1 and 70`,
     [BIN_FARM_ERROR__INVALID_MINT_DATA]: `Invalid mint account data (too short to read decimals)`,
+    [BIN_FARM_ERROR__INVALID_NEW_OWNER]: `New owner must be non-default and differ from the current owner`,
+    [BIN_FARM_ERROR__INVALID_OWNER_STATS]: `OwnerStats account is not initialized or belongs to another owner`,
     [BIN_FARM_ERROR__INVALID_POOL]: `Invalid pool`,
+    [BIN_FARM_ERROR__INVALID_POOL_STATUS]: `PoolStatus account is not a valid monke.army PoolStatus PDA`,
     [BIN_FARM_ERROR__INVALID_POSITION]: `Invalid Meteora position`,
+    [BIN_FARM_ERROR__INVALID_POSITION_ACCOUNT]: `Position account is not a valid monke.army Position PDA`,
+    [BIN_FARM_ERROR__INVALID_POSITION_DATA]: `Meteora position account does not match or is not a PositionV2`,
     [BIN_FARM_ERROR__INVALID_PROGRAM]: `Invalid Meteora program ID`,
-    [BIN_FARM_ERROR__INVALID_SLIPPAGE]: `Invalid slippage (must be 0-20)`,
+    [BIN_FARM_ERROR__INVALID_REFERRER]: `Referrer cannot be the position owner`,
+    [BIN_FARM_ERROR__INVALID_REWARD_INDEX]: `reward_index must be 0 or 1 (DLMM pools have two reward slots)`,
+    [BIN_FARM_ERROR__INVALID_ROVER_BATCH]: `Rover batch: 1-4 entries, remaining_accounts must be entries × 16`,
+    [BIN_FARM_ERROR__INVALID_SLIPPAGE]: `Invalid slippage (must be 1-20)`,
+    [BIN_FARM_ERROR__INVALID_STRATEGY]: `Invalid strategy (0 = Spot, 1 = Curve, 2 = BidAsk)`,
+    [BIN_FARM_ERROR__INVALID_TIP_SPLIT]: `tip_split_bps must be at most 10000`,
     [BIN_FARM_ERROR__INVALID_TOKEN_OWNER]: `Token account owner mismatch`,
+    [BIN_FARM_ERROR__INVALID_TOKEN_PROGRAM_ALLOWLIST]: `Token program allowlist must hold 1-4 distinct, non-default program IDs`,
+    [BIN_FARM_ERROR__KEEPER_ATA_OWNER_MISMATCH]: `Keeper ATA must be owned by the caller or a registered KeeperPool (pass it after the tip ATAs)`,
+    [BIN_FARM_ERROR__MIN_SWEEP_EXCEEDS_MAX]: `Min sweep exceeds maximum (1 SOL)`,
+    [BIN_FARM_ERROR__MINT_MISMATCH]: `Token mint does not match the lb_pair's token_x_mint / token_y_mint`,
+    [BIN_FARM_ERROR__MISSING_BIN_ARRAY_MIDDLE]: `Range spans three bin arrays — pass the middle bin array PDA as bin_array_middle`,
+    [BIN_FARM_ERROR__MISSING_HOOK_ACCOUNTS]: `Fewer remaining accounts than hook_x_len + hook_y_len`,
     [BIN_FARM_ERROR__MISSING_KEEPER_ATA]: `Permissionless harvester must provide keeper ATA in remaining_accounts`,
+    [BIN_FARM_ERROR__MISSING_REFERRER_ATA]: `Referred position: pass the referrer's ATA for the fee mint as the last remaining account before any hook accounts`,
     [BIN_FARM_ERROR__NO_BINS_PROVIDED]: `No bin IDs provided`,
     [BIN_FARM_ERROR__NON_CONTIGUOUS_BINS]: `Bin IDs must be contiguous (no gaps)`,
     [BIN_FARM_ERROR__NO_PENDING_AUTHORITY]: `No pending authority`,
     [BIN_FARM_ERROR__NO_PENDING_EMERGENCY_CLOSE]: `No pending emergency close`,
     [BIN_FARM_ERROR__NO_PENDING_FEE_CHANGE]: `No pending fee change`,
+    [BIN_FARM_ERROR__NOTHING_CONVERTED]: `No fully converted bins with liquidity left to harvest`,
     [BIN_FARM_ERROR__NOTHING_TO_SWEEP]: `Nothing to sweep (rover authority has no excess SOL)`,
+    [BIN_FARM_ERROR__NOT_SOL_POOL]: `Native SOL rover requires a SOL-quoted pool (token Y = wSOL)`,
     [BIN_FARM_ERROR__OVERFLOW]: `Arithmetic overflow`,
     [BIN_FARM_ERROR__PAUSED]: `Protocol is paused`,
+    [BIN_FARM_ERROR__POOL_BLOCKED]: `Pool is blocked for new positions`,
+    [BIN_FARM_ERROR__POSITION_TOO_NARROW]: `Position narrower than config.min_position_width — see log for the minimum`,
     [BIN_FARM_ERROR__POSITION_TOO_SMALL]: `Position amount below minimum (anti-griefing)`,
     [BIN_FARM_ERROR__POSITION_TOO_WIDE]: `Position width exceeds maximum (70 bins)`,
     [BIN_FARM_ERROR__PRIORITY_SLOTS_EXCEED_MAX]: `Priority slots exceed maximum (9000 slots / ~1 hour)`,
+    [BIN_FARM_ERROR__REVENUE_DEST_PENDING]: `Revenue dest change pending — apply or cancel it before sweeping`,
+    [BIN_FARM_ERROR__ROVER_BATCH_ACCOUNT_MISMATCH]: `Rover batch: position/vault PDA mismatch, already funded, or missing signer`,
     [BIN_FARM_ERROR__ROVER_BIN_STEP_TOO_SMALL]: `Rover bin_step too small (minimum 20 — prevents instant liquidation on tight pools)`,
     [BIN_FARM_ERROR__ROVER_DEPOSIT_TOO_SMALL]: `Rover deposit below minimum (anti-griefing)`,
-    [BIN_FARM_ERROR__TOO_MANY_BINS]: `Too many bins (max 70 per call)`,
+    [BIN_FARM_ERROR__ROVER_FEE_MINT_MISMATCH]: `Rover fee token account mint does not match the pool token mint`,
+    [BIN_FARM_ERROR__SLIPPAGE_EXCEEDED]: `Close output below min_x_out / min_y_out`,
+    [BIN_FARM_ERROR__STALE_FEE_CHANGE]: `Fee change nonce mismatch — the pending change was replaced or cancelled`,
+    [BIN_FARM_ERROR__SWEEP_INVARIANT_VIOLATED]: `sweep_rover lamport accounting mismatch — rover_authority not left at rent or destinations off`,
+    [BIN_FARM_ERROR__TAKE_PROFIT_NOT_REACHED]: `Take-profit not reached: active_id below max_bin_id + take_profit_bins`,
+    [BIN_FARM_ERROR__TOO_MANY_BINS]: `Too many bins (exceeds config.max_bins_per_harvest, max 70 per call)`,
+    [BIN_FARM_ERROR__TOO_MANY_POSITIONS]: `Owner already holds max_positions_per_owner open positions`,
     [BIN_FARM_ERROR__UNAUTHORIZED]: `Not authorized`,
+    [BIN_FARM_ERROR__UNWRAP_ACCOUNT_NOT_EMPTY]: `user_token_y must be a temporary WSOL account holding only this close's output`,
+    [BIN_FARM_ERROR__UNWRAP_NOT_WSOL]: `user_close_unwrap needs a WSOL-quoted pool (token_y_mint = WSOL)`,
+    [BIN_FARM_ERROR__VAULT_BINDING_MISMATCH]: `Vault is not bound to this position's Meteora position`,
+    [BIN_FARM_ERROR__VAULT_NOT_ORPHANED]: `Position PDA still exists — vault is not orphaned`,
     [BIN_FARM_ERROR__ZERO_AMOUNT]: `Amount must be greater than zero`,
   };
 }
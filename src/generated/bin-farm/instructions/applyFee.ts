@@ -15,6 +15,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -61,13 +63,19 @@ export type ApplyFeeInstruction<
     ]
   >;
 
-export type ApplyFeeInstructionData = { discriminator: ReadonlyUint8Array };
+export type ApplyFeeInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  nonce: number;
+};
 
-export type ApplyFeeInstructionDataArgs = {};
+export type ApplyFeeInstructionDataArgs = { nonce: number };
 
 export function getApplyFeeInstructionDataEncoder(): FixedSizeEncoder<ApplyFeeInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['nonce', getU32Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: APPLY_FEE_DISCRIMINATOR })
   );
 }
@@ -75,6 +83,7 @@ export function getApplyFeeInstructionDataEncoder(): FixedSizeEncoder<ApplyFeeIn
 export function getApplyFeeInstructionDataDecoder(): FixedSizeDecoder<ApplyFeeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['nonce', getU32Decoder()],
   ]);
 }
 
@@ -94,6 +103,7 @@ export type ApplyFeeAsyncInput<
 > = {
   caller: TransactionSigner<TAccountCaller>;
   config?: Address<TAccountConfig>;
+  nonce: ApplyFeeInstructionDataArgs['nonce'];
 };
 
 export async function getApplyFeeInstructionAsync<
@@ -119,6 +129,9 @@ export async function getApplyFeeInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.config.value) {
     accounts.config.value = await getProgramDerivedAddress({
@@ -135,7 +148,9 @@ export async function getApplyFeeInstructionAsync<
       getAccountMeta(accounts.caller),
       getAccountMeta(accounts.config),
     ],
-    data: getApplyFeeInstructionDataEncoder().encode({}),
+    data: getApplyFeeInstructionDataEncoder().encode(
+      args as ApplyFeeInstructionDataArgs
+    ),
     programAddress,
   } as ApplyFeeInstruction<TProgramAddress, TAccountCaller, TAccountConfig>);
 }
@@ -146,6 +161,7 @@ export type ApplyFeeInput<
 > = {
   caller: TransactionSigner<TAccountCaller>;
   config: Address<TAccountConfig>;
+  nonce: ApplyFeeInstructionDataArgs['nonce'];
 };
 
 export function getApplyFeeInstruction<
@@ -169,13 +185,18 @@ export function getApplyFeeInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.caller),
       getAccountMeta(accounts.config),
     ],
-    data: getApplyFeeInstructionDataEncoder().encode({}),
+    data: getApplyFeeInstructionDataEncoder().encode(
+      args as ApplyFeeInstructionDataArgs
+    ),
     programAddress,
   } as ApplyFeeInstruction<TProgramAddress, TAccountCaller, TAccountConfig>);
 }
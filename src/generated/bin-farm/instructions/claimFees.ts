@@ -14,6 +14,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -132,13 +134,24 @@ export type ClaimFeesInstruction<
     ]
   >;
 
-export type ClaimFeesInstructionData = { discriminator: ReadonlyUint8Array };
+export type ClaimFeesInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  hookXLen: number;
+  hookYLen: number;
+};
 
-export type ClaimFeesInstructionDataArgs = {};
+export type ClaimFeesInstructionDataArgs = {
+  hookXLen: number;
+  hookYLen: number;
+};
 
 export function getClaimFeesInstructionDataEncoder(): FixedSizeEncoder<ClaimFeesInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['hookXLen', getU8Encoder()],
+      ['hookYLen', getU8Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: CLAIM_FEES_DISCRIMINATOR })
   );
 }
@@ -146,6 +159,8 @@ export function getClaimFeesInstructionDataEncoder(): FixedSizeEncoder<ClaimFees
 export function getClaimFeesInstructionDataDecoder(): FixedSizeDecoder<ClaimFeesInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['hookXLen', getU8Decoder()],
+    ['hookYLen', getU8Decoder()],
   ]);
 }
 
@@ -201,6 +216,8 @@ export type ClaimFeesInput<
   tokenXProgram: Address<TAccountTokenXProgram>;
   tokenYProgram: Address<TAccountTokenYProgram>;
   memoProgram: Address<TAccountMemoProgram>;
+  hookXLen: ClaimFeesInstructionDataArgs['hookXLen'];
+  hookYLen: ClaimFeesInstructionDataArgs['hookYLen'];
 };
 
 export function getClaimFeesInstruction<
@@ -303,6 +320,9 @@ export function getClaimFeesInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -327,7 +347,9 @@ export function getClaimFeesInstruction<
       getAccountMeta(accounts.tokenYProgram),
       getAccountMeta(accounts.memoProgram),
     ],
-    data: getClaimFeesInstructionDataEncoder().encode({}),
+    data: getClaimFeesInstructionDataEncoder().encode(
+      args as ClaimFeesInstructionDataArgs
+    ),
     programAddress,
   } as ClaimFeesInstruction<
     TProgramAddress,
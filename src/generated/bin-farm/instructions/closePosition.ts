@@ -10,11 +10,16 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -32,7 +37,11 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { BIN_FARM_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const CLOSE_POSITION_DISCRIMINATOR = new Uint8Array([
   123, 134, 81, 0, 49, 68, 98, 98,
@@ -50,9 +59,11 @@ export type ClosePositionInstruction<
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountPosition extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountOwnerStats extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountMeteoraPosition extends string | AccountMeta<string> = string,
   TAccountLbPair extends string | AccountMeta<string> = string,
+  TAccountPoolMetrics extends string | AccountMeta<string> = string,
   TAccountBinArrayBitmapExt extends string | AccountMeta<string> = string,
   TAccountBinArrayLower extends string | AccountMeta<string> = string,
   TAccountBinArrayUpper extends string | AccountMeta<string> = string,
@@ -91,6 +102,9 @@ export type ClosePositionInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountOwnerStats extends string
+        ? WritableAccount<TAccountOwnerStats>
+        : TAccountOwnerStats,
       TAccountOwner extends string
         ? WritableAccount<TAccountOwner>
         : TAccountOwner,
@@ -100,6 +114,9 @@ export type ClosePositionInstruction<
       TAccountLbPair extends string
         ? WritableAccount<TAccountLbPair>
         : TAccountLbPair,
+      TAccountPoolMetrics extends string
+        ? WritableAccount<TAccountPoolMetrics>
+        : TAccountPoolMetrics,
       TAccountBinArrayBitmapExt extends string
         ? ReadonlyAccount<TAccountBinArrayBitmapExt>
         : TAccountBinArrayBitmapExt,
@@ -166,13 +183,28 @@ export type ClosePositionInstruction<
 
 export type ClosePositionInstructionData = {
   discriminator: ReadonlyUint8Array;
+  minXOut: bigint;
+  minYOut: bigint;
+  hookXLen: number;
+  hookYLen: number;
 };
 
-export type ClosePositionInstructionDataArgs = {};
+export type ClosePositionInstructionDataArgs = {
+  minXOut: number | bigint;
+  minYOut: number | bigint;
+  hookXLen: number;
+  hookYLen: number;
+};
 
 export function getClosePositionInstructionDataEncoder(): FixedSizeEncoder<ClosePositionInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['minXOut', getU64Encoder()],
+      ['minYOut', getU64Encoder()],
+      ['hookXLen', getU8Encoder()],
+      ['hookYLen', getU8Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: CLOSE_POSITION_DISCRIMINATOR })
   );
 }
@@ -180,6 +212,10 @@ export function getClosePositionInstructionDataEncoder(): FixedSizeEncoder<Close
 export function getClosePositionInstructionDataDecoder(): FixedSizeDecoder<ClosePositionInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['minXOut', getU64Decoder()],
+    ['minYOut', getU64Decoder()],
+    ['hookXLen', getU8Decoder()],
+    ['hookYLen', getU8Decoder()],
   ]);
 }
 
@@ -198,9 +234,11 @@ export type ClosePositionAsyncInput<
   TAccountConfig extends string = string,
   TAccountPosition extends string = string,
   TAccountVault extends string = string,
+  TAccountOwnerStats extends string = string,
   TAccountOwner extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolMetrics extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountBinArrayLower extends string = string,
   TAccountBinArrayUpper extends string = string,
@@ -226,9 +264,11 @@ export type ClosePositionAsyncInput<
   config?: Address<TAccountConfig>;
   position: Address<TAccountPosition>;
   vault: Address<TAccountVault>;
+  ownerStats: Address<TAccountOwnerStats>;
   owner: Address<TAccountOwner>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   lbPair: Address<TAccountLbPair>;
+  poolMetrics?: Address<TAccountPoolMetrics>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   binArrayLower: Address<TAccountBinArrayLower>;
   binArrayUpper: Address<TAccountBinArrayUpper>;
@@ -249,6 +289,10 @@ export type ClosePositionAsyncInput<
   tokenYProgram: Address<TAccountTokenYProgram>;
   memoProgram: Address<TAccountMemoProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  minXOut: ClosePositionInstructionDataArgs['minXOut'];
+  minYOut: ClosePositionInstructionDataArgs['minYOut'];
+  hookXLen: ClosePositionInstructionDataArgs['hookXLen'];
+  hookYLen: ClosePositionInstructionDataArgs['hookYLen'];
 };
 
 export async function getClosePositionInstructionAsync<
@@ -256,9 +300,11 @@ export async function getClosePositionInstructionAsync<
   TAccountConfig extends string,
   TAccountPosition extends string,
   TAccountVault extends string,
+  TAccountOwnerStats extends string,
   TAccountOwner extends string,
   TAccountMeteoraPosition extends string,
   TAccountLbPair extends string,
+  TAccountPoolMetrics extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountBinArrayLower extends string,
   TAccountBinArrayUpper extends string,
@@ -286,9 +332,11 @@ export async function getClosePositionInstructionAsync<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -318,9 +366,11 @@ export async function getClosePositionInstructionAsync<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -352,9 +402,11 @@ export async function getClosePositionInstructionAsync<
     config: { value: input.config ?? null, isWritable: true },
     position: { value: input.position ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    ownerStats: { value: input.ownerStats ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolMetrics: { value: input.poolMetrics ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
       isWritable: false,
@@ -384,6 +436,9 @@ export async function getClosePositionInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.config.value) {
     accounts.config.value = await getProgramDerivedAddress({
@@ -393,6 +448,19 @@ export async function getClosePositionInstructionAsync<
       ],
     });
   }
+  if (!accounts.poolMetrics.value) {
+    accounts.poolMetrics.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 111, 111, 108, 95, 109, 101, 116, 114, 105, 99, 115,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.lbPair.value)),
+      ],
+    });
+  }
   if (!accounts.roverAuthority.value) {
     accounts.roverAuthority.value = await getProgramDerivedAddress({
       programAddress,
@@ -418,9 +486,11 @@ export async function getClosePositionInstructionAsync<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.position),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ownerStats),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolMetrics),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.binArrayLower),
       getAccountMeta(accounts.binArrayUpper),
@@ -442,7 +512,9 @@ export async function getClosePositionInstructionAsync<
       getAccountMeta(accounts.memoProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getClosePositionInstructionDataEncoder().encode({}),
+    data: getClosePositionInstructionDataEncoder().encode(
+      args as ClosePositionInstructionDataArgs
+    ),
     programAddress,
   } as ClosePositionInstruction<
     TProgramAddress,
@@ -450,9 +522,11 @@ export async function getClosePositionInstructionAsync<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -481,9 +555,11 @@ export type ClosePositionInput<
   TAccountConfig extends string = string,
   TAccountPosition extends string = string,
   TAccountVault extends string = string,
+  TAccountOwnerStats extends string = string,
   TAccountOwner extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolMetrics extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountBinArrayLower extends string = string,
   TAccountBinArrayUpper extends string = string,
@@ -509,9 +585,11 @@ export type ClosePositionInput<
   config: Address<TAccountConfig>;
   position: Address<TAccountPosition>;
   vault: Address<TAccountVault>;
+  ownerStats: Address<TAccountOwnerStats>;
   owner: Address<TAccountOwner>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   lbPair: Address<TAccountLbPair>;
+  poolMetrics: Address<TAccountPoolMetrics>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   binArrayLower: Address<TAccountBinArrayLower>;
   binArrayUpper: Address<TAccountBinArrayUpper>;
@@ -532,6 +610,10 @@ export type ClosePositionInput<
   tokenYProgram: Address<TAccountTokenYProgram>;
   memoProgram: Address<TAccountMemoProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  minXOut: ClosePositionInstructionDataArgs['minXOut'];
+  minYOut: ClosePositionInstructionDataArgs['minYOut'];
+  hookXLen: ClosePositionInstructionDataArgs['hookXLen'];
+  hookYLen: ClosePositionInstructionDataArgs['hookYLen'];
 };
 
 export function getClosePositionInstruction<
@@ -539,9 +621,11 @@ export function getClosePositionInstruction<
   TAccountConfig extends string,
   TAccountPosition extends string,
   TAccountVault extends string,
+  TAccountOwnerStats extends string,
   TAccountOwner extends string,
   TAccountMeteoraPosition extends string,
   TAccountLbPair extends string,
+  TAccountPoolMetrics extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountBinArrayLower extends string,
   TAccountBinArrayUpper extends string,
@@ -569,9 +653,11 @@ export function getClosePositionInstruction<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -600,9 +686,11 @@ export function getClosePositionInstruction<
   TAccountConfig,
   TAccountPosition,
   TAccountVault,
+  TAccountOwnerStats,
   TAccountOwner,
   TAccountMeteoraPosition,
   TAccountLbPair,
+  TAccountPoolMetrics,
   TAccountBinArrayBitmapExt,
   TAccountBinArrayLower,
   TAccountBinArrayUpper,
@@ -633,9 +721,11 @@ export function getClosePositionInstruction<
     config: { value: input.config ?? null, isWritable: true },
     position: { value: input.position ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    ownerStats: { value: input.ownerStats ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolMetrics: { value: input.poolMetrics ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
       isWritable: false,
@@ -665,6 +755,9 @@ export function getClosePositionInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -678,9 +771,11 @@ export function getClosePositionInstruction<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.position),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ownerStats),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolMetrics),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.binArrayLower),
       getAccountMeta(accounts.binArrayUpper),
@@ -702,7 +797,9 @@ export function getClosePositionInstruction<
       getAccountMeta(accounts.memoProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getClosePositionInstructionDataEncoder().encode({}),
+    data: getClosePositionInstructionDataEncoder().encode(
+      args as ClosePositionInstructionDataArgs
+    ),
     programAddress,
   } as ClosePositionInstruction<
     TProgramAddress,
@@ -710,9 +807,11 @@ export function getClosePositionInstruction<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -746,29 +845,31 @@ export type ParsedClosePositionInstruction<
     config: TAccountMetas[1];
     position: TAccountMetas[2];
     vault: TAccountMetas[3];
-    owner: TAccountMetas[4];
-    meteoraPosition: TAccountMetas[5];
-    lbPair: TAccountMetas[6];
-    binArrayBitmapExt: TAccountMetas[7];
-    binArrayLower: TAccountMetas[8];
-    binArrayUpper: TAccountMetas[9];
-    reserveX: TAccountMetas[10];
-    reserveY: TAccountMetas[11];
-    tokenXMint: TAccountMetas[12];
-    tokenYMint: TAccountMetas[13];
-    eventAuthority: TAccountMetas[14];
-    dlmmProgram: TAccountMetas[15];
-    vaultTokenX: TAccountMetas[16];
-    vaultTokenY: TAccountMetas[17];
-    ownerTokenX: TAccountMetas[18];
-    ownerTokenY: TAccountMetas[19];
-    roverAuthority: TAccountMetas[20];
-    roverFeeTokenX: TAccountMetas[21];
-    roverFeeTokenY: TAccountMetas[22];
-    tokenXProgram: TAccountMetas[23];
-    tokenYProgram: TAccountMetas[24];
-    memoProgram: TAccountMetas[25];
-    systemProgram: TAccountMetas[26];
+    ownerStats: TAccountMetas[4];
+    owner: TAccountMetas[5];
+    meteoraPosition: TAccountMetas[6];
+    lbPair: TAccountMetas[7];
+    poolMetrics: TAccountMetas[8];
+    binArrayBitmapExt: TAccountMetas[9];
+    binArrayLower: TAccountMetas[10];
+    binArrayUpper: TAccountMetas[11];
+    reserveX: TAccountMetas[12];
+    reserveY: TAccountMetas[13];
+    tokenXMint: TAccountMetas[14];
+    tokenYMint: TAccountMetas[15];
+    eventAuthority: TAccountMetas[16];
+    dlmmProgram: TAccountMetas[17];
+    vaultTokenX: TAccountMetas[18];
+    vaultTokenY: TAccountMetas[19];
+    ownerTokenX: TAccountMetas[20];
+    ownerTokenY: TAccountMetas[21];
+    roverAuthority: TAccountMetas[22];
+    roverFeeTokenX: TAccountMetas[23];
+    roverFeeTokenY: TAccountMetas[24];
+    tokenXProgram: TAccountMetas[25];
+    tokenYProgram: TAccountMetas[26];
+    memoProgram: TAccountMetas[27];
+    systemProgram: TAccountMetas[28];
   };
   data: ClosePositionInstructionData;
 };
@@ -781,7 +882,7 @@ export function parseClosePositionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedClosePositionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 27) {
+  if (instruction.accounts.length < 29) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -798,9 +899,11 @@ export function parseClosePositionInstruction<
       config: getNextAccount(),
       position: getNextAccount(),
       vault: getNextAccount(),
+      ownerStats: getNextAccount(),
       owner: getNextAccount(),
       meteoraPosition: getNextAccount(),
       lbPair: getNextAccount(),
+      poolMetrics: getNextAccount(),
       binArrayBitmapExt: getNextAccount(),
       binArrayLower: getNextAccount(),
       binArrayUpper: getNextAccount(),
@@ -10,6 +10,7 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getBytesDecoder,
@@ -19,6 +20,10 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -36,7 +41,11 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { BIN_FARM_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const HARVEST_BINS_DISCRIMINATOR = new Uint8Array([
   94, 39, 110, 216, 191, 68, 13, 196,
@@ -57,6 +66,7 @@ export type HarvestBinsInstruction<
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountMeteoraPosition extends string | AccountMeta<string> = string,
   TAccountLbPair extends string | AccountMeta<string> = string,
+  TAccountPoolMetrics extends string | AccountMeta<string> = string,
   TAccountBinArrayBitmapExt extends string | AccountMeta<string> = string,
   TAccountBinArrayLower extends string | AccountMeta<string> = string,
   TAccountBinArrayUpper extends string | AccountMeta<string> = string,
@@ -102,6 +112,9 @@ export type HarvestBinsInstruction<
       TAccountLbPair extends string
         ? WritableAccount<TAccountLbPair>
         : TAccountLbPair,
+      TAccountPoolMetrics extends string
+        ? WritableAccount<TAccountPoolMetrics>
+        : TAccountPoolMetrics,
       TAccountBinArrayBitmapExt extends string
         ? ReadonlyAccount<TAccountBinArrayBitmapExt>
         : TAccountBinArrayBitmapExt,
@@ -166,15 +179,29 @@ export type HarvestBinsInstruction<
 export type HarvestBinsInstructionData = {
   discriminator: ReadonlyUint8Array;
   binIds: Array<number>;
+  bpsToRemove: number;
+  tipSplitBps: number;
+  hookXLen: number;
+  hookYLen: number;
 };
 
-export type HarvestBinsInstructionDataArgs = { binIds: Array<number> };
+export type HarvestBinsInstructionDataArgs = {
+  binIds: Array<number>;
+  bpsToRemove: number;
+  tipSplitBps: number;
+  hookXLen: number;
+  hookYLen: number;
+};
 
 export function getHarvestBinsInstructionDataEncoder(): Encoder<HarvestBinsInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['binIds', getArrayEncoder(getI32Encoder())],
+      ['bpsToRemove', getU16Encoder()],
+      ['tipSplitBps', getU16Encoder()],
+      ['hookXLen', getU8Encoder()],
+      ['hookYLen', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: HARVEST_BINS_DISCRIMINATOR })
   );
@@ -184,6 +211,10 @@ export function getHarvestBinsInstructionDataDecoder(): Decoder<HarvestBinsInstr
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['binIds', getArrayDecoder(getI32Decoder())],
+    ['bpsToRemove', getU16Decoder()],
+    ['tipSplitBps', getU16Decoder()],
+    ['hookXLen', getU8Decoder()],
+    ['hookYLen', getU8Decoder()],
   ]);
 }
 
@@ -205,6 +236,7 @@ export type HarvestBinsAsyncInput<
   TAccountOwner extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolMetrics extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountBinArrayLower extends string = string,
   TAccountBinArrayUpper extends string = string,
@@ -232,6 +264,7 @@ export type HarvestBinsAsyncInput<
   owner: Address<TAccountOwner>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   lbPair: Address<TAccountLbPair>;
+  poolMetrics?: Address<TAccountPoolMetrics>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   binArrayLower: Address<TAccountBinArrayLower>;
   binArrayUpper: Address<TAccountBinArrayUpper>;
@@ -252,6 +285,10 @@ export type HarvestBinsAsyncInput<
   tokenYProgram: Address<TAccountTokenYProgram>;
   memoProgram: Address<TAccountMemoProgram>;
   binIds: HarvestBinsInstructionDataArgs['binIds'];
+  bpsToRemove: HarvestBinsInstructionDataArgs['bpsToRemove'];
+  tipSplitBps: HarvestBinsInstructionDataArgs['tipSplitBps'];
+  hookXLen: HarvestBinsInstructionDataArgs['hookXLen'];
+  hookYLen: HarvestBinsInstructionDataArgs['hookYLen'];
 };
 
 export async function getHarvestBinsInstructionAsync<
@@ -262,6 +299,7 @@ export async function getHarvestBinsInstructionAsync<
   TAccountOwner extends string,
   TAccountMeteoraPosition extends string,
   TAccountLbPair extends string,
+  TAccountPoolMetrics extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountBinArrayLower extends string,
   TAccountBinArrayUpper extends string,
@@ -291,6 +329,7 @@ export async function getHarvestBinsInstructionAsync<
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -322,6 +361,7 @@ export async function getHarvestBinsInstructionAsync<
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -355,6 +395,7 @@ export async function getHarvestBinsInstructionAsync<
     owner: { value: input.owner ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolMetrics: { value: input.poolMetrics ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
       isWritable: false,
@@ -395,6 +436,19 @@ export async function getHarvestBinsInstructionAsync<
       ],
     });
   }
+  if (!accounts.poolMetrics.value) {
+    accounts.poolMetrics.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 111, 111, 108, 95, 109, 101, 116, 114, 105, 99, 115,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.lbPair.value)),
+      ],
+    });
+  }
   if (!accounts.roverAuthority.value) {
     accounts.roverAuthority.value = await getProgramDerivedAddress({
       programAddress,
@@ -419,6 +473,7 @@ export async function getHarvestBinsInstructionAsync<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolMetrics),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.binArrayLower),
       getAccountMeta(accounts.binArrayUpper),
@@ -452,6 +507,7 @@ export async function getHarvestBinsInstructionAsync<
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -482,6 +538,7 @@ export type HarvestBinsInput<
   TAccountOwner extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolMetrics extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountBinArrayLower extends string = string,
   TAccountBinArrayUpper extends string = string,
@@ -509,6 +566,7 @@ export type HarvestBinsInput<
   owner: Address<TAccountOwner>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   lbPair: Address<TAccountLbPair>;
+  poolMetrics: Address<TAccountPoolMetrics>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   binArrayLower: Address<TAccountBinArrayLower>;
   binArrayUpper: Address<TAccountBinArrayUpper>;
@@ -529,6 +587,10 @@ export type HarvestBinsInput<
   tokenYProgram: Address<TAccountTokenYProgram>;
   memoProgram: Address<TAccountMemoProgram>;
   binIds: HarvestBinsInstructionDataArgs['binIds'];
+  bpsToRemove: HarvestBinsInstructionDataArgs['bpsToRemove'];
+  tipSplitBps: HarvestBinsInstructionDataArgs['tipSplitBps'];
+  hookXLen: HarvestBinsInstructionDataArgs['hookXLen'];
+  hookYLen: HarvestBinsInstructionDataArgs['hookYLen'];
 };
 
 export function getHarvestBinsInstruction<
@@ -539,6 +601,7 @@ export function getHarvestBinsInstruction<
   TAccountOwner extends string,
   TAccountMeteoraPosition extends string,
   TAccountLbPair extends string,
+  TAccountPoolMetrics extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountBinArrayLower extends string,
   TAccountBinArrayUpper extends string,
@@ -568,6 +631,7 @@ export function getHarvestBinsInstruction<
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -598,6 +662,7 @@ export function getHarvestBinsInstruction<
   TAccountOwner,
   TAccountMeteoraPosition,
   TAccountLbPair,
+  TAccountPoolMetrics,
   TAccountBinArrayBitmapExt,
   TAccountBinArrayLower,
   TAccountBinArrayUpper,
@@ -630,6 +695,7 @@ export function getHarvestBinsInstruction<
     owner: { value: input.owner ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolMetrics: { value: input.poolMetrics ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
       isWritable: false,
@@ -671,6 +737,7 @@ export function getHarvestBinsInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolMetrics),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.binArrayLower),
       getAccountMeta(accounts.binArrayUpper),
@@ -704,6 +771,7 @@ export function getHarvestBinsInstruction<
     TAccountOwner,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -739,25 +807,26 @@ export type ParsedHarvestBinsInstruction<
     owner: TAccountMetas[4];
     meteoraPosition: TAccountMetas[5];
     lbPair: TAccountMetas[6];
-    binArrayBitmapExt: TAccountMetas[7];
-    binArrayLower: TAccountMetas[8];
-    binArrayUpper: TAccountMetas[9];
-    reserveX: TAccountMetas[10];
-    reserveY: TAccountMetas[11];
-    tokenXMint: TAccountMetas[12];
-    tokenYMint: TAccountMetas[13];
-    eventAuthority: TAccountMetas[14];
-    dlmmProgram: TAccountMetas[15];
-    vaultTokenX: TAccountMetas[16];
-    vaultTokenY: TAccountMetas[17];
-    ownerTokenX: TAccountMetas[18];
-    ownerTokenY: TAccountMetas[19];
-    roverAuthority: TAccountMetas[20];
-    roverFeeTokenX: TAccountMetas[21];
-    roverFeeTokenY: TAccountMetas[22];
-    tokenXProgram: TAccountMetas[23];
-    tokenYProgram: TAccountMetas[24];
-    memoProgram: TAccountMetas[25];
+    poolMetrics: TAccountMetas[7];
+    binArrayBitmapExt: TAccountMetas[8];
+    binArrayLower: TAccountMetas[9];
+    binArrayUpper: TAccountMetas[10];
+    reserveX: TAccountMetas[11];
+    reserveY: TAccountMetas[12];
+    tokenXMint: TAccountMetas[13];
+    tokenYMint: TAccountMetas[14];
+    eventAuthority: TAccountMetas[15];
+    dlmmProgram: TAccountMetas[16];
+    vaultTokenX: TAccountMetas[17];
+    vaultTokenY: TAccountMetas[18];
+    ownerTokenX: TAccountMetas[19];
+    ownerTokenY: TAccountMetas[20];
+    roverAuthority: TAccountMetas[21];
+    roverFeeTokenX: TAccountMetas[22];
+    roverFeeTokenY: TAccountMetas[23];
+    tokenXProgram: TAccountMetas[24];
+    tokenYProgram: TAccountMetas[25];
+    memoProgram: TAccountMetas[26];
   };
   data: HarvestBinsInstructionData;
 };
@@ -770,7 +839,7 @@ export function parseHarvestBinsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedHarvestBinsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 26) {
+  if (instruction.accounts.length < 27) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -790,6 +859,7 @@ export function parseHarvestBinsInstruction<
       owner: getNextAccount(),
       meteoraPosition: getNextAccount(),
       lbPair: getNextAccount(),
+      poolMetrics: getNextAccount(),
       binArrayBitmapExt: getNextAccount(),
       binArrayLower: getNextAccount(),
       binArrayUpper: getNextAccount(),
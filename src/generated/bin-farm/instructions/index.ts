@@ -9,10 +9,14 @@
 export * from './acceptAuthority';
 export * from './applyEmergencyClose';
 export * from './applyFee';
+export * from './applyKeeperTipBps';
+export * from './applyPrioritySlots';
 export * from './applyRevenueDest';
 export * from './botPause';
 export * from './botUnpause';
 export * from './cancelPendingFee';
+export * from './cancelPendingKeeperTipBps';
+export * from './cancelPendingPrioritySlots';
 export * from './cancelPendingRevenueDest';
 export * from './claimFees';
 export * from './closePosition';
@@ -26,11 +30,11 @@ export * from './openRoverPosition';
 export * from './pause';
 export * from './proposeEmergencyClose';
 export * from './proposeFee';
+export * from './proposeKeeperTipBps';
+export * from './proposePrioritySlots';
 export * from './proposeRevenueDest';
 export * from './sweepRover';
 export * from './transferAuthority';
 export * from './unpause';
 export * from './updateBot';
-export * from './updateKeeperTipBps';
-export * from './updatePrioritySlots';
 export * from './userClose';
@@ -13,6 +13,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI32Decoder,
+  getI32Encoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -150,11 +152,13 @@ export type OpenFeeRoverInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
   binStep: number;
+  maxActiveBinSlippage: number;
 };
 
 export type OpenFeeRoverInstructionDataArgs = {
   amount: number | bigint;
   binStep: number;
+  maxActiveBinSlippage: number;
 };
 
 export function getOpenFeeRoverInstructionDataEncoder(): FixedSizeEncoder<OpenFeeRoverInstructionDataArgs> {
@@ -163,6 +167,7 @@ export function getOpenFeeRoverInstructionDataEncoder(): FixedSizeEncoder<OpenFe
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amount', getU64Encoder()],
       ['binStep', getU16Encoder()],
+      ['maxActiveBinSlippage', getI32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: OPEN_FEE_ROVER_DISCRIMINATOR })
   );
@@ -173,6 +178,7 @@ export function getOpenFeeRoverInstructionDataDecoder(): FixedSizeDecoder<OpenFe
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amount', getU64Decoder()],
     ['binStep', getU16Decoder()],
+    ['maxActiveBinSlippage', getI32Decoder()],
   ]);
 }
 
@@ -233,6 +239,7 @@ export type OpenFeeRoverAsyncInput<
   systemProgram?: Address<TAccountSystemProgram>;
   amount: OpenFeeRoverInstructionDataArgs['amount'];
   binStep: OpenFeeRoverInstructionDataArgs['binStep'];
+  maxActiveBinSlippage: OpenFeeRoverInstructionDataArgs['maxActiveBinSlippage'];
 };
 
 export async function getOpenFeeRoverInstructionAsync<
@@ -497,6 +504,7 @@ export type OpenFeeRoverInput<
   systemProgram?: Address<TAccountSystemProgram>;
   amount: OpenFeeRoverInstructionDataArgs['amount'];
   binStep: OpenFeeRoverInstructionDataArgs['binStep'];
+  maxActiveBinSlippage: OpenFeeRoverInstructionDataArgs['maxActiveBinSlippage'];
 };
 
 export function getOpenFeeRoverInstruction<
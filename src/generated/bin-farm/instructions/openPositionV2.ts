@@ -10,6 +10,7 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
@@ -18,8 +19,12 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -31,6 +36,7 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -64,7 +70,9 @@ export type OpenPositionV2Instruction<
   TAccountUser extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountLbPair extends string | AccountMeta<string> = string,
+  TAccountPoolStatus extends string | AccountMeta<string> = string,
   TAccountPositionCounter extends string | AccountMeta<string> = string,
+  TAccountOwnerStats extends string | AccountMeta<string> = string,
   TAccountMeteoraPosition extends string | AccountMeta<string> = string,
   TAccountBinArrayBitmapExt extends string | AccountMeta<string> = string,
   TAccountReserveX extends string | AccountMeta<string> = string,
@@ -84,6 +92,8 @@ export type OpenPositionV2Instruction<
   TAccountDlmmProgram extends string | AccountMeta<string> = string,
   TAccountTokenXMint extends string | AccountMeta<string> = string,
   TAccountTokenYMint extends string | AccountMeta<string> = string,
+  TAccountBinArrayMiddle extends string | AccountMeta<string> = string,
+  TAccountFeeAuthority extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -98,9 +108,15 @@ export type OpenPositionV2Instruction<
       TAccountLbPair extends string
         ? WritableAccount<TAccountLbPair>
         : TAccountLbPair,
+      TAccountPoolStatus extends string
+        ? ReadonlyAccount<TAccountPoolStatus>
+        : TAccountPoolStatus,
       TAccountPositionCounter extends string
         ? WritableAccount<TAccountPositionCounter>
         : TAccountPositionCounter,
+      TAccountOwnerStats extends string
+        ? WritableAccount<TAccountOwnerStats>
+        : TAccountOwnerStats,
       TAccountMeteoraPosition extends string
         ? WritableAccount<TAccountMeteoraPosition>
         : TAccountMeteoraPosition,
@@ -155,6 +171,13 @@ export type OpenPositionV2Instruction<
       TAccountTokenYMint extends string
         ? ReadonlyAccount<TAccountTokenYMint>
         : TAccountTokenYMint,
+      TAccountBinArrayMiddle extends string
+        ? WritableAccount<TAccountBinArrayMiddle>
+        : TAccountBinArrayMiddle,
+      TAccountFeeAuthority extends string
+        ? ReadonlySignerAccount<TAccountFeeAuthority> &
+            AccountSignerMeta<TAccountFeeAuthority>
+        : TAccountFeeAuthority,
       ...TRemainingAccounts,
     ]
   >;
@@ -166,6 +189,15 @@ export type OpenPositionV2InstructionData = {
   maxBinId: number;
   side: Side;
   maxActiveBinSlippage: number;
+  takeProfitBins: number;
+  strategy: number;
+  referrer: Address;
+  prioritySlotsOverride: bigint;
+  hookXLen: number;
+  hookYLen: number;
+  feeBpsOverride: number;
+  expectedActiveId: number;
+  maxDrift: number;
 };
 
 export type OpenPositionV2InstructionDataArgs = {
@@ -174,6 +206,15 @@ export type OpenPositionV2InstructionDataArgs = {
   maxBinId: number;
   side: SideArgs;
   maxActiveBinSlippage: number;
+  takeProfitBins: number;
+  strategy: number;
+  referrer: Address;
+  prioritySlotsOverride: number | bigint;
+  hookXLen: number;
+  hookYLen: number;
+  feeBpsOverride: number;
+  expectedActiveId: number;
+  maxDrift: number;
 };
 
 export function getOpenPositionV2InstructionDataEncoder(): FixedSizeEncoder<OpenPositionV2InstructionDataArgs> {
@@ -185,6 +226,15 @@ export function getOpenPositionV2InstructionDataEncoder(): FixedSizeEncoder<Open
       ['maxBinId', getI32Encoder()],
       ['side', getSideEncoder()],
       ['maxActiveBinSlippage', getI32Encoder()],
+      ['takeProfitBins', getU16Encoder()],
+      ['strategy', getU8Encoder()],
+      ['referrer', getAddressEncoder()],
+      ['prioritySlotsOverride', getU64Encoder()],
+      ['hookXLen', getU8Encoder()],
+      ['hookYLen', getU8Encoder()],
+      ['feeBpsOverride', getU16Encoder()],
+      ['expectedActiveId', getI32Encoder()],
+      ['maxDrift', getI32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: OPEN_POSITION_V2_DISCRIMINATOR })
   );
@@ -198,6 +248,15 @@ export function getOpenPositionV2InstructionDataDecoder(): FixedSizeDecoder<Open
     ['maxBinId', getI32Decoder()],
     ['side', getSideDecoder()],
     ['maxActiveBinSlippage', getI32Decoder()],
+    ['takeProfitBins', getU16Decoder()],
+    ['strategy', getU8Decoder()],
+    ['referrer', getAddressDecoder()],
+    ['prioritySlotsOverride', getU64Decoder()],
+    ['hookXLen', getU8Decoder()],
+    ['hookYLen', getU8Decoder()],
+    ['feeBpsOverride', getU16Decoder()],
+    ['expectedActiveId', getI32Decoder()],
+    ['maxDrift', getI32Decoder()],
   ]);
 }
 
@@ -215,7 +274,9 @@ export type OpenPositionV2AsyncInput<
   TAccountUser extends string = string,
   TAccountConfig extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolStatus extends string = string,
   TAccountPositionCounter extends string = string,
+  TAccountOwnerStats extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountReserveX extends string = string,
@@ -234,11 +295,16 @@ export type OpenPositionV2AsyncInput<
   TAccountDlmmProgram extends string = string,
   TAccountTokenXMint extends string = string,
   TAccountTokenYMint extends string = string,
+  TAccountBinArrayMiddle extends string = string,
+  TAccountFeeAuthority extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
   config?: Address<TAccountConfig>;
   lbPair: Address<TAccountLbPair>;
+  /** Address enforced by seeds; blocked flag checked in handler. */
+  poolStatus?: Address<TAccountPoolStatus>;
   positionCounter?: Address<TAccountPositionCounter>;
+  ownerStats?: Address<TAccountOwnerStats>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   reserveX: Address<TAccountReserveX>;
@@ -257,18 +323,35 @@ export type OpenPositionV2AsyncInput<
   dlmmProgram: Address<TAccountDlmmProgram>;
   tokenXMint: Address<TAccountTokenXMint>;
   tokenYMint: Address<TAccountTokenYMint>;
+  /**
+   * spans three bin arrays; PDA checked in handler, Meteora validates contents via CPI.
+   */
+  binArrayMiddle?: Address<TAccountBinArrayMiddle>;
+  /** config.authority co-signing a fee_bps_override. Only required when one is set. */
+  feeAuthority?: TransactionSigner<TAccountFeeAuthority>;
   amount: OpenPositionV2InstructionDataArgs['amount'];
   minBinId: OpenPositionV2InstructionDataArgs['minBinId'];
   maxBinId: OpenPositionV2InstructionDataArgs['maxBinId'];
   side: OpenPositionV2InstructionDataArgs['side'];
   maxActiveBinSlippage: OpenPositionV2InstructionDataArgs['maxActiveBinSlippage'];
+  takeProfitBins: OpenPositionV2InstructionDataArgs['takeProfitBins'];
+  strategy: OpenPositionV2InstructionDataArgs['strategy'];
+  referrer: OpenPositionV2InstructionDataArgs['referrer'];
+  prioritySlotsOverride: OpenPositionV2InstructionDataArgs['prioritySlotsOverride'];
+  hookXLen: OpenPositionV2InstructionDataArgs['hookXLen'];
+  hookYLen: OpenPositionV2InstructionDataArgs['hookYLen'];
+  feeBpsOverride: OpenPositionV2InstructionDataArgs['feeBpsOverride'];
+  expectedActiveId: OpenPositionV2InstructionDataArgs['expectedActiveId'];
+  maxDrift: OpenPositionV2InstructionDataArgs['maxDrift'];
 };
 
 export async function getOpenPositionV2InstructionAsync<
   TAccountUser extends string,
   TAccountConfig extends string,
   TAccountLbPair extends string,
+  TAccountPoolStatus extends string,
   TAccountPositionCounter extends string,
+  TAccountOwnerStats extends string,
   TAccountMeteoraPosition extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountReserveX extends string,
@@ -287,13 +370,17 @@ export async function getOpenPositionV2InstructionAsync<
   TAccountDlmmProgram extends string,
   TAccountTokenXMint extends string,
   TAccountTokenYMint extends string,
+  TAccountBinArrayMiddle extends string,
+  TAccountFeeAuthority extends string,
   TProgramAddress extends Address = typeof BIN_FARM_PROGRAM_ADDRESS,
 >(
   input: OpenPositionV2AsyncInput<
     TAccountUser,
     TAccountConfig,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountPositionCounter,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -320,7 +407,9 @@ export async function getOpenPositionV2InstructionAsync<
     TAccountUser,
     TAccountConfig,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountPositionCounter,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -349,7 +438,9 @@ export async function getOpenPositionV2InstructionAsync<
     user: { value: input.user ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolStatus: { value: input.poolStatus ?? null, isWritable: false },
     positionCounter: { value: input.positionCounter ?? null, isWritable: true },
+    ownerStats: { value: input.ownerStats ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
@@ -374,6 +465,8 @@ export async function getOpenPositionV2InstructionAsync<
     dlmmProgram: { value: input.dlmmProgram ?? null, isWritable: false },
     tokenXMint: { value: input.tokenXMint ?? null, isWritable: false },
     tokenYMint: { value: input.tokenYMint ?? null, isWritable: false },
+    binArrayMiddle: { value: input.binArrayMiddle ?? null, isWritable: true },
+    feeAuthority: { value: input.feeAuthority ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -392,6 +485,17 @@ export async function getOpenPositionV2InstructionAsync<
       ],
     });
   }
+  if (!accounts.poolStatus.value) {
+    accounts.poolStatus.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([112, 111, 111, 108, 95, 115, 116, 97, 116, 117, 115])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.lbPair.value)),
+      ],
+    });
+  }
   if (!accounts.positionCounter.value) {
     accounts.positionCounter.value = await getProgramDerivedAddress({
       programAddress,
@@ -404,6 +508,17 @@ export async function getOpenPositionV2InstructionAsync<
       ],
     });
   }
+  if (!accounts.ownerStats.value) {
+    accounts.ownerStats.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([111, 119, 110, 101, 114, 95, 115, 116, 97, 116, 115])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.user.value)),
+      ],
+    });
+  }
   if (!accounts.position.value) {
     accounts.position.value = await getProgramDerivedAddress({
       programAddress,
@@ -439,7 +554,9 @@ export async function getOpenPositionV2InstructionAsync<
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolStatus),
       getAccountMeta(accounts.positionCounter),
+      getAccountMeta(accounts.ownerStats),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.reserveX),
@@ -458,6 +575,8 @@ export async function getOpenPositionV2InstructionAsync<
       getAccountMeta(accounts.dlmmProgram),
       getAccountMeta(accounts.tokenXMint),
       getAccountMeta(accounts.tokenYMint),
+      getAccountMeta(accounts.binArrayMiddle),
+      getAccountMeta(accounts.feeAuthority),
     ],
     data: getOpenPositionV2InstructionDataEncoder().encode(
       args as OpenPositionV2InstructionDataArgs
@@ -468,7 +587,9 @@ export async function getOpenPositionV2InstructionAsync<
     TAccountUser,
     TAccountConfig,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountPositionCounter,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -494,7 +615,9 @@ export type OpenPositionV2Input<
   TAccountUser extends string = string,
   TAccountConfig extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolStatus extends string = string,
   TAccountPositionCounter extends string = string,
+  TAccountOwnerStats extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountReserveX extends string = string,
@@ -513,11 +636,16 @@ export type OpenPositionV2Input<
   TAccountDlmmProgram extends string = string,
   TAccountTokenXMint extends string = string,
   TAccountTokenYMint extends string = string,
+  TAccountBinArrayMiddle extends string = string,
+  TAccountFeeAuthority extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
   config: Address<TAccountConfig>;
   lbPair: Address<TAccountLbPair>;
+  /** Address enforced by seeds; blocked flag checked in handler. */
+  poolStatus: Address<TAccountPoolStatus>;
   positionCounter: Address<TAccountPositionCounter>;
+  ownerStats: Address<TAccountOwnerStats>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   reserveX: Address<TAccountReserveX>;
@@ -536,18 +664,35 @@ export type OpenPositionV2Input<
   dlmmProgram: Address<TAccountDlmmProgram>;
   tokenXMint: Address<TAccountTokenXMint>;
   tokenYMint: Address<TAccountTokenYMint>;
+  /**
+   * spans three bin arrays; PDA checked in handler, Meteora validates contents via CPI.
+   */
+  binArrayMiddle?: Address<TAccountBinArrayMiddle>;
+  /** config.authority co-signing a fee_bps_override. Only required when one is set. */
+  feeAuthority?: TransactionSigner<TAccountFeeAuthority>;
   amount: OpenPositionV2InstructionDataArgs['amount'];
   minBinId: OpenPositionV2InstructionDataArgs['minBinId'];
   maxBinId: OpenPositionV2InstructionDataArgs['maxBinId'];
   side: OpenPositionV2InstructionDataArgs['side'];
   maxActiveBinSlippage: OpenPositionV2InstructionDataArgs['maxActiveBinSlippage'];
+  takeProfitBins: OpenPositionV2InstructionDataArgs['takeProfitBins'];
+  strategy: OpenPositionV2InstructionDataArgs['strategy'];
+  referrer: OpenPositionV2InstructionDataArgs['referrer'];
+  prioritySlotsOverride: OpenPositionV2InstructionDataArgs['prioritySlotsOverride'];
+  hookXLen: OpenPositionV2InstructionDataArgs['hookXLen'];
+  hookYLen: OpenPositionV2InstructionDataArgs['hookYLen'];
+  feeBpsOverride: OpenPositionV2InstructionDataArgs['feeBpsOverride'];
+  expectedActiveId: OpenPositionV2InstructionDataArgs['expectedActiveId'];
+  maxDrift: OpenPositionV2InstructionDataArgs['maxDrift'];
 };
 
 export function getOpenPositionV2Instruction<
   TAccountUser extends string,
   TAccountConfig extends string,
   TAccountLbPair extends string,
+  TAccountPoolStatus extends string,
   TAccountPositionCounter extends string,
+  TAccountOwnerStats extends string,
   TAccountMeteoraPosition extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountReserveX extends string,
@@ -566,13 +711,17 @@ export function getOpenPositionV2Instruction<
   TAccountDlmmProgram extends string,
   TAccountTokenXMint extends string,
   TAccountTokenYMint extends string,
+  TAccountBinArrayMiddle extends string,
+  TAccountFeeAuthority extends string,
   TProgramAddress extends Address = typeof BIN_FARM_PROGRAM_ADDRESS,
 >(
   input: OpenPositionV2Input<
     TAccountUser,
     TAccountConfig,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountPositionCounter,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -598,7 +747,9 @@ export function getOpenPositionV2Instruction<
   TAccountUser,
   TAccountConfig,
   TAccountLbPair,
+  TAccountPoolStatus,
   TAccountPositionCounter,
+  TAccountOwnerStats,
   TAccountMeteoraPosition,
   TAccountBinArrayBitmapExt,
   TAccountReserveX,
@@ -626,7 +777,9 @@ export function getOpenPositionV2Instruction<
     user: { value: input.user ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolStatus: { value: input.poolStatus ?? null, isWritable: false },
     positionCounter: { value: input.positionCounter ?? null, isWritable: true },
+    ownerStats: { value: input.ownerStats ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
@@ -651,6 +804,8 @@ export function getOpenPositionV2Instruction<
     dlmmProgram: { value: input.dlmmProgram ?? null, isWritable: false },
     tokenXMint: { value: input.tokenXMint ?? null, isWritable: false },
     tokenYMint: { value: input.tokenYMint ?? null, isWritable: false },
+    binArrayMiddle: { value: input.binArrayMiddle ?? null, isWritable: true },
+    feeAuthority: { value: input.feeAuthority ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -672,7 +827,9 @@ export function getOpenPositionV2Instruction<
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolStatus),
       getAccountMeta(accounts.positionCounter),
+      getAccountMeta(accounts.ownerStats),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.reserveX),
@@ -691,6 +848,8 @@ export function getOpenPositionV2Instruction<
       getAccountMeta(accounts.dlmmProgram),
       getAccountMeta(accounts.tokenXMint),
       getAccountMeta(accounts.tokenYMint),
+      getAccountMeta(accounts.binArrayMiddle),
+      getAccountMeta(accounts.feeAuthority),
     ],
     data: getOpenPositionV2InstructionDataEncoder().encode(
       args as OpenPositionV2InstructionDataArgs
@@ -701,7 +860,9 @@ export function getOpenPositionV2Instruction<
     TAccountUser,
     TAccountConfig,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountPositionCounter,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -732,25 +893,34 @@ export type ParsedOpenPositionV2Instruction<
     user: TAccountMetas[0];
     config: TAccountMetas[1];
     lbPair: TAccountMetas[2];
-    positionCounter: TAccountMetas[3];
-    meteoraPosition: TAccountMetas[4];
-    binArrayBitmapExt: TAccountMetas[5];
-    reserveX: TAccountMetas[6];
-    reserveY: TAccountMetas[7];
-    position: TAccountMetas[8];
-    vault: TAccountMetas[9];
-    userTokenAccount: TAccountMetas[10];
-    vaultTokenX: TAccountMetas[11];
-    vaultTokenY: TAccountMetas[12];
-    tokenXProgram: TAccountMetas[13];
-    tokenYProgram: TAccountMetas[14];
-    systemProgram: TAccountMetas[15];
-    binArrayLower: TAccountMetas[16];
-    binArrayUpper: TAccountMetas[17];
-    eventAuthority: TAccountMetas[18];
-    dlmmProgram: TAccountMetas[19];
-    tokenXMint: TAccountMetas[20];
-    tokenYMint: TAccountMetas[21];
+    /** Address enforced by seeds; blocked flag checked in handler. */
+    poolStatus: TAccountMetas[3];
+    positionCounter: TAccountMetas[4];
+    ownerStats: TAccountMetas[5];
+    meteoraPosition: TAccountMetas[6];
+    binArrayBitmapExt: TAccountMetas[7];
+    reserveX: TAccountMetas[8];
+    reserveY: TAccountMetas[9];
+    position: TAccountMetas[10];
+    vault: TAccountMetas[11];
+    userTokenAccount: TAccountMetas[12];
+    vaultTokenX: TAccountMetas[13];
+    vaultTokenY: TAccountMetas[14];
+    tokenXProgram: TAccountMetas[15];
+    tokenYProgram: TAccountMetas[16];
+    systemProgram: TAccountMetas[17];
+    binArrayLower: TAccountMetas[18];
+    binArrayUpper: TAccountMetas[19];
+    eventAuthority: TAccountMetas[20];
+    dlmmProgram: TAccountMetas[21];
+    tokenXMint: TAccountMetas[22];
+    tokenYMint: TAccountMetas[23];
+    /**
+     * spans three bin arrays; PDA checked in handler, Meteora validates contents via CPI.
+     */
+    binArrayMiddle?: TAccountMetas[24] | undefined;
+    /** config.authority co-signing a fee_bps_override. Only required when one is set. */
+    feeAuthority?: TAccountMetas[25] | undefined;
   };
   data: OpenPositionV2InstructionData;
 };
@@ -763,7 +933,7 @@ export function parseOpenPositionV2Instruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedOpenPositionV2Instruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 22) {
+  if (instruction.accounts.length < 26) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -773,13 +943,21 @@ export function parseOpenPositionV2Instruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BIN_FARM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       user: getNextAccount(),
       config: getNextAccount(),
       lbPair: getNextAccount(),
+      poolStatus: getNextAccount(),
       positionCounter: getNextAccount(),
+      ownerStats: getNextAccount(),
       meteoraPosition: getNextAccount(),
       binArrayBitmapExt: getNextAccount(),
       reserveX: getNextAccount(),
@@ -798,6 +976,8 @@ export function parseOpenPositionV2Instruction<
       dlmmProgram: getNextAccount(),
       tokenXMint: getNextAccount(),
       tokenYMint: getNextAccount(),
+      binArrayMiddle: getNextOptionalAccount(),
+      feeAuthority: getNextOptionalAccount(),
     },
     data: getOpenPositionV2InstructionDataDecoder().decode(instruction.data),
   };
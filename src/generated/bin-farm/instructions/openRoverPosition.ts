@@ -13,6 +13,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI32Decoder,
+  getI32Encoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -42,6 +44,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getSideDecoder,
+  getSideEncoder,
+  type Side,
+  type SideArgs,
+} from '../types';
 
 export const OPEN_ROVER_POSITION_DISCRIMINATOR = new Uint8Array([
   242, 48, 23, 106, 252, 194, 30, 50,
@@ -59,6 +67,7 @@ export type OpenRoverPositionInstruction<
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountRoverAuthority extends string | AccountMeta<string> = string,
   TAccountLbPair extends string | AccountMeta<string> = string,
+  TAccountPoolStatus extends string | AccountMeta<string> = string,
   TAccountMeteoraPosition extends string | AccountMeta<string> = string,
   TAccountBinArrayBitmapExt extends string | AccountMeta<string> = string,
   TAccountReserveX extends string | AccountMeta<string> = string,
@@ -94,6 +103,9 @@ export type OpenRoverPositionInstruction<
       TAccountLbPair extends string
         ? WritableAccount<TAccountLbPair>
         : TAccountLbPair,
+      TAccountPoolStatus extends string
+        ? ReadonlyAccount<TAccountPoolStatus>
+        : TAccountPoolStatus,
       TAccountMeteoraPosition extends string
         ? WritableSignerAccount<TAccountMeteoraPosition> &
             AccountSignerMeta<TAccountMeteoraPosition>
@@ -151,11 +163,15 @@ export type OpenRoverPositionInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
   binStep: number;
+  side: Side;
+  maxActiveBinSlippage: number;
 };
 
 export type OpenRoverPositionInstructionDataArgs = {
   amount: number | bigint;
   binStep: number;
+  side: SideArgs;
+  maxActiveBinSlippage: number;
 };
 
 export function getOpenRoverPositionInstructionDataEncoder(): FixedSizeEncoder<OpenRoverPositionInstructionDataArgs> {
@@ -164,6 +180,8 @@ export function getOpenRoverPositionInstructionDataEncoder(): FixedSizeEncoder<O
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amount', getU64Encoder()],
       ['binStep', getU16Encoder()],
+      ['side', getSideEncoder()],
+      ['maxActiveBinSlippage', getI32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: OPEN_ROVER_POSITION_DISCRIMINATOR })
   );
@@ -174,6 +192,8 @@ export function getOpenRoverPositionInstructionDataDecoder(): FixedSizeDecoder<O
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amount', getU64Decoder()],
     ['binStep', getU16Decoder()],
+    ['side', getSideDecoder()],
+    ['maxActiveBinSlippage', getI32Decoder()],
   ]);
 }
 
@@ -192,6 +212,7 @@ export type OpenRoverPositionAsyncInput<
   TAccountConfig extends string = string,
   TAccountRoverAuthority extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolStatus extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountReserveX extends string = string,
@@ -214,6 +235,8 @@ export type OpenRoverPositionAsyncInput<
   config?: Address<TAccountConfig>;
   roverAuthority?: Address<TAccountRoverAuthority>;
   lbPair: Address<TAccountLbPair>;
+  /** Address enforced by seeds; blocked flag checked in handler. */
+  poolStatus?: Address<TAccountPoolStatus>;
   /** New position keypair (frontend generates) */
   meteoraPosition: TransactionSigner<TAccountMeteoraPosition>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
@@ -233,6 +256,8 @@ export type OpenRoverPositionAsyncInput<
   systemProgram?: Address<TAccountSystemProgram>;
   amount: OpenRoverPositionInstructionDataArgs['amount'];
   binStep: OpenRoverPositionInstructionDataArgs['binStep'];
+  side: OpenRoverPositionInstructionDataArgs['side'];
+  maxActiveBinSlippage: OpenRoverPositionInstructionDataArgs['maxActiveBinSlippage'];
 };
 
 export async function getOpenRoverPositionInstructionAsync<
@@ -240,6 +265,7 @@ export async function getOpenRoverPositionInstructionAsync<
   TAccountConfig extends string,
   TAccountRoverAuthority extends string,
   TAccountLbPair extends string,
+  TAccountPoolStatus extends string,
   TAccountMeteoraPosition extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountReserveX extends string,
@@ -263,6 +289,7 @@ export async function getOpenRoverPositionInstructionAsync<
     TAccountConfig,
     TAccountRoverAuthority,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -288,6 +315,7 @@ export async function getOpenRoverPositionInstructionAsync<
     TAccountConfig,
     TAccountRoverAuthority,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -315,6 +343,7 @@ export async function getOpenRoverPositionInstructionAsync<
     config: { value: input.config ?? null, isWritable: true },
     roverAuthority: { value: input.roverAuthority ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolStatus: { value: input.poolStatus ?? null, isWritable: false },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
@@ -368,6 +397,17 @@ export async function getOpenRoverPositionInstructionAsync<
       ],
     });
   }
+  if (!accounts.poolStatus.value) {
+    accounts.poolStatus.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([112, 111, 111, 108, 95, 115, 116, 97, 116, 117, 115])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.lbPair.value)),
+      ],
+    });
+  }
   if (!accounts.position.value) {
     accounts.position.value = await getProgramDerivedAddress({
       programAddress,
@@ -404,6 +444,7 @@ export async function getOpenRoverPositionInstructionAsync<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.roverAuthority),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolStatus),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.reserveX),
@@ -431,6 +472,7 @@ export async function getOpenRoverPositionInstructionAsync<
     TAccountConfig,
     TAccountRoverAuthority,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -455,6 +497,7 @@ export type OpenRoverPositionInput<
   TAccountConfig extends string = string,
   TAccountRoverAuthority extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolStatus extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountReserveX extends string = string,
@@ -477,6 +520,8 @@ export type OpenRoverPositionInput<
   config: Address<TAccountConfig>;
   roverAuthority: Address<TAccountRoverAuthority>;
   lbPair: Address<TAccountLbPair>;
+  /** Address enforced by seeds; blocked flag checked in handler. */
+  poolStatus: Address<TAccountPoolStatus>;
   /** New position keypair (frontend generates) */
   meteoraPosition: TransactionSigner<TAccountMeteoraPosition>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
@@ -496,6 +541,8 @@ export type OpenRoverPositionInput<
   systemProgram?: Address<TAccountSystemProgram>;
   amount: OpenRoverPositionInstructionDataArgs['amount'];
   binStep: OpenRoverPositionInstructionDataArgs['binStep'];
+  side: OpenRoverPositionInstructionDataArgs['side'];
+  maxActiveBinSlippage: OpenRoverPositionInstructionDataArgs['maxActiveBinSlippage'];
 };
 
 export function getOpenRoverPositionInstruction<
@@ -503,6 +550,7 @@ export function getOpenRoverPositionInstruction<
   TAccountConfig extends string,
   TAccountRoverAuthority extends string,
   TAccountLbPair extends string,
+  TAccountPoolStatus extends string,
   TAccountMeteoraPosition extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountReserveX extends string,
@@ -526,6 +574,7 @@ export function getOpenRoverPositionInstruction<
     TAccountConfig,
     TAccountRoverAuthority,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -550,6 +599,7 @@ export function getOpenRoverPositionInstruction<
   TAccountConfig,
   TAccountRoverAuthority,
   TAccountLbPair,
+  TAccountPoolStatus,
   TAccountMeteoraPosition,
   TAccountBinArrayBitmapExt,
   TAccountReserveX,
@@ -576,6 +626,7 @@ export function getOpenRoverPositionInstruction<
     config: { value: input.config ?? null, isWritable: true },
     roverAuthority: { value: input.roverAuthority ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolStatus: { value: input.poolStatus ?? null, isWritable: false },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
@@ -620,6 +671,7 @@ export function getOpenRoverPositionInstruction<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.roverAuthority),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolStatus),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.reserveX),
@@ -647,6 +699,7 @@ export function getOpenRoverPositionInstruction<
     TAccountConfig,
     TAccountRoverAuthority,
     TAccountLbPair,
+    TAccountPoolStatus,
     TAccountMeteoraPosition,
     TAccountBinArrayBitmapExt,
     TAccountReserveX,
@@ -677,23 +730,25 @@ export type ParsedOpenRoverPositionInstruction<
     config: TAccountMetas[1];
     roverAuthority: TAccountMetas[2];
     lbPair: TAccountMetas[3];
+    /** Address enforced by seeds; blocked flag checked in handler. */
+    poolStatus: TAccountMetas[4];
     /** New position keypair (frontend generates) */
-    meteoraPosition: TAccountMetas[4];
-    binArrayBitmapExt: TAccountMetas[5];
-    reserveX: TAccountMetas[6];
-    reserveY: TAccountMetas[7];
-    binArrayLower: TAccountMetas[8];
-    binArrayUpper: TAccountMetas[9];
-    position: TAccountMetas[10];
-    vault: TAccountMetas[11];
-    depositorTokenAccount: TAccountMetas[12];
-    vaultTokenX: TAccountMetas[13];
-    vaultTokenY: TAccountMetas[14];
-    tokenXMint: TAccountMetas[15];
-    tokenYMint: TAccountMetas[16];
-    tokenXProgram: TAccountMetas[17];
-    tokenYProgram: TAccountMetas[18];
-    systemProgram: TAccountMetas[19];
+    meteoraPosition: TAccountMetas[5];
+    binArrayBitmapExt: TAccountMetas[6];
+    reserveX: TAccountMetas[7];
+    reserveY: TAccountMetas[8];
+    binArrayLower: TAccountMetas[9];
+    binArrayUpper: TAccountMetas[10];
+    position: TAccountMetas[11];
+    vault: TAccountMetas[12];
+    depositorTokenAccount: TAccountMetas[13];
+    vaultTokenX: TAccountMetas[14];
+    vaultTokenY: TAccountMetas[15];
+    tokenXMint: TAccountMetas[16];
+    tokenYMint: TAccountMetas[17];
+    tokenXProgram: TAccountMetas[18];
+    tokenYProgram: TAccountMetas[19];
+    systemProgram: TAccountMetas[20];
   };
   data: OpenRoverPositionInstructionData;
 };
@@ -706,7 +761,7 @@ export function parseOpenRoverPositionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedOpenRoverPositionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 20) {
+  if (instruction.accounts.length < 21) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -723,6 +778,7 @@ export function parseOpenRoverPositionInstruction<
       config: getNextAccount(),
       roverAuthority: getNextAccount(),
       lbPair: getNextAccount(),
+      poolStatus: getNextAccount(),
       meteoraPosition: getNextAccount(),
       binArrayBitmapExt: getNextAccount(),
       reserveX: getNextAccount(),
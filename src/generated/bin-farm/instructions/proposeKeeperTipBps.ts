@@ -35,17 +35,17 @@ import {
 import { BIN_FARM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
-export const UPDATE_KEEPER_TIP_BPS_DISCRIMINATOR = new Uint8Array([
-  152, 249, 255, 70, 156, 110, 103, 145,
+export const PROPOSE_KEEPER_TIP_BPS_DISCRIMINATOR = new Uint8Array([
+  107, 58, 89, 249, 71, 75, 64, 96,
 ]);
 
-export function getUpdateKeeperTipBpsDiscriminatorBytes() {
+export function getProposeKeeperTipBpsDiscriminatorBytes() {
   return fixEncoderSize(getBytesEncoder(), 8).encode(
-    UPDATE_KEEPER_TIP_BPS_DISCRIMINATOR
+    PROPOSE_KEEPER_TIP_BPS_DISCRIMINATOR
   );
 }
 
-export type UpdateKeeperTipBpsInstruction<
+export type ProposeKeeperTipBpsInstruction<
   TProgram extends string = typeof BIN_FARM_PROGRAM_ADDRESS,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
@@ -65,14 +65,14 @@ export type UpdateKeeperTipBpsInstruction<
     ]
   >;
 
-export type UpdateKeeperTipBpsInstructionData = {
+export type ProposeKeeperTipBpsInstructionData = {
   discriminator: ReadonlyUint8Array;
   newBps: number;
 };
 
-export type UpdateKeeperTipBpsInstructionDataArgs = { newBps: number };
+export type ProposeKeeperTipBpsInstructionDataArgs = { newBps: number };
 
-export function getUpdateKeeperTipBpsInstructionDataEncoder(): FixedSizeEncoder<UpdateKeeperTipBpsInstructionDataArgs> {
+export function getProposeKeeperTipBpsInstructionDataEncoder(): FixedSizeEncoder<ProposeKeeperTipBpsInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -80,46 +80,46 @@ export function getUpdateKeeperTipBpsInstructionDataEncoder(): FixedSizeEncoder<
     ]),
     (value) => ({
       ...value,
-      discriminator: UPDATE_KEEPER_TIP_BPS_DISCRIMINATOR,
+      discriminator: PROPOSE_KEEPER_TIP_BPS_DISCRIMINATOR,
     })
   );
 }
 
-export function getUpdateKeeperTipBpsInstructionDataDecoder(): FixedSizeDecoder<UpdateKeeperTipBpsInstructionData> {
+export function getProposeKeeperTipBpsInstructionDataDecoder(): FixedSizeDecoder<ProposeKeeperTipBpsInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['newBps', getU16Decoder()],
   ]);
 }
 
-export function getUpdateKeeperTipBpsInstructionDataCodec(): FixedSizeCodec<
-  UpdateKeeperTipBpsInstructionDataArgs,
-  UpdateKeeperTipBpsInstructionData
+export function getProposeKeeperTipBpsInstructionDataCodec(): FixedSizeCodec<
+  ProposeKeeperTipBpsInstructionDataArgs,
+  ProposeKeeperTipBpsInstructionData
 > {
   return combineCodec(
-    getUpdateKeeperTipBpsInstructionDataEncoder(),
-    getUpdateKeeperTipBpsInstructionDataDecoder()
+    getProposeKeeperTipBpsInstructionDataEncoder(),
+    getProposeKeeperTipBpsInstructionDataDecoder()
   );
 }
 
-export type UpdateKeeperTipBpsAsyncInput<
+export type ProposeKeeperTipBpsAsyncInput<
   TAccountAuthority extends string = string,
   TAccountConfig extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   config?: Address<TAccountConfig>;
-  newBps: UpdateKeeperTipBpsInstructionDataArgs['newBps'];
+  newBps: ProposeKeeperTipBpsInstructionDataArgs['newBps'];
 };
 
-export async function getUpdateKeeperTipBpsInstructionAsync<
+export async function getProposeKeeperTipBpsInstructionAsync<
   TAccountAuthority extends string,
   TAccountConfig extends string,
   TProgramAddress extends Address = typeof BIN_FARM_PROGRAM_ADDRESS,
 >(
-  input: UpdateKeeperTipBpsAsyncInput<TAccountAuthority, TAccountConfig>,
+  input: ProposeKeeperTipBpsAsyncInput<TAccountAuthority, TAccountConfig>,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  UpdateKeeperTipBpsInstruction<
+  ProposeKeeperTipBpsInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountConfig
@@ -157,34 +157,34 @@ export async function getUpdateKeeperTipBpsInstructionAsync<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.config),
     ],
-    data: getUpdateKeeperTipBpsInstructionDataEncoder().encode(
-      args as UpdateKeeperTipBpsInstructionDataArgs
+    data: getProposeKeeperTipBpsInstructionDataEncoder().encode(
+      args as ProposeKeeperTipBpsInstructionDataArgs
     ),
     programAddress,
-  } as UpdateKeeperTipBpsInstruction<
+  } as ProposeKeeperTipBpsInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountConfig
   >);
 }
 
-export type UpdateKeeperTipBpsInput<
+export type ProposeKeeperTipBpsInput<
   TAccountAuthority extends string = string,
   TAccountConfig extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   config: Address<TAccountConfig>;
-  newBps: UpdateKeeperTipBpsInstructionDataArgs['newBps'];
+  newBps: ProposeKeeperTipBpsInstructionDataArgs['newBps'];
 };
 
-export function getUpdateKeeperTipBpsInstruction<
+export function getProposeKeeperTipBpsInstruction<
   TAccountAuthority extends string,
   TAccountConfig extends string,
   TProgramAddress extends Address = typeof BIN_FARM_PROGRAM_ADDRESS,
 >(
-  input: UpdateKeeperTipBpsInput<TAccountAuthority, TAccountConfig>,
+  input: ProposeKeeperTipBpsInput<TAccountAuthority, TAccountConfig>,
   config?: { programAddress?: TProgramAddress }
-): UpdateKeeperTipBpsInstruction<
+): ProposeKeeperTipBpsInstruction<
   TProgramAddress,
   TAccountAuthority,
   TAccountConfig
@@ -211,18 +211,18 @@ export function getUpdateKeeperTipBpsInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.config),
     ],
-    data: getUpdateKeeperTipBpsInstructionDataEncoder().encode(
-      args as UpdateKeeperTipBpsInstructionDataArgs
+    data: getProposeKeeperTipBpsInstructionDataEncoder().encode(
+      args as ProposeKeeperTipBpsInstructionDataArgs
     ),
     programAddress,
-  } as UpdateKeeperTipBpsInstruction<
+  } as ProposeKeeperTipBpsInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountConfig
   >);
 }
 
-export type ParsedUpdateKeeperTipBpsInstruction<
+export type ParsedProposeKeeperTipBpsInstruction<
   TProgram extends string = typeof BIN_FARM_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
@@ -231,17 +231,17 @@ export type ParsedUpdateKeeperTipBpsInstruction<
     authority: TAccountMetas[0];
     config: TAccountMetas[1];
   };
-  data: UpdateKeeperTipBpsInstructionData;
+  data: ProposeKeeperTipBpsInstructionData;
 };
 
-export function parseUpdateKeeperTipBpsInstruction<
+export function parseProposeKeeperTipBpsInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedUpdateKeeperTipBpsInstruction<TProgram, TAccountMetas> {
+): ParsedProposeKeeperTipBpsInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
@@ -255,7 +255,7 @@ export function parseUpdateKeeperTipBpsInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: { authority: getNextAccount(), config: getNextAccount() },
-    data: getUpdateKeeperTipBpsInstructionDataDecoder().decode(
+    data: getProposeKeeperTipBpsInstructionDataDecoder().decode(
       instruction.data
     ),
   };
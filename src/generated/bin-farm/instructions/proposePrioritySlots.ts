@@ -35,17 +35,17 @@ import {
 import { BIN_FARM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
-export const UPDATE_PRIORITY_SLOTS_DISCRIMINATOR = new Uint8Array([
-  50, 112, 88, 95, 51, 65, 14, 69,
+export const PROPOSE_PRIORITY_SLOTS_DISCRIMINATOR = new Uint8Array([
+  214, 155, 107, 107, 35, 134, 118, 209,
 ]);
 
-export function getUpdatePrioritySlotsDiscriminatorBytes() {
+export function getProposePrioritySlotsDiscriminatorBytes() {
   return fixEncoderSize(getBytesEncoder(), 8).encode(
-    UPDATE_PRIORITY_SLOTS_DISCRIMINATOR
+    PROPOSE_PRIORITY_SLOTS_DISCRIMINATOR
   );
 }
 
-export type UpdatePrioritySlotsInstruction<
+export type ProposePrioritySlotsInstruction<
   TProgram extends string = typeof BIN_FARM_PROGRAM_ADDRESS,
   TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
@@ -65,16 +65,16 @@ export type UpdatePrioritySlotsInstruction<
     ]
   >;
 
-export type UpdatePrioritySlotsInstructionData = {
+export type ProposePrioritySlotsInstructionData = {
   discriminator: ReadonlyUint8Array;
   newSlots: bigint;
 };
 
-export type UpdatePrioritySlotsInstructionDataArgs = {
+export type ProposePrioritySlotsInstructionDataArgs = {
   newSlots: number | bigint;
 };
 
-export function getUpdatePrioritySlotsInstructionDataEncoder(): FixedSizeEncoder<UpdatePrioritySlotsInstructionDataArgs> {
+export function getProposePrioritySlotsInstructionDataEncoder(): FixedSizeEncoder<ProposePrioritySlotsInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -82,46 +82,46 @@ export function getUpdatePrioritySlotsInstructionDataEncoder(): FixedSizeEncoder
     ]),
     (value) => ({
       ...value,
-      discriminator: UPDATE_PRIORITY_SLOTS_DISCRIMINATOR,
+      discriminator: PROPOSE_PRIORITY_SLOTS_DISCRIMINATOR,
     })
   );
 }
 
-export function getUpdatePrioritySlotsInstructionDataDecoder(): FixedSizeDecoder<UpdatePrioritySlotsInstructionData> {
+export function getProposePrioritySlotsInstructionDataDecoder(): FixedSizeDecoder<ProposePrioritySlotsInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['newSlots', getU64Decoder()],
   ]);
 }
 
-export function getUpdatePrioritySlotsInstructionDataCodec(): FixedSizeCodec<
-  UpdatePrioritySlotsInstructionDataArgs,
-  UpdatePrioritySlotsInstructionData
+export function getProposePrioritySlotsInstructionDataCodec(): FixedSizeCodec<
+  ProposePrioritySlotsInstructionDataArgs,
+  ProposePrioritySlotsInstructionData
 > {
   return combineCodec(
-    getUpdatePrioritySlotsInstructionDataEncoder(),
-    getUpdatePrioritySlotsInstructionDataDecoder()
+    getProposePrioritySlotsInstructionDataEncoder(),
+    getProposePrioritySlotsInstructionDataDecoder()
   );
 }
 
-export type UpdatePrioritySlotsAsyncInput<
+export type ProposePrioritySlotsAsyncInput<
   TAccountAuthority extends string = string,
   TAccountConfig extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   config?: Address<TAccountConfig>;
-  newSlots: UpdatePrioritySlotsInstructionDataArgs['newSlots'];
+  newSlots: ProposePrioritySlotsInstructionDataArgs['newSlots'];
 };
 
-export async function getUpdatePrioritySlotsInstructionAsync<
+export async function getProposePrioritySlotsInstructionAsync<
   TAccountAuthority extends string,
   TAccountConfig extends string,
   TProgramAddress extends Address = typeof BIN_FARM_PROGRAM_ADDRESS,
 >(
-  input: UpdatePrioritySlotsAsyncInput<TAccountAuthority, TAccountConfig>,
+  input: ProposePrioritySlotsAsyncInput<TAccountAuthority, TAccountConfig>,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  UpdatePrioritySlotsInstruction<
+  ProposePrioritySlotsInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountConfig
@@ -159,34 +159,34 @@ export async function getUpdatePrioritySlotsInstructionAsync<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.config),
     ],
-    data: getUpdatePrioritySlotsInstructionDataEncoder().encode(
-      args as UpdatePrioritySlotsInstructionDataArgs
+    data: getProposePrioritySlotsInstructionDataEncoder().encode(
+      args as ProposePrioritySlotsInstructionDataArgs
     ),
     programAddress,
-  } as UpdatePrioritySlotsInstruction<
+  } as ProposePrioritySlotsInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountConfig
   >);
 }
 
-export type UpdatePrioritySlotsInput<
+export type ProposePrioritySlotsInput<
   TAccountAuthority extends string = string,
   TAccountConfig extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   config: Address<TAccountConfig>;
-  newSlots: UpdatePrioritySlotsInstructionDataArgs['newSlots'];
+  newSlots: ProposePrioritySlotsInstructionDataArgs['newSlots'];
 };
 
-export function getUpdatePrioritySlotsInstruction<
+export function getProposePrioritySlotsInstruction<
   TAccountAuthority extends string,
   TAccountConfig extends string,
   TProgramAddress extends Address = typeof BIN_FARM_PROGRAM_ADDRESS,
 >(
-  input: UpdatePrioritySlotsInput<TAccountAuthority, TAccountConfig>,
+  input: ProposePrioritySlotsInput<TAccountAuthority, TAccountConfig>,
   config?: { programAddress?: TProgramAddress }
-): UpdatePrioritySlotsInstruction<
+): ProposePrioritySlotsInstruction<
   TProgramAddress,
   TAccountAuthority,
   TAccountConfig
@@ -213,18 +213,18 @@ export function getUpdatePrioritySlotsInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.config),
     ],
-    data: getUpdatePrioritySlotsInstructionDataEncoder().encode(
-      args as UpdatePrioritySlotsInstructionDataArgs
+    data: getProposePrioritySlotsInstructionDataEncoder().encode(
+      args as ProposePrioritySlotsInstructionDataArgs
     ),
     programAddress,
-  } as UpdatePrioritySlotsInstruction<
+  } as ProposePrioritySlotsInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountConfig
   >);
 }
 
-export type ParsedUpdatePrioritySlotsInstruction<
+export type ParsedProposePrioritySlotsInstruction<
   TProgram extends string = typeof BIN_FARM_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
@@ -233,17 +233,17 @@ export type ParsedUpdatePrioritySlotsInstruction<
     authority: TAccountMetas[0];
     config: TAccountMetas[1];
   };
-  data: UpdatePrioritySlotsInstructionData;
+  data: ProposePrioritySlotsInstructionData;
 };
 
-export function parseUpdatePrioritySlotsInstruction<
+export function parseProposePrioritySlotsInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedUpdatePrioritySlotsInstruction<TProgram, TAccountMetas> {
+): ParsedProposePrioritySlotsInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
@@ -257,7 +257,7 @@ export function parseUpdatePrioritySlotsInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: { authority: getNextAccount(), config: getNextAccount() },
-    data: getUpdatePrioritySlotsInstructionDataDecoder().decode(
+    data: getProposePrioritySlotsInstructionDataDecoder().decode(
       instruction.data
     ),
   };
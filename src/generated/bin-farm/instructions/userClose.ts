@@ -10,11 +10,14 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -32,7 +35,11 @@ import {
   type WritableSignerAccount,
 } from '@solana/kit';
 import { BIN_FARM_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const USER_CLOSE_DISCRIMINATOR = new Uint8Array([
   126, 78, 180, 205, 96, 242, 20, 2,
@@ -48,8 +55,10 @@ export type UserCloseInstruction<
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountPosition extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountOwnerStats extends string | AccountMeta<string> = string,
   TAccountMeteoraPosition extends string | AccountMeta<string> = string,
   TAccountLbPair extends string | AccountMeta<string> = string,
+  TAccountPoolMetrics extends string | AccountMeta<string> = string,
   TAccountBinArrayBitmapExt extends string | AccountMeta<string> = string,
   TAccountBinArrayLower extends string | AccountMeta<string> = string,
   TAccountBinArrayUpper extends string | AccountMeta<string> = string,
@@ -88,12 +97,18 @@ export type UserCloseInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountOwnerStats extends string
+        ? WritableAccount<TAccountOwnerStats>
+        : TAccountOwnerStats,
       TAccountMeteoraPosition extends string
         ? WritableAccount<TAccountMeteoraPosition>
         : TAccountMeteoraPosition,
       TAccountLbPair extends string
         ? WritableAccount<TAccountLbPair>
         : TAccountLbPair,
+      TAccountPoolMetrics extends string
+        ? WritableAccount<TAccountPoolMetrics>
+        : TAccountPoolMetrics,
       TAccountBinArrayBitmapExt extends string
         ? ReadonlyAccount<TAccountBinArrayBitmapExt>
         : TAccountBinArrayBitmapExt,
@@ -158,13 +173,24 @@ export type UserCloseInstruction<
     ]
   >;
 
-export type UserCloseInstructionData = { discriminator: ReadonlyUint8Array };
+export type UserCloseInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  minXOut: bigint;
+  minYOut: bigint;
+};
 
-export type UserCloseInstructionDataArgs = {};
+export type UserCloseInstructionDataArgs = {
+  minXOut: number | bigint;
+  minYOut: number | bigint;
+};
 
 export function getUserCloseInstructionDataEncoder(): FixedSizeEncoder<UserCloseInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['minXOut', getU64Encoder()],
+      ['minYOut', getU64Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: USER_CLOSE_DISCRIMINATOR })
   );
 }
@@ -172,6 +198,8 @@ export function getUserCloseInstructionDataEncoder(): FixedSizeEncoder<UserClose
 export function getUserCloseInstructionDataDecoder(): FixedSizeDecoder<UserCloseInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['minXOut', getU64Decoder()],
+    ['minYOut', getU64Decoder()],
   ]);
 }
 
@@ -190,8 +218,10 @@ export type UserCloseAsyncInput<
   TAccountConfig extends string = string,
   TAccountPosition extends string = string,
   TAccountVault extends string = string,
+  TAccountOwnerStats extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolMetrics extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountBinArrayLower extends string = string,
   TAccountBinArrayUpper extends string = string,
@@ -217,8 +247,10 @@ export type UserCloseAsyncInput<
   config?: Address<TAccountConfig>;
   position: Address<TAccountPosition>;
   vault: Address<TAccountVault>;
+  ownerStats: Address<TAccountOwnerStats>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   lbPair: Address<TAccountLbPair>;
+  poolMetrics?: Address<TAccountPoolMetrics>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   binArrayLower: Address<TAccountBinArrayLower>;
   binArrayUpper: Address<TAccountBinArrayUpper>;
@@ -239,6 +271,8 @@ export type UserCloseAsyncInput<
   tokenYProgram: Address<TAccountTokenYProgram>;
   memoProgram: Address<TAccountMemoProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  minXOut: UserCloseInstructionDataArgs['minXOut'];
+  minYOut: UserCloseInstructionDataArgs['minYOut'];
 };
 
 export async function getUserCloseInstructionAsync<
@@ -246,8 +280,10 @@ export async function getUserCloseInstructionAsync<
   TAccountConfig extends string,
   TAccountPosition extends string,
   TAccountVault extends string,
+  TAccountOwnerStats extends string,
   TAccountMeteoraPosition extends string,
   TAccountLbPair extends string,
+  TAccountPoolMetrics extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountBinArrayLower extends string,
   TAccountBinArrayUpper extends string,
@@ -275,8 +311,10 @@ export async function getUserCloseInstructionAsync<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -306,8 +344,10 @@ export async function getUserCloseInstructionAsync<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -339,8 +379,10 @@ export async function getUserCloseInstructionAsync<
     config: { value: input.config ?? null, isWritable: true },
     position: { value: input.position ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    ownerStats: { value: input.ownerStats ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolMetrics: { value: input.poolMetrics ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
       isWritable: false,
@@ -370,6 +412,9 @@ export async function getUserCloseInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.config.value) {
     accounts.config.value = await getProgramDerivedAddress({
@@ -379,6 +424,19 @@ export async function getUserCloseInstructionAsync<
       ],
     });
   }
+  if (!accounts.poolMetrics.value) {
+    accounts.poolMetrics.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 111, 111, 108, 95, 109, 101, 116, 114, 105, 99, 115,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.lbPair.value)),
+      ],
+    });
+  }
   if (!accounts.roverAuthority.value) {
     accounts.roverAuthority.value = await getProgramDerivedAddress({
       programAddress,
@@ -404,8 +462,10 @@ export async function getUserCloseInstructionAsync<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.position),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ownerStats),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolMetrics),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.binArrayLower),
       getAccountMeta(accounts.binArrayUpper),
@@ -427,7 +487,9 @@ export async function getUserCloseInstructionAsync<
       getAccountMeta(accounts.memoProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getUserCloseInstructionDataEncoder().encode({}),
+    data: getUserCloseInstructionDataEncoder().encode(
+      args as UserCloseInstructionDataArgs
+    ),
     programAddress,
   } as UserCloseInstruction<
     TProgramAddress,
@@ -435,8 +497,10 @@ export async function getUserCloseInstructionAsync<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -465,8 +529,10 @@ export type UserCloseInput<
   TAccountConfig extends string = string,
   TAccountPosition extends string = string,
   TAccountVault extends string = string,
+  TAccountOwnerStats extends string = string,
   TAccountMeteoraPosition extends string = string,
   TAccountLbPair extends string = string,
+  TAccountPoolMetrics extends string = string,
   TAccountBinArrayBitmapExt extends string = string,
   TAccountBinArrayLower extends string = string,
   TAccountBinArrayUpper extends string = string,
@@ -492,8 +558,10 @@ export type UserCloseInput<
   config: Address<TAccountConfig>;
   position: Address<TAccountPosition>;
   vault: Address<TAccountVault>;
+  ownerStats: Address<TAccountOwnerStats>;
   meteoraPosition: Address<TAccountMeteoraPosition>;
   lbPair: Address<TAccountLbPair>;
+  poolMetrics: Address<TAccountPoolMetrics>;
   binArrayBitmapExt: Address<TAccountBinArrayBitmapExt>;
   binArrayLower: Address<TAccountBinArrayLower>;
   binArrayUpper: Address<TAccountBinArrayUpper>;
@@ -514,6 +582,8 @@ export type UserCloseInput<
   tokenYProgram: Address<TAccountTokenYProgram>;
   memoProgram: Address<TAccountMemoProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  minXOut: UserCloseInstructionDataArgs['minXOut'];
+  minYOut: UserCloseInstructionDataArgs['minYOut'];
 };
 
 export function getUserCloseInstruction<
@@ -521,8 +591,10 @@ export function getUserCloseInstruction<
   TAccountConfig extends string,
   TAccountPosition extends string,
   TAccountVault extends string,
+  TAccountOwnerStats extends string,
   TAccountMeteoraPosition extends string,
   TAccountLbPair extends string,
+  TAccountPoolMetrics extends string,
   TAccountBinArrayBitmapExt extends string,
   TAccountBinArrayLower extends string,
   TAccountBinArrayUpper extends string,
@@ -550,8 +622,10 @@ export function getUserCloseInstruction<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -580,8 +654,10 @@ export function getUserCloseInstruction<
   TAccountConfig,
   TAccountPosition,
   TAccountVault,
+  TAccountOwnerStats,
   TAccountMeteoraPosition,
   TAccountLbPair,
+  TAccountPoolMetrics,
   TAccountBinArrayBitmapExt,
   TAccountBinArrayLower,
   TAccountBinArrayUpper,
@@ -612,8 +688,10 @@ export function getUserCloseInstruction<
     config: { value: input.config ?? null, isWritable: true },
     position: { value: input.position ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    ownerStats: { value: input.ownerStats ?? null, isWritable: true },
     meteoraPosition: { value: input.meteoraPosition ?? null, isWritable: true },
     lbPair: { value: input.lbPair ?? null, isWritable: true },
+    poolMetrics: { value: input.poolMetrics ?? null, isWritable: true },
     binArrayBitmapExt: {
       value: input.binArrayBitmapExt ?? null,
       isWritable: false,
@@ -643,6 +721,9 @@ export function getUserCloseInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -656,8 +737,10 @@ export function getUserCloseInstruction<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.position),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ownerStats),
       getAccountMeta(accounts.meteoraPosition),
       getAccountMeta(accounts.lbPair),
+      getAccountMeta(accounts.poolMetrics),
       getAccountMeta(accounts.binArrayBitmapExt),
       getAccountMeta(accounts.binArrayLower),
       getAccountMeta(accounts.binArrayUpper),
@@ -679,7 +762,9 @@ export function getUserCloseInstruction<
       getAccountMeta(accounts.memoProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getUserCloseInstructionDataEncoder().encode({}),
+    data: getUserCloseInstructionDataEncoder().encode(
+      args as UserCloseInstructionDataArgs
+    ),
     programAddress,
   } as UserCloseInstruction<
     TProgramAddress,
@@ -687,8 +772,10 @@ export function getUserCloseInstruction<
     TAccountConfig,
     TAccountPosition,
     TAccountVault,
+    TAccountOwnerStats,
     TAccountMeteoraPosition,
     TAccountLbPair,
+    TAccountPoolMetrics,
     TAccountBinArrayBitmapExt,
     TAccountBinArrayLower,
     TAccountBinArrayUpper,
@@ -722,28 +809,30 @@ export type ParsedUserCloseInstruction<
     config: TAccountMetas[1];
     position: TAccountMetas[2];
     vault: TAccountMetas[3];
-    meteoraPosition: TAccountMetas[4];
-    lbPair: TAccountMetas[5];
-    binArrayBitmapExt: TAccountMetas[6];
-    binArrayLower: TAccountMetas[7];
-    binArrayUpper: TAccountMetas[8];
-    reserveX: TAccountMetas[9];
-    reserveY: TAccountMetas[10];
-    tokenXMint: TAccountMetas[11];
-    tokenYMint: TAccountMetas[12];
-    eventAuthority: TAccountMetas[13];
-    dlmmProgram: TAccountMetas[14];
-    vaultTokenX: TAccountMetas[15];
-    vaultTokenY: TAccountMetas[16];
-    userTokenX: TAccountMetas[17];
-    userTokenY: TAccountMetas[18];
-    roverAuthority: TAccountMetas[19];
-    roverFeeTokenX: TAccountMetas[20];
-    roverFeeTokenY: TAccountMetas[21];
-    tokenXProgram: TAccountMetas[22];
-    tokenYProgram: TAccountMetas[23];
-    memoProgram: TAccountMetas[24];
-    systemProgram: TAccountMetas[25];
+    ownerStats: TAccountMetas[4];
+    meteoraPosition: TAccountMetas[5];
+    lbPair: TAccountMetas[6];
+    poolMetrics: TAccountMetas[7];
+    binArrayBitmapExt: TAccountMetas[8];
+    binArrayLower: TAccountMetas[9];
+    binArrayUpper: TAccountMetas[10];
+    reserveX: TAccountMetas[11];
+    reserveY: TAccountMetas[12];
+    tokenXMint: TAccountMetas[13];
+    tokenYMint: TAccountMetas[14];
+    eventAuthority: TAccountMetas[15];
+    dlmmProgram: TAccountMetas[16];
+    vaultTokenX: TAccountMetas[17];
+    vaultTokenY: TAccountMetas[18];
+    userTokenX: TAccountMetas[19];
+    userTokenY: TAccountMetas[20];
+    roverAuthority: TAccountMetas[21];
+    roverFeeTokenX: TAccountMetas[22];
+    roverFeeTokenY: TAccountMetas[23];
+    tokenXProgram: TAccountMetas[24];
+    tokenYProgram: TAccountMetas[25];
+    memoProgram: TAccountMetas[26];
+    systemProgram: TAccountMetas[27];
   };
   data: UserCloseInstructionData;
 };
@@ -756,7 +845,7 @@ export function parseUserCloseInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserCloseInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 26) {
+  if (instruction.accounts.length < 28) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -773,8 +862,10 @@ export function parseUserCloseInstruction<
       config: getNextAccount(),
       position: getNextAccount(),
       vault: getNextAccount(),
+      ownerStats: getNextAccount(),
       meteoraPosition: getNextAccount(),
       lbPair: getNextAccount(),
+      poolMetrics: getNextAccount(),
       binArrayBitmapExt: getNextAccount(),
       binArrayLower: getNextAccount(),
       binArrayUpper: getNextAccount(),
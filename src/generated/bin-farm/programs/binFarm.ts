@@ -17,10 +17,14 @@ import {
   type ParsedAcceptAuthorityInstruction,
   type ParsedApplyEmergencyCloseInstruction,
   type ParsedApplyFeeInstruction,
+  type ParsedApplyKeeperTipBpsInstruction,
+  type ParsedApplyPrioritySlotsInstruction,
   type ParsedApplyRevenueDestInstruction,
   type ParsedBotPauseInstruction,
   type ParsedBotUnpauseInstruction,
   type ParsedCancelPendingFeeInstruction,
+  type ParsedCancelPendingKeeperTipBpsInstruction,
+  type ParsedCancelPendingPrioritySlotsInstruction,
   type ParsedCancelPendingRevenueDestInstruction,
   type ParsedClaimFeesInstruction,
   type ParsedClosePositionInstruction,
@@ -34,13 +38,13 @@ import {
   type ParsedPauseInstruction,
   type ParsedProposeEmergencyCloseInstruction,
   type ParsedProposeFeeInstruction,
+  type ParsedProposeKeeperTipBpsInstruction,
+  type ParsedProposePrioritySlotsInstruction,
   type ParsedProposeRevenueDestInstruction,
   type ParsedSweepRoverInstruction,
   type ParsedTransferAuthorityInstruction,
   type ParsedUnpauseInstruction,
   type ParsedUpdateBotInstruction,
-  type ParsedUpdateKeeperTipBpsInstruction,
-  type ParsedUpdatePrioritySlotsInstruction,
   type ParsedUserCloseInstruction,
 } from '../instructions';
 
@@ -123,10 +127,14 @@ export enum BinFarmInstruction {
   AcceptAuthority,
   ApplyEmergencyClose,
   ApplyFee,
+  ApplyKeeperTipBps,
+  ApplyPrioritySlots,
   ApplyRevenueDest,
   BotPause,
   BotUnpause,
   CancelPendingFee,
+  CancelPendingKeeperTipBps,
+  CancelPendingPrioritySlots,
   CancelPendingRevenueDest,
   ClaimFees,
   ClosePosition,
@@ -140,13 +148,13 @@ export enum BinFarmInstruction {
   Pause,
   ProposeEmergencyClose,
   ProposeFee,
+  ProposeKeeperTipBps,
+  ProposePrioritySlots,
   ProposeRevenueDest,
   SweepRover,
   TransferAuthority,
   Unpause,
   UpdateBot,
-  UpdateKeeperTipBps,
-  UpdatePrioritySlots,
   UserClose,
 }
 
@@ -187,6 +195,28 @@ export function identifyBinFarmInstruction(
   ) {
     return BinFarmInstruction.ApplyFee;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([13, 134, 69, 171, 28, 8, 66, 189])
+      ),
+      0
+    )
+  ) {
+    return BinFarmInstruction.ApplyKeeperTipBps;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([135, 203, 29, 34, 5, 216, 35, 238])
+      ),
+      0
+    )
+  ) {
+    return BinFarmInstruction.ApplyPrioritySlots;
+  }
   if (
     containsBytes(
       data,
@@ -231,6 +261,28 @@ export function identifyBinFarmInstruction(
   ) {
     return BinFarmInstruction.CancelPendingFee;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 93, 152, 177, 214, 181, 239, 182])
+      ),
+      0
+    )
+  ) {
+    return BinFarmInstruction.CancelPendingKeeperTipBps;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([43, 189, 83, 33, 176, 136, 192, 17])
+      ),
+      0
+    )
+  ) {
+    return BinFarmInstruction.CancelPendingPrioritySlots;
+  }
   if (
     containsBytes(
       data,
@@ -378,78 +430,78 @@ export function identifyBinFarmInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([66, 201, 224, 148, 215, 200, 177, 47])
+        new Uint8Array([107, 58, 89, 249, 71, 75, 64, 96])
       ),
       0
     )
   ) {
-    return BinFarmInstruction.ProposeRevenueDest;
+    return BinFarmInstruction.ProposeKeeperTipBps;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([199, 22, 58, 214, 89, 187, 187, 249])
+        new Uint8Array([214, 155, 107, 107, 35, 134, 118, 209])
       ),
       0
     )
   ) {
-    return BinFarmInstruction.SweepRover;
+    return BinFarmInstruction.ProposePrioritySlots;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([48, 169, 76, 72, 229, 180, 55, 161])
+        new Uint8Array([66, 201, 224, 148, 215, 200, 177, 47])
       ),
       0
     )
   ) {
-    return BinFarmInstruction.TransferAuthority;
+    return BinFarmInstruction.ProposeRevenueDest;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([169, 144, 4, 38, 10, 141, 188, 255])
+        new Uint8Array([199, 22, 58, 214, 89, 187, 187, 249])
       ),
       0
     )
   ) {
-    return BinFarmInstruction.Unpause;
+    return BinFarmInstruction.SweepRover;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([72, 160, 124, 173, 234, 179, 108, 203])
+        new Uint8Array([48, 169, 76, 72, 229, 180, 55, 161])
       ),
       0
     )
   ) {
-    return BinFarmInstruction.UpdateBot;
+    return BinFarmInstruction.TransferAuthority;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([152, 249, 255, 70, 156, 110, 103, 145])
+        new Uint8Array([169, 144, 4, 38, 10, 141, 188, 255])
       ),
       0
     )
   ) {
-    return BinFarmInstruction.UpdateKeeperTipBps;
+    return BinFarmInstruction.Unpause;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([50, 112, 88, 95, 51, 65, 14, 69])
+        new Uint8Array([72, 160, 124, 173, 234, 179, 108, 203])
       ),
       0
     )
   ) {
-    return BinFarmInstruction.UpdatePrioritySlots;
+    return BinFarmInstruction.UpdateBot;
   }
   if (
     containsBytes(
@@ -479,6 +531,12 @@ export type ParsedBinFarmInstruction<
   | ({
       instructionType: BinFarmInstruction.ApplyFee;
     } & ParsedApplyFeeInstruction<TProgram>)
+  | ({
+      instructionType: BinFarmInstruction.ApplyKeeperTipBps;
+    } & ParsedApplyKeeperTipBpsInstruction<TProgram>)
+  | ({
+      instructionType: BinFarmInstruction.ApplyPrioritySlots;
+    } & ParsedApplyPrioritySlotsInstruction<TProgram>)
   | ({
       instructionType: BinFarmInstruction.ApplyRevenueDest;
     } & ParsedApplyRevenueDestInstruction<TProgram>)
@@ -491,6 +549,12 @@ export type ParsedBinFarmInstruction<
   | ({
       instructionType: BinFarmInstruction.CancelPendingFee;
     } & ParsedCancelPendingFeeInstruction<TProgram>)
+  | ({
+      instructionType: BinFarmInstruction.CancelPendingKeeperTipBps;
+    } & ParsedCancelPendingKeeperTipBpsInstruction<TProgram>)
+  | ({
+      instructionType: BinFarmInstruction.CancelPendingPrioritySlots;
+    } & ParsedCancelPendingPrioritySlotsInstruction<TProgram>)
   | ({
       instructionType: BinFarmInstruction.CancelPendingRevenueDest;
     } & ParsedCancelPendingRevenueDestInstruction<TProgram>)
@@ -530,6 +594,12 @@ export type ParsedBinFarmInstruction<
   | ({
       instructionType: BinFarmInstruction.ProposeFee;
     } & ParsedProposeFeeInstruction<TProgram>)
+  | ({
+      instructionType: BinFarmInstruction.ProposeKeeperTipBps;
+    } & ParsedProposeKeeperTipBpsInstruction<TProgram>)
+  | ({
+      instructionType: BinFarmInstruction.ProposePrioritySlots;
+    } & ParsedProposePrioritySlotsInstruction<TProgram>)
   | ({
       instructionType: BinFarmInstruction.ProposeRevenueDest;
     } & ParsedProposeRevenueDestInstruction<TProgram>)
@@ -545,12 +615,6 @@ export type ParsedBinFarmInstruction<
   | ({
       instructionType: BinFarmInstruction.UpdateBot;
     } & ParsedUpdateBotInstruction<TProgram>)
-  | ({
-      instructionType: BinFarmInstruction.UpdateKeeperTipBps;
-    } & ParsedUpdateKeeperTipBpsInstruction<TProgram>)
-  | ({
-      instructionType: BinFarmInstruction.UpdatePrioritySlots;
-    } & ParsedUpdatePrioritySlotsInstruction<TProgram>)
   | ({
       instructionType: BinFarmInstruction.UserClose;
     } & ParsedUserCloseInstruction<TProgram>);
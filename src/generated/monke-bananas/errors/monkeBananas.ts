@@ -56,54 +56,186 @@ export const MONKE_BANANAS_ERROR__GOOSE_DAO_MEMBERSHIP_REQUIRED = 0x1782; // 601
 export const MONKE_BANANAS_ERROR__INVALID_GOOSE_COLLECTION = 0x1783; // 6019
 /** InvalidCoreAsset: Invalid Metaplex Core asset account */
 export const MONKE_BANANAS_ERROR__INVALID_CORE_ASSET = 0x1784; // 6020
+/** InvalidDistPool: dist_pool cannot be the null address or does not match state */
+export const MONKE_BANANAS_ERROR__INVALID_DIST_POOL = 0x1785; // 6021
+/** NoPendingDistPoolChange: No pending dist_pool change */
+export const MONKE_BANANAS_ERROR__NO_PENDING_DIST_POOL_CHANGE = 0x1786; // 6022
+/** DistPoolTimelockNotExpired: dist_pool timelock not expired (24 hours required) */
+export const MONKE_BANANAS_ERROR__DIST_POOL_TIMELOCK_NOT_EXPIRED = 0x1787; // 6023
+/** NothingToReconcile: Nothing to reconcile (program_vault has no untracked surplus) */
+export const MONKE_BANANAS_ERROR__NOTHING_TO_RECONCILE = 0x1788; // 6024
+/** MonkeStillEligible: NFT still belongs to an approved collection — nothing to purge */
+export const MONKE_BANANAS_ERROR__MONKE_STILL_ELIGIBLE = 0x1789; // 6025
+/** InvalidMinDeposit: min_deposit_lamports must be between 1 lamport and 10 SOL */
+export const MONKE_BANANAS_ERROR__INVALID_MIN_DEPOSIT = 0x178a; // 6026
+/** CollectionPaused: Feeding is paused for this collection */
+export const MONKE_BANANAS_ERROR__COLLECTION_PAUSED = 0x178b; // 6027
+/** InvalidFeedBatch: Feed batch: 1-8 NFTs, remaining_accounts must be NFTs × 4 with matching MonkeBurn PDAs and no duplicate mints */
+export const MONKE_BANANAS_ERROR__INVALID_FEED_BATCH = 0x178c; // 6028
+/** InvalidCompostBatch: Compost batch: 1-10 [monke_burn, nft_mint] pairs, valid PDAs, no duplicates */
+export const MONKE_BANANAS_ERROR__INVALID_COMPOST_BATCH = 0x178d; // 6029
+/** InvalidClaimAllBatch: Claim-all batch: 1-10 [monke_burn, nft_mint, user_nft_account] groups, valid PDAs, no duplicate mints */
+export const MONKE_BANANAS_ERROR__INVALID_CLAIM_ALL_BATCH = 0x178e; // 6030
+/** InvalidCompostBounty: compost_bounty_lamports exceeds MAX_COMPOST_BOUNTY_LAMPORTS (0.01 SOL) */
+export const MONKE_BANANAS_ERROR__INVALID_COMPOST_BOUNTY = 0x178f; // 6031
+/** InvalidBananasPerFeed: bananas_per_feed must be 1 to 10M whole tokens */
+export const MONKE_BANANAS_ERROR__INVALID_BANANAS_PER_FEED = 0x1790; // 6032
+/** CompostBountyExceedsHeadroom: Compost bounty exceeds program_vault surplus above sol_liability */
+export const MONKE_BANANAS_ERROR__COMPOST_BOUNTY_EXCEEDS_HEADROOM = 0x1791; // 6033
+/** InvalidRecipient: Claim recipient cannot be an executable account */
+export const MONKE_BANANAS_ERROR__INVALID_RECIPIENT = 0x1792; // 6034
+/** InvalidWeight: Weight multiplier must be between 1 and MAX_WEIGHT_MULTIPLIER */
+export const MONKE_BANANAS_ERROR__INVALID_WEIGHT = 0x1793; // 6035
+/** InvalidDecayParams: Decay must be at most MAX_DECAY_BPS_PER_EPOCH with an epoch of at least MIN_DECAY_EPOCH_SECONDS */
+export const MONKE_BANANAS_ERROR__INVALID_DECAY_PARAMS = 0x1794; // 6036
+/** InvalidDefeedWeight: Defeed weight must be between 1 and the monke's current weight */
+export const MONKE_BANANAS_ERROR__INVALID_DEFEED_WEIGHT = 0x1795; // 6037
+/** CollectionDisabled: Collection is registered but disabled for new feeds */
+export const MONKE_BANANAS_ERROR__COLLECTION_DISABLED = 0x1796; // 6038
+/** InvalidCollectionConfig: CollectionConfig account does not match the NFT's collection */
+export const MONKE_BANANAS_ERROR__INVALID_COLLECTION_CONFIG = 0x1797; // 6039
+/** InvalidUnverifiedWindow: allow_unverified_until must be 0 or in the future, at most MAX_UNVERIFIED_WINDOW_SECONDS ahead */
+export const MONKE_BANANAS_ERROR__INVALID_UNVERIFIED_WINDOW = 0x1798; // 6040
+/** LiabilityNotInitialized: sol_liability not initialized — admin must call init_sol_liability first */
+export const MONKE_BANANAS_ERROR__LIABILITY_NOT_INITIALIZED = 0x1799; // 6041
+/** LiabilityAlreadyInitialized: sol_liability already initialized */
+export const MONKE_BANANAS_ERROR__LIABILITY_ALREADY_INITIALIZED = 0x179a; // 6042
+/** DistPoolNotSigner: dist_pool is not a dist_pool PDA and must sign the deposit */
+export const MONKE_BANANAS_ERROR__DIST_POOL_NOT_SIGNER = 0x179b; // 6043
+/** InvalidMigrationAccount: Account is not a migratable monke_bananas account */
+export const MONKE_BANANAS_ERROR__INVALID_MIGRATION_ACCOUNT = 0x179c; // 6044
+/** RewardTokenNotConfigured: Reward token mint not configured — call set_reward_token_mint first */
+export const MONKE_BANANAS_ERROR__REWARD_TOKEN_NOT_CONFIGURED = 0x179d; // 6045
+/** RewardTokenLocked: Reward token mint can't change after tokens have been distributed */
+export const MONKE_BANANAS_ERROR__REWARD_TOKEN_LOCKED = 0x179e; // 6046
+/** UnclaimedRewardToken: Claim pending reward tokens before defeeding */
+export const MONKE_BANANAS_ERROR__UNCLAIMED_REWARD_TOKEN = 0x179f; // 6047
+/** DistributionAlreadyOpen: A snapshot distribution is already open */
+export const MONKE_BANANAS_ERROR__DISTRIBUTION_ALREADY_OPEN = 0x17a0; // 6048
+/** NoOpenDistribution: No snapshot distribution is open */
+export const MONKE_BANANAS_ERROR__NO_OPEN_DISTRIBUTION = 0x17a1; // 6049
+/** DistributionNotClosed: Distribution window is still open */
+export const MONKE_BANANAS_ERROR__DISTRIBUTION_NOT_CLOSED = 0x17a2; // 6050
+/** NoPendingWeight: Monke has no pending weight to activate */
+export const MONKE_BANANAS_ERROR__NO_PENDING_WEIGHT = 0x17a3; // 6051
+/** PendingWeightNotActivated: Activate pending weight from a closed distribution before feeding again */
+export const MONKE_BANANAS_ERROR__PENDING_WEIGHT_NOT_ACTIVATED = 0x17a4; // 6052
+/** ClaimExceedsOwed: Partial claim amount exceeds owed SOL */
+export const MONKE_BANANAS_ERROR__CLAIM_EXCEEDS_OWED = 0x17a5; // 6053
 
 export type MonkeBananasError =
+  | typeof MONKE_BANANAS_ERROR__CLAIM_EXCEEDS_OWED
+  | typeof MONKE_BANANAS_ERROR__COLLECTION_DISABLED
   | typeof MONKE_BANANAS_ERROR__COLLECTION_NOT_VERIFIED
+  | typeof MONKE_BANANAS_ERROR__COLLECTION_PAUSED
+  | typeof MONKE_BANANAS_ERROR__COMPOST_BOUNTY_EXCEEDS_HEADROOM
+  | typeof MONKE_BANANAS_ERROR__DIST_POOL_NOT_SIGNER
+  | typeof MONKE_BANANAS_ERROR__DIST_POOL_TIMELOCK_NOT_EXPIRED
+  | typeof MONKE_BANANAS_ERROR__DISTRIBUTION_ALREADY_OPEN
+  | typeof MONKE_BANANAS_ERROR__DISTRIBUTION_NOT_CLOSED
   | typeof MONKE_BANANAS_ERROR__GOOSE_DAO_MEMBERSHIP_REQUIRED
   | typeof MONKE_BANANAS_ERROR__INSUFFICIENT_VAULT_BALANCE
+  | typeof MONKE_BANANAS_ERROR__INVALID_BANANAS_PER_FEED
+  | typeof MONKE_BANANAS_ERROR__INVALID_CLAIM_ALL_BATCH
   | typeof MONKE_BANANAS_ERROR__INVALID_COLLECTION
+  | typeof MONKE_BANANAS_ERROR__INVALID_COLLECTION_CONFIG
+  | typeof MONKE_BANANAS_ERROR__INVALID_COMPOST_BATCH
+  | typeof MONKE_BANANAS_ERROR__INVALID_COMPOST_BOUNTY
   | typeof MONKE_BANANAS_ERROR__INVALID_CORE_ASSET
+  | typeof MONKE_BANANAS_ERROR__INVALID_DECAY_PARAMS
+  | typeof MONKE_BANANAS_ERROR__INVALID_DEFEED_WEIGHT
+  | typeof MONKE_BANANAS_ERROR__INVALID_DIST_POOL
+  | typeof MONKE_BANANAS_ERROR__INVALID_FEED_BATCH
   | typeof MONKE_BANANAS_ERROR__INVALID_GOOSE_COLLECTION
   | typeof MONKE_BANANAS_ERROR__INVALID_METADATA
+  | typeof MONKE_BANANAS_ERROR__INVALID_MIGRATION_ACCOUNT
+  | typeof MONKE_BANANAS_ERROR__INVALID_MIN_DEPOSIT
   | typeof MONKE_BANANAS_ERROR__INVALID_MINT
   | typeof MONKE_BANANAS_ERROR__INVALID_NFT_MINT
+  | typeof MONKE_BANANAS_ERROR__INVALID_RECIPIENT
   | typeof MONKE_BANANAS_ERROR__INVALID_TOKEN_ACCOUNT
+  | typeof MONKE_BANANAS_ERROR__INVALID_UNVERIFIED_WINDOW
+  | typeof MONKE_BANANAS_ERROR__INVALID_WEIGHT
+  | typeof MONKE_BANANAS_ERROR__LIABILITY_ALREADY_INITIALIZED
+  | typeof MONKE_BANANAS_ERROR__LIABILITY_NOT_INITIALIZED
+  | typeof MONKE_BANANAS_ERROR__MONKE_STILL_ELIGIBLE
   | typeof MONKE_BANANAS_ERROR__NFT_NOT_BURNED
   | typeof MONKE_BANANAS_ERROR__NO_MONKES
+  | typeof MONKE_BANANAS_ERROR__NO_OPEN_DISTRIBUTION
   | typeof MONKE_BANANAS_ERROR__NO_PENDING_AUTHORITY
+  | typeof MONKE_BANANAS_ERROR__NO_PENDING_DIST_POOL_CHANGE
+  | typeof MONKE_BANANAS_ERROR__NO_PENDING_WEIGHT
   | typeof MONKE_BANANAS_ERROR__NOTHING_TO_CLAIM
   | typeof MONKE_BANANAS_ERROR__NOTHING_TO_DEPOSIT
+  | typeof MONKE_BANANAS_ERROR__NOTHING_TO_RECONCILE
   | typeof MONKE_BANANAS_ERROR__NOT_NFT_HOLDER
   | typeof MONKE_BANANAS_ERROR__NOT_TOKEN_OWNER
   | typeof MONKE_BANANAS_ERROR__OVERFLOW
   | typeof MONKE_BANANAS_ERROR__PAUSED
   | typeof MONKE_BANANAS_ERROR__PEGGED_NOT_CONFIGURED
-  | typeof MONKE_BANANAS_ERROR__UNAUTHORIZED;
+  | typeof MONKE_BANANAS_ERROR__PENDING_WEIGHT_NOT_ACTIVATED
+  | typeof MONKE_BANANAS_ERROR__REWARD_TOKEN_LOCKED
+  | typeof MONKE_BANANAS_ERROR__REWARD_TOKEN_NOT_CONFIGURED
+  | typeof MONKE_BANANAS_ERROR__UNAUTHORIZED
+  | typeof MONKE_BANANAS_ERROR__UNCLAIMED_REWARD_TOKEN;
 
 let monkeBananasErrorMessages: Record<MonkeBananasError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   monkeBananasErrorMessages = {
+    [MONKE_BANANAS_ERROR__CLAIM_EXCEEDS_OWED]: `Partial claim amount exceeds owed SOL`,
+    [MONKE_BANANAS_ERROR__COLLECTION_DISABLED]: `Collection is registered but disabled for new feeds`,
     [MONKE_BANANAS_ERROR__COLLECTION_NOT_VERIFIED]: `NFT collection is not verified`,
+    [MONKE_BANANAS_ERROR__COLLECTION_PAUSED]: `Feeding is paused for this collection`,
+    [MONKE_BANANAS_ERROR__COMPOST_BOUNTY_EXCEEDS_HEADROOM]: `Compost bounty exceeds program_vault surplus above sol_liability`,
+    [MONKE_BANANAS_ERROR__DIST_POOL_NOT_SIGNER]: `dist_pool is not a dist_pool PDA and must sign the deposit`,
+    [MONKE_BANANAS_ERROR__DIST_POOL_TIMELOCK_NOT_EXPIRED]: `dist_pool timelock not expired (24 hours required)`,
+    [MONKE_BANANAS_ERROR__DISTRIBUTION_ALREADY_OPEN]: `A snapshot distribution is already open`,
+    [MONKE_BANANAS_ERROR__DISTRIBUTION_NOT_CLOSED]: `Distribution window is still open`,
     [MONKE_BANANAS_ERROR__GOOSE_DAO_MEMBERSHIP_REQUIRED]: `Wallet does not hold a GooseDAO Core NFT (required on first feed)`,
     [MONKE_BANANAS_ERROR__INSUFFICIENT_VAULT_BALANCE]: `Insufficient SOL in program vault — wait for next deposit_sol`,
+    [MONKE_BANANAS_ERROR__INVALID_BANANAS_PER_FEED]: `bananas_per_feed must be 1 to 10M whole tokens`,
+    [MONKE_BANANAS_ERROR__INVALID_CLAIM_ALL_BATCH]: `Claim-all batch: 1-10 [monke_burn, nft_mint, user_nft_account] groups, valid PDAs, no duplicate mints`,
     [MONKE_BANANAS_ERROR__INVALID_COLLECTION]: `NFT is not from the SMB Gen2 or Gen3 collection`,
+    [MONKE_BANANAS_ERROR__INVALID_COLLECTION_CONFIG]: `CollectionConfig account does not match the NFT's collection`,
+    [MONKE_BANANAS_ERROR__INVALID_COMPOST_BATCH]: `Compost batch: 1-10 [monke_burn, nft_mint] pairs, valid PDAs, no duplicates`,
+    [MONKE_BANANAS_ERROR__INVALID_COMPOST_BOUNTY]: `compost_bounty_lamports exceeds MAX_COMPOST_BOUNTY_LAMPORTS (0.01 SOL)`,
     [MONKE_BANANAS_ERROR__INVALID_CORE_ASSET]: `Invalid Metaplex Core asset account`,
+    [MONKE_BANANAS_ERROR__INVALID_DECAY_PARAMS]: `Decay must be at most MAX_DECAY_BPS_PER_EPOCH with an epoch of at least MIN_DECAY_EPOCH_SECONDS`,
+    [MONKE_BANANAS_ERROR__INVALID_DEFEED_WEIGHT]: `Defeed weight must be between 1 and the monke's current weight`,
+    [MONKE_BANANAS_ERROR__INVALID_DIST_POOL]: `dist_pool cannot be the null address or does not match state`,
+    [MONKE_BANANAS_ERROR__INVALID_FEED_BATCH]: `Feed batch: 1-8 NFTs, remaining_accounts must be NFTs × 4 with matching MonkeBurn PDAs and no duplicate mints`,
     [MONKE_BANANAS_ERROR__INVALID_GOOSE_COLLECTION]: `Pixel goose NFT is not from the gooseswtf collection`,
     [MONKE_BANANAS_ERROR__INVALID_METADATA]: `Invalid metadata account`,
+    [MONKE_BANANAS_ERROR__INVALID_MIGRATION_ACCOUNT]: `Account is not a migratable monke_bananas account`,
+    [MONKE_BANANAS_ERROR__INVALID_MIN_DEPOSIT]: `min_deposit_lamports must be between 1 lamport and 10 SOL`,
     [MONKE_BANANAS_ERROR__INVALID_MINT]: `Invalid token mint`,
     [MONKE_BANANAS_ERROR__INVALID_NFT_MINT]: `Invalid NFT mint`,
+    [MONKE_BANANAS_ERROR__INVALID_RECIPIENT]: `Claim recipient cannot be an executable account`,
     [MONKE_BANANAS_ERROR__INVALID_TOKEN_ACCOUNT]: `Invalid token account owner`,
+    [MONKE_BANANAS_ERROR__INVALID_UNVERIFIED_WINDOW]: `allow_unverified_until must be 0 or in the future, at most MAX_UNVERIFIED_WINDOW_SECONDS ahead`,
+    [MONKE_BANANAS_ERROR__INVALID_WEIGHT]: `Weight multiplier must be between 1 and MAX_WEIGHT_MULTIPLIER`,
+    [MONKE_BANANAS_ERROR__LIABILITY_ALREADY_INITIALIZED]: `sol_liability already initialized`,
+    [MONKE_BANANAS_ERROR__LIABILITY_NOT_INITIALIZED]: `sol_liability not initialized — admin must call init_sol_liability first`,
+    [MONKE_BANANAS_ERROR__MONKE_STILL_ELIGIBLE]: `NFT still belongs to an approved collection — nothing to purge`,
     [MONKE_BANANAS_ERROR__NFT_NOT_BURNED]: `NFT has not been burned (supply must be 0)`,
     [MONKE_BANANAS_ERROR__NO_MONKES]: `No monkes exist (total share weight is 0)`,
+    [MONKE_BANANAS_ERROR__NO_OPEN_DISTRIBUTION]: `No snapshot distribution is open`,
     [MONKE_BANANAS_ERROR__NO_PENDING_AUTHORITY]: `No pending authority`,
+    [MONKE_BANANAS_ERROR__NO_PENDING_DIST_POOL_CHANGE]: `No pending dist_pool change`,
+    [MONKE_BANANAS_ERROR__NO_PENDING_WEIGHT]: `Monke has no pending weight to activate`,
     [MONKE_BANANAS_ERROR__NOTHING_TO_CLAIM]: `Nothing to claim`,
     [MONKE_BANANAS_ERROR__NOTHING_TO_DEPOSIT]: `Nothing to deposit (dist_pool below minimum)`,
+    [MONKE_BANANAS_ERROR__NOTHING_TO_RECONCILE]: `Nothing to reconcile (program_vault has no untracked surplus)`,
     [MONKE_BANANAS_ERROR__NOT_NFT_HOLDER]: `Caller does not hold the NFT`,
     [MONKE_BANANAS_ERROR__NOT_TOKEN_OWNER]: `Not the token owner`,
     [MONKE_BANANAS_ERROR__OVERFLOW]: `Arithmetic overflow`,
     [MONKE_BANANAS_ERROR__PAUSED]: `Program is paused`,
     [MONKE_BANANAS_ERROR__PEGGED_NOT_CONFIGURED]: `$PEGGED mint not configured — call set_pegged_mint first`,
+    [MONKE_BANANAS_ERROR__PENDING_WEIGHT_NOT_ACTIVATED]: `Activate pending weight from a closed distribution before feeding again`,
+    [MONKE_BANANAS_ERROR__REWARD_TOKEN_LOCKED]: `Reward token mint can't change after tokens have been distributed`,
+    [MONKE_BANANAS_ERROR__REWARD_TOKEN_NOT_CONFIGURED]: `Reward token mint not configured — call set_reward_token_mint first`,
     [MONKE_BANANAS_ERROR__UNAUTHORIZED]: `Not authorized`,
+    [MONKE_BANANAS_ERROR__UNCLAIMED_REWARD_TOKEN]: `Claim pending reward tokens before defeeding`,
   };
 }
 
@@ -26,7 +26,7 @@ export type CompostEvent = {
   nftMint: Address;
   weightRemoved: bigint;
   /** SOL stays in program_vault as surplus, boosting future claims for all holders */
-  unclaimedSolAbsorbed: bigint;
+  unclaimedSolReleased: bigint;
   newTotalWeight: bigint;
   timestamp: bigint;
 };
@@ -35,7 +35,7 @@ export type CompostEventArgs = {
   nftMint: Address;
   weightRemoved: number | bigint;
   /** SOL stays in program_vault as surplus, boosting future claims for all holders */
-  unclaimedSolAbsorbed: number | bigint;
+  unclaimedSolReleased: number | bigint;
   newTotalWeight: number | bigint;
   timestamp: number | bigint;
 };
@@ -44,7 +44,7 @@ export function getCompostEventEncoder(): FixedSizeEncoder<CompostEventArgs> {
   return getStructEncoder([
     ['nftMint', getAddressEncoder()],
     ['weightRemoved', getU64Encoder()],
-    ['unclaimedSolAbsorbed', getU64Encoder()],
+    ['unclaimedSolReleased', getU64Encoder()],
     ['newTotalWeight', getU64Encoder()],
     ['timestamp', getI64Encoder()],
   ]);
@@ -54,7 +54,7 @@ export function getCompostEventDecoder(): FixedSizeDecoder<CompostEvent> {
   return getStructDecoder([
     ['nftMint', getAddressDecoder()],
     ['weightRemoved', getU64Decoder()],
-    ['unclaimedSolAbsorbed', getU64Decoder()],
+    ['unclaimedSolReleased', getU64Decoder()],
     ['newTotalWeight', getU64Decoder()],
     ['timestamp', getI64Decoder()],
   ]);